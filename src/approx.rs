@@ -0,0 +1,342 @@
+//! Approximate equality, for comparing geometry that has been through floating-point computation.
+//!
+//! Two values are approximately equal if they have the same structure
+//! and their numeric contents differ by no more than a tolerance.
+//! Note that this compares representations:
+//! e.g. two Lines along the same path but with different p0 are not approximately equal.
+//! (See `curves_coincident` for the geometric question.)
+
+use crate::pga::*;
+use crate::global::*;
+use crate::curve::*;
+use crate::surface::*;
+use crate::topo::*;
+
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    /// Returns whether self and other differ by no more than the given tolerance
+    fn approx_eq_tol(&self, other: &Rhs, tolerance: Float) -> bool;
+
+    /// Returns whether self and other are equal within the default tolerance
+    /// (the distance at which two vertices are considered coincident)
+    fn approx_eq(&self, other: &Rhs) -> bool {
+        self.approx_eq_tol(other, EPSILON_COINCIDENT_DISTANCE)
+    }
+}
+
+impl ApproxEq for Float {
+    fn approx_eq_tol(&self, other: &Float, tolerance: Float) -> bool {
+        (self - other).abs() <= tolerance
+    }
+}
+
+// PGA elements are compared by the euclidean distance between their coefficient vectors.
+// For two normalized points, this is the distance between them.
+fn coefficients_within(a: FullMultivector, b: FullMultivector, tolerance: Float) -> bool {
+    let (a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15) = (a - b).as_tuple();
+    let distance_sq = [a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15].iter().map(|x| x * x).sum::<Float>();
+    distance_sq <= tolerance * tolerance
+}
+
+macro_rules! impl_approx_eq_pga {
+    ($($t:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                fn approx_eq_tol(&self, other: &$t, tolerance: Float) -> bool {
+                    coefficients_within(self.full_multivector(), other.full_multivector(), tolerance)
+                }
+            }
+        )*
+    }
+}
+
+impl_approx_eq_pga!(Vector, Bivector, Trivector, Pseudoscalar, ScalarAndBivector, Motor);
+
+impl ApproxEq for FullMultivector {
+    fn approx_eq_tol(&self, other: &FullMultivector, tolerance: Float) -> bool {
+        coefficients_within(*self, *other, tolerance)
+    }
+}
+
+impl ApproxEq for Line {
+    fn approx_eq_tol(&self, other: &Line, tolerance: Float) -> bool {
+        self.p0.approx_eq_tol(&other.p0, tolerance) && self.d.approx_eq_tol(&other.d, tolerance)
+    }
+}
+
+impl ApproxEq for Circle {
+    fn approx_eq_tol(&self, other: &Circle, tolerance: Float) -> bool {
+        self.p0.approx_eq_tol(&other.p0, tolerance) && self.a.approx_eq_tol(&other.a, tolerance)
+    }
+}
+
+impl ApproxEq for Ellipse {
+    fn approx_eq_tol(&self, other: &Ellipse, tolerance: Float) -> bool {
+        // Compare the frames by where they put the ellipse, since a motor and its negation move points alike
+        let (center0, u0, v0) = self.center_and_axes();
+        let (center1, u1, v1) = other.center_and_axes();
+        center0.approx_eq_tol(&center1, tolerance)
+            && u0.approx_eq_tol(&u1, tolerance)
+            && v0.approx_eq_tol(&v1, tolerance)
+    }
+}
+
+impl ApproxEq for Hyperbola {
+    fn approx_eq_tol(&self, other: &Hyperbola, tolerance: Float) -> bool {
+        let (center0, u0, v0) = self.center_and_axes();
+        let (center1, u1, v1) = other.center_and_axes();
+        center0.approx_eq_tol(&center1, tolerance)
+            && u0.approx_eq_tol(&u1, tolerance)
+            && v0.approx_eq_tol(&v1, tolerance)
+    }
+}
+
+impl ApproxEq for Parabola {
+    fn approx_eq_tol(&self, other: &Parabola, tolerance: Float) -> bool {
+        let (vertex0, u0, v0) = self.vertex_and_axes();
+        let (vertex1, u1, v1) = other.vertex_and_axes();
+        vertex0.approx_eq_tol(&vertex1, tolerance)
+            && u0.approx_eq_tol(&u1, tolerance)
+            && v0.approx_eq_tol(&v1, tolerance)
+            && self.focal_length.approx_eq_tol(&other.focal_length, tolerance)
+    }
+}
+
+impl ApproxEq for Nurbs {
+    fn approx_eq_tol(&self, other: &Nurbs, tolerance: Float) -> bool {
+        self.degree == other.degree
+            && self.knots.len() == other.knots.len()
+            && self.points.len() == other.points.len()
+            && self.knots.iter().zip(other.knots.iter()).all(|(a, b)| a.approx_eq_tol(b, tolerance))
+            && self.points.iter().zip(other.points.iter()).all(|(a, b)| a.approx_eq_tol(b, tolerance))
+    }
+}
+
+impl ApproxEq for Bezier {
+    fn approx_eq_tol(&self, other: &Bezier, tolerance: Float) -> bool {
+        self.points.len() == other.points.len()
+            && self.points.iter().zip(other.points.iter()).all(|(a, b)| a.approx_eq_tol(b, tolerance))
+    }
+}
+
+impl ApproxEq for Helix {
+    fn approx_eq_tol(&self, other: &Helix, tolerance: Float) -> bool {
+        self.p0.approx_eq_tol(&other.p0, tolerance)
+            && self.s.approx_eq_tol(&other.s, tolerance)
+            && self.taper.approx_eq_tol(&other.taper, tolerance)
+    }
+}
+
+impl ApproxEq for Spiral {
+    fn approx_eq_tol(&self, other: &Spiral, tolerance: Float) -> bool {
+        self.center.approx_eq_tol(&other.center, tolerance)
+            && self.a.approx_eq_tol(&other.a, tolerance)
+            && self.u.approx_eq_tol(&other.u, tolerance)
+            && self.radius.approx_eq_tol(&other.radius, tolerance)
+            && self.growth.approx_eq_tol(&other.growth, tolerance)
+    }
+}
+
+impl ApproxEq for Offset {
+    fn approx_eq_tol(&self, other: &Offset, tolerance: Float) -> bool {
+        self.curve.approx_eq_tol(&other.curve, tolerance)
+            && self.distance.approx_eq_tol(&other.distance, tolerance)
+            && self.v.approx_eq_tol(&other.v, tolerance)
+    }
+}
+
+impl ApproxEq for Polyline {
+    fn approx_eq_tol(&self, other: &Polyline, tolerance: Float) -> bool {
+        self.closed() == other.closed()
+            && self.points().len() == other.points().len()
+            && self.points().iter().zip(other.points().iter()).all(|(a, b)| a.approx_eq_tol(b, tolerance))
+    }
+}
+
+impl ApproxEq for TrimmedCurve {
+    fn approx_eq_tol(&self, other: &TrimmedCurve, tolerance: Float) -> bool {
+        self.curve.approx_eq_tol(&other.curve, tolerance)
+            && self.t_start.approx_eq_tol(&other.t_start, tolerance)
+            && self.t_end.approx_eq_tol(&other.t_end, tolerance)
+    }
+}
+
+impl ApproxEq for Curve {
+    fn approx_eq_tol(&self, other: &Curve, tolerance: Float) -> bool {
+        match (self, other) {
+            (Curve::Line(x), Curve::Line(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Circle(x), Curve::Circle(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Ellipse(x), Curve::Ellipse(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Hyperbola(x), Curve::Hyperbola(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Parabola(x), Curve::Parabola(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Nurbs(x), Curve::Nurbs(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Bezier(x), Curve::Bezier(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Helix(x), Curve::Helix(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Spiral(x), Curve::Spiral(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Offset(x), Curve::Offset(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::Polyline(x), Curve::Polyline(y)) => x.approx_eq_tol(y, tolerance),
+            (Curve::TrimmedCurve(x), Curve::TrimmedCurve(y)) => x.approx_eq_tol(y, tolerance),
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for Plane {
+    fn approx_eq_tol(&self, other: &Plane, tolerance: Float) -> bool {
+        self.p0.approx_eq_tol(&other.p0, tolerance)
+            && self.du.approx_eq_tol(&other.du, tolerance)
+            && self.dv.approx_eq_tol(&other.dv, tolerance)
+    }
+}
+
+impl ApproxEq for Surface {
+    fn approx_eq_tol(&self, other: &Surface, tolerance: Float) -> bool {
+        match (self, other) {
+            (Surface::Plane(x), Surface::Plane(y)) => x.approx_eq_tol(y, tolerance),
+        }
+    }
+}
+
+// Edges and faces only refer to geometry by index, so on their own they can only be compared exactly.
+// To compare the geometry that they refer to, compare the Topo that contains them.
+
+impl ApproxEq for Edge {
+    fn approx_eq_tol(&self, other: &Edge, _tolerance: Float) -> bool {
+        self == other
+    }
+}
+
+impl ApproxEq for Face {
+    fn approx_eq_tol(&self, other: &Face, _tolerance: Float) -> bool {
+        self == other
+    }
+}
+
+// Pair up each element of a with an approximately equal element of b,
+// returning the index in b for each element of a,
+// or None if there is no one-to-one pairing.
+// Elements are paired greedily, so this relies on the tolerance
+// being smaller than the distance between distinct elements.
+fn match_elements<T, F>(a: &[T], b: &[T], eq: F) -> Option<Vec<usize>>
+where F: Fn(&T, &T) -> bool {
+    if a.len() != b.len() {
+        return None;
+    }
+    let mut used = vec![false; b.len()];
+    a.iter().map(|x| {
+        let j = b.iter().enumerate().position(|(j, y)| !used[j] && eq(x, y))?;
+        used[j] = true;
+        Some(j)
+    }).collect()
+}
+
+/// Two Topos are approximately equal if their geometry is approximately equal
+/// and their connectivity is the same, regardless of the order in which elements are stored.
+impl ApproxEq for Topo {
+    fn approx_eq_tol(&self, other: &Topo, tolerance: Float) -> bool {
+        let vertex_map = match match_elements(self.vertices(), other.vertices(), |a, b| a.approx_eq_tol(b, tolerance)) {
+            Some(m) => m,
+            None => return false,
+        };
+        let curve_map = match match_elements(self.curves(), other.curves(), |a, b| a.approx_eq_tol(b, tolerance)) {
+            Some(m) => m,
+            None => return false,
+        };
+        let surface_map = match match_elements(self.surfaces(), other.surfaces(), |a, b| a.approx_eq_tol(b, tolerance)) {
+            Some(m) => m,
+            None => return false,
+        };
+
+        // Rewrite self's connectivity in terms of other's indices, then compare exactly
+        let edges: Vec<Edge> = self.edges().iter().map(|edge| Edge {
+            curve: curve_map[edge.curve],
+            bounds: edge.bounds.as_ref().map(|EdgeEndpoints {start, end}| EdgeEndpoints {
+                start: vertex_map[*start],
+                end: vertex_map[*end],
+            }),
+        }).collect();
+        let edge_map = match match_elements(&edges, other.edges(), |a, b| a == b) {
+            Some(m) => m,
+            None => return false,
+        };
+
+        let faces: Vec<Face> = self.faces().iter().map(|face| Face {
+            surface: surface_map[face.surface],
+            bounds: face.bounds.iter().map(|l| Loop {
+                elements: l.elements.iter().map(|&DirectedEdge {edge, direction}| DirectedEdge {
+                    edge: edge_map[edge],
+                    direction,
+                }).collect(),
+            }).collect(),
+        }).collect();
+        let face_map = match match_elements(&faces, other.faces(), |a, b| a == b) {
+            Some(m) => m,
+            None => return false,
+        };
+
+        // Shells are compared as unordered sets of directed faces
+        let shell_faces = |shell: &Shell, face_map: Option<&[usize]>| {
+            let mut faces: Vec<(FaceIndex, Direction)> = shell.elements.iter().map(|DirectedFace {face, direction}| {
+                (face_map.map_or(*face, |m| m[*face]), *direction)
+            }).collect();
+            faces.sort_by_key(|&(face, direction)| (face, direction == Direction::Reverse));
+            faces
+        };
+        let solids: Vec<Vec<_>> = self.solids().iter().map(|solid| {
+            solid.bounds.iter().map(|shell| shell_faces(shell, Some(&face_map))).collect()
+        }).collect();
+        let other_solids: Vec<Vec<_>> = other.solids().iter().map(|solid| {
+            solid.bounds.iter().map(|shell| shell_faces(shell, None)).collect()
+        }).collect();
+        match_elements(&solids, &other_solids, |a: &Vec<_>, b: &Vec<_>| {
+            match_elements(a, b, |x, y| x == y).is_some()
+        }).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::construct::*;
+
+    fn square(points: &[[Float; 3]]) -> Topo {
+        Topo::line_segments_from_points(points.iter().map(|&[x, y, z]| point_from_xyz(x, y, z)).collect(), true).unwrap()
+    }
+
+    #[test]
+    fn tolerance() {
+        assert!(1.0.approx_eq_tol(&1.5, 0.5));
+        assert!(!1.0.approx_eq_tol(&1.5, 0.4));
+        let p = point_from_xyz(1., 2., 3.);
+        assert!(p.approx_eq(&point_from_xyz(1., 2., 3. + 0.5 * EPSILON_COINCIDENT_DISTANCE)));
+        assert!(!p.approx_eq(&point_from_xyz(1., 2., 3. + 2. * EPSILON_COINCIDENT_DISTANCE)));
+    }
+
+    #[test]
+    fn curves_compare_representations() {
+        let a = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.));
+        let b = Curve::line_from_two_points(point_from_xyz(1e-12, 0., 0.), point_from_xyz(1., 0., 0.));
+        let c = Curve::line_from_two_points(point_from_xyz(2., 0., 0.), point_from_xyz(3., 0., 0.));
+        assert!(a.approx_eq(&b));
+        // The same path with a different starting point is a different representation
+        assert!(!a.approx_eq(&c));
+        let circle = Curve::circle_from_three_points(point_from_xyz(1., 0., 0.), point_from_xyz(0., 1., 0.), point_from_xyz(-1., 0., 0.));
+        assert!(!a.approx_eq(&circle));
+    }
+
+    #[test]
+    fn topos_ignore_storage_order() {
+        let a = square(&[[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]]);
+        let b = square(&[[1., 0., 0.], [1., 1., 0.], [0., 1., 0.], [0., 0., 0.]]);
+        assert!(a.approx_eq(&b));
+        assert!(b.approx_eq(&a));
+
+        let nudged = square(&[[0., 0., 1e-12], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]]);
+        assert!(a.approx_eq(&nudged));
+        let moved = square(&[[0., 0., 1e-3], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]]);
+        assert!(!a.approx_eq(&moved));
+
+        // The same corners joined up in a different order
+        let crossed = square(&[[0., 0., 0.], [1., 1., 0.], [1., 0., 0.], [0., 1., 0.]]);
+        assert!(!a.approx_eq(&crossed));
+    }
+}
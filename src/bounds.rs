@@ -0,0 +1,293 @@
+//! Bounding boxes give a cheap, conservative picture of where a piece of geometry lies,
+//! for quickly ruling out interactions between distant objects
+//! (e.g. culling in booleans, framing a view, or spatial indexing.)
+
+use crate::pga::*;
+use crate::global::*;
+use crate::construct::*;
+use crate::vec3::{xyz, dot, cross, add, sub, scale, length, dehomogenize};
+
+/// An axis-aligned box, given by its minimum and maximum corners.
+/// The empty box contains nothing, and has each coordinate of min above the corresponding coordinate of max.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+    pub min: [Float; 3],
+    pub max: [Float; 3],
+}
+
+impl BoundingBox {
+    /// The box containing nothing, which is the identity for union
+    pub fn empty() -> BoundingBox {
+        BoundingBox {min: [Float::INFINITY; 3], max: [-Float::INFINITY; 3]}
+    }
+
+    /// The smallest box containing the given (euclidean) points
+    pub fn from_points(points: &[Trivector]) -> BoundingBox {
+        points.iter().fold(BoundingBox::empty(), |b, &p| b.include(p))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|i| self.min[i] > self.max[i])
+    }
+
+    /// The smallest box containing this box and the given (euclidean) point
+    pub fn include(&self, p: Trivector) -> BoundingBox {
+        assert!(p.is_finite(), "Cannot bound an ideal point");
+        let p = xyz(dehomogenize(p));
+        BoundingBox {
+            min: [0, 1, 2].map(|i| self.min[i].min(p[i])),
+            max: [0, 1, 2].map(|i| self.max[i].max(p[i])),
+        }
+    }
+
+    /// The smallest box containing both boxes
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: [0, 1, 2].map(|i| self.min[i].min(other.min[i])),
+            max: [0, 1, 2].map(|i| self.max[i].max(other.max[i])),
+        }
+    }
+
+    /// The box grown by the given distance on every side
+    pub fn grow(&self, distance: Float) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+        BoundingBox {
+            min: self.min.map(|x| x - distance),
+            max: self.max.map(|x| x + distance),
+        }
+    }
+
+    /// Whether the boxes overlap, or come within the given distance of each other
+    pub fn intersects(&self, other: &BoundingBox, tolerance: Float) -> bool {
+        !self.is_empty() && !other.is_empty()
+            && (0..3).all(|i| self.min[i] <= other.max[i] + tolerance && other.min[i] <= self.max[i] + tolerance)
+    }
+
+    /// Whether the (euclidean) point lies in the box, or within the given distance of it
+    pub fn contains(&self, p: Trivector, tolerance: Float) -> bool {
+        let p = xyz(dehomogenize(p));
+        (0..3).all(|i| p[i] >= self.min[i] - tolerance && p[i] <= self.max[i] + tolerance)
+    }
+
+    /// The center of the box. Panics if the box is empty.
+    pub fn center(&self) -> Trivector {
+        assert!(!self.is_empty(), "An empty box has no center");
+        let [x, y, z] = scale(add(self.min, self.max), 0.5);
+        point_from_xyz(x, y, z)
+    }
+
+    /// The extent of the box along each axis, which is zero for the empty box
+    pub fn size(&self) -> [Float; 3] {
+        if self.is_empty() {
+            return [0.; 3];
+        }
+        sub(self.max, self.min)
+    }
+
+    /// The length of the box's diagonal, which is zero for the empty box
+    pub fn diagonal(&self) -> Float {
+        length(self.size())
+    }
+
+    /// The eight corners of the box. Panics if the box is empty.
+    pub fn corners(&self) -> [Trivector; 8] {
+        assert!(!self.is_empty(), "An empty box has no corners");
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| {
+            let pick = |axis: usize| if i & (1 << axis) == 0 { self.min[axis] } else { self.max[axis] };
+            point_from_xyz(pick(0), pick(1), pick(2))
+        })
+    }
+}
+
+/// A box which may be rotated to fit its contents more closely than an axis-aligned one.
+/// It covers center + a * axes[0] + b * axes[1] + c * axes[2]
+/// for |a| <= half_extents[0], |b| <= half_extents[1], |c| <= half_extents[2].
+/// The axes are unit length and mutually orthogonal, and form a right-handed frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedBoundingBox {
+    pub center: [Float; 3],
+    pub axes: [[Float; 3]; 3],
+    pub half_extents: [Float; 3],
+}
+
+impl OrientedBoundingBox {
+    /// Fit a box to the given (euclidean) points, aligned with their principal axes,
+    /// or with the coordinate axes if that gives a smaller box.
+    /// Returns None if there are no points.
+    pub fn from_points(points: &[Trivector]) -> Option<OrientedBoundingBox> {
+        if points.is_empty() {
+            return None;
+        }
+        let points: Vec<[Float; 3]> = points.iter().map(|&p| {
+            assert!(p.is_finite(), "Cannot bound an ideal point");
+            xyz(dehomogenize(p))
+        }).collect();
+
+        // The eigenvectors of the points' covariance are the directions in which they spread the most and least
+        let n = points.len() as Float;
+        let mean = scale(points.iter().fold([0.; 3], |sum, &p| add(sum, p)), 1. / n);
+        let mut covariance = [[0.; 3]; 3];
+        for &p in points.iter() {
+            let d = sub(p, mean);
+            for i in 0..3 {
+                for j in 0..3 {
+                    covariance[i][j] += d[i] * d[j] / n;
+                }
+            }
+        }
+        let [x, y, _] = symmetric_eigenvectors(covariance);
+        let principal = OrientedBoundingBox::fit(&points, mean, [x, y, cross(x, y)]);
+
+        // The principal axes can be a poor fit for lopsided sets of points,
+        // so fall back to the coordinate axes if they do better
+        let aligned = OrientedBoundingBox::fit(&points, mean, [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+        Some(if aligned.volume() < principal.volume() { aligned } else { principal })
+    }
+
+    // Returns the smallest box with the given axes that contains the points
+    fn fit(points: &[[Float; 3]], origin: [Float; 3], axes: [[Float; 3]; 3]) -> OrientedBoundingBox {
+        let mut low = [Float::INFINITY; 3];
+        let mut high = [-Float::INFINITY; 3];
+        for &p in points.iter() {
+            for i in 0..3 {
+                let s = dot(sub(p, origin), axes[i]);
+                low[i] = low[i].min(s);
+                high[i] = high[i].max(s);
+            }
+        }
+        let center = (0..3).fold(origin, |c, i| add(c, scale(axes[i], 0.5 * (low[i] + high[i]))));
+        OrientedBoundingBox {center, axes, half_extents: [0, 1, 2].map(|i| 0.5 * (high[i] - low[i]))}
+    }
+
+    /// Whether the (euclidean) point lies in the box, or within the given distance of it
+    pub fn contains(&self, p: Trivector, tolerance: Float) -> bool {
+        let d = sub(xyz(dehomogenize(p)), self.center);
+        (0..3).all(|i| dot(d, self.axes[i]).abs() <= self.half_extents[i] + tolerance)
+    }
+
+    /// The eight corners of the box
+    pub fn corners(&self) -> [Trivector; 8] {
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| {
+            let [x, y, z] = (0..3).fold(self.center, |c, axis| {
+                let sign = if i & (1 << axis) == 0 { -1. } else { 1. };
+                add(c, scale(self.axes[axis], sign * self.half_extents[axis]))
+            });
+            point_from_xyz(x, y, z)
+        })
+    }
+
+    /// The smallest axis-aligned box containing this box
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.corners())
+    }
+
+    pub fn volume(&self) -> Float {
+        8. * self.half_extents[0] * self.half_extents[1] * self.half_extents[2]
+    }
+}
+
+// Returns the unit eigenvectors of a symmetric 3x3 matrix, in order of decreasing eigenvalue.
+// Uses the cyclic Jacobi method, which rotates away the off-diagonal entries one at a time.
+fn symmetric_eigenvectors(mut m: [[Float; 3]; 3]) -> [[Float; 3]; 3] {
+    // Columns of v accumulate the rotations, and converge to the eigenvectors
+    let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+    for _ in 0..64 {
+        let off_diagonal = m[0][1].powi(2) + m[0][2].powi(2) + m[1][2].powi(2);
+        let diagonal = m[0][0].powi(2) + m[1][1].powi(2) + m[2][2].powi(2);
+        if off_diagonal <= FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON * diagonal || off_diagonal == 0. {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if m[p][q] == 0. {
+                continue;
+            }
+            // Choose the rotation angle that zeroes m[p][q]
+            let theta = 0.5 * (m[q][q] - m[p][p]) / m[p][q];
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+            let c = 1. / (t * t + 1.).sqrt();
+            let s = t * c;
+            for row in m.iter_mut() {
+                let (a, b) = (row[p], row[q]);
+                row[p] = c * a - s * b;
+                row[q] = s * a + c * b;
+            }
+            let (row_p, row_q) = (m[p], m[q]);
+            m[p] = [0, 1, 2].map(|k| c * row_p[k] - s * row_q[k]);
+            m[q] = [0, 1, 2].map(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (a, b) = (row[p], row[q]);
+                row[p] = c * a - s * b;
+                row[q] = s * a + c * b;
+            }
+        }
+    }
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| m[j][j].partial_cmp(&m[i][i]).unwrap());
+    order.map(|i| [v[0][i], v[1][i], v[2][i]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::*;
+
+    #[test]
+    fn boxes_grow_and_combine() {
+        let empty = BoundingBox::empty();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), [0.; 3]);
+        assert_eq!(empty.grow(1.), empty);
+        assert!(!empty.contains(point_from_xyz(0., 0., 0.), 1.));
+
+        let b = BoundingBox::from_points(&[point_from_xyz(1., 2., 3.), 2. * point_from_xyz(-1., 0., 5.)]);
+        assert_eq!(b, BoundingBox {min: [-1., 0., 3.], max: [1., 2., 5.]});
+        assert_eq!(b.union(&empty), b);
+        assert!(b.center().approx_eq(&point_from_xyz(0., 1., 4.)));
+        assert_eq!(b.size(), [2., 2., 2.]);
+        assert!((b.diagonal() - 12_f64.sqrt()).abs() < 1e-12);
+        assert!(b.corners().iter().all(|&p| b.contains(p, 0.)));
+        assert!(b.contains(point_from_xyz(1.05, 0., 3.), 0.1) && !b.contains(point_from_xyz(1.05, 0., 3.), 0.));
+
+        let other = BoundingBox::from_points(&[point_from_xyz(1.5, 0., 3.), point_from_xyz(2., 1., 4.)]);
+        assert!(!b.intersects(&other, 0.) && b.intersects(&other, 0.5) && b.grow(0.5).intersects(&other, 0.));
+        assert!(!b.intersects(&empty, 10.));
+        assert_eq!(b.union(&other), BoundingBox {min: [-1., 0., 3.], max: [2., 2., 5.]});
+    }
+
+    #[test]
+    fn oriented_boxes_fit_rotated_points() {
+        assert!(OrientedBoundingBox::from_points(&[]).is_none());
+
+        // The corners of a 4 x 2 x 1 box, turned about an oblique axis and moved
+        let motor = motor_from_rotation((point_from_xyz(0., 0., 0.) & point_from_xyz(1., 2., 3.)).hat(), 0.7)
+            * motor_from_translation(3., -1., 2.);
+        let points: Vec<Trivector> = BoundingBox {min: [-2., -1., -0.5], max: [2., 1., 0.5]}.corners().iter()
+            .map(|p| p.transform(motor).hat()).collect();
+        let obb = OrientedBoundingBox::from_points(&points).unwrap();
+        let mut half_extents = obb.half_extents;
+        half_extents.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((0..3).all(|i| (half_extents[i] - [0.5, 1., 2.][i]).abs() < 1e-9));
+        assert!((obb.volume() - 8.).abs() < 1e-9);
+        assert!(points.iter().all(|&p| obb.contains(p, 1e-9)));
+        for i in 0..3 {
+            assert!((length(obb.axes[i]) - 1.).abs() < 1e-12);
+            assert!(dot(obb.axes[i], obb.axes[(i + 1) % 3]).abs() < 1e-12);
+        }
+        assert!(dot(cross(obb.axes[0], obb.axes[1]), obb.axes[2]) > 0.);
+
+        // The axis-aligned box around it holds its corners, and is bigger
+        let aabb = obb.bounding_box();
+        assert!(obb.corners().iter().all(|&p| aabb.contains(p, 1e-9)));
+        let [x, y, z] = aabb.size();
+        assert!(x * y * z > obb.volume());
+
+        // Points that are already axis-aligned keep an axis-aligned box
+        let points = BoundingBox {min: [0., 0., 0.], max: [3., 1., 1.]}.corners();
+        let obb = OrientedBoundingBox::from_points(&points).unwrap();
+        assert!((obb.volume() - 3.).abs() < 1e-9);
+    }
+}
@@ -10,6 +10,9 @@ use crate::pga::*;
 use crate::global::*;
 use crate::curve::*;
 use crate::surface::*;
+use crate::predicates::*;
+use crate::vertex::*;
+use crate::vec3::*;
 
 pub fn point_from_xyz(x: Float, y: Float, z: Float) -> Trivector {
     Trivector::new(x, y, z, 1.)
@@ -40,19 +43,40 @@ impl Curve {
         Curve::Line(Line {p0: p0, d: direction})
     }
 
+    /// Returns the circle through the three given points, starting at p0 and passing through p1 on its way to p2.
+    /// Panics if the points are coincident or collinear: see try_circle_from_three_points for a variant that doesn't.
     pub fn circle_from_three_points(p0: Trivector, p1: Trivector, p2: Trivector) -> Curve {
+        Curve::try_circle_from_three_points(p0, p1, p2).expect("no circle passes through the points")
+    }
+
+    /// Returns the circle through the three given points, starting at p0 and passing through p1 on its way to p2,
+    /// or an error if the points are ideal, coincident, or collinear.
+    pub fn try_circle_from_three_points(p0: Trivector, p1: Trivector, p2: Trivector) -> Result<Curve, CircleError> {
+        for &p in &[p0, p1, p2] {
+            euclidean(p)?;
+        }
+        if vertices_coincident(p0, p2) {
+            return Err(CircleError::CoincidentPoints);
+        }
+
         fn perpendicular_bisector(a: Trivector, b: Trivector) -> Vector {
             (a & b) | (a + b)
         }
 
         let bisector0 = perpendicular_bisector(p0, p1);
-        assert!(bisector0.is_finite(), "p0 and p1 are coincident");
+        if !bisector0.is_finite() {
+            return Err(CircleError::CoincidentPoints);
+        }
         let bisector1 = perpendicular_bisector(p1, p2);
-        assert!(bisector0.is_finite(), "p1 and p2 are coincident");
+        if !bisector1.is_finite() {
+            return Err(CircleError::CoincidentPoints);
+        }
+        // The bisectors of collinear points are parallel, so they meet only at infinity
         let axis = bisector1 ^ bisector0;
-        assert!(axis.is_finite(), "p0, p1, and p2 are too close together");
+        if !axis.is_finite() {
+            return Err(CircleError::Collinear);
+        }
         let axis = axis.hat();
-        assert!(bisector0.is_finite());
 
         //let plane0 = (p0 & axis).hat();
         //let plane1 = (p2 & axis).hat();
@@ -72,17 +96,581 @@ impl Curve {
         // Since the normalization process above always minimizes the angle of the arc,
         // we should flip the axis direction if the angle is > 180.
         let center = axis ^ circle_plane;
-        let sense = separating_plane & center > 0.;
+        let sense = plane_side(separating_plane, center) > 0.;
 
         let axis = match sense {
             true => axis.reverse(),
             false => axis,
         };
 
-        Curve::Circle(Circle {p0: p0, a: axis})
+        Ok(Curve::Circle(Circle {p0: p0, a: axis}))
+    }
+
+    /// Returns a circle around the given center, in the plane perpendicular to the axis direction (an ideal point.)
+    /// The circle turns right-handedly about the axis, starting from an arbitrary point.
+    pub fn circle_from_center_axis_radius(center: Trivector, axis: Trivector, radius: Float) -> Result<Curve, CircleError> {
+        let center = euclidean(center)?;
+        let axis = ideal_direction(axis)?;
+        if radius <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::ZeroRadius);
+        }
+        Ok(Curve::Circle(circle_about(center, axis, add(center, scale(any_perpendicular(axis), radius)))))
+    }
+
+    /// Returns an arc around the given center that starts at the given point
+    /// and turns by the given angle (in radians) right-handedly about the axis direction (an ideal point.)
+    /// A negative angle turns the other way. An angle of a full turn gives a whole circle.
+    pub fn arc_from_center_start_angle(center: Trivector, axis: Trivector, start: Trivector, angle: Float) -> Result<Curve, CircleError> {
+        let center = euclidean(center)?;
+        let axis = ideal_direction(axis)?;
+        let start = euclidean(start)?;
+        let radial = sub(start, center);
+        if dot(radial, axis).abs() > EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::NotInPlane);
+        }
+        if length(radial) <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::ZeroRadius);
+        }
+        let sweep = angle.abs();
+        if sweep <= FLOAT_DIVISION_EPSILON {
+            return Err(CircleError::ZeroAngle);
+        }
+        if sweep > 2. * PI + FLOAT_DIVISION_EPSILON {
+            return Err(CircleError::AngleTooLarge);
+        }
+
+        let axis = if angle < 0. { scale(axis, -1.) } else { axis };
+        let circle = Curve::Circle(circle_about(center, axis, start));
+        if sweep >= 2. * PI - FLOAT_DIVISION_EPSILON {
+            Ok(circle)
+        } else {
+            Ok(circle.trim(0., sweep))
+        }
+    }
+
+    /// Returns an arc that starts at a point on the given line, heading in the line's direction,
+    /// and curves around to end at the given end point.
+    pub fn arc_tangent_to_line(line: Bivector, start: Trivector, end: Trivector) -> Result<Curve, CircleError> {
+        let tangent = line_direction(line)?;
+        let start = euclidean(start)?;
+        let end = euclidean(end)?;
+        if length(sub(foot_on_line(line, start), start)) > EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::NotOnLine);
+        }
+        let chord = sub(end, start);
+        if length(chord) <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::CoincidentPoints);
+        }
+
+        // The center lies on the perpendicular to the line at the start, on the side towards the end
+        let toward_center = unit(sub(chord, scale(tangent, dot(chord, tangent)))).ok_or(CircleError::Collinear)?;
+        let offset = dot(chord, toward_center);
+        if offset <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::Collinear);
+        }
+        let radius = dot(chord, chord) / (2. * offset);
+        let center = add(start, scale(toward_center, radius));
+
+        let circle = circle_about(center, cross(tangent, toward_center), start);
+        let sweep = circle.t(point_from_xyz(end[0], end[1], end[2]));
+        Ok(Curve::Circle(circle).trim(0., sweep))
+    }
+
+    /// Returns an arc of the given radius from start to end,
+    /// turning right-handedly about the axis direction (an ideal point, perpendicular to the chord between the points.)
+    /// A positive radius gives the shorter of the two arcs, and a negative radius gives the longer one.
+    pub fn arc_from_two_points_and_radius(start: Trivector, end: Trivector, axis: Trivector, radius: Float) -> Result<Curve, CircleError> {
+        let start = euclidean(start)?;
+        let end = euclidean(end)?;
+        let axis = ideal_direction(axis)?;
+        let chord = sub(end, start);
+        let half_chord = 0.5 * length(chord);
+        if half_chord <= 0.5 * EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::CoincidentPoints);
+        }
+        if dot(chord, axis).abs() > EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::NotInPlane);
+        }
+        if radius.abs() <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::ZeroRadius);
+        }
+        if radius.abs() < half_chord - EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::RadiusTooSmall);
+        }
+
+        // Turning right-handedly, the center of the shorter arc is to the left of the chord
+        let left = unit(cross(axis, chord)).unwrap();
+        let height = (radius * radius - half_chord * half_chord).max(0.).sqrt();
+        let center = add(scale(add(start, end), 0.5), scale(left, height.copysign(radius)));
+
+        let circle = circle_about(center, axis, start);
+        let sweep = circle.t(point_from_xyz(end[0], end[1], end[2]));
+        Ok(Curve::Circle(circle).trim(0., sweep))
+    }
+
+    /// Returns an arc of the given radius that is tangent to two curves lying in the plane perpendicular to the axis direction
+    /// (an ideal point.)
+    /// Of the corners that the curves make, the arc rounds off the one that the point "near" lies in,
+    /// and of the possible arcs there, the one whose center is closest to near is returned.
+    /// The arc runs from where it touches c0 to where it touches c1, the short way around.
+    pub fn fillet(c0: &Curve, c1: &Curve, radius: Float, axis: Trivector, near: Trivector) -> Result<Curve, CircleError> {
+        let near_xyz = euclidean(near)?;
+        let axis_xyz = ideal_direction(axis)?;
+        if radius <= EPSILON_COINCIDENT_DISTANCE {
+            return Err(CircleError::ZeroRadius);
+        }
+        let v = inf_point_from_xyz(axis_xyz[0], axis_xyz[1], axis_xyz[2]);
+
+        // The center lies on each curve's offset towards the side that near is on
+        let offset_towards_near = |c: &Curve| -> Result<Curve, CircleError> {
+            let (t, distance, _) = c.closest_point(near).map_err(|_| CircleError::NoSolution)?;
+            if distance <= EPSILON_COINCIDENT_DISTANCE {
+                return Err(CircleError::NoSolution);
+            }
+            let normal = unit(cross(xyz(c.d1(t)), axis_xyz)).ok_or(CircleError::NotInPlane)?;
+            let offset_distance = radius.copysign(dot(sub(near_xyz, xyz(dehomogenize(c.d0(t)))), normal));
+            // Offsetting a circle inwards by its radius would collapse it onto its center
+            let base_circle = match c {
+                Curve::Circle(x) => Some(x),
+                Curve::TrimmedCurve(x) => match x.curve.as_ref() {
+                    Curve::Circle(x) => Some(x),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(circle) = base_circle {
+                let p = add(xyz(dehomogenize(circle.p0)), scale(unit(cross(xyz(circle.d1(0.)), axis_xyz)).unwrap(), offset_distance));
+                if (circle.a.hat() & point_from_xyz(p[0], p[1], p[2])).norm() <= EPSILON_COINCIDENT_DISTANCE {
+                    return Err(CircleError::NoSolution);
+                }
+            }
+            Ok(c.clone().offset(offset_distance, v))
+        };
+        let offset0 = offset_towards_near(c0)?;
+        let offset1 = offset_towards_near(c1)?;
+
+        // Offsets share their curve's parameterization, so the parameters of the center on each offset
+        // are also those of the points where the arc touches the curves
+        let (t0, t1, center) = intersect(&offset0, &offset1).into_iter().filter_map(|x| match x {
+            CurveIntersection::Point {t0, t1, point} => Some((t0, t1, xyz(dehomogenize(point)))),
+            CurveIntersection::Overlap {..} => None,
+        }).min_by(|a, b| length(sub(a.2, near_xyz)).partial_cmp(&length(sub(b.2, near_xyz))).unwrap()).ok_or(CircleError::NoSolution)?;
+
+        let start = xyz(dehomogenize(c0.d0(t0)));
+        let end = xyz(dehomogenize(c1.d0(t1)));
+        let turn = cross(sub(start, center), sub(end, center));
+        if length(sub(end, start)) <= EPSILON_COINCIDENT_DISTANCE || length(turn) <= FLOAT_DIVISION_EPSILON * radius * radius {
+            // The curves are tangent to each other where the fillet would be
+            return Err(CircleError::NoSolution);
+        }
+
+        let circle = circle_about(center, unit(turn).unwrap(), start);
+        let sweep = circle.t(point_from_xyz(end[0], end[1], end[2]));
+        Ok(Curve::Circle(circle).trim(0., sweep))
+    }
+
+    /// Returns every circle that is tangent to all three of the given coplanar lines:
+    /// up to four for a triangle (the incircle and three excircles), or two when a pair of the lines is parallel.
+    /// Each circle starts where it touches the first line and turns right-handedly about the same normal.
+    pub fn circles_tangent_to_three_lines(l0: Bivector, l1: Bivector, l2: Bivector) -> Result<Vec<Curve>, CircleError> {
+        let lines = [l0, l1, l2];
+        let directions = lines.iter().map(|&l| line_direction(l)).collect::<Result<Vec<[Float; 3]>, CircleError>>()?;
+        let points: Vec<[Float; 3]> = lines.iter().map(|&l| foot_on_line(l, [0., 0., 0.])).collect();
+
+        // Find the common plane from the first direction and whatever else isn't parallel to it
+        let normal = [cross(directions[0], directions[1]), cross(directions[0], directions[2]), cross(directions[0], sub(points[1], points[0])), cross(directions[0], sub(points[2], points[0]))]
+            .iter().find_map(|&n| if length(n) > FLOAT_DIVISION_EPSILON { unit(n) } else { None })
+            .ok_or(CircleError::NoSolution)?;
+        for i in 1..3 {
+            if dot(directions[i], normal).abs() > FLOAT_DIVISION_EPSILON || dot(sub(points[i], points[0]), normal).abs() > EPSILON_COINCIDENT_DISTANCE {
+                return Err(CircleError::NotInPlane);
+            }
+        }
+
+        // Work in coordinates (x, y) within the plane, where each line is m . (x, y) = c for a unit normal m.
+        // A circle of radius r centered at (x, y) is tangent to a line when m . (x, y) - c = +/- r
+        let e1 = directions[0];
+        let e2 = cross(normal, e1);
+        let to_plane = |p: [Float; 3]| [dot(sub(p, points[0]), e1), dot(sub(p, points[0]), e2)];
+        let lines_2d: Vec<([Float; 2], Float)> = (0..3).map(|i| {
+            let m = [-dot(directions[i], e2), dot(directions[i], e1)];
+            let q = to_plane(points[i]);
+            (m, m[0] * q[0] + m[1] * q[1])
+        }).collect();
+
+        let mut circles: Vec<([Float; 3], Float)> = Vec::new();
+        for signs in [[1., 1., 1.], [1., 1., -1.], [1., -1., 1.], [1., -1., -1.]] {
+            let row = |i: usize, column: Option<usize>| -> Vec<Float> {
+                let (m, c) = lines_2d[i];
+                let mut row = vec![m[0], m[1], -signs[i]];
+                if let Some(j) = column {
+                    row[j] = c;
+                }
+                row
+            };
+            let matrix = |column: Option<usize>| (0..3).map(|i| row(i, column)).collect::<Vec<_>>();
+            let det = determinant(matrix(None));
+            if det.abs() <= FLOAT_DIVISION_EPSILON {
+                continue;
+            }
+            // Solve by Cramer's rule
+            let [x, y, r] = [0, 1, 2].map(|j| determinant(matrix(Some(j))) / det);
+            let radius = r.abs();
+            if radius <= EPSILON_COINCIDENT_DISTANCE {
+                continue;
+            }
+            let center = add(points[0], add(scale(e1, x), scale(e2, y)));
+            if !circles.iter().any(|&(c, r)| length(sub(c, center)) <= EPSILON_COINCIDENT_DISTANCE && (r - radius).abs() <= EPSILON_COINCIDENT_DISTANCE) {
+                circles.push((center, radius));
+            }
+        }
+        if circles.is_empty() {
+            // The lines are all parallel, or all meet at one point
+            return Err(CircleError::NoSolution);
+        }
+
+        Ok(circles.into_iter().map(|(center, _)| Curve::Circle(circle_about(center, normal, foot_on_line(l0, center)))).collect())
+    }
+
+    /// Returns an ellipse from its center point and its semi-major and semi-minor axes,
+    /// given as ideal points (i.e. displacements from the center to the ellipse.)
+    /// The ellipse starts at the end of the major axis and travels towards the end of the minor axis.
+    pub fn ellipse_from_center_and_axes(center: Trivector, major: Trivector, minor: Trivector) -> Curve {
+        let frame = conic_frame(center, major, minor);
+        Curve::Ellipse(Ellipse {frame, a: length(xyz(major)), b: length(xyz(minor))})
+    }
+
+    /// Returns one branch of a hyperbola from its center point and its semi-transverse and semi-conjugate axes,
+    /// given as ideal points.
+    /// The branch that is returned is the one that the transverse axis points towards.
+    pub fn hyperbola_from_center_and_axes(center: Trivector, transverse: Trivector, conjugate: Trivector) -> Curve {
+        let frame = conic_frame(center, transverse, conjugate);
+        Curve::Hyperbola(Hyperbola {frame, a: length(xyz(transverse)), b: length(xyz(conjugate))})
+    }
+
+    /// Returns a parabola from its focus and its directrix line.
+    pub fn parabola_from_focus_and_directrix(focus: Trivector, directrix: Bivector) -> Curve {
+        Curve::conic_from_focus_and_directrix(focus, directrix, 1.)
+    }
+
+    /// Returns the conic section with the given focus, directrix line and eccentricity:
+    /// the set of points whose distance from the focus is eccentricity times their distance from the directrix.
+    /// This is an ellipse if the eccentricity is less than 1, a parabola if it is 1,
+    /// and a hyperbola (the branch around the focus) if it is greater than 1.
+    pub fn conic_from_focus_and_directrix(focus: Trivector, directrix: Bivector, eccentricity: Float) -> Curve {
+        assert!(eccentricity > FLOAT_DIVISION_EPSILON, "eccentricity must be positive");
+        assert!(directrix.is_finite(), "directrix must be a euclidean line");
+
+        // Find the foot of the perpendicular from the focus to the directrix
+        let directrix_direction = unit(xyz(directrix.direction())).unwrap();
+        let directrix_point = scale(cross(xyz(directrix.direction()), xyz(directrix.moment())), 1. / directrix.norm().powi(2));
+        let f = xyz(focus.hat());
+        let foot = add(directrix_point, scale(directrix_direction, dot(sub(f, directrix_point), directrix_direction)));
+        let d = length(sub(f, foot));
+        assert!(d > FLOAT_DIVISION_EPSILON, "focus lies on the directrix");
+
+        // The axis of symmetry runs from the directrix through the focus
+        let axis = scale(sub(f, foot), 1. / d);
+        let ideal = |v: [Float; 3]| inf_point_from_xyz(v[0], v[1], v[2]);
+        let point = |v: [Float; 3]| point_from_xyz(v[0], v[1], v[2]);
+        let e = eccentricity;
+        let semi_latus_rectum = e * d;
+
+        if (e - 1.).abs() < FLOAT_DIVISION_EPSILON {
+            Curve::Parabola(Parabola {
+                frame: conic_frame(point(add(foot, scale(axis, 0.5 * d))), ideal(axis), ideal(directrix_direction)),
+                focal_length: 0.5 * d,
+            })
+        } else if e < 1. {
+            // The center lies beyond the focus, away from the directrix
+            let a = semi_latus_rectum / (1. - e * e);
+            let b = a * (1. - e * e).sqrt();
+            Curve::Ellipse(Ellipse {
+                frame: conic_frame(point(add(f, scale(axis, a * e))), ideal(axis), ideal(directrix_direction)),
+                a,
+                b,
+            })
+        } else {
+            // The center lies on the far side of the directrix
+            let a = semi_latus_rectum / (e * e - 1.);
+            let b = a * (e * e - 1.).sqrt();
+            Curve::Hyperbola(Hyperbola {
+                frame: conic_frame(point(sub(f, scale(axis, a * e))), ideal(axis), ideal(directrix_direction)),
+                a,
+                b,
+            })
+        }
+    }
+
+    /// Returns the conic section through five coplanar points.
+    /// Depending on the points, this may be an ellipse, a parabola, or a hyperbola.
+    /// In the case of a hyperbola, all five points must lie on the same branch.
+    /// Panics if the conic is degenerate (e.g. a pair of lines.)
+    pub fn conic_from_five_points(p0: Trivector, p1: Trivector, p2: Trivector, p3: Trivector, p4: Trivector) -> Curve {
+        let pts: Vec<[Float; 3]> = [p0, p1, p2, p3, p4].iter().map(|p| {
+            assert!(p.is_finite(), "points must be finite");
+            xyz(p.hat())
+        }).collect();
+
+        // Set up a local 2D coordinate system in the plane of the points,
+        // centered and scaled to keep the fit well-conditioned
+        let centroid = scale(pts.iter().fold([0., 0., 0.], |acc, &p| add(acc, p)), 0.2);
+        let size = pts.iter().map(|&p| length(sub(p, centroid))).fold(0., Float::max);
+        assert!(size > FLOAT_DIVISION_EPSILON, "points are coincident");
+        let ex = pts.iter().filter_map(|&p| unit(sub(p, centroid))).next().unwrap();
+        let normal = pts.iter().filter_map(|&p| unit(cross(ex, sub(p, centroid)))).next().expect("points are collinear");
+        let ey = cross(normal, ex);
+        for &p in &pts {
+            assert!(dot(sub(p, centroid), normal).abs() < EPSILON_COINCIDENT_DISTANCE, "points are not coplanar");
+        }
+        let local: Vec<[Float; 2]> = pts.iter().map(|&p| {
+            let offset = scale(sub(p, centroid), 1. / size);
+            [dot(offset, ex), dot(offset, ey)]
+        }).collect();
+        let to_point = |[x, y]: [Float; 2]| {
+            let p = add(centroid, scale(add(scale(ex, x), scale(ey, y)), size));
+            point_from_xyz(p[0], p[1], p[2])
+        };
+        let to_ideal = |[x, y]: [Float; 2]| {
+            let v = scale(add(scale(ex, x), scale(ey, y)), size);
+            inf_point_from_xyz(v[0], v[1], v[2])
+        };
+
+        // Solve for the coefficients of A x^2 + B xy + C y^2 + D x + E y + F = 0.
+        // Each point gives one linear equation in the coefficients;
+        // the solution is the null vector of the 5x6 system, found by cofactors.
+        let rows: Vec<[Float; 6]> = local.iter().map(|&[x, y]| [x * x, x * y, y * y, x, y, 1.]).collect();
+        let coefficients: Vec<Float> = (0..6).map(|k| {
+            let minor: Vec<Vec<Float>> = rows.iter().map(|row| {
+                row.iter().enumerate().filter(|&(j, _)| j != k).map(|(_, &x)| x).collect()
+            }).collect();
+            let sign = if k % 2 == 0 { 1. } else { -1. };
+            sign * determinant(minor)
+        }).collect();
+        let max_coefficient = coefficients.iter().fold(0., |acc: Float, x| acc.max(x.abs()));
+        assert!(max_coefficient > FLOAT_DIVISION_EPSILON, "conic is degenerate");
+        let c: Vec<Float> = coefficients.iter().map(|x| x / max_coefficient).collect();
+        let (a, b, c, d, e, f) = (c[0], c[1], c[2], c[3], c[4], c[5]);
+
+        // Rotate the quadratic part onto its principal axes
+        let theta = 0.5 * b.atan2(a - c);
+        let (sin, cos) = theta.sin_cos();
+        let e1 = [cos, sin];
+        let e2 = [-sin, cos];
+        let lambda1 = a * cos * cos + b * cos * sin + c * sin * sin;
+        let lambda2 = a * sin * sin - b * sin * cos + c * cos * cos;
+        let discriminant = b * b - 4. * a * c;
+
+        if discriminant.abs() < FLOAT_DIVISION_EPSILON * (a * a + b * b + c * c) {
+            // Parabola: one principal axis has a zero eigenvalue, and that is the axis of symmetry
+            let (lambda, axis, across) = if lambda1.abs() > lambda2.abs() {
+                (lambda1, e2, e1)
+            } else {
+                (lambda2, e1, e2)
+            };
+            // In coordinates (X along the axis, Y across it) the equation is
+            // lambda Y^2 + d_axis X + d_across Y + f = 0, i.e. X - X0 = k (Y - Y0)^2
+            let d_axis = d * axis[0] + e * axis[1];
+            let d_across = d * across[0] + e * across[1];
+            assert!(d_axis.abs() > FLOAT_DIVISION_EPSILON, "conic is degenerate");
+            let y0 = -d_across / (2. * lambda);
+            let x0 = (d_across * d_across / (4. * lambda) - f) / d_axis;
+            let k = -lambda / d_axis;
+            let sign = if k > 0. { 1. } else { -1. };
+            let vertex = [x0 * axis[0] + y0 * across[0], x0 * axis[1] + y0 * across[1]];
+            Curve::Parabola(Parabola {
+                frame: conic_frame(to_point(vertex), to_ideal([sign * axis[0], sign * axis[1]]), to_ideal(across)),
+                focal_length: size / (4. * k.abs()),
+            })
+        } else {
+            // Translate to the center, where the equation becomes lambda1 X^2 + lambda2 Y^2 + f_center = 0
+            let det = 4. * a * c - b * b;
+            let center = [(b * e - 2. * c * d) / det, (b * d - 2. * a * e) / det];
+            let f_center = f + 0.5 * (d * center[0] + e * center[1]);
+            let along = |axis: [Float; 2], r: Float| to_ideal([axis[0] * r, axis[1] * r]);
+            let r1_sq = -f_center / lambda1;
+            let r2_sq = -f_center / lambda2;
+
+            if discriminant < 0. {
+                assert!(r1_sq > 0. && r2_sq > 0., "conic is degenerate");
+                let (r1, r2) = (r1_sq.sqrt(), r2_sq.sqrt());
+                let (u, v) = if r1 >= r2 {
+                    (along(e1, r1), along(e2, r2))
+                } else {
+                    (along(e2, r2), along(e1, r1))
+                };
+                // Keep the travel direction right-handed about the plane normal
+                let (u, v) = (u, if dot(cross(xyz(u), xyz(v)), normal) < 0. { -v } else { v });
+                Curve::Ellipse(Ellipse {frame: conic_frame(to_point(center), u, v), a: length(xyz(u)), b: length(xyz(v))})
+            } else {
+                assert!(r1_sq.abs() > FLOAT_DIVISION_EPSILON, "conic is degenerate (a pair of lines)");
+                let (transverse, conjugate, rt, rc) = if r1_sq > 0. {
+                    (e1, e2, r1_sq.sqrt(), (-r2_sq).sqrt())
+                } else {
+                    (e2, e1, r2_sq.sqrt(), (-r1_sq).sqrt())
+                };
+                // Pick the branch that the points lie on
+                let side = |p: [Float; 2]| (p[0] - center[0]) * transverse[0] + (p[1] - center[1]) * transverse[1];
+                let sign = if side(local[0]) > 0. { 1. } else { -1. };
+                assert!(local.iter().all(|&p| side(p) * sign > 0.), "points lie on both branches of a hyperbola");
+                let (u, v) = (along(transverse, sign * rt), along(conjugate, rc));
+                Curve::Hyperbola(Hyperbola {frame: conic_frame(to_point(center), u, v), a: length(xyz(u)), b: length(xyz(v))})
+            }
+        }
+    }
+
+    /// Returns a NURBS curve of the given degree from its knot vector and control points.
+    /// The control points are given in homogeneous form:
+    /// a control point at p with weight w is w * p, where p has unit weight.
+    /// The knot vector must be non-decreasing and have (number of control points + degree + 1) entries,
+    /// and all weights must be positive.
+    pub fn nurbs(degree: usize, knots: Vec<Float>, points: Vec<Trivector>) -> Curve {
+        assert!(degree >= 1, "degree must be at least 1");
+        assert!(points.len() > degree, "there must be more control points than the degree");
+        assert!(knots.len() == points.len() + degree + 1, "knot vector has the wrong length");
+        assert!(knots.windows(2).all(|pair| pair[0] <= pair[1]), "knot vector must be non-decreasing");
+        assert!(knots[degree] < knots[points.len()], "knot vector has an empty range");
+        assert!(points.iter().all(|p| p.as_tuple().3 > FLOAT_DIVISION_EPSILON), "weights must be positive");
+
+        Curve::Nurbs(Nurbs {degree, knots, points})
+    }
+
+    /// Returns a Bezier curve from its control points, of degree one less than the number of points.
+    /// As for NURBS curves, the control points are given in homogeneous form, and all weights must be positive.
+    pub fn bezier(points: Vec<Trivector>) -> Curve {
+        assert!(points.len() >= 2, "there must be at least 2 control points");
+        assert!(points.iter().all(|p| p.as_tuple().3 > FLOAT_DIVISION_EPSILON), "weights must be positive");
+
+        Curve::Bezier(Bezier {points})
+    }
+
+    /// Returns a chain of line segments through the given (euclidean) points.
+    /// If closed, a final segment joins the last point back to the first; the first point should not be repeated at the end.
+    /// Points that coincide with the one before them are dropped.
+    /// The curve is parameterized by distance along the chain.
+    pub fn polyline(points: Vec<Trivector>, closed: bool) -> Curve {
+        assert!(points.iter().all(|p| p.as_tuple().3.abs() > FLOAT_DIVISION_EPSILON), "points must be euclidean");
+        Curve::Polyline(Polyline::new(points.into_iter().map(dehomogenize).collect(), closed))
+    }
+
+    /// Returns a helix that starts at p0 and screws around the given axis line,
+    /// advancing by pitch along the axis's direction for every full turn.
+    /// A nonzero taper makes the helix move away from the axis (or towards it, if negative) by taper per radian,
+    /// so that it lies on a cone.
+    pub fn helix(p0: Trivector, axis: Bivector, pitch: Float, taper: Float) -> Curve {
+        assert!(axis.is_finite(), "axis must be a euclidean line");
+        let axis = axis.hat();
+        assert!((axis & p0.hat()).norm() > EPSILON_COINCIDENT_DISTANCE, "p0 lies on the axis");
+
+        // A screw is a rotation about the axis plus a translation along it
+        let (dx, dy, dz, _) = axis.direction().ihat().as_tuple();
+        let translation = Bivector::new(-dz, -dy, -dx, 0., 0., 0.);
+        let s = axis + (pitch / (2. * PI)) * translation;
+
+        Curve::Helix(Helix {p0: p0.hat(), s, taper})
+    }
+
+    /// Returns a planar spiral around the given center point,
+    /// rotating around the given axis line (which must pass through the center, normal to the spiral's plane.)
+    /// The spiral starts at center + radius * direction, where direction is an ideal point perpendicular to the axis,
+    /// and moves away from the center by growth for every radian it turns.
+    /// With a radius of zero, this is an Archimedean spiral starting from the center.
+    pub fn spiral(center: Trivector, axis: Bivector, direction: Trivector, radius: Float, growth: Float) -> Curve {
+        assert!(axis.is_finite(), "axis must be a euclidean line");
+        let axis = axis.hat();
+        assert!((axis & center.hat()).norm() < EPSILON_COINCIDENT_DISTANCE, "axis must pass through the center");
+        let u = direction.ihat();
+        let (ux, uy, uz, _) = u.as_tuple();
+        let (ax, ay, az, _) = axis.direction().ihat().as_tuple();
+        assert!((ux * ax + uy * ay + uz * az).abs() < FLOAT_DIVISION_EPSILON, "direction must be perpendicular to the axis");
+
+        Curve::Spiral(Spiral {center: center.hat(), a: axis, u, radius, growth})
     }
 }
 
+/// The reasons that a circle or arc may fail to be constructed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircleError {
+    IdealPoint, // A point at infinity was given where a finite point was expected
+    IdealLine, // A line at infinity was given where a euclidean line was expected
+    ZeroDirection, // A direction was given that is zero, or is not an ideal point
+    ZeroRadius, // The radius is zero, or negative where it must be positive
+    ZeroAngle, // The arc would turn through no angle
+    AngleTooLarge, // The arc would turn through more than a full circle
+    CoincidentPoints, // Points that must be distinct are coincident
+    NotInPlane, // The given points, lines or curves don't lie in the plane of the circle
+    NotOnLine, // The point where the arc should touch a line does not lie on it
+    Collinear, // The points lie along a line, so the circle through them would be infinitely large
+    RadiusTooSmall, // The radius is too small for the arc to reach between its endpoints
+    NoSolution, // No circle meets all of the given conditions
+}
+
+// Returns the coordinates of a point that must be euclidean
+fn euclidean(p: Trivector) -> Result<[Float; 3], CircleError> {
+    if !p.is_finite() {
+        return Err(CircleError::IdealPoint);
+    }
+    Ok(xyz(dehomogenize(p)))
+}
+
+// Returns the unit vector of a direction given as an ideal point
+fn ideal_direction(d: Trivector) -> Result<[Float; 3], CircleError> {
+    if d.is_finite() {
+        return Err(CircleError::ZeroDirection);
+    }
+    unit(xyz(d)).ok_or(CircleError::ZeroDirection)
+}
+
+// Returns the unit direction of a line that must be euclidean
+fn line_direction(l: Bivector) -> Result<[Float; 3], CircleError> {
+    if !l.is_finite() {
+        return Err(CircleError::IdealLine);
+    }
+    unit(xyz(l.direction())).ok_or(CircleError::IdealLine)
+}
+
+// Returns the circle starting at p0 that turns right-handedly about the unit axis direction, around the given center
+fn circle_about(center: [Float; 3], axis: [Float; 3], p0: [Float; 3]) -> Circle {
+    let a = (point_from_xyz(center[0], center[1], center[2]) & inf_point_from_xyz(axis[0], axis[1], axis[2])).hat();
+    Circle {p0: point_from_xyz(p0[0], p0[1], p0[2]), a}
+}
+
+// Checks that two ideal points are suitable axes for a conic (nonzero and perpendicular)
+// and returns the conic's frame: the motor that takes the origin to center, and the X and Y axes along u and v
+fn conic_frame(center: Trivector, u: Trivector, v: Trivector) -> Motor {
+    assert!(!u.is_finite() && !v.is_finite(), "axes must be ideal points");
+    assert!(u.is_infinite() && v.is_infinite(), "axes must be nonzero");
+    assert!(dot(xyz(u.ihat()), xyz(v.ihat())).abs() < FLOAT_DIVISION_EPSILON, "axes must be perpendicular");
+    let center = center.hat();
+    frame_from_three_points(center, center + u, center + v)
+}
+
+// Determinant of a square matrix by Gaussian elimination with partial pivoting
+fn determinant(mut m: Vec<Vec<Float>>) -> Float {
+    let n = m.len();
+    let mut det = 1.;
+    for i in 0..n {
+        let pivot = (i..n).max_by(|&a, &b| m[a][i].abs().partial_cmp(&m[b][i].abs()).unwrap()).unwrap();
+        if m[pivot][i] == 0. {
+            return 0.;
+        }
+        if pivot != i {
+            m.swap(pivot, i);
+            det = -det;
+        }
+        det *= m[i][i];
+        let (top, bottom) = m.split_at_mut(i + 1);
+        let pivot_row = &top[i];
+        for row in bottom.iter_mut() {
+            let factor = row[i] / pivot_row[i];
+            for (x, p) in row[i..].iter_mut().zip(pivot_row[i..].iter()) {
+                *x -= factor * p;
+            }
+        }
+    }
+    det
+}
+
 impl Surface {
     pub fn plane(p: Vector) -> Surface {
         let projected_origin = point_from_xyz(0., 0., 0.).project(p);
@@ -130,3 +718,399 @@ impl Surface {
         Surface::Plane(Plane {p0, du, dv})
     }
 }
+
+/// Returns a motor that rotates by the given angle (in radians) around the given line.
+/// The rotation follows the right-hand rule with respect to the direction of the line.
+pub fn motor_from_rotation(axis: Bivector, angle: Float) -> Motor {
+    assert!(axis.is_finite(), "axis must be a euclidean line");
+    (0.5 * angle * axis.hat()).exp()
+}
+
+/// Returns a motor that translates by the given displacement.
+pub fn motor_from_translation(x: Float, y: Float, z: Float) -> Motor {
+    Motor::new(1., -0.5 * z, -0.5 * y, -0.5 * x, 0., 0., 0., 0.)
+}
+
+/// Returns a motor from a unit quaternion w + xi + yj + zk.
+/// The quaternion is normalized before conversion.
+pub fn motor_from_quaternion(w: Float, x: Float, y: Float, z: Float) -> Motor {
+    let motor = Motor::new(w, 0., 0., 0., x, y, z, 0.);
+    assert!(motor.is_finite(), "quaternion is zero");
+    motor.normalize()
+}
+
+/// Returns a motor from Euler angles (in radians) about the coordinate axes through the origin.
+/// The rotations are applied in the order roll (about X), pitch (about Y), yaw (about Z),
+/// each about the fixed world axes.
+pub fn motor_from_euler_angles(roll: Float, pitch: Float, yaw: Float) -> Motor {
+    let o = point_from_xyz(0., 0., 0.);
+    let rx = motor_from_rotation(o & point_from_xyz(1., 0., 0.), roll);
+    let ry = motor_from_rotation(o & point_from_xyz(0., 1., 0.), pitch);
+    let rz = motor_from_rotation(o & point_from_xyz(0., 0., 1.), yaw);
+    rz * ry * rx
+}
+
+/// Returns the motor that takes the world frame onto the frame defined by three points:
+/// the world origin maps to `origin`, the +X axis maps to the ray from `origin` through `x_point`,
+/// and the XY plane maps to the plane containing all three points (with `xy_point` on the +Y side.)
+pub fn frame_from_three_points(origin: Trivector, x_point: Trivector, xy_point: Trivector) -> Motor {
+    assert!(origin.is_finite() && x_point.is_finite() && xy_point.is_finite(), "point is ideal");
+
+    let o = xyz(dehomogenize(origin));
+    let ex = unit(sub(xyz(dehomogenize(x_point)), o)).expect("origin and x_point are coincident");
+    let v = sub(xyz(dehomogenize(xy_point)), o);
+    let ey = unit(sub(v, scale(ex, dot(v, ex)))).expect("origin, x_point and xy_point are collinear");
+    let ez = cross(ex, ey);
+
+    motor_from_matrix([
+        [ex[0], ey[0], ez[0], o[0]],
+        [ex[1], ey[1], ez[1], o[1]],
+        [ex[2], ey[2], ez[2], o[2]],
+        [0., 0., 0., 1.],
+    ])
+}
+
+/// Returns the motor that takes frame `a` onto frame `b`,
+/// where each frame is given as the motor that takes the world frame onto it
+/// (see `frame_from_three_points`.)
+pub fn motor_between_frames(a: Motor, b: Motor) -> Motor {
+    b * a.reverse()
+}
+
+/// Returns the motor corresponding to a 4x4 homogeneous transformation matrix.
+/// The matrix is indexed as m[row][column] and acts on column vectors [x, y, z, 1],
+/// so the translation lives in the last column.
+/// The upper-left 3x3 block must be a rotation matrix.
+pub fn motor_from_matrix(m: [[Float; 4]; 4]) -> Motor {
+    assert!(m[3][0].abs() < FLOAT_DIVISION_EPSILON
+        && m[3][1].abs() < FLOAT_DIVISION_EPSILON
+        && m[3][2].abs() < FLOAT_DIVISION_EPSILON
+        && (m[3][3] - 1.).abs() < FLOAT_DIVISION_EPSILON, "matrix is not an affine transformation");
+
+    // Convert the rotation block to a quaternion,
+    // choosing the largest diagonal term to divide by for numerical stability
+    let trace = m[0][0] + m[1][1] + m[2][2];
+    let (w, x, y, z) = if trace > 0. {
+        let s = 2. * (trace + 1.).sqrt();
+        (0.25 * s, (m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s)
+    } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+        let s = 2. * (1. + m[0][0] - m[1][1] - m[2][2]).sqrt();
+        ((m[2][1] - m[1][2]) / s, 0.25 * s, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s)
+    } else if m[1][1] > m[2][2] {
+        let s = 2. * (1. + m[1][1] - m[0][0] - m[2][2]).sqrt();
+        ((m[0][2] - m[2][0]) / s, (m[0][1] + m[1][0]) / s, 0.25 * s, (m[1][2] + m[2][1]) / s)
+    } else {
+        let s = 2. * (1. + m[2][2] - m[0][0] - m[1][1]).sqrt();
+        ((m[1][0] - m[0][1]) / s, (m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, 0.25 * s)
+    };
+
+    // Rotate first, then translate
+    motor_from_translation(m[0][3], m[1][3], m[2][3]) * motor_from_quaternion(w, x, y, z)
+}
+
+/// Returns the 4x4 homogeneous transformation matrix corresponding to a motor.
+/// See `motor_from_matrix` for the matrix layout.
+pub fn motor_to_matrix(motor: Motor) -> [[Float; 4]; 4] {
+    // The columns of the matrix are the images of the three ideal points along the axes,
+    // and the image of the origin
+    let (x0, x1, x2, _) = inf_point_from_xyz(1., 0., 0.).transform(motor).as_tuple();
+    let (y0, y1, y2, _) = inf_point_from_xyz(0., 1., 0.).transform(motor).as_tuple();
+    let (z0, z1, z2, _) = inf_point_from_xyz(0., 0., 1.).transform(motor).as_tuple();
+    let (o0, o1, o2, w) = point_from_xyz(0., 0., 0.).transform(motor).as_tuple();
+
+    [
+        [x0, y0, z0, o0 / w],
+        [x1, y1, z1, o1 / w],
+        [x2, y2, z2, o2 / w],
+        [0., 0., 0., 1.],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::*;
+
+    fn matrix_approx_eq(a: [[Float; 4]; 4], b: [[Float; 4]; 4]) -> bool {
+        (0..4).all(|i| (0..4).all(|j| (a[i][j] - b[i][j]).abs() < 1e-9))
+    }
+
+    #[test]
+    fn rotation_follows_right_hand_rule() {
+        // A quarter turn about +Z takes +X to +Y
+        let z_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.);
+        let m = motor_from_rotation(z_axis, 0.5 * PI);
+        assert!(point_from_xyz(1., 0., 0.).transform(m).hat().approx_eq(&point_from_xyz(0., 1., 0.)));
+    }
+
+    #[test]
+    fn translation_moves_points() {
+        let m = motor_from_translation(1., -2., 3.);
+        assert!(point_from_xyz(4., 5., 6.).transform(m).hat().approx_eq(&point_from_xyz(5., 3., 9.)));
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let motors = [
+            Motor::new(1., 0., 0., 0., 0., 0., 0., 0.),
+            motor_from_translation(1., -2., 3.),
+            motor_from_euler_angles(0.3, -1.1, 2.5),
+            // Rotations near a half turn exercise each branch of the quaternion extraction
+            motor_from_quaternion(0.01, 1., 0.2, 0.1),
+            motor_from_quaternion(0.01, 0.2, 1., 0.1),
+            motor_from_quaternion(0.01, 0.1, 0.2, 1.),
+            motor_from_translation(-4., 0.5, 2.) * motor_from_rotation(point_from_xyz(1., 2., 3.) & point_from_xyz(2., 2., 4.), 2.),
+        ];
+        for &m in motors.iter() {
+            let matrix = motor_to_matrix(m);
+            let back = motor_from_matrix(matrix);
+            // A motor and its negation are the same motion, so compare the matrices
+            assert!(matrix_approx_eq(motor_to_matrix(back), matrix));
+            assert!(back.approx_eq(&m) || back.approx_eq(&-m));
+        }
+    }
+
+    #[test]
+    fn quaternion_matches_matrix() {
+        // The quaternion (cos 45, 0, 0, sin 45) is a quarter turn about Z
+        let h = 0.5_f64.sqrt();
+        let matrix = motor_to_matrix(motor_from_quaternion(h, 0., 0., h));
+        assert!(matrix_approx_eq(matrix, [
+            [0., -1., 0., 0.],
+            [1., 0., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ]));
+    }
+
+    #[test]
+    fn frames() {
+        let a = frame_from_three_points(point_from_xyz(1., 1., 1.), point_from_xyz(1., 3., 1.), point_from_xyz(0., 2., 1.));
+        // The world origin and axes land on the frame's origin and axes
+        assert!(point_from_xyz(0., 0., 0.).transform(a).hat().approx_eq(&point_from_xyz(1., 1., 1.)));
+        assert!(point_from_xyz(1., 0., 0.).transform(a).hat().approx_eq(&point_from_xyz(1., 2., 1.)));
+        assert!(point_from_xyz(0., 1., 0.).transform(a).hat().approx_eq(&point_from_xyz(0., 1., 1.)));
+
+        let b = frame_from_three_points(point_from_xyz(-2., 0., 5.), point_from_xyz(-2., 0., 4.), point_from_xyz(0., 0., 5.));
+        let ab = motor_between_frames(a, b);
+        let p = point_from_xyz(0.5, 0.25, -3.);
+        assert!(p.transform(a).transform(ab).hat().approx_eq(&p.transform(b).hat()));
+    }
+
+    // Whether p lies on the curve, according to the curve's own inversion
+    fn on_curve(c: &Curve, p: Trivector) -> bool {
+        c.d0(c.t(p)).hat().approx_eq_tol(&p.hat(), 1e-6)
+    }
+
+    #[test]
+    fn conics_from_center_and_axes() {
+        let center = point_from_xyz(1., 2., 0.);
+        let e = Curve::ellipse_from_center_and_axes(center, inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 2., 0.));
+        assert!(matches!(e, Curve::Ellipse(_)) && e.closed());
+        assert!(e.d0(0.).hat().approx_eq(&point_from_xyz(4., 2., 0.)));
+        assert!(e.d0(0.5 * PI).hat().approx_eq(&point_from_xyz(1., 4., 0.)));
+
+        let h = Curve::hyperbola_from_center_and_axes(center, inf_point_from_xyz(-3., 0., 0.), inf_point_from_xyz(0., 2., 0.));
+        assert!(matches!(h, Curve::Hyperbola(_)) && !h.closed());
+        // The branch is the one the transverse axis points towards
+        assert!(h.d0(0.).hat().approx_eq(&point_from_xyz(-2., 2., 0.)));
+        for &t in &[-1., 0.5, 2.] {
+            let (x, y, _, _) = h.d0(t).hat().as_tuple();
+            assert!((((x - 1.) / 3.).powi(2) - ((y - 2.) / 2.).powi(2) - 1.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn conics_from_focus_and_directrix() {
+        let focus = point_from_xyz(0., 0., 1.);
+        let directrix = point_from_xyz(-3., 0., 1.) & point_from_xyz(-3., 1., 1.);
+        for &(eccentricity, closed) in &[(0.5, true), (1., false), (2., false)] {
+            let c = Curve::conic_from_focus_and_directrix(focus, directrix, eccentricity);
+            assert_eq!(c.closed(), closed);
+            for &t in &[-0.9, 0., 0.4, 1.3] {
+                let p = c.d0(t).hat();
+                let to_focus = length(sub(xyz(p), xyz(focus)));
+                let to_directrix = (directrix.hat() & p).norm();
+                assert!((to_focus - eccentricity * to_directrix).abs() < 1e-9);
+            }
+        }
+        assert!(matches!(Curve::parabola_from_focus_and_directrix(focus, directrix), Curve::Parabola(_)));
+    }
+
+    #[test]
+    fn conics_from_five_points() {
+        let center = point_from_xyz(1., 2., 3.);
+        let curves = [
+            Curve::ellipse_from_center_and_axes(center, inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 2., 0.)),
+            Curve::hyperbola_from_center_and_axes(center, inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 2., 0.)),
+            Curve::parabola_from_focus_and_directrix(center, point_from_xyz(0., 0., 3.) & point_from_xyz(0., 1., 3.)),
+        ];
+        for original in curves.iter() {
+            let p = [-1.2, -0.5, 0.1, 0.8, 1.5].map(|t| original.d0(t).hat());
+            let c = Curve::conic_from_five_points(p[0], p[1], p[2], p[3], p[4]);
+            assert_eq!(std::mem::discriminant(&c), std::mem::discriminant(original));
+            for &t in &[-2., 0.3, 1.1, 2.5] {
+                assert!(on_curve(&c, original.d0(t)));
+            }
+        }
+    }
+
+    // Whether the whole of a circle or arc lies on the circle with the given center and radius,
+    // turning right-handedly about the given axis direction
+    fn on_circle(c: &Curve, center: [Float; 3], axis: [Float; 3], radius: Float) -> bool {
+        let (start_t, end_t) = (c.t_min().unwrap(), c.t_max().unwrap());
+        (0..=16).all(|i| {
+            let t = start_t + (end_t - start_t) * i as Float / 16.;
+            let radial = sub(xyz(c.d0(t).hat()), center);
+            (length(radial) - radius).abs() < 1e-9 && dot(radial, axis).abs() < 1e-9
+                && dot(cross(radial, xyz(c.d1(t))), axis) > 0.
+        })
+    }
+
+    fn ends(c: &Curve) -> (Trivector, Trivector) {
+        (c.d0(c.t_min().unwrap()).hat(), c.d0(c.t_max().unwrap()).hat())
+    }
+
+    #[test]
+    fn circles_and_arcs_from_center() {
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), inf_point_from_xyz(1., 1., 1.), 2.).unwrap();
+        assert!(on_circle(&c, [1., 2., 3.], unit([1., 1., 1.]).unwrap(), 2.));
+        assert_eq!(Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), up, 0.).unwrap_err(), CircleError::ZeroRadius);
+        assert_eq!(Curve::circle_from_center_axis_radius(inf_point_from_xyz(1., 2., 3.), up, 1.).unwrap_err(), CircleError::IdealPoint);
+        assert_eq!(Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), point_from_xyz(0., 0., 1.), 1.).unwrap_err(), CircleError::ZeroDirection);
+
+        let origin = point_from_xyz(0., 0., 0.);
+        let start = point_from_xyz(2., 0., 0.);
+        let arc = Curve::arc_from_center_start_angle(origin, up, start, 0.5 * PI).unwrap();
+        assert!(on_circle(&arc, [0., 0., 0.], [0., 0., 1.], 2.));
+        let (a, b) = ends(&arc);
+        assert!(a.approx_eq(&start) && b.approx_eq(&point_from_xyz(0., 2., 0.)));
+        // A negative angle turns the other way
+        let arc = Curve::arc_from_center_start_angle(origin, up, start, -0.5 * PI).unwrap();
+        assert!(on_circle(&arc, [0., 0., 0.], [0., 0., -1.], 2.));
+        assert!(ends(&arc).1.approx_eq(&point_from_xyz(0., -2., 0.)));
+        assert!(matches!(Curve::arc_from_center_start_angle(origin, up, start, 2. * PI).unwrap(), Curve::Circle(_)));
+
+        assert_eq!(Curve::arc_from_center_start_angle(origin, up, point_from_xyz(2., 0., 1.), 1.).unwrap_err(), CircleError::NotInPlane);
+        assert_eq!(Curve::arc_from_center_start_angle(origin, up, origin, 1.).unwrap_err(), CircleError::ZeroRadius);
+        assert_eq!(Curve::arc_from_center_start_angle(origin, up, start, 0.).unwrap_err(), CircleError::ZeroAngle);
+        assert_eq!(Curve::arc_from_center_start_angle(origin, up, start, 7.).unwrap_err(), CircleError::AngleTooLarge);
+    }
+
+    #[test]
+    fn arcs_from_points() {
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let x_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(1., 0., 0.);
+
+        // Leaving the origin along X and curving up to (2, 2) is a quarter of a circle around (0, 2)
+        let arc = Curve::arc_tangent_to_line(x_axis, point_from_xyz(0., 0., 0.), point_from_xyz(2., 2., 0.)).unwrap();
+        assert!(on_circle(&arc, [0., 2., 0.], [0., 0., 1.], 2.));
+        assert!(arc.d1(arc.t_min().unwrap()).ihat().approx_eq(&inf_point_from_xyz(1., 0., 0.)));
+        assert!(ends(&arc).1.approx_eq(&point_from_xyz(2., 2., 0.)));
+        let tangent = |start: Trivector, end: Trivector| Curve::arc_tangent_to_line(x_axis, start, end).unwrap_err();
+        assert_eq!(tangent(point_from_xyz(0., 1., 0.), point_from_xyz(2., 2., 0.)), CircleError::NotOnLine);
+        assert_eq!(tangent(point_from_xyz(1., 0., 0.), point_from_xyz(1., 0., 0.)), CircleError::CoincidentPoints);
+        assert_eq!(tangent(point_from_xyz(1., 0., 0.), point_from_xyz(3., 0., 0.)), CircleError::Collinear);
+        assert_eq!(Curve::arc_tangent_to_line(x_axis * I, point_from_xyz(0., 0., 0.), point_from_xyz(2., 2., 0.)).unwrap_err(), CircleError::IdealLine);
+        assert_eq!(Curve::arc_tangent_to_line(x_axis * 1e-14, point_from_xyz(0., 0., 0.), point_from_xyz(2., 2., 0.)).unwrap_err(), CircleError::IdealLine);
+
+        // Three points on the unit circle, and the ways they can fail to define one
+        let circle = Curve::try_circle_from_three_points(point_from_xyz(1., 0., 0.), point_from_xyz(0., 1., 0.), point_from_xyz(-1., 0., 0.)).unwrap();
+        assert!(on_circle(&circle, [0., 0., 0.], [0., 0., 1.], 1.));
+        let three_points = |p1: Trivector, p2: Trivector| Curve::try_circle_from_three_points(point_from_xyz(1., 0., 0.), p1, p2).unwrap_err();
+        assert_eq!(three_points(point_from_xyz(1., 0., 0.), point_from_xyz(-1., 0., 0.)), CircleError::CoincidentPoints);
+        assert_eq!(three_points(point_from_xyz(0., 1., 0.), point_from_xyz(0., 1., 0.)), CircleError::CoincidentPoints);
+        assert_eq!(three_points(point_from_xyz(0., 1., 0.), point_from_xyz(1., 0., 0.)), CircleError::CoincidentPoints);
+        assert_eq!(three_points(point_from_xyz(2., 0., 0.), point_from_xyz(3., 0., 0.)), CircleError::Collinear);
+        assert_eq!(three_points(inf_point_from_xyz(0., 1., 0.), point_from_xyz(-1., 0., 0.)), CircleError::IdealPoint);
+
+        // Between (2, 0) and (0, 2) with radius 2, the short way around the origin and the long way around (2, 2)
+        let (start, end) = (point_from_xyz(2., 0., 0.), point_from_xyz(0., 2., 0.));
+        let short = Curve::arc_from_two_points_and_radius(start, end, up, 2.).unwrap();
+        assert!(on_circle(&short, [0., 0., 0.], [0., 0., 1.], 2.));
+        assert!((short.length(short.t_min().unwrap(), short.t_max().unwrap()) - PI).abs() < 1e-9);
+        let long = Curve::arc_from_two_points_and_radius(start, end, up, -2.).unwrap();
+        assert!(on_circle(&long, [2., 2., 0.], [0., 0., 1.], 2.));
+        assert!((long.length(long.t_min().unwrap(), long.t_max().unwrap()) - 3. * PI).abs() < 1e-9);
+        for arc in [&short, &long] {
+            let (a, b) = ends(arc);
+            assert!(a.approx_eq(&start) && b.approx_eq(&end));
+        }
+        let two_points = |end: Trivector, axis: Trivector, radius: Float| Curve::arc_from_two_points_and_radius(start, end, axis, radius).unwrap_err();
+        assert_eq!(two_points(end, up, 1.), CircleError::RadiusTooSmall);
+        assert_eq!(two_points(end, up, 0.), CircleError::ZeroRadius);
+        assert_eq!(two_points(start, up, 2.), CircleError::CoincidentPoints);
+        assert_eq!(two_points(end, inf_point_from_xyz(1., 0., 0.), 2.), CircleError::NotInPlane);
+    }
+
+    #[test]
+    fn fillets() {
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let x_axis = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.));
+        let y_axis = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(0., 1., 0.));
+
+        // Rounding the corner between the positive axes
+        let arc = Curve::fillet(&x_axis, &y_axis, 1., up, point_from_xyz(0.5, 0.5, 0.)).unwrap();
+        let (a, b) = ends(&arc);
+        assert!(a.approx_eq(&point_from_xyz(1., 0., 0.)) && b.approx_eq(&point_from_xyz(0., 1., 0.)));
+        assert!(on_circle(&arc, [1., 1., 0.], [0., 0., -1.], 1.));
+        // The opposite corner
+        let arc = Curve::fillet(&x_axis, &y_axis, 1., up, point_from_xyz(-0.5, -0.5, 0.)).unwrap();
+        assert!(ends(&arc).0.approx_eq(&point_from_xyz(-1., 0., 0.)));
+
+        // Between the X axis and the inside of a circle of radius 3, the center is 2.5 from the origin at height 0.5
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), up, 3.).unwrap();
+        let arc = Curve::fillet(&x_axis, &c, 0.5, up, point_from_xyz(2.5, 0.2, 0.)).unwrap();
+        let center = [6_f64.sqrt(), 0.5, 0.];
+        let (a, b) = ends(&arc);
+        assert!(a.approx_eq(&point_from_xyz(center[0], 0., 0.)));
+        assert!(b.approx_eq(&point_from_xyz(1.2 * center[0], 0.6, 0.)));
+        assert!((0..=8).all(|i| {
+            let t = arc.t_min().unwrap() + (arc.t_max().unwrap() - arc.t_min().unwrap()) * i as Float / 8.;
+            (length(sub(xyz(arc.d0(t).hat()), center)) - 0.5).abs() < 1e-9
+        }));
+
+        let parallel = Curve::line_from_two_points(point_from_xyz(0., 5., 0.), point_from_xyz(1., 5., 0.));
+        assert_eq!(Curve::fillet(&x_axis, &parallel, 1., up, point_from_xyz(0., 1., 0.)).unwrap_err(), CircleError::NoSolution);
+        assert_eq!(Curve::fillet(&x_axis, &y_axis, 0., up, point_from_xyz(0.5, 0.5, 0.)).unwrap_err(), CircleError::ZeroRadius);
+        assert_eq!(Curve::fillet(&x_axis, &y_axis, 1., up, inf_point_from_xyz(0.5, 0.5, 0.)).unwrap_err(), CircleError::IdealPoint);
+    }
+
+    #[test]
+    fn circles_tangent_to_lines() {
+        // The distance from a point to a line
+        let distance = |l: Bivector, p: [Float; 3]| length(sub(foot_on_line(l, p), p));
+        let line = |x0: Float, y0: Float, x1: Float, y1: Float| point_from_xyz(x0, y0, 0.) & point_from_xyz(x1, y1, 0.);
+        let tangent_to_all = |lines: [Bivector; 3], circles: &[Curve]| circles.iter().all(|c| {
+            let circle = match c {
+                Curve::Circle(x) => x,
+                _ => return false,
+            };
+            let p0 = xyz(circle.p0.hat());
+            let center = foot_on_line(circle.a, p0);
+            let radius = length(sub(p0, center));
+            // Each circle starts where it touches the first line
+            distance(lines[0], p0) < 1e-9 && lines.iter().all(|&l| (distance(l, center) - radius).abs() < 1e-9)
+        });
+
+        // A 3-4-5 right triangle has an incircle of radius 1 and three excircles
+        let triangle = [line(0., 0., 1., 0.), line(0., 0., 0., 1.), line(3., 0., 0., 4.)];
+        let circles = Curve::circles_tangent_to_three_lines(triangle[0], triangle[1], triangle[2]).unwrap();
+        assert_eq!(circles.len(), 4);
+        assert!(tangent_to_all(triangle, &circles));
+        assert!(circles.iter().any(|c| on_curve(c, point_from_xyz(1., 0., 0.)) && on_curve(c, point_from_xyz(0., 1., 0.))));
+
+        // With a pair of parallel lines, there are two circles between them
+        let channel = [line(0., 0., 1., 0.), line(0., 2., 1., 2.), line(0., 0., 0., 1.)];
+        let circles = Curve::circles_tangent_to_three_lines(channel[0], channel[1], channel[2]).unwrap();
+        assert_eq!(circles.len(), 2);
+        assert!(tangent_to_all(channel, &circles));
+
+        let star = [line(0., 0., 1., 0.), line(0., 0., 0., 1.), line(0., 0., 1., 1.)];
+        assert_eq!(Curve::circles_tangent_to_three_lines(star[0], star[1], star[2]).unwrap_err(), CircleError::NoSolution);
+        let skew = point_from_xyz(0., 0., 1.) & point_from_xyz(1., 1., 2.);
+        assert_eq!(Curve::circles_tangent_to_three_lines(triangle[0], triangle[1], skew).unwrap_err(), CircleError::NotInPlane);
+        assert_eq!(Curve::circles_tangent_to_three_lines(triangle[0], triangle[1], triangle[2] * I).unwrap_err(), CircleError::IdealLine);
+    }
+}
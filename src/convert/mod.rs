@@ -0,0 +1,69 @@
+//! Conversions between the PGA types and those of other math libraries,
+//! each behind a cargo feature of the same name:
+//! * `nalgebra`
+//! * `mint`
+//!
+//! In both cases:
+//! * A finite `Trivector` converts to and from a point.
+//!   Ideal points are rejected, since they have no location;
+//!   to convert them, ask for a direction (3-vector) instead, which in turn rejects finite points.
+//!   Homogeneous 4-vectors (x, y, z, w) convert losslessly in both directions.
+//! * A `Vector` converts to and from a 4-vector (a, b, c, d) of plane coefficients,
+//!   i.e. the plane ax + by + cz + d = 0.
+//! * A `Bivector` converts to and from Plücker coordinates, given as a (direction, moment) pair of 3-vectors
+//!   where the moment is p x direction for any point p on the line.
+//! * A `Motor` converts to a 4x4 homogeneous transformation matrix (see `construct::motor_to_matrix`.)
+
+use crate::pga::*;
+use crate::global::*;
+
+#[cfg(feature = "nalgebra")]
+mod with_nalgebra;
+
+#[cfg(feature = "mint")]
+mod with_mint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    IdealPoint, // A point at infinity was given where a finite point was expected
+    FinitePoint, // A finite point was given where a direction (ideal point) was expected
+}
+
+// These helpers hold the coordinate conventions shared by all of the conversions
+
+fn point_to_xyz(p: Trivector) -> Result<[Float; 3], ConversionError> {
+    let (x, y, z, w) = p.as_tuple();
+    if w.abs() < FLOAT_DIVISION_EPSILON {
+        return Err(ConversionError::IdealPoint);
+    }
+    Ok([x / w, y / w, z / w])
+}
+
+fn direction_to_xyz(p: Trivector) -> Result<[Float; 3], ConversionError> {
+    let (x, y, z, w) = p.as_tuple();
+    if w.abs() >= FLOAT_DIVISION_EPSILON {
+        return Err(ConversionError::FinitePoint);
+    }
+    Ok([x, y, z])
+}
+
+// Returns the plane coefficients [a, b, c, d] of ax + by + cz + d = 0
+fn plane_to_abcd(p: Vector) -> [Float; 4] {
+    let (a1, a2, a3, a4) = p.as_tuple();
+    [a4, a3, a2, a1]
+}
+
+fn plane_from_abcd([a, b, c, d]: [Float; 4]) -> Vector {
+    Vector::new(d, c, b, a)
+}
+
+// Returns the Plücker coordinates (direction, moment) of a line
+fn line_to_plucker(l: Bivector) -> ([Float; 3], [Float; 3]) {
+    let (d0, d1, d2, _) = l.direction().as_tuple();
+    let (m0, m1, m2, _) = l.moment().as_tuple();
+    ([d0, d1, d2], [m0, m1, m2])
+}
+
+fn line_from_plucker(d: [Float; 3], m: [Float; 3]) -> Bivector {
+    Bivector::new(-m[2], -m[1], -m[0], d[0], d[1], d[2])
+}
@@ -0,0 +1,104 @@
+use std::convert::TryFrom;
+use mint::{Point3, Vector3, Vector4, ColumnMatrix4};
+use crate::global::*;
+use crate::construct::*;
+use super::*;
+
+impl TryFrom<Trivector> for Point3<Float> {
+    type Error = ConversionError;
+
+    fn try_from(p: Trivector) -> Result<Self, ConversionError> {
+        Ok(point_to_xyz(p)?.into())
+    }
+}
+
+impl From<Point3<Float>> for Trivector {
+    fn from(p: Point3<Float>) -> Self {
+        point_from_xyz(p.x, p.y, p.z)
+    }
+}
+
+impl TryFrom<Trivector> for Vector3<Float> {
+    type Error = ConversionError;
+
+    fn try_from(p: Trivector) -> Result<Self, ConversionError> {
+        Ok(direction_to_xyz(p)?.into())
+    }
+}
+
+impl From<Vector3<Float>> for Trivector {
+    fn from(v: Vector3<Float>) -> Self {
+        inf_point_from_xyz(v.x, v.y, v.z)
+    }
+}
+
+impl From<Trivector> for Vector4<Float> {
+    fn from(p: Trivector) -> Self {
+        let (x, y, z, w) = p.as_tuple();
+        Vector4 {x, y, z, w}
+    }
+}
+
+impl From<Vector4<Float>> for Trivector {
+    fn from(v: Vector4<Float>) -> Self {
+        Trivector::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector> for Vector4<Float> {
+    fn from(p: Vector) -> Self {
+        plane_to_abcd(p).into()
+    }
+}
+
+impl From<Vector4<Float>> for Vector {
+    fn from(v: Vector4<Float>) -> Self {
+        plane_from_abcd(v.into())
+    }
+}
+
+impl From<Bivector> for (Vector3<Float>, Vector3<Float>) {
+    fn from(l: Bivector) -> Self {
+        let (d, m) = line_to_plucker(l);
+        (d.into(), m.into())
+    }
+}
+
+impl From<(Vector3<Float>, Vector3<Float>)> for Bivector {
+    fn from((d, m): (Vector3<Float>, Vector3<Float>)) -> Self {
+        line_from_plucker(d.into(), m.into())
+    }
+}
+
+impl From<Motor> for ColumnMatrix4<Float> {
+    fn from(motor: Motor) -> Self {
+        let m = motor_to_matrix(motor);
+        let column = |j: usize| Vector4 {x: m[0][j], y: m[1][j], z: m[2][j], w: m[3][j]};
+        ColumnMatrix4 {x: column(0), y: column(1), z: column(2), w: column(3)}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_and_directions() {
+        let p = Point3::try_from(Trivector::new(2., 4., 6., 2.)).unwrap();
+        assert_eq!(p, Point3 {x: 1., y: 2., z: 3.});
+        assert_eq!(Trivector::from(p), point_from_xyz(1., 2., 3.));
+        assert_eq!(Point3::try_from(inf_point_from_xyz(1., 0., 0.)), Err(ConversionError::IdealPoint));
+        assert_eq!(Vector3::try_from(point_from_xyz(1., 2., 3.)), Err(ConversionError::FinitePoint));
+        assert_eq!(Trivector::from(Vector3 {x: 1., y: 2., z: 3.}), inf_point_from_xyz(1., 2., 3.));
+    }
+
+    #[test]
+    fn motor_matrix_columns() {
+        let motor = motor_from_translation(1., -2., 3.) * motor_from_euler_angles(0.3, -1.1, 2.5);
+        let m = motor_to_matrix(motor);
+        let columns = ColumnMatrix4::from(motor);
+        // The last column is the translation, and the matrix is stored column by column
+        assert_eq!(columns.w, Vector4 {x: m[0][3], y: m[1][3], z: m[2][3], w: 1.});
+        assert_eq!(columns.y, Vector4 {x: m[0][1], y: m[1][1], z: m[2][1], w: 0.});
+    }
+}
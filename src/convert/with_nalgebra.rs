@@ -0,0 +1,159 @@
+use std::convert::TryFrom;
+use nalgebra::{Point3, Vector3, Vector4, Matrix4, Isometry3, Translation3, UnitQuaternion, Quaternion};
+use crate::global::*;
+use crate::construct::*;
+use super::*;
+
+impl TryFrom<Trivector> for Point3<Float> {
+    type Error = ConversionError;
+
+    fn try_from(p: Trivector) -> Result<Self, ConversionError> {
+        let [x, y, z] = point_to_xyz(p)?;
+        Ok(Point3::new(x, y, z))
+    }
+}
+
+impl From<Point3<Float>> for Trivector {
+    fn from(p: Point3<Float>) -> Self {
+        point_from_xyz(p.x, p.y, p.z)
+    }
+}
+
+impl TryFrom<Trivector> for Vector3<Float> {
+    type Error = ConversionError;
+
+    fn try_from(p: Trivector) -> Result<Self, ConversionError> {
+        let [x, y, z] = direction_to_xyz(p)?;
+        Ok(Vector3::new(x, y, z))
+    }
+}
+
+impl From<Vector3<Float>> for Trivector {
+    fn from(v: Vector3<Float>) -> Self {
+        inf_point_from_xyz(v.x, v.y, v.z)
+    }
+}
+
+impl From<Trivector> for Vector4<Float> {
+    fn from(p: Trivector) -> Self {
+        let (x, y, z, w) = p.as_tuple();
+        Vector4::new(x, y, z, w)
+    }
+}
+
+impl From<Vector4<Float>> for Trivector {
+    fn from(v: Vector4<Float>) -> Self {
+        Trivector::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector> for Vector4<Float> {
+    fn from(p: Vector) -> Self {
+        let [a, b, c, d] = plane_to_abcd(p);
+        Vector4::new(a, b, c, d)
+    }
+}
+
+impl From<Vector4<Float>> for Vector {
+    fn from(v: Vector4<Float>) -> Self {
+        plane_from_abcd([v.x, v.y, v.z, v.w])
+    }
+}
+
+impl From<Bivector> for (Vector3<Float>, Vector3<Float>) {
+    fn from(l: Bivector) -> Self {
+        let (d, m) = line_to_plucker(l);
+        (Vector3::from(d), Vector3::from(m))
+    }
+}
+
+impl From<(Vector3<Float>, Vector3<Float>)> for Bivector {
+    fn from((d, m): (Vector3<Float>, Vector3<Float>)) -> Self {
+        line_from_plucker(d.into(), m.into())
+    }
+}
+
+impl From<Motor> for Isometry3<Float> {
+    fn from(motor: Motor) -> Self {
+        let motor = motor.normalize();
+        let (w, _, _, _, x, y, z, _) = motor.as_tuple();
+        let m = motor_to_matrix(motor);
+        Isometry3::from_parts(
+            Translation3::new(m[0][3], m[1][3], m[2][3]),
+            UnitQuaternion::from_quaternion(Quaternion::new(w, x, y, z)),
+        )
+    }
+}
+
+impl From<Isometry3<Float>> for Motor {
+    fn from(isometry: Isometry3<Float>) -> Self {
+        let t = isometry.translation.vector;
+        let q = isometry.rotation.quaternion();
+        motor_from_translation(t.x, t.y, t.z) * motor_from_quaternion(q.w, q.i, q.j, q.k)
+    }
+}
+
+impl From<Motor> for Matrix4<Float> {
+    fn from(motor: Motor) -> Self {
+        let m = motor_to_matrix(motor);
+        Matrix4::from_fn(|i, j| m[i][j])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::*;
+
+    #[test]
+    fn points_and_directions() {
+        let p = Point3::try_from(point_from_xyz(1., 2., 3.)).unwrap();
+        assert_eq!(p, Point3::new(1., 2., 3.));
+        // Homogeneous points are divided through by their weight
+        assert_eq!(Point3::try_from(Trivector::new(2., 4., 6., 2.)).unwrap(), p);
+        assert_eq!(Trivector::from(p), point_from_xyz(1., 2., 3.));
+        assert_eq!(Point3::try_from(inf_point_from_xyz(1., 0., 0.)), Err(ConversionError::IdealPoint));
+
+        assert_eq!(Vector3::try_from(inf_point_from_xyz(1., 2., 3.)).unwrap(), Vector3::new(1., 2., 3.));
+        assert_eq!(Vector3::try_from(point_from_xyz(1., 2., 3.)), Err(ConversionError::FinitePoint));
+
+        let h = Trivector::new(2., 4., 6., 2.);
+        assert_eq!(Trivector::from(Vector4::from(h)), h);
+    }
+
+    #[test]
+    fn plane_coefficients() {
+        let points = [point_from_xyz(1., 0., 0.), point_from_xyz(0., 2., 0.), point_from_xyz(0., 0., 3.)];
+        let plane = plane_from_three_points(points[0], points[1], points[2]);
+        let abcd = Vector4::from(plane);
+        for &p in points.iter() {
+            let p = Point3::try_from(p).unwrap();
+            assert!((abcd.x * p.x + abcd.y * p.y + abcd.z * p.z + abcd.w).abs() < 1e-12);
+        }
+        assert_eq!(Vector::from(abcd), plane);
+    }
+
+    #[test]
+    fn plucker_coordinates() {
+        let l = point_from_xyz(1., 2., 3.) & point_from_xyz(1., 3., 3.);
+        let (d, m) = <(Vector3<Float>, Vector3<Float>)>::from(l);
+        assert!(d.cross(&Vector3::new(0., 1., 0.)).norm() < 1e-12);
+        assert!((m - Vector3::new(1., 2., 3.).cross(&d)).norm() < 1e-12);
+        assert!(Bivector::from((d, m)).approx_eq(&l));
+    }
+
+    #[test]
+    fn motors() {
+        let motor = motor_from_translation(1., -2., 3.) * motor_from_euler_angles(0.3, -1.1, 2.5);
+        let p = point_from_xyz(0.5, 4., -1.);
+        let moved = Point3::try_from(p.transform(motor)).unwrap();
+
+        let isometry = Isometry3::from(motor);
+        assert!((isometry * Point3::new(0.5, 4., -1.) - moved).norm() < 1e-12);
+        let back = Motor::from(isometry);
+        assert!(back.approx_eq(&motor) || back.approx_eq(&-motor));
+
+        let matrix = Matrix4::from(motor);
+        assert!((matrix.transform_point(&Point3::new(0.5, 4., -1.)) - moved).norm() < 1e-12);
+    }
+}
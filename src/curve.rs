@@ -5,24 +5,127 @@
 ///
 /// Curves as defined by this module may exist outside the context of a Topo
 /// and are at a lower level of abstraction.
+///
+/// Curves are generic over their scalar type (see the scalar module), which defaults to Float.
+/// Only evaluation (d0 through d3 and derivatives, along with closed, t_min and t_max) is available for other scalar types,
+/// e.g. a Curve<DualNumber> gives the derivative of a point with respect to a construction parameter.
+/// Everything else is built on numerical searches and tolerances, and is Float only.
 
 use crate::pga::*;
 use crate::global::*;
+use crate::predicates::*;
+use crate::construct::*;
+use crate::vertex::*;
+use crate::bounds::*;
+use crate::vec3::*;
+use crate::scalar::Scalar;
 
 #[derive(Debug, Clone)]
-pub enum Curve {
-    Line(Line),
-    Circle(Circle),
-    //TrimmedCurve(TrimmedCurve),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "T: Scalar + serde::Deserialize<'de>")))]
+pub enum Curve<T = Float> {
+    Line(Line<T>),
+    Circle(Circle<T>),
+    Ellipse(Ellipse<T>),
+    Hyperbola(Hyperbola<T>),
+    Parabola(Parabola<T>),
+    Nurbs(Nurbs<T>),
+    Bezier(Bezier<T>),
+    Helix(Helix<T>),
+    Spiral(Spiral<T>),
+    Offset(Offset<T>),
+    Polyline(Polyline<T>),
+    TrimmedCurve(TrimmedCurve<T>),
 }
 
-impl Curve {
+impl<T: Scalar> Curve<T> {
     // Evaluate the point on the curve at parameter value t
-    pub fn d0(&self, t: Float) -> Trivector {
+    pub fn d0(&self, t: T) -> Trivector<T> {
         match &self {
             Curve::Line(x) => x.d0(t),
             Curve::Circle(x) => x.d0(t),
-            //Curve::TrimmedCurve(x) => x.d0(t),
+            Curve::Ellipse(x) => x.d0(t),
+            Curve::Hyperbola(x) => x.d0(t),
+            Curve::Parabola(x) => x.d0(t),
+            Curve::Nurbs(x) => x.d0(t),
+            Curve::Bezier(x) => x.d0(t),
+            Curve::Helix(x) => x.d0(t),
+            Curve::Spiral(x) => x.d0(t),
+            Curve::Offset(x) => x.d0(t),
+            Curve::Polyline(x) => x.d0(t),
+            Curve::TrimmedCurve(x) => x.d0(t),
+        }
+    }
+
+    // Evaluate the first derivative of the curve with respect to t, as an ideal point
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        match &self {
+            Curve::Line(x) => x.d1(t),
+            Curve::Circle(x) => x.d1(t),
+            Curve::Ellipse(x) => x.d1(t),
+            Curve::Hyperbola(x) => x.d1(t),
+            Curve::Parabola(x) => x.d1(t),
+            Curve::Nurbs(x) => x.d1(t),
+            Curve::Bezier(x) => x.d1(t),
+            Curve::Helix(x) => x.d1(t),
+            Curve::Spiral(x) => x.d1(t),
+            Curve::Offset(x) => x.d1(t),
+            Curve::Polyline(x) => x.d1(t),
+            Curve::TrimmedCurve(x) => x.d1(t),
+        }
+    }
+
+    // Evaluate the second derivative of the curve with respect to t, as an ideal point
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        match &self {
+            Curve::Line(x) => x.d2(t),
+            Curve::Circle(x) => x.d2(t),
+            Curve::Ellipse(x) => x.d2(t),
+            Curve::Hyperbola(x) => x.d2(t),
+            Curve::Parabola(x) => x.d2(t),
+            Curve::Nurbs(x) => x.d2(t),
+            Curve::Bezier(x) => x.d2(t),
+            Curve::Helix(x) => x.d2(t),
+            Curve::Spiral(x) => x.d2(t),
+            Curve::Offset(x) => x.d2(t),
+            Curve::Polyline(x) => x.d2(t),
+            Curve::TrimmedCurve(x) => x.d2(t),
+        }
+    }
+
+    // Evaluate the third derivative of the curve with respect to t, as an ideal point
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        match &self {
+            Curve::Line(x) => x.d3(t),
+            Curve::Circle(x) => x.d3(t),
+            Curve::Ellipse(x) => x.d3(t),
+            Curve::Hyperbola(x) => x.d3(t),
+            Curve::Parabola(x) => x.d3(t),
+            Curve::Nurbs(x) => x.d3(t),
+            Curve::Bezier(x) => x.d3(t),
+            Curve::Helix(x) => x.d3(t),
+            Curve::Spiral(x) => x.d3(t),
+            Curve::Offset(x) => x.d3(t),
+            Curve::Polyline(x) => x.d3(t),
+            Curve::TrimmedCurve(x) => x.d3(t),
+        }
+    }
+
+    // Evaluate the point on the curve at parameter t, followed by its derivatives up to order n (as ideal points)
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        match &self {
+            Curve::Line(x) => x.derivatives(t, n),
+            Curve::Circle(x) => x.derivatives(t, n),
+            Curve::Ellipse(x) => x.derivatives(t, n),
+            Curve::Hyperbola(x) => x.derivatives(t, n),
+            Curve::Parabola(x) => x.derivatives(t, n),
+            Curve::Nurbs(x) => x.derivatives(t, n),
+            Curve::Bezier(x) => x.derivatives(t, n),
+            Curve::Helix(x) => x.derivatives(t, n),
+            Curve::Spiral(x) => x.derivatives(t, n),
+            Curve::Offset(x) => x.derivatives(t, n),
+            Curve::Polyline(x) => x.derivatives(t, n),
+            Curve::TrimmedCurve(x) => x.derivatives(t, n),
         }
     }
 
@@ -31,37 +134,75 @@ impl Curve {
         match &self {
             Curve::Line(x) => x.closed(),
             Curve::Circle(x) => x.closed(),
-            //Curve::TrimmedCurve(x) => x.closed(),
+            Curve::Ellipse(x) => x.closed(),
+            Curve::Hyperbola(x) => x.closed(),
+            Curve::Parabola(x) => x.closed(),
+            Curve::Nurbs(x) => x.closed(),
+            Curve::Bezier(x) => x.closed(),
+            Curve::Helix(x) => x.closed(),
+            Curve::Spiral(x) => x.closed(),
+            Curve::Offset(x) => x.closed(),
+            Curve::Polyline(x) => x.closed(),
+            Curve::TrimmedCurve(x) => x.closed(),
         }
     }
 
     // Get the smallest valid parameter value of the curve,
     // or None if the curve extends without bound in the negative parameter direction
-    pub fn t_min(&self) -> Option<Float> {
+    pub fn t_min(&self) -> Option<T> {
         match &self {
             Curve::Line(x) => x.t_min(),
             Curve::Circle(x) => x.t_min(),
-            //Curve::TrimmedCurve(x) => x.t_min(),
+            Curve::Ellipse(x) => x.t_min(),
+            Curve::Hyperbola(x) => x.t_min(),
+            Curve::Parabola(x) => x.t_min(),
+            Curve::Nurbs(x) => x.t_min(),
+            Curve::Bezier(x) => x.t_min(),
+            Curve::Helix(x) => x.t_min(),
+            Curve::Spiral(x) => x.t_min(),
+            Curve::Offset(x) => x.t_min(),
+            Curve::Polyline(x) => x.t_min(),
+            Curve::TrimmedCurve(x) => x.t_min(),
         }
     }
 
     // Get the largest valid parameter value of the curve,
     // or None if the curve extends without bound in the positive parameter direction
-    pub fn t_max(&self) -> Option<Float> {
+    pub fn t_max(&self) -> Option<T> {
         match &self {
             Curve::Line(x) => x.t_max(),
             Curve::Circle(x) => x.t_max(),
-            //Curve::TrimmedCurve(x) => x.t_max(),
+            Curve::Ellipse(x) => x.t_max(),
+            Curve::Hyperbola(x) => x.t_max(),
+            Curve::Parabola(x) => x.t_max(),
+            Curve::Nurbs(x) => x.t_max(),
+            Curve::Bezier(x) => x.t_max(),
+            Curve::Helix(x) => x.t_max(),
+            Curve::Spiral(x) => x.t_max(),
+            Curve::Offset(x) => x.t_max(),
+            Curve::Polyline(x) => x.t_max(),
+            Curve::TrimmedCurve(x) => x.t_max(),
         }
     }
+}
 
+impl Curve {
     // Reflect the curve about the given entity (point, line, plane)
     pub fn reflect<T>(&self, entity: T) -> Curve
     where Vector: Reflect<T>, Bivector: Reflect<T>, Trivector: Reflect<T>, FullMultivector: Reflect<T>, T: Copy {
         match &self {
             Curve::Line(x) => Curve::Line(x.reflect(entity)),
             Curve::Circle(x) => Curve::Circle(x.reflect(entity)),
-            //Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.reflect(entity)),
+            Curve::Ellipse(x) => Curve::Ellipse(x.reflect(entity)),
+            Curve::Hyperbola(x) => Curve::Hyperbola(x.reflect(entity)),
+            Curve::Parabola(x) => Curve::Parabola(x.reflect(entity)),
+            Curve::Nurbs(x) => Curve::Nurbs(x.reflect(entity)),
+            Curve::Bezier(x) => Curve::Bezier(x.reflect(entity)),
+            Curve::Helix(x) => Curve::Helix(x.reflect(entity)),
+            Curve::Spiral(x) => Curve::Spiral(x.reflect(entity)),
+            Curve::Offset(x) => Curve::Offset(x.reflect(entity)),
+            Curve::Polyline(x) => Curve::Polyline(x.reflect(entity)),
+            Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.reflect(entity)),
         }
     }
 
@@ -71,7 +212,16 @@ impl Curve {
         match &self {
             Curve::Line(x) => Curve::Line(x.transform(entity)),
             Curve::Circle(x) => Curve::Circle(x.transform(entity)),
-            //Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.transform(entity)),
+            Curve::Ellipse(x) => Curve::Ellipse(x.transform(entity)),
+            Curve::Hyperbola(x) => Curve::Hyperbola(x.transform(entity)),
+            Curve::Parabola(x) => Curve::Parabola(x.transform(entity)),
+            Curve::Nurbs(x) => Curve::Nurbs(x.transform(entity)),
+            Curve::Bezier(x) => Curve::Bezier(x.transform(entity)),
+            Curve::Helix(x) => Curve::Helix(x.transform(entity)),
+            Curve::Spiral(x) => Curve::Spiral(x.transform(entity)),
+            Curve::Offset(x) => Curve::Offset(x.transform(entity)),
+            Curve::Polyline(x) => Curve::Polyline(x.transform(entity)),
+            Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.transform(entity)),
         }
     }
 
@@ -80,7 +230,117 @@ impl Curve {
         match &self {
             Curve::Line(x) => x.t(p),
             Curve::Circle(x) => x.t(p),
+            Curve::Ellipse(x) => x.t(p),
+            Curve::Hyperbola(x) => x.t(p),
+            Curve::Parabola(x) => x.t(p),
+            Curve::Nurbs(x) => x.t(p),
+            Curve::Bezier(x) => x.t(p),
+            Curve::Helix(x) => x.t(p),
+            Curve::Spiral(x) => x.t(p),
+            Curve::Offset(x) => x.t(p),
+            Curve::Polyline(x) => x.t(p),
+            Curve::TrimmedCurve(x) => x.t(p),
+        }
+    }
+
+    /// Find the point on the curve closest to p,
+    /// returning its parameter value, its distance from p, and the point itself
+    pub fn closest_point(&self, p: Trivector) -> Result<(Float, Float, Trivector), ClosestPointError> {
+        Ok(self.closest_points(p)?[0])
+    }
+
+    /// Find every point on the curve at which the distance from p has a local minimum,
+    /// as for closest_point, in order of increasing distance.
+    /// Where a bounded curve is closest to p at one of its ends, that end is included.
+    /// Unbounded curves are only searched near p:
+    /// far enough out that the curve is heading away from p at more than twice the distance of the closest point found.
+    pub fn closest_points(&self, p: Trivector) -> Result<Vec<(Float, Float, Trivector)>, ClosestPointError> {
+        if !p.is_finite() {
+            return Err(ClosestPointError::IdealPoint);
+        }
+        match &self {
+            Curve::Line(x) => Ok(vec![x.closest_point(p)]),
+            Curve::Circle(x) => Ok(vec![x.closest_point(p)?]),
+            Curve::Polyline(x) => Ok(x.closest_points(p)),
+            _ => closest_points_numeric(self, xyz(dehomogenize(p))),
+        }
+    }
+
+    /// Get the length of the curve between start_t and end_t, measured along the curve.
+    /// The length is negative if end_t is less than start_t.
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        match &self {
+            Curve::Line(x) => x.length(start_t, end_t),
+            Curve::Circle(x) => x.length(start_t, end_t),
+            Curve::Parabola(x) => x.length(start_t, end_t),
+            Curve::Helix(x) => x.length(start_t, end_t),
+            Curve::Spiral(x) => x.length(start_t, end_t),
+            Curve::Nurbs(x) => x.length(start_t, end_t),
+            Curve::Polyline(x) => x.length(start_t, end_t),
+            Curve::TrimmedCurve(x) => x.length(start_t, end_t),
+            // Ellipses and hyperbolas need elliptic integrals, so these are measured numerically along with the rest
+            _ => integrate_speed(|t| length(xyz(self.d1(t))), start_t, end_t, &[]),
+        }
+    }
+
+    /// Get the parameter value that is the given distance along the curve from start_t,
+    /// i.e. the t for which length(start_t, t) == distance.
+    /// A negative distance moves backwards along the curve.
+    /// On a closed curve, the distance wraps around through the seam as many times as necessary;
+    /// otherwise, returns None if the curve ends first.
+    pub fn t_at_length(&self, start_t: Float, distance: Float) -> Option<Float> {
+        if distance == 0. {
+            return Some(start_t);
+        }
+        if self.closed() {
+            let (t_min, t_max) = (self.t_min().unwrap(), self.t_max().unwrap());
+            let distance = distance.rem_euclid(self.length(t_min, t_max));
+            let to_seam = self.length(start_t, t_max);
+            return Some(if distance <= to_seam {
+                self.t_at_length_within(start_t, t_max, distance)
+            } else {
+                self.t_at_length_within(t_min, start_t, distance - to_seam)
+            });
+        }
+
+        let end_t = match if distance > 0. { self.t_max() } else { self.t_min() } {
+            Some(end_t) => {
+                if self.length(start_t, end_t).abs() < distance.abs() - EPSILON_COINCIDENT_DISTANCE {
+                    return None;
+                }
+                end_t
+            },
+            None => {
+                // Step out until the curve is long enough
+                let mut step = distance;
+                while self.length(start_t, start_t + step).abs() < distance.abs() {
+                    step *= 2.;
+                }
+                start_t + step
+            },
+        };
+        Some(self.t_at_length_within(start_t, end_t, distance))
+    }
+
+    // Find the parameter value that is the given distance along the curve from start_t,
+    // which must be no farther than end_t
+    fn t_at_length_within(&self, start_t: Float, end_t: Float, distance: Float) -> Float {
+        // The length from start_t increases with t at the speed of the curve,
+        // so Newton's method is used, falling back to bisection if it leaves the bracket
+        let (mut below, mut above) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        let total = self.length(start_t, end_t);
+        let mut t = if total.abs() > FLOAT_DIVISION_EPSILON { start_t + (end_t - start_t) * distance / total } else { start_t };
+        for _ in 0..64 {
+            let error = self.length(start_t, t) - distance;
+            if error.abs() < EPSILON_COINCIDENT_DISTANCE {
+                break;
+            }
+            if error < 0. { below = t } else { above = t }
+            let speed = length(xyz(self.d1(t)));
+            let next_t = t - error / speed;
+            t = if next_t > below && next_t < above { next_t } else { 0.5 * (below + above) };
         }
+        t
     }
 
     /// Get a set of points, the convex hull of which bounds the curve on the given parameter range
@@ -88,8 +348,484 @@ impl Curve {
         match &self {
             Curve::Line(x) => x.hull(start_t, end_t),
             Curve::Circle(x) => x.hull(start_t, end_t),
+            Curve::Ellipse(x) => x.hull(start_t, end_t),
+            Curve::Hyperbola(x) => x.hull(start_t, end_t),
+            Curve::Parabola(x) => x.hull(start_t, end_t),
+            Curve::Nurbs(x) => x.hull(start_t, end_t),
+            Curve::Bezier(x) => x.hull(start_t, end_t),
+            Curve::Helix(x) => x.hull(start_t, end_t),
+            Curve::Spiral(x) => x.hull(start_t, end_t),
+            Curve::Offset(x) => x.hull(start_t, end_t),
+            Curve::Polyline(x) => x.hull(start_t, end_t),
+            Curve::TrimmedCurve(x) => x.hull(start_t, end_t),
+        }
+    }
+
+    /// Get the smallest axis-aligned box containing the curve on the given parameter range.
+    /// The box is exact for lines, polylines and conics,
+    /// and is found numerically by locating the extremes of each coordinate for other curves.
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        let (start_t, end_t) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        match &self {
+            // The hulls of lines and polylines are the curves' own corners
+            Curve::Line(_) | Curve::Polyline(_) => BoundingBox::from_points(&self.hull(start_t, end_t)),
+            Curve::Circle(x) => x.bounding_box(start_t, end_t),
+            Curve::Ellipse(x) => x.bounding_box(start_t, end_t),
+            Curve::Hyperbola(x) => x.bounding_box(start_t, end_t),
+            Curve::Parabola(x) => x.bounding_box(start_t, end_t),
+            Curve::TrimmedCurve(x) => x.bounding_box(start_t, end_t),
+            _ => bounding_box_numeric(self, start_t, end_t),
+        }
+    }
+
+    /// Get a box, not necessarily axis-aligned, containing the curve on the given parameter range.
+    /// It is fitted to the curve's hull, so it is cheap to compute but not always tight.
+    pub fn oriented_bounding_box(&self, start_t: Float, end_t: Float) -> OrientedBoundingBox {
+        OrientedBoundingBox::from_points(&self.hull(start_t, end_t)).unwrap()
+    }
+
+    /// Get the range [start_t, end_t] of this curve as a NURBS curve,
+    /// along with a bound on the distance between the two.
+    /// Lines, polylines, conics, Bezier curves and splines have exact NURBS forms, for which the bound is zero.
+    /// Other curves are approximated to within EPSILON_APPROXIMATION.
+    /// The NURBS curve starts at start_t and ends at end_t,
+    /// but in between it generally has a different parameterization from this curve.
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> (Nurbs, Float) {
+        match &self {
+            Curve::Line(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Circle(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Ellipse(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Hyperbola(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Parabola(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Nurbs(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Bezier(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::Helix(_) => approximate_with_cubics(self, start_t, end_t),
+            Curve::Spiral(_) => approximate_with_cubics(self, start_t, end_t),
+            Curve::Offset(_) => approximate_with_cubics(self, start_t, end_t),
+            Curve::Polyline(x) => (x.to_nurbs(start_t, end_t), 0.),
+            Curve::TrimmedCurve(x) => x.to_nurbs(start_t, end_t),
+        }
+    }
+
+    /// Get the sub-range [t_start, t_end] of this curve as a TrimmedCurve.
+    /// If the curve is closed, t_end may be less than t_start, in which case the range wraps around.
+    pub fn trim(self, t_start: Float, t_end: Float) -> Curve {
+        Curve::TrimmedCurve(TrimmedCurve::new(self, t_start, t_end))
+    }
+
+    /// Offset the curve by a signed distance along the normal T x v, where T is its tangent and v is a reference direction
+    /// given as an ideal point (see Offset.)
+    /// For a planar curve, v is normal to its plane, and positive distances move the curve to the right of its direction of travel
+    /// when viewed looking down v.
+    /// Offsets of lines, and of circles about their own axis, are exact lines and circles.
+    pub fn offset(self, distance: Float, v: Trivector) -> Curve {
+        assert!(v.is_infinite(), "reference direction must be an ideal point");
+        let normal = |d1: Trivector| {
+            let n = unit(cross(xyz(d1), xyz(v.ihat()))).expect("offset normal is undefined where the curve is parallel to the reference direction");
+            distance * inf_point_from_xyz(n[0], n[1], n[2])
+        };
+        match self {
+            Curve::Line(x) => {
+                // The normal is the same everywhere along a line
+                Curve::Line(Line {p0: x.p0.hat() + normal(x.d1(0.)), d: x.d})
+            },
+            Curve::Circle(x) if length(cross(unit(xyz(x.a.direction())).unwrap(), xyz(v.ihat()))) < FLOAT_DIVISION_EPSILON => {
+                // The normal is radial, so the offset is a concentric circle
+                let p0 = x.p0.hat() + normal(x.d1(0.));
+                assert!((x.a.hat() & p0).norm() > EPSILON_COINCIDENT_DISTANCE, "offset circle has zero radius");
+                Curve::Circle(Circle {p0, a: x.a})
+            },
+            Curve::TrimmedCurve(x) => {
+                // Offsets share the base curve's parameterization, so the trim range carries over
+                Curve::TrimmedCurve(TrimmedCurve {curve: Box::new(x.curve.offset(distance, v)), t_start: x.t_start, t_end: x.t_end})
+            },
+            curve => Curve::Offset(Offset::new(curve, distance, v)),
+        }
+    }
+
+    /// Split a bounded curve at parameter t into two trims of it,
+    /// covering the ranges [t_min, t] and [t, t_max] and keeping this curve's parameterization.
+    pub fn split_at(&self, t: Float) -> (Curve, Curve) {
+        let (t_min, t_max) = match (self.t_min(), self.t_max()) {
+            (Some(t_min), Some(t_max)) => (t_min, t_max),
+            _ => panic!("Only bounded curves can be split"),
+        };
+        assert!(t > t_min && t < t_max, "split point must lie inside the curve's range");
+        (self.clone().trim(t_min, t), self.clone().trim(t, t_max))
+    }
+
+    /// Get the curve traveling in the opposite direction.
+    /// Bounded curves keep their range, and trims are reversed along with their base curve,
+    /// keeping the start of their range within the base curve's range.
+    /// (See reversal_remap for how the parameters of the two curves correspond.)
+    pub fn reversed(&self) -> Curve {
+        match &self {
+            Curve::Line(x) => Curve::Line(x.reversed()),
+            Curve::Circle(x) => Curve::Circle(x.reversed()),
+            Curve::Ellipse(x) => Curve::Ellipse(x.reversed()),
+            Curve::Hyperbola(x) => Curve::Hyperbola(x.reversed()),
+            Curve::Parabola(x) => Curve::Parabola(x.reversed()),
+            Curve::Nurbs(x) => Curve::Nurbs(x.reversed()),
+            Curve::Bezier(x) => Curve::Bezier(x.reversed()),
+            Curve::Helix(x) => Curve::Helix(x.reversed()),
+            Curve::Spiral(x) => Curve::Spiral(x.reversed()),
+            Curve::Polyline(x) => Curve::Polyline(x.reversed()),
+            Curve::Offset(x) => Curve::Offset(x.reversed()),
+            Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.reversed()),
+        }
+    }
+
+    /// Get the map from parameters on this curve to parameters on the reversed curve.
+    /// Bounded curves map t to t_min + t_max - t, and unbounded curves map t to -t.
+    pub fn reversal_remap(&self) -> CurveRemap {
+        match &self {
+            Curve::Offset(x) => x.reversal_remap(),
+            Curve::TrimmedCurve(x) => x.reversal_remap(),
+            _ => {
+                let offset = match (self.t_min(), self.t_max()) {
+                    (Some(t_min), Some(t_max)) => t_min + t_max,
+                    _ => 0.,
+                };
+                CurveRemap {offset, scale: -1.}
+            },
+        }
+    }
+
+    /// Continue the curve past its ends, over start_delta more of parameter before t_min and end_delta more after t_max,
+    /// keeping the parameterization of the existing part.
+    /// Trims of lines, circles and other analytic curves are continued exactly, by covering more of the underlying curve.
+    /// Splines are continued along the polynomial of their end pieces, so that they join with continuous curvature;
+    /// Bezier curves and polylines become NURBS curves to do this.
+    /// Unbounded ends and closed curves are left as they are,
+    /// and analytic curves are only continued as far as they are defined (e.g. up to the apex of a tapered helix.)
+    pub fn extend(&self, start_delta: Float, end_delta: Float) -> Curve {
+        assert!(start_delta >= 0. && end_delta >= 0., "extensions must not be negative");
+        if self.closed() {
+            return self.clone();
+        }
+        match &self {
+            Curve::Nurbs(x) => Curve::Nurbs(x.extend(start_delta, end_delta)),
+            Curve::Bezier(x) => Curve::Nurbs(x.to_nurbs(0., 1.).extend(start_delta, end_delta)),
+            Curve::Polyline(x) => Curve::Nurbs(x.to_nurbs(0., x.t_max().unwrap()).extend(start_delta, end_delta)),
+            Curve::Offset(x) => Curve::Offset(Offset {curve: Box::new(x.curve.extend(start_delta, end_delta)), distance: x.distance, v: x.v}),
+            Curve::TrimmedCurve(x) => Curve::TrimmedCurve(x.extend(start_delta, end_delta)),
+            // The remaining curves already reach as far as they are defined
+            _ => self.clone(),
+        }
+    }
+
+    /// Get the unit tangent vector (as an ideal point) at parameter value t
+    pub fn tangent(&self, t: Float) -> Trivector {
+        let d1 = self.d1(t);
+        assert!(d1.is_infinite(), "curve has zero velocity");
+        d1.ihat()
+    }
+
+    /// Get the curvature (the reciprocal of the radius of the osculating circle) at parameter value t
+    pub fn curvature(&self, t: Float) -> Float {
+        let d1 = xyz(self.d1(t));
+        let d2 = xyz(self.d2(t));
+        length(cross(d1, d2)) / length(d1).powi(3)
+    }
+
+    /// Get the torsion (the rate at which the curve twists out of its osculating plane) at parameter value t.
+    /// The torsion is zero wherever the curvature is zero.
+    pub fn torsion(&self, t: Float) -> Float {
+        let d1 = xyz(self.d1(t));
+        let d2 = xyz(self.d2(t));
+        let d3 = xyz(self.d3(t));
+        let binormal = cross(d1, d2);
+        let binormal_sq = dot(binormal, binormal);
+        if binormal_sq < FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON {
+            return 0.;
+        }
+        dot(binormal, d3) / binormal_sq
+    }
+
+    /// Get the Frenet frame at parameter value t,
+    /// as the motor that takes the world frame onto it:
+    /// the origin maps to the point on the curve, X to the tangent, Y to the normal and Z to the binormal.
+    /// Returns None where the curvature is zero, since the normal is undefined there.
+    pub fn frenet_frame(&self, t: Float) -> Option<Motor> {
+        let d1 = xyz(self.d1(t));
+        let d2 = xyz(self.d2(t));
+        let tangent = unit(d1)?;
+        let binormal = unit(cross(d1, d2))?;
+        let normal = cross(binormal, tangent);
+        Some(frame_motor(xyz(self.d0(t).hat()), tangent, normal, binormal))
+    }
+
+    /// Get n rotation-minimizing (Bishop) frames at parameter values evenly spaced from start_t to end_t,
+    /// as motors in the same form as frenet_frame.
+    /// Unlike the Frenet frame, these do not spin around the tangent, and stay defined where the curvature is zero.
+    /// The first frame takes its normal from the Frenet frame if it exists.
+    /// Precondition: n >= 2.
+    pub fn bishop_frames(&self, start_t: Float, end_t: Float, n: usize) -> Vec<Motor> {
+        assert!(n >= 2);
+
+        let ts: Vec<Float> = (0..n).map(|i| {
+            let alpha = i as Float / (n - 1) as Float;
+            start_t + alpha * (end_t - start_t)
+        }).collect();
+
+        // Propagate the normal along the curve using the double reflection method
+        // (Wang et al., "Computation of Rotation Minimizing Frames", 2008)
+        let reflect = |v: [Float; 3], axis: [Float; 3]| {
+            let c = dot(axis, axis);
+            if c < FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON {
+                v
+            } else {
+                sub(v, scale(axis, 2. * dot(axis, v) / c))
+            }
+        };
+
+        let mut point = xyz(self.d0(ts[0]).hat());
+        let mut tangent = unit(xyz(self.d1(ts[0]))).expect("curve has zero velocity");
+        let mut normal = unit(cross(cross(tangent, xyz(self.d2(ts[0]))), tangent))
+            .unwrap_or_else(|| any_perpendicular(tangent));
+
+        let mut frames = Vec::with_capacity(n);
+        frames.push(frame_motor(point, tangent, normal, cross(tangent, normal)));
+        for &t in &ts[1..] {
+            let next_point = xyz(self.d0(t).hat());
+            let next_tangent = unit(xyz(self.d1(t))).expect("curve has zero velocity");
+            let v1 = sub(next_point, point);
+            let reflected_normal = reflect(normal, v1);
+            let reflected_tangent = reflect(tangent, v1);
+            normal = reflect(reflected_normal, sub(next_tangent, reflected_tangent));
+
+            // Remove any drift out of the plane perpendicular to the tangent
+            normal = unit(sub(normal, scale(next_tangent, dot(normal, next_tangent)))).unwrap_or_else(|| any_perpendicular(next_tangent));
+
+            point = next_point;
+            tangent = next_tangent;
+            frames.push(frame_motor(point, tangent, normal, cross(tangent, normal)));
+        }
+        frames
+    }
+}
+
+/// The reasons that a closest point on a curve may not exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosestPointError {
+    IdealPoint, // The given point is at infinity, so it is no closer to one part of the curve than another
+    Equidistant, // The whole curve is equally close to the given point, e.g. a circle and a point on its axis
+}
+
+// Returns a finite parameter range in which to look for points on the curve close to p.
+// This is the curve's own range if it is bounded.
+// Otherwise, each unbounded end is pushed out until the curve there is heading away from p
+// and is more than twice as far from it as the closest point seen so far.
+fn closest_point_search_range(curve: &Curve, p: [Float; 3]) -> (Float, Float) {
+    let mut start_t = curve.t_min().unwrap_or(curve.t_max().map_or(-1., |t| t - 2.));
+    let mut end_t = curve.t_max().unwrap_or(curve.t_min().map_or(1., |t| t + 2.));
+    let distance = |t: Float| length(sub(xyz(curve.d0(t)), p));
+    let heading_away = |t: Float, sign: Float| sign * dot(sub(xyz(curve.d0(t)), p), xyz(curve.d1(t))) > 0.;
+
+    let mut closest = (0..=16).map(|i| distance(start_t + (end_t - start_t) * i as Float / 16.)).fold(Float::INFINITY, Float::min);
+    for (unbounded, sign) in [(curve.t_min().is_none(), -1.), (curve.t_max().is_none(), 1.)] {
+        if !unbounded {
+            continue;
+        }
+        let mut step = end_t - start_t;
+        for _ in 0..64 {
+            let t = if sign < 0. { start_t } else { end_t };
+            let d = distance(t);
+            closest = closest.min(d);
+            if d > 2. * closest && heading_away(t, sign) {
+                break;
+            }
+            let next_t = t + sign * step;
+            if !distance(next_t).is_finite() {
+                break;
+            }
+            if sign < 0. { start_t = next_t } else { end_t = next_t }
+            step *= 2.;
+        }
+    }
+    (start_t, end_t)
+}
+
+// The number of samples to take of the curve on [start_t, end_t] when searching it numerically:
+// more where the curve has more pieces to it, since each piece of the hull may hide a feature
+fn sample_count(curve: &Curve, start_t: Float, end_t: Float) -> usize {
+    (4 * curve.hull(start_t, end_t).len()).clamp(256, 65536)
+}
+
+// Finds the local minima of the distance from p to the curve
+// by sampling the curve and refining each sample that is no farther than its neighbors with Newton's method
+fn closest_points_numeric(curve: &Curve, p: [Float; 3]) -> Result<Vec<(Float, Float, Trivector)>, ClosestPointError> {
+    let (start_t, end_t) = closest_point_search_range(curve, p);
+    let samples = sample_count(curve, start_t, end_t);
+    let step = (end_t - start_t) / samples as Float;
+    let closed = curve.closed();
+    let wrap = |t: Float| if closed { start_t + (t - start_t).rem_euclid(end_t - start_t) } else { t };
+    let distance = |t: Float| length(sub(xyz(curve.d0(wrap(t))), p));
+
+    let ts: Vec<Float> = (0..=samples).map(|i| start_t + i as Float * step).collect();
+    let ds: Vec<Float> = ts.iter().map(|&t| distance(t)).collect();
+    let nearest = ds.iter().copied().fold(Float::INFINITY, Float::min);
+    let farthest = ds.iter().copied().fold(0., Float::max);
+    if farthest - nearest < EPSILON_COINCIDENT_DISTANCE {
+        return Err(ClosestPointError::Equidistant);
+    }
+
+    // On a closed curve, the last sample is the same as the first
+    let n = if closed { samples } else { samples + 1 };
+    let mut result: Vec<(Float, Float, Trivector)> = Vec::new();
+    for i in 0..n {
+        let (before, after) = if closed {
+            ((i + n - 1) % n, (i + 1) % n)
+        } else {
+            (i.saturating_sub(1), (i + 1).min(n - 1))
+        };
+        if ds[i] > ds[before] || ds[i] > ds[after] {
+            continue;
+        }
+
+        // The minimum lies between the neighboring samples.
+        // Newton's method finds a root of f(t) = (r(t) - p) . r'(t), where f'(t) = r'(t) . r'(t) + (r(t) - p) . r''(t)
+        let low_t = if closed || i > 0 { ts[i] - step } else { ts[i] };
+        let high_t = if closed || i + 1 < n { ts[i] + step } else { ts[i] };
+        let mut t = ts[i];
+        for _ in 0..32 {
+            let r = sub(xyz(curve.d0(wrap(t))), p);
+            let d1 = xyz(curve.d1(wrap(t)));
+            let d2 = xyz(curve.d2(wrap(t)));
+            let df = dot(d1, d1) + dot(r, d2);
+            if df <= FLOAT_DIVISION_EPSILON {
+                break;
+            }
+            let next_t = (t - dot(r, d1) / df).clamp(low_t, high_t);
+            let converged = (next_t - t).abs() < FLOAT_DIVISION_EPSILON;
+            t = next_t;
+            if converged {
+                break;
+            }
+        }
+        if distance(t) > ds[i] {
+            t = ts[i];
+        }
+
+        let t = wrap(t);
+        let q = curve.d0(t);
+        if !result.iter().any(|&(_, _, other)| vertices_coincident(q, other)) {
+            result.push((t, length(sub(xyz(q), p)), q));
+        }
+    }
+    result.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    Ok(result)
+}
+
+// Bounds the arc center + cos(t) a + sin(t) b for t in [start_t, end_t].
+// Each coordinate is extremal where -sin(t) a + cos(t) b = 0,
+// i.e. at t = atan2(b, a) and half a turn from there, repeating every full turn
+fn trig_bounding_box(center: [Float; 3], a: [Float; 3], b: [Float; 3], start_t: Float, end_t: Float) -> BoundingBox {
+    let point = |t: Float| {
+        let [x, y, z] = add(center, add(scale(a, t.cos()), scale(b, t.sin())));
+        point_from_xyz(x, y, z)
+    };
+    let mut bounds = BoundingBox::from_points(&[point(start_t), point(end_t)]);
+    for k in 0..3 {
+        let first_t = b[k].atan2(a[k]);
+        // The first extremum at or after start_t
+        let mut t = first_t + ((start_t - first_t) / PI).ceil() * PI;
+        while t < end_t {
+            bounds = bounds.include(point(t));
+            t += PI;
+        }
+    }
+    bounds
+}
+
+// Bounds the curve on [start_t, end_t] by finding where each coordinate is extremal.
+// The curve is sampled as finely as in closest_points_numeric (see sample_count),
+// and each change in sign of a coordinate of the derivative between samples is refined by bisection.
+fn bounding_box_numeric(curve: &Curve, start_t: Float, end_t: Float) -> BoundingBox {
+    let samples = sample_count(curve, start_t, end_t);
+    let ts: Vec<Float> = (0..=samples).map(|i| start_t + (end_t - start_t) * i as Float / samples as Float).collect();
+    let velocities: Vec<[Float; 3]> = ts.iter().map(|&t| xyz(curve.d1(t))).collect();
+
+    let mut bounds = ts.iter().fold(BoundingBox::empty(), |bounds, &t| bounds.include(curve.d0(t)));
+    for (range, velocity) in ts.windows(2).zip(velocities.windows(2)) {
+        for (k, (&before, &after)) in velocity[0].iter().zip(&velocity[1]).enumerate() {
+            if before * after >= 0. {
+                continue;
+            }
+            let (mut low_t, mut high_t) = (range[0], range[1]);
+            let low_sign = before < 0.;
+            for _ in 0..64 {
+                let t = 0.5 * (low_t + high_t);
+                if (xyz(curve.d1(t))[k] < 0.) == low_sign { low_t = t } else { high_t = t }
+            }
+            bounds = bounds.include(curve.d0(0.5 * (low_t + high_t)));
+        }
+    }
+    bounds
+}
+
+// Integrates the speed of a curve from start_t to end_t to find its length,
+// using Gauss-Legendre quadrature on pieces that are bisected until their length stops changing.
+// The speed should be smooth except at the given breakpoints.
+fn integrate_speed(speed: impl Fn(Float) -> Float, start_t: Float, end_t: Float, breaks: &[Float]) -> Float {
+    const NODES: [(Float, Float); 5] = [
+        (0., 0.5688888888888889),
+        (-0.5384693101056831, 0.47862867049936647),
+        (0.5384693101056831, 0.47862867049936647),
+        (-0.906179845938664, 0.23692688505618908),
+        (0.906179845938664, 0.23692688505618908),
+    ];
+    let gauss = |a: Float, b: Float| {
+        0.5 * (b - a) * NODES.iter().map(|&(x, w)| w * speed(0.5 * (a + b) + 0.5 * (b - a) * x)).sum::<Float>()
+    };
+    fn adapt(gauss: &impl Fn(Float, Float) -> Float, a: Float, b: Float, whole: Float, tolerance: Float, depth: usize) -> Float {
+        let middle = 0.5 * (a + b);
+        let (left, right) = (gauss(a, middle), gauss(middle, b));
+        if depth == 0 || (left + right - whole).abs() <= tolerance {
+            left + right
+        } else {
+            adapt(gauss, a, middle, left, 0.5 * tolerance, depth - 1) + adapt(gauss, middle, b, right, 0.5 * tolerance, depth - 1)
         }
     }
+
+    let (low_t, high_t, sign) = if start_t <= end_t { (start_t, end_t, 1.) } else { (end_t, start_t, -1.) };
+    let mut pieces = vec![low_t];
+    pieces.extend(breaks.iter().copied().filter(|&t| t > low_t && t < high_t));
+    pieces.push(high_t);
+    // Start from a few subdivisions of each piece, so that features smaller than a piece aren't missed
+    sign * pieces.windows(2).map(|pair| {
+        let step = (pair[1] - pair[0]) / 8.;
+        (0..8).map(|i| {
+            let (a, b) = (pair[0] + i as Float * step, pair[0] + (i + 1) as Float * step);
+            adapt(&gauss, a, b, gauss(a, b), EPSILON_COINCIDENT_DISTANCE, 32)
+        }).sum::<Float>()
+    }).sum::<Float>()
+}
+
+// For a curve swept out by a motor r(t) = m(t) * p0 * ~m(t) where m(t) = exp(t / 2 * b),
+// the derivative is the commutator r'(t) = (b * r(t) - r(t) * b) / 2.
+// The derivative is itself carried along by m(t), so higher derivatives are repeated commutators.
+fn motion_derivative<T: Scalar>(b: Bivector<T>, r: Trivector<T>) -> Trivector<T> {
+    ((b * r - r * b) * T::from_f64(0.5)).trivector()
+}
+
+// Returns r followed by its first n derivatives, for a curve swept out by a motor as above
+fn motion_derivatives<T: Scalar>(b: Bivector<T>, r: Trivector<T>, n: usize) -> Vec<Trivector<T>> {
+    let mut derivatives = vec![r];
+    for _ in 0..n {
+        derivatives.push(motion_derivative(b, derivatives[derivatives.len() - 1]));
+    }
+    derivatives
+}
+
+// Returns the motor that takes the world frame onto the given orthonormal frame
+fn frame_motor(origin: [Float; 3], x: [Float; 3], y: [Float; 3], z: [Float; 3]) -> Motor {
+    motor_from_matrix([
+        [x[0], y[0], z[0], origin[0]],
+        [x[1], y[1], z[1], origin[1]],
+        [x[2], y[2], z[2], origin[2]],
+        [0., 0., 0., 1.],
+    ])
 }
 
 // A line is parameterized by a (euclidean) point p0 and an infinite line d.
@@ -97,28 +833,47 @@ impl Curve {
 // The parametric equation for the point r at position t on the line is: r(t) = m(t) * p0 * ~m(t)
 // where m(t) = exp(t / 2 * d)
 #[derive(Debug, Clone)]
-pub struct Line {
-    pub p0: Trivector,
-    pub d: Bivector,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line<T = Float> {
+    pub p0: Trivector<T>,
+    pub d: Bivector<T>,
 }
 
-impl Line {
-    pub fn d0(&self, t: Float) -> Trivector {
-        self.p0.transform((0.5 * t * self.d).exp())
+impl<T: Scalar> Line<T> {
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.p0.transform((self.d * (T::from_f64(0.5) * t)).exp())
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.d, self.d0(t))
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.d, self.d1(t))
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.d, self.d2(t))
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        motion_derivatives(self.d, self.d0(t), n)
     }
 
     pub fn closed(&self) -> bool {
         false
     }
 
-    pub fn t_min(&self) -> Option<Float> {
+    pub fn t_min(&self) -> Option<T> {
         None
     }
 
-    pub fn t_max(&self) -> Option<Float> {
+    pub fn t_max(&self) -> Option<T> {
         None
     }
+}
 
+impl Line {
     pub fn reflect<T>(&self, entity: T) -> Line
     where Bivector: Reflect<T>, Trivector: Reflect<T>, T: Copy {
         Line {p0: self.p0.reflect(entity), d: self.d.reflect(entity)}
@@ -129,6 +884,10 @@ impl Line {
         Line {p0: self.p0.transform(entity), d: self.d.transform(entity)}
     }
 
+    pub fn reversed(&self) -> Line {
+        Line {p0: self.p0, d: -self.d}
+    }
+
     pub fn t(&self, p: Trivector) -> Float {
         // Compute a plane through p0 perpendicular to the line
         let plane = self.d & self.p0;
@@ -137,10 +896,31 @@ impl Line {
         p.hat() & plane
     }
 
+    // Returns the foot of the perpendicular from p to the line, as for Curve::closest_point
+    pub fn closest_point(&self, p: Trivector) -> (Float, Float, Trivector) {
+        // The line moves at a constant velocity, so the foot is found by projecting onto it
+        let p = xyz(dehomogenize(p));
+        let p0 = xyz(dehomogenize(self.p0));
+        let velocity = xyz(self.d1(0.));
+        let t = dot(sub(p, p0), velocity) / dot(velocity, velocity);
+        let foot = add(p0, scale(velocity, t));
+        (t, length(sub(p, foot)), point_from_xyz(foot[0], foot[1], foot[2]))
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // The line moves at a constant speed
+        (end_t - start_t) * length(xyz(self.d1(0.)))
+    }
+
     pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
         // The bounding hull of a line segment is just its two endpoints
         vec![self.d0(start_t), self.d0(end_t)]
     }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // The parameter of a line is linear in its position, so a degree 1 curve reproduces it exactly
+        Nurbs {degree: 1, knots: vec![start_t, start_t, end_t, end_t], points: vec![self.d0(start_t), self.d0(end_t)]}
+    }
 }
 
 // A circle is parameterized by a (euclidean) point p0 and a euclidean line a.
@@ -148,28 +928,47 @@ impl Line {
 // The parametric equation for the point r at position t on the line is: r(t) = m(t) * p0 * ~m(t)
 // where m(t) = exp(t / 2 * d)
 #[derive(Debug, Clone)]
-pub struct Circle {
-    pub p0: Trivector,
-    pub a: Bivector,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle<T = Float> {
+    pub p0: Trivector<T>,
+    pub a: Bivector<T>,
 }
 
-impl Circle {
-    pub fn d0(&self, t: Float) -> Trivector {
-        self.p0.transform((0.5 * t * self.a).exp())
+impl<T: Scalar> Circle<T> {
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.p0.transform((self.a * (T::from_f64(0.5) * t)).exp())
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.a, self.d0(t))
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.a, self.d1(t))
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        motion_derivative(self.a, self.d2(t))
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        motion_derivatives(self.a, self.d0(t), n)
     }
 
     pub fn closed(&self) -> bool {
         true
     }
 
-    pub fn t_min(&self) -> Option<Float> {
-        Some(0.)
+    pub fn t_min(&self) -> Option<T> {
+        Some(T::zero())
     }
 
-    pub fn t_max(&self) -> Option<Float> {
-        Some(2. * PI)
+    pub fn t_max(&self) -> Option<T> {
+        Some(T::from_f64(2. * PI))
     }
+}
 
+impl Circle {
     pub fn reflect<T>(&self, entity: T) -> Circle
     where Bivector: Reflect<T>, Trivector: Reflect<T>, T: Copy {
         Circle {p0: self.p0.reflect(entity), a: self.a.reflect(entity)}
@@ -180,6 +979,10 @@ impl Circle {
         Circle {p0: self.p0.transform(entity), a: self.a.transform(entity)}
     }
 
+    pub fn reversed(&self) -> Circle {
+        Circle {p0: self.p0, a: -self.a}
+    }
+
     pub fn t(&self, p: Trivector) -> Float {
         // Compute plane through l and p0
         let plane0 = (self.a & self.p0).hat();
@@ -190,7 +993,7 @@ impl Circle {
         // Get angle between planes
         let angle = (plane0 | plane1).acos();
         // See if angle > 180 by comparing sign of pt0 wrt plane1
-        let lower_quadrants = plane1 & self.p0 < 0.;
+        let lower_quadrants = line_side(self.a, p, self.p0) < 0.;
 
         match lower_quadrants {
             true => 2. * PI - angle,
@@ -198,6 +1001,24 @@ impl Circle {
         }
     }
 
+    // Returns the point on the circle in the direction of p from the axis, as for Curve::closest_point.
+    // Every point on the circle is equally close to a point on its axis.
+    pub fn closest_point(&self, p: Trivector) -> Result<(Float, Float, Trivector), ClosestPointError> {
+        let p = xyz(dehomogenize(p));
+        let p0 = xyz(dehomogenize(self.p0));
+        let center = foot_on_line(self.a, p0);
+        let radius = length(sub(p0, center));
+        let radial = unit(sub(p, foot_on_line(self.a, p))).ok_or(ClosestPointError::Equidistant)?;
+        let closest = add(center, scale(radial, radius));
+        let q = point_from_xyz(closest[0], closest[1], closest[2]);
+        Ok((self.t(q), length(sub(p, closest)), q))
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // The parameter is the angle, so the length is the radius times the angle
+        (end_t - start_t) * length(xyz(self.d1(0.)))
+    }
+
     pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
         // The bounding hull of a circular arc is the circumscribed polygon consisting of:
         // The start point, the end point, and additional points formed by the intersection of the circle's tangent lines.
@@ -249,58 +1070,4236 @@ impl Circle {
 
         hull
     }
+
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        // Relative to its center, the circle is cos(t) r0 + sin(t) r0', where r0 is the radius to p0
+        let center = foot_on_line(self.a, xyz(dehomogenize(self.p0)));
+        trig_bounding_box(center, sub(xyz(dehomogenize(self.p0)), center), xyz(self.d1(0.)), start_t, end_t)
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // Each arc of up to 90 degrees is a rational quadratic whose middle control point is
+        // the intersection of the tangents at its endpoints (as in Circle::hull),
+        // weighted by the cosine of half the arc's angle
+        let center = foot_on_line(self.a, xyz(self.p0.hat()));
+
+        quadratic_segments_to_nurbs(&breakpoints(start_t, end_t, 0.5 * PI), |t0, t1| {
+            let half_angle = 0.5 * (t1 - t0);
+            let middle = sub(xyz(self.d0(0.5 * (t0 + t1))), center);
+            let p1 = add(center, scale(middle, 1. / half_angle.cos()));
+            [self.d0(t0), half_angle.cos() * point_from_xyz(p1[0], p1[1], p1[2]), self.d0(t1)]
+        })
+    }
 }
 
-//#[derive(Debug, Clone)]
-//pub struct TrimmedCurve {
-//    pub curve: Box<Curve>,
-//    pub t_start: Float,
-//    pub t_end: Float,
-//}
-
-//impl Clone for TrimmedCurve {
-//    fn clone(&self) -> TrimmedCurve {
-//        TrimmedCurve {
-//            curve: Box::new(self.curve.clone()),
-//            t_start: self.t_start,
-//            t_end: self.t_end,
-//        }
-//    }
-//}
-
-//impl TrimmedCurve {
-//    pub fn d0(&self, t: Float) -> Trivector {
-//        self.curve.d0(t)
-//    }
-//
-//    pub fn closed(&self) -> bool {
-//        false
-//    }
-//
-//    pub fn t_min(&self) -> Option<Float> {
-//        Some(self.t_start)
-//    }
-//
-//    pub fn t_max(&self) -> Option<Float> {
-//        Some(self.t_end)
-//    }
-//
-//    pub fn reflect<T>(&self, entity: T) -> TrimmedCurve
-//    where Vector: Reflect<T>, Bivector: Reflect<T>, Trivector: Reflect<T>, FullMultivector: Reflect<T>, T: Copy {
-//        TrimmedCurve {curve: Box::new(self.curve.reflect(entity)), t_start: self.t_start, t_end: self.t_end}
-//    }
-//
-//    pub fn transform<T>(&self, entity: T) -> TrimmedCurve
-//    where Vector: Transform<T>, Bivector: Transform<T>, Trivector: Transform<T>, FullMultivector: Transform<T>, T: Copy {
-//        TrimmedCurve {curve: Box::new(self.curve.transform(entity)), t_start: self.t_start, t_end: self.t_end}
-//    }
-//}
-
-pub fn curves_coincident(c0: &Curve, c1: &Curve) -> Option<Direction> {
-    // Some(Forward) => Curves are coincident and have the same travel direction
-    // Some(Reverse) => Curves are coincident but have opposing travel directions
-    // None => Curves are not coincident
+// Conic sections are placed the way a circle is: each one is a standard conic in the XY plane,
+// centered on the origin, which a motor (the conic's frame) carries into position.
+// An ellipse is the unit circle swept out by rotating (1, 0, 0) about the Z axis, as in Circle,
+// scaled along X and Y before the frame is applied.
+// A hyperbola or a parabola is not the orbit of a point under any motion,
+// since motions keep every point at a fixed distance from their axis,
+// so their standard forms are written out directly.
+// Their hulls are built from the tangent lines at points along the curve, like Circle::hull,
+// working in the curve's local 2D coordinates.
+
+// Scales a point or ideal point in a conic's local coordinates by a along X and b along Y,
+// then carries it into place with the conic's frame
+fn place<T: Scalar>(frame: Motor<T>, a: T, b: T, p: Trivector<T>) -> Trivector<T> {
+    let (x, y, z, w) = p.as_tuple();
+    Trivector::new(x * a, y * b, z, w).transform(frame)
+}
+
+// Returns a conic's center (or vertex) and its X and Y axes scaled by a and b, as ideal points
+fn conic_axes<T: Scalar>(frame: Motor<T>, a: T, b: T) -> (Trivector<T>, Trivector<T>, Trivector<T>) {
+    let (zero, one) = (T::zero(), T::one());
+    (
+        place(frame, a, b, Trivector::new(zero, zero, zero, one)),
+        place(frame, a, b, Trivector::new(one, zero, zero, zero)),
+        place(frame, a, b, Trivector::new(zero, one, zero, zero)),
+    )
+}
+
+// Returns a conic's frame carried along by f, which may be a motion or a reflection.
+// A reflection would turn the frame inside out,
+// so the frame is rebuilt from the images of its origin and its X and Y axes, and its Z axis follows from those.
+fn map_frame(frame: Motor, f: impl Fn(Trivector) -> Trivector) -> Motor {
+    let image = |x: Float, y: Float| f(point_from_xyz(x, y, 0.).transform(frame));
+    frame_from_three_points(image(0., 0.), image(1., 0.), image(0., 1.))
+}
+
+// Returns the frame turned half way around its X axis, so that its Y axis points the other way
+fn flip_frame(frame: Motor) -> Motor {
+    frame * ((point_from_xyz(0., 0., 0.) & point_from_xyz(1., 0., 0.)) * (0.5 * PI)).exp()
+}
+
+// Returns the point where the tangent lines (p1, d1) and (p2, d2) cross in 2D,
+// or None if they are parallel
+fn tangent_intersection_2d(p1: [Float; 2], d1: [Float; 2], p2: [Float; 2], d2: [Float; 2]) -> Option<[Float; 2]> {
+    let det = d1[0] * d2[1] - d1[1] * d2[0];
+    if det.abs() < FLOAT_DIVISION_EPSILON {
+        return None;
+    }
+    let alpha = ((p2[0] - p1[0]) * d2[1] - (p2[1] - p1[1]) * d2[0]) / det;
+    Some([p1[0] + alpha * d1[0], p1[1] + alpha * d1[1]])
+}
+
+// Hull of a convex arc that turns by less than 180 degrees:
+// its endpoints, and the intersection of the tangent lines at its endpoints
+fn convex_arc_hull_2d(p1: [Float; 2], d1: [Float; 2], p2: [Float; 2], d2: [Float; 2]) -> Vec<[Float; 2]> {
+    let mut hull = vec![p1, p2];
+    if let Some(p) = tangent_intersection_2d(p1, d1, p2, d2) {
+        hull.push(p);
+    }
+    hull
+}
+
+// An ellipse is parameterized by its frame and its semi-axes a and b, which lie along the frame's X and Y axes.
+// The parametric equation for the point r at position t on the ellipse is: r(t) = M * S(c(t)) * ~M
+// where M is the frame, S scales by a along X and by b along Y,
+// and c(t) is the unit circle that starts at (1, 0, 0) and rotates around the Z axis (see Circle)
+// so t is the eccentric anomaly.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipse<T = Float> {
+    pub frame: Motor<T>,
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: Scalar> Ellipse<T> {
+    fn unit_circle() -> Circle<T> {
+        let (zero, one) = (T::zero(), T::one());
+        Circle {
+            p0: Trivector::new(one, zero, zero, one),
+            a: Trivector::new(zero, zero, zero, one) & Trivector::new(zero, zero, one, one),
+        }
+    }
+
+    fn local_to_world(&self, [x, y]: [T; 2]) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Trivector::new(x, y, T::zero(), T::one()))
+    }
+
+    // Returns the center of the ellipse, and its semi-axes as ideal points
+    pub fn center_and_axes(&self) -> (Trivector<T>, Trivector<T>, Trivector<T>) {
+        conic_axes(self.frame, self.a, self.b)
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Self::unit_circle().d0(t))
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Self::unit_circle().d1(t))
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Self::unit_circle().d2(t))
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Self::unit_circle().d3(t))
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        Self::unit_circle().derivatives(t, n).into_iter().map(|p| place(self.frame, self.a, self.b, p)).collect()
+    }
+
+    pub fn closed(&self) -> bool {
+        true
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        Some(T::zero())
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        Some(T::from_f64(2. * PI))
+    }
+}
+
+impl Ellipse {
+    pub fn reflect<T>(&self, entity: T) -> Ellipse
+    where Trivector: Reflect<T>, T: Copy {
+        Ellipse {frame: map_frame(self.frame, |p| p.reflect(entity)), a: self.a, b: self.b}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Ellipse
+    where Trivector: Transform<T>, T: Copy {
+        Ellipse {frame: map_frame(self.frame, |p| p.transform(entity)), a: self.a, b: self.b}
+    }
+
+    pub fn reversed(&self) -> Ellipse {
+        Ellipse {frame: flip_frame(self.frame), a: self.a, b: self.b}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // Bring the point into the ellipse's frame, and scale it onto the unit circle
+        let (x, y, _, _) = p.transform(self.frame.reverse()).hat().as_tuple();
+        let t = (y / self.b).atan2(x / self.a);
+        if t < 0. {
+            t + 2. * PI
+        } else {
+            t
+        }
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // An affine map takes tangent lines to tangent lines,
+        // so this is the hull of the corresponding arc of the unit circle (see Circle::hull)
+        let mut hull = Vec::<Trivector>::new();
+
+        let p0 = self.d0(start_t);
+        let p1 = self.d0(end_t);
+        if (p0 | p1).norm() > EPSILON_COINCIDENT_DISTANCE {
+            hull.push(p0);
+            hull.push(p1);
+        }
+
+        let n_tangents = (2. + (end_t - start_t) / (0.5 * PI)).floor().min(5.) as usize;
+        let tangent_ts: Vec<Float> = (0..n_tangents).map(|i| {
+            let alpha = i as Float / (n_tangents - 1) as Float;
+            start_t * (1. - alpha) + end_t * alpha
+        }).collect();
+
+        for pair in tangent_ts.windows(2) {
+            // Tangents to the unit circle at angles t1 and t2 meet at the middle angle,
+            // at a distance of 1 / cos(half the angle between them)
+            let middle = 0.5 * (pair[0] + pair[1]);
+            let r = 1. / (0.5 * (pair[1] - pair[0])).cos();
+            hull.push(self.local_to_world([r * middle.cos(), r * middle.sin()]));
+        }
 
-    // TODO implement this
-    None
+        hull
+    }
+
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        let (center, u, v) = self.center_and_axes();
+        trig_bounding_box(xyz(dehomogenize(center)), xyz(u), xyz(v), start_t, end_t)
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // An affine map of homogeneous control points maps the rational curve along with them,
+        // so this is the image of the corresponding arcs of the unit circle (see Circle::to_nurbs)
+        quadratic_segments_to_nurbs(&breakpoints(start_t, end_t, 0.5 * PI), |t0, t1| {
+            let middle = 0.5 * (t0 + t1);
+            let half_angle = 0.5 * (t1 - t0);
+            let r = 1. / half_angle.cos();
+            [self.d0(t0), half_angle.cos() * self.local_to_world([r * middle.cos(), r * middle.sin()]), self.d0(t1)]
+        })
+    }
+}
+
+// A hyperbola is parameterized by its frame and its semi-transverse and semi-conjugate axes a and b,
+// which lie along the frame's X and Y axes.
+// Only the branch on the +X side of the center is represented.
+// The parametric equation for the point r at position t on the hyperbola is: r(t) = M * S(c(t)) * ~M
+// where M is the frame, S scales by a along X and by b along Y,
+// and c(t) = (cosh(t), sinh(t), 0) is the unit hyperbola.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hyperbola<T = Float> {
+    pub frame: Motor<T>,
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: Scalar> Hyperbola<T> {
+    fn local_to_world(&self, [x, y]: [T; 2]) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Trivector::new(x, y, T::zero(), T::one()))
+    }
+
+    fn local_direction(&self, [x, y]: [T; 2]) -> Trivector<T> {
+        place(self.frame, self.a, self.b, Trivector::new(x, y, T::zero(), T::zero()))
+    }
+
+    // Returns the center of the hyperbola, and its semi-transverse and semi-conjugate axes as ideal points
+    pub fn center_and_axes(&self) -> (Trivector<T>, Trivector<T>, Trivector<T>) {
+        conic_axes(self.frame, self.a, self.b)
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.local_to_world([t.cosh(), t.sinh()])
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.local_direction([t.sinh(), t.cosh()])
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.local_direction([t.cosh(), t.sinh()])
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.d1(t)
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        // The derivatives of cosh and sinh alternate between sinh and cosh
+        (0..=n).map(|k| match k {
+            0 => self.d0(t),
+            k if k % 2 == 1 => self.d1(t),
+            _ => self.d2(t),
+        }).collect()
+    }
+
+    pub fn closed(&self) -> bool {
+        false
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        None
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        None
+    }
+}
+
+impl Hyperbola {
+    pub fn reflect<T>(&self, entity: T) -> Hyperbola
+    where Trivector: Reflect<T>, T: Copy {
+        Hyperbola {frame: map_frame(self.frame, |p| p.reflect(entity)), a: self.a, b: self.b}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Hyperbola
+    where Trivector: Transform<T>, T: Copy {
+        Hyperbola {frame: map_frame(self.frame, |p| p.transform(entity)), a: self.a, b: self.b}
+    }
+
+    pub fn reversed(&self) -> Hyperbola {
+        Hyperbola {frame: flip_frame(self.frame), a: self.a, b: self.b}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // The coordinate along the conjugate axis is sinh(t)
+        let (_, y, _, _) = p.transform(self.frame.reverse()).hat().as_tuple();
+        (y / self.b).asinh()
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // The tangent of a hyperbola turns by less than 180 degrees between its asymptotes,
+        // so any arc is bounded by the triangle formed by its endpoints and tangents
+        convex_arc_hull_2d(
+            [start_t.cosh(), start_t.sinh()], [start_t.sinh(), start_t.cosh()],
+            [end_t.cosh(), end_t.sinh()], [end_t.sinh(), end_t.cosh()],
+        ).into_iter().map(|p| self.local_to_world(p)).collect()
+    }
+
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        // Each coordinate cosh(t) u + sinh(t) v is extremal where sinh(t) u + cosh(t) v = 0,
+        // i.e. where tanh(t) = -v / u, which has a solution only if |v| < |u|
+        let (_, u, v) = self.center_and_axes();
+        let (u, v) = (xyz(u), xyz(v));
+        let mut bounds = BoundingBox::from_points(&[self.d0(start_t), self.d0(end_t)]);
+        for k in 0..3 {
+            if v[k].abs() < u[k].abs() {
+                let t = (-v[k] / u[k]).atanh();
+                if t > start_t && t < end_t {
+                    bounds = bounds.include(self.d0(t));
+                }
+            }
+        }
+        bounds
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // On the unit hyperbola, the tangents at t0 and t1 meet at (cosh(m), sinh(m)) / cosh(h),
+        // where m is the middle parameter and h is half the difference,
+        // and the arc between them is a rational quadratic with middle weight cosh(h).
+        // One segment would suffice, but the weight grows exponentially, so split up long arcs.
+        quadratic_segments_to_nurbs(&breakpoints(start_t, end_t, 1.), |t0, t1| {
+            let middle = 0.5 * (t0 + t1);
+            let half_width = 0.5 * (t1 - t0);
+            let r = 1. / half_width.cosh();
+            [self.d0(t0), half_width.cosh() * self.local_to_world([r * middle.cosh(), r * middle.sinh()]), self.d0(t1)]
+        })
+    }
+}
+
+// A parabola is parameterized by its frame, whose origin is the parabola's vertex,
+// and its focal length f. The frame's X axis points along the parabola's axis towards the focus,
+// and its Y axis is tangent to the parabola at the vertex.
+// The parametric equation for the point r at position t on the parabola is: r(t) = M * c(t) * ~M
+// where M is the frame and c(t) = (t^2 / (4 f), t, 0),
+// so t is the signed distance from the axis.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parabola<T = Float> {
+    pub frame: Motor<T>,
+    pub focal_length: T,
+}
+
+impl<T: Scalar> Parabola<T> {
+    fn local_to_world(&self, [x, y]: [T; 2]) -> Trivector<T> {
+        Trivector::new(x, y, T::zero(), T::one()).transform(self.frame)
+    }
+
+    fn local_direction(&self, [x, y]: [T; 2]) -> Trivector<T> {
+        Trivector::new(x, y, T::zero(), T::zero()).transform(self.frame)
+    }
+
+    // Returns the vertex of the parabola, the unit ideal point along its axis towards the focus,
+    // and the unit ideal point tangent to it at the vertex
+    pub fn vertex_and_axes(&self) -> (Trivector<T>, Trivector<T>, Trivector<T>) {
+        conic_axes(self.frame, T::one(), T::one())
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.local_to_world([t * t / (T::from_f64(4.) * self.focal_length), t])
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.local_direction([t / (T::from_f64(2.) * self.focal_length), T::one()])
+    }
+
+    pub fn d2(&self, _t: T) -> Trivector<T> {
+        self.local_direction([T::one() / (T::from_f64(2.) * self.focal_length), T::zero()])
+    }
+
+    pub fn d3(&self, _t: T) -> Trivector<T> {
+        Trivector::zero()
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        // A parabola is quadratic in t
+        (0..=n).map(|k| match k {
+            0 => self.d0(t),
+            1 => self.d1(t),
+            2 => self.d2(t),
+            _ => Trivector::zero(),
+        }).collect()
+    }
+
+    pub fn closed(&self) -> bool {
+        false
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        None
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        None
+    }
+}
+
+impl Parabola {
+    pub fn reflect<T>(&self, entity: T) -> Parabola
+    where Trivector: Reflect<T>, T: Copy {
+        Parabola {frame: map_frame(self.frame, |p| p.reflect(entity)), focal_length: self.focal_length}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Parabola
+    where Trivector: Transform<T>, T: Copy {
+        Parabola {frame: map_frame(self.frame, |p| p.transform(entity)), focal_length: self.focal_length}
+    }
+
+    pub fn reversed(&self) -> Parabola {
+        Parabola {frame: flip_frame(self.frame), focal_length: self.focal_length}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        let (_, y, _, _) = p.transform(self.frame.reverse()).hat().as_tuple();
+        y
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // The speed is sqrt(1 + x^2) where x = t / (2 f), which integrates to f * (x * sqrt(1 + x^2) + asinh(x))
+        let f = self.focal_length;
+        let integral = |t: Float| {
+            let x = t / (2. * f);
+            f * (x * (1. + x * x).sqrt() + x.asinh())
+        };
+        integral(end_t) - integral(start_t)
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // The tangent of a parabola never turns by 180 degrees,
+        // so any arc is bounded by the triangle formed by its endpoints and tangents
+        let f4 = 4. * self.focal_length;
+        convex_arc_hull_2d(
+            [start_t * start_t / f4, start_t], [2. * start_t / f4, 1.],
+            [end_t * end_t / f4, end_t], [2. * end_t / f4, 1.],
+        ).into_iter().map(|p| self.local_to_world(p)).collect()
+    }
+
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        // Each coordinate t^2 / 4f u + t v is extremal where t / 2f u + v = 0
+        let (_, u, v) = self.vertex_and_axes();
+        let (u, v) = (xyz(u), xyz(v));
+        let mut bounds = BoundingBox::from_points(&[self.d0(start_t), self.d0(end_t)]);
+        for k in 0..3 {
+            if u[k] != 0. {
+                let t = -2. * self.focal_length * v[k] / u[k];
+                if t > start_t && t < end_t {
+                    bounds = bounds.include(self.d0(t));
+                }
+            }
+        }
+        bounds
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // A parabola is a polynomial quadratic in t, so a single Bezier segment reproduces it exactly.
+        // Its middle control point is the intersection of the tangents at the endpoints.
+        let middle = self.d0(start_t) + (0.5 * (end_t - start_t)) * self.d1(start_t);
+        Nurbs {
+            degree: 2,
+            knots: vec![start_t, start_t, start_t, end_t, end_t, end_t],
+            points: vec![self.d0(start_t), middle, self.d0(end_t)],
+        }
+    }
+}
+
+// Helices and spirals sweep a point around an axis like a circle does,
+// but the point may drift away from the axis as it goes.
+// Both have the form r(t) = m(t) * q(t) * ~m(t), where m(t) = exp(t / 2 * b) and q(t) = q0 + t * e
+// for a bivector b and a constant ideal point e.
+// Writing D for the commutator with b (see motion_derivative), and E(t) = m(t) * e * ~m(t),
+// the derivatives are r^(k) = D^k(r) + k D^(k-1)(E), e.g. r' = D(r) + E and r'' = D(D(r)) + 2 D(E).
+// Returns r followed by its first n derivatives.
+fn drifting_motion<T: Scalar>(b: Bivector<T>, q0: Trivector<T>, e: Trivector<T>, t: T, n: usize) -> Vec<Trivector<T>> {
+    let m = (b * (T::from_f64(0.5) * t)).exp();
+    let r = motion_derivatives(b, (q0 + e * t).transform(m), n);
+    let e = motion_derivatives(b, e.transform(m), n);
+    (0..=n).map(|k| match k {
+        0 => r[0],
+        k => r[k] + e[k - 1] * T::from_f64(k as Float),
+    }).collect()
+}
+
+// The parameter at which a radius that grows by growth per radian shrinks to zero, if it does
+fn t_apex<T: Scalar>(radius: T, growth: T) -> Option<T> {
+    if growth.abs() > T::from_f64(FLOAT_DIVISION_EPSILON) {
+        Some(-radius / growth)
+    } else {
+        None
+    }
+}
+
+// The shape of a helix or spiral in euclidean coordinates:
+// r(t) = center + (radius + growth * t) * (cos(t) * x + sin(t) * y) + rise * t * axis
+// where axis, x and y are orthonormal.
+struct SweptFrame {
+    center: [Float; 3],
+    axis: [Float; 3],
+    x: [Float; 3],
+    y: [Float; 3],
+    radius: Float,
+    growth: Float,
+    rise: Float,
+}
+
+impl SweptFrame {
+    fn point(&self, radius: Float, angle: Float, height: Float) -> Trivector {
+        let p = add(self.center, add(
+            scale(add(scale(self.x, angle.cos()), scale(self.y, angle.sin())), radius),
+            scale(self.axis, height),
+        ));
+        point_from_xyz(p[0], p[1], p[2])
+    }
+
+    fn t(&self, p: Trivector) -> Float {
+        // The angle around the axis only determines t up to a whole number of turns,
+        // so pick the turn using the height along the axis or the distance from it
+        let offset = sub(xyz(p.hat()), self.center);
+        let height = dot(offset, self.axis);
+        let planar = sub(offset, scale(self.axis, height));
+        let angle = dot(planar, self.y).atan2(dot(planar, self.x));
+        let angle = if angle < 0. { angle + 2. * PI } else { angle };
+
+        let estimate = if self.rise.abs() > FLOAT_DIVISION_EPSILON {
+            height / self.rise
+        } else if self.growth.abs() > FLOAT_DIVISION_EPSILON {
+            (length(planar) - self.radius) / self.growth
+        } else {
+            return angle;
+        };
+        angle + 2. * PI * ((estimate - angle) / (2. * PI)).round()
+    }
+
+    fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // At radius r = radius + growth * t, the speed is sqrt(r^2 + c^2) where c^2 = growth^2 + rise^2.
+        // With dr = growth * dt, this integrates to (r * sqrt(r^2 + c^2) + c^2 * asinh(r / c)) / (2 * growth)
+        if self.growth.abs() <= FLOAT_DIVISION_EPSILON {
+            return (end_t - start_t) * (self.radius * self.radius + self.rise * self.rise).sqrt();
+        }
+        let c_sq = self.growth * self.growth + self.rise * self.rise;
+        let integral = |t: Float| {
+            let r = self.radius + self.growth * t;
+            (r * (r * r + c_sq).sqrt() + c_sq * (r / c_sq.sqrt()).asinh()) / (2. * self.growth)
+        };
+        integral(end_t) - integral(start_t)
+    }
+
+    fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // Each piece of up to 90 degrees lies within a sector of an annulus, extruded along the axis.
+        // That is bounded by the ends of its inner arc,
+        // and the circumscribed polygon of its outer arc (see Circle::hull), at both ends of its height range.
+        let (start_t, end_t) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        if end_t - start_t < FLOAT_DIVISION_EPSILON {
+            return vec![self.point(self.radius + self.growth * start_t, start_t, self.rise * start_t)];
+        }
+        let mut hull = Vec::<Trivector>::new();
+        for pair in breakpoints(start_t, end_t, 0.5 * PI).windows(2) {
+            let (t0, t1) = (pair[0], pair[1]);
+            let r0 = self.radius + self.growth * t0;
+            let r1 = self.radius + self.growth * t1;
+            let (inner, outer) = (r0.min(r1), r0.max(r1));
+            let middle = 0.5 * (t0 + t1);
+            let heights = if self.rise.abs() > FLOAT_DIVISION_EPSILON {
+                vec![self.rise * t0, self.rise * t1]
+            } else {
+                vec![0.]
+            };
+            for &h in &heights {
+                if outer - inner > FLOAT_DIVISION_EPSILON {
+                    hull.push(self.point(inner, t0, h));
+                    hull.push(self.point(inner, t1, h));
+                }
+                hull.push(self.point(outer, t0, h));
+                hull.push(self.point(outer, t1, h));
+                hull.push(self.point(outer / (0.5 * (t1 - t0)).cos(), middle, h));
+            }
+        }
+        hull
+    }
+}
+
+// A helix is parameterized by a (euclidean) point p0, a screw s, and a taper.
+// The helix starts at p0 and screws around the axis of s, advancing along it as it turns.
+// The parameter t is the angle of rotation from p0, and the point moves away from the axis
+// by taper for every radian it turns, so that a helix with a nonzero taper lies on a cone.
+// The parametric equation for the point r at position t on the helix is: r(t) = m(t) * q(t) * ~m(t)
+// where m(t) = exp(t / 2 * s) and q(t) = p0 + t * taper * (the unit ideal point pointing from the axis to p0)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Helix<T = Float> {
+    pub p0: Trivector<T>,
+    pub s: Bivector<T>,
+    pub taper: T,
+}
+
+impl<T: Scalar> Helix<T> {
+    // The vector from the axis to p0
+    fn radial(&self) -> [T; 3] {
+        let p0 = xyz(self.p0.hat());
+        sub(p0, foot_on_line(self.s.axis(), p0))
+    }
+
+    // The ideal point by which the taper moves the point away from the axis per radian
+    fn drift(&self) -> Trivector<T> {
+        let [x, y, z] = scale(unit(self.radial()).expect("p0 lies on the helix axis"), self.taper);
+        Trivector::new(x, y, z, T::zero())
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        drifting_motion(self.s, self.p0, self.drift(), t, 0)[0]
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        drifting_motion(self.s, self.p0, self.drift(), t, 1)[1]
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        drifting_motion(self.s, self.p0, self.drift(), t, 2)[2]
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        drifting_motion(self.s, self.p0, self.drift(), t, 3)[3]
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        drifting_motion(self.s, self.p0, self.drift(), t, n)
+    }
+
+    pub fn closed(&self) -> bool {
+        false
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        // A tapered helix ends at the apex of its cone
+        t_apex(length(self.radial()), self.taper).filter(|_| self.taper > T::zero())
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        t_apex(length(self.radial()), self.taper).filter(|_| self.taper < T::zero())
+    }
+}
+
+impl Helix {
+    fn frame(&self) -> SweptFrame {
+        let axis_line = self.s.axis();
+        let p0 = xyz(self.p0.hat());
+        let center = foot_on_line(axis_line, p0);
+        let axis = unit(xyz(axis_line.direction())).unwrap();
+        let velocity = xyz(motion_derivative(self.s, self.p0.hat()));
+        let tangential = sub(velocity, scale(axis, dot(velocity, axis)));
+        SweptFrame {
+            center,
+            axis,
+            x: unit(sub(p0, center)).expect("p0 lies on the helix axis"),
+            y: unit(tangential).unwrap(),
+            radius: length(sub(p0, center)),
+            growth: self.taper,
+            rise: dot(velocity, axis),
+        }
+    }
+
+    pub fn reflect<T>(&self, entity: T) -> Helix
+    where Bivector: Reflect<T>, Trivector: Reflect<T>, T: Copy {
+        Helix {p0: self.p0.reflect(entity), s: self.s.reflect(entity), taper: self.taper}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Helix
+    where Bivector: Transform<T>, Trivector: Transform<T>, T: Copy {
+        Helix {p0: self.p0.transform(entity), s: self.s.transform(entity), taper: self.taper}
+    }
+
+    pub fn reversed(&self) -> Helix {
+        // Screw the other way, and drift towards the axis where it drifted away
+        Helix {p0: self.p0, s: -self.s, taper: -self.taper}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        self.frame().t(p)
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        self.frame().hull(start_t, end_t)
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        self.frame().length(start_t, end_t)
+    }
+}
+
+// A spiral is a planar curve parameterized by its (euclidean) center point, a euclidean line a through the center
+// normal to the spiral's plane, a unit ideal point u in the plane, a starting radius, and a growth rate.
+// The spiral starts at center + radius * u and rotates around a like a circle,
+// moving away from the center by growth for every radian it turns.
+// With a starting radius of zero, this is an Archimedean spiral.
+// The parametric equation for the point r at position t on the spiral is: r(t) = m(t) * q(t) * ~m(t)
+// where m(t) = exp(t / 2 * a) and q(t) = center + (radius + growth * t) * u
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spiral<T = Float> {
+    pub center: Trivector<T>,
+    pub a: Bivector<T>,
+    pub u: Trivector<T>,
+    pub radius: T,
+    pub growth: T,
+}
+
+impl<T: Scalar> Spiral<T> {
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        drifting_motion(self.a, self.center.hat() + self.u * self.radius, self.u * self.growth, t, n)
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 0)[0]
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 1)[1]
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 2)[2]
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 3)[3]
+    }
+
+    pub fn closed(&self) -> bool {
+        false
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        // A spiral ends where it reaches its center
+        t_apex(self.radius, self.growth).filter(|_| self.growth > T::zero())
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        t_apex(self.radius, self.growth).filter(|_| self.growth < T::zero())
+    }
+}
+
+impl Spiral {
+    fn frame(&self) -> SweptFrame {
+        let x = unit(xyz(self.u)).unwrap();
+        let tangential = xyz(motion_derivative(self.a, self.center.hat() + self.u));
+        SweptFrame {
+            center: xyz(self.center.hat()),
+            axis: cross(x, unit(tangential).unwrap()),
+            x,
+            y: unit(tangential).unwrap(),
+            radius: self.radius,
+            growth: self.growth,
+            rise: 0.,
+        }
+    }
+
+    pub fn reflect<T>(&self, entity: T) -> Spiral
+    where Bivector: Reflect<T>, Trivector: Reflect<T>, T: Copy {
+        Spiral {center: self.center.reflect(entity), a: self.a.reflect(entity), u: self.u.reflect(entity), radius: self.radius, growth: self.growth}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Spiral
+    where Bivector: Transform<T>, Trivector: Transform<T>, T: Copy {
+        Spiral {center: self.center.transform(entity), a: self.a.transform(entity), u: self.u.transform(entity), radius: self.radius, growth: self.growth}
+    }
+
+    pub fn reversed(&self) -> Spiral {
+        Spiral {center: self.center, a: -self.a, u: self.u, radius: self.radius, growth: -self.growth}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        self.frame().t(p)
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        self.frame().hull(start_t, end_t)
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        self.frame().length(start_t, end_t)
+    }
+}
+
+// Approximates the range [start_t, end_t] of a curve by a piecewise cubic NURBS curve
+// that matches the curve's position and velocity at each breakpoint,
+// bisecting pieces until each is within EPSILON_APPROXIMATION of the curve.
+// Returns the approximation and the largest distance found between the two
+// (measured at points of equal parameter, which bounds the distance between the curves.)
+fn approximate_with_cubics(curve: &Curve, start_t: Float, end_t: Float) -> (Nurbs, Float) {
+    const SAMPLES: usize = 8;
+    const MAX_DEPTH: usize = 20;
+
+    // Each piece is a cubic Hermite segment in Bezier form
+    let segment = |t0: Float, t1: Float| {
+        let (p0, p1) = (curve.d0(t0), curve.d0(t1));
+        let k = (t1 - t0) / 3.;
+        Bezier {points: vec![p0, p0 + k * curve.d1(t0), p1 - k * curve.d1(t1), p1]}
+    };
+    let error = |t0: Float, t1: Float, bezier: &Bezier| {
+        (1..SAMPLES).map(|i| {
+            let alpha = i as Float / SAMPLES as Float;
+            length(sub(xyz(bezier.d0(alpha)), xyz(curve.d0(t0 + alpha * (t1 - t0)))))
+        }).fold(0., Float::max)
+    };
+
+    let mut pending = vec![(start_t, end_t, 0)];
+    let mut pieces = vec![];
+    let mut max_error: Float = 0.;
+    while let Some((t0, t1, depth)) = pending.pop() {
+        let bezier = segment(t0, t1);
+        let e = error(t0, t1, &bezier);
+        if e > EPSILON_APPROXIMATION && depth < MAX_DEPTH {
+            // Push the second half first so pieces come off the stack in order
+            let middle = 0.5 * (t0 + t1);
+            pending.push((middle, t1, depth + 1));
+            pending.push((t0, middle, depth + 1));
+        } else {
+            max_error = max_error.max(e);
+            pieces.push((t0, t1, bezier));
+        }
+    }
+
+    // Join the pieces, whose knots are their parameter ranges on the original curve
+    let mut knots = vec![start_t; 4];
+    let mut points = vec![];
+    for (_, t1, bezier) in pieces.iter() {
+        let start = if points.is_empty() { 0 } else { 1 };
+        points.extend_from_slice(&bezier.points[start..]);
+        knots.extend(std::iter::repeat(*t1).take(3));
+    }
+    knots.push(end_t);
+    let nurbs = Nurbs {degree: 3, knots, points};
+
+    // The pieces join with matching velocity, so one copy of each interior knot can be removed
+    let nurbs = pieces[..pieces.len() - 1].iter().fold(nurbs, |c, &(_, t1, _)| c.remove_knot(t1, 1, EPSILON_COINCIDENT_DISTANCE).0);
+    (nurbs, max_error)
+}
+
+// Splits the range [start_t, end_t] into the fewest equal pieces no longer than max_step,
+// returning the breakpoints including both ends
+fn breakpoints(start_t: Float, end_t: Float, max_step: Float) -> Vec<Float> {
+    assert!(end_t > start_t, "end_t must be greater than start_t");
+    let n = ((end_t - start_t) / max_step - FLOAT_DIVISION_EPSILON).ceil().max(1.) as usize;
+    (0..=n).map(|i| if i == n { end_t } else { start_t + (end_t - start_t) * i as Float / n as Float }).collect()
+}
+
+// Builds a piecewise rational quadratic NURBS curve with a segment between each pair of breakpoints,
+// given a function that returns the homogeneous start, middle and end control points of a segment.
+// The knots are the breakpoints, so the curve matches the original parameterization at the segment ends.
+fn quadratic_segments_to_nurbs(breaks: &[Float], segment: impl Fn(Float, Float) -> [Trivector; 3]) -> Nurbs {
+    let mut knots = vec![breaks[0]; 3];
+    let mut points = vec![];
+    for pair in breaks.windows(2) {
+        let [p0, p1, p2] = segment(pair[0], pair[1]);
+        if points.is_empty() {
+            points.push(p0);
+        }
+        points.push(p1);
+        points.push(p2);
+        knots.push(pair[1]);
+        knots.push(pair[1]);
+    }
+    knots.push(breaks[breaks.len() - 1]);
+    Nurbs {degree: 2, knots, points}
+}
+
+// A NURBS curve is parameterized by its degree p, a non-decreasing knot vector,
+// and a list of control points in homogeneous form, i.e. a control point at (x, y, z) with weight w
+// is stored as the Trivector (w x, w y, w z, w). All weights must be positive.
+// The knot vector must have (number of control points + p + 1) entries.
+// The parametric equation for the point r at position t on the curve is:
+// r(t) = sum(N_i(t) * P_i) / sum(N_i(t) * w_i)
+// where N_i are the B-spline basis functions of degree p over the knot vector,
+// and t ranges over [knots[p], knots[number of control points]].
+// See "The NURBS Book" (Piegl & Tiller), which the algorithm numbers below refer to.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nurbs<T = Float> {
+    pub degree: usize,
+    pub knots: Vec<T>,
+    pub points: Vec<Trivector<T>>,
+}
+
+fn binomial<T: Scalar>(n: usize, k: usize) -> T {
+    T::from_f64((0..k).fold(1., |acc, i| acc * (n - i) as Float / (i + 1) as Float))
+}
+
+// Given the derivatives of a curve in homogeneous coordinates (starting with the point itself),
+// applies the quotient rule to find the point on the projected curve and its derivatives (as ideal points).
+// See "The NURBS Book", page 127, equation 4.8
+fn rational_derivatives<T: Scalar>(homogeneous: &[[T; 4]]) -> Vec<Trivector<T>> {
+    let w0 = homogeneous[0][3];
+    let mut result: Vec<[T; 3]> = Vec::with_capacity(homogeneous.len());
+    for (k, a) in homogeneous.iter().enumerate() {
+        let mut v = [a[0], a[1], a[2]];
+        for i in 1..=k {
+            v = sub(v, scale(result[k - i], binomial::<T>(k, i) * homogeneous[i][3]));
+        }
+        result.push(scale(v, T::one() / w0));
+    }
+
+    result.iter().enumerate().map(|(k, &[x, y, z])| {
+        Trivector::new(x, y, z, if k == 0 { T::one() } else { T::zero() })
+    }).collect()
+}
+
+impl<T: Scalar> Nurbs<T> {
+    // Returns the knot span that the given parameter lies within:
+    // t falls within [knots[span], knots[span + 1]),
+    // with the exception of the last span, which is closed on top
+    pub fn find_span(&self, t: T) -> usize {
+        // See "The NURBS Book", page 68, algorithm A2.1
+        let p = self.degree;
+        let n = self.points.len() - 1;
+
+        if t >= self.knots[n + 1] {
+            // The last non-empty span
+            return (p..=n).rev().find(|&i| self.knots[i] < self.knots[i + 1]).unwrap_or(n);
+        }
+
+        if t <= self.knots[p] {
+            return (p..=n).find(|&i| self.knots[i] < self.knots[i + 1]).unwrap_or(p);
+        }
+
+        // Binary search
+        let mut low = p;
+        let mut high = n + 1;
+        let mut mid = (low + high) / 2;
+
+        while t < self.knots[mid] || t >= self.knots[mid + 1] {
+            if t < self.knots[mid] {
+                high = mid;
+            } else {
+                low = mid;
+            }
+            mid = (low + high) / 2;
+        }
+        mid
+    }
+
+    // Returns the number of times the given parameter value appears in the knot vector
+    pub fn multiplicity(&self, t: T) -> usize {
+        self.knots.iter().filter(|&&k| k == t).count()
+    }
+
+    // Returns the values of the p + 1 nonzero basis functions on the given span,
+    // and their derivatives up to order n, evaluated at t.
+    // result[k][j] is the k-th derivative of basis function (span - p + j).
+    fn basis_function_derivatives(&self, span: usize, t: T, n: usize) -> Vec<Vec<T>> {
+        // See "The NURBS Book", page 72, algorithm A2.3
+        let p = self.degree;
+        let mut ndu = vec![vec![T::zero(); p + 1]; p + 1];
+        let mut left = vec![T::zero(); p + 1];
+        let mut right = vec![T::zero(); p + 1];
+        ndu[0][0] = T::one();
+
+        for j in 1..=p {
+            left[j] = t - self.knots[span + 1 - j];
+            right[j] = self.knots[span + j] - t;
+            let mut saved = T::zero();
+            for r in 0..j {
+                // Lower triangle holds the knot differences
+                ndu[j][r] = right[r + 1] + left[j - r];
+                assert!(ndu[j][r].abs() >= T::from_f64(FLOAT_DIVISION_EPSILON));
+                let temp = ndu[r][j - 1] / ndu[j][r];
+                // Upper triangle holds the basis functions
+                ndu[r][j] = saved + right[r + 1] * temp;
+                saved = left[j - r] * temp;
+            }
+            ndu[j][j] = saved;
+        }
+
+        let mut ders = vec![vec![T::zero(); p + 1]; n + 1];
+        ders[0] = ndu.iter().map(|row| row[p]).collect();
+
+        // Compute the derivatives, alternating between two rows of coefficients
+        let mut a = vec![vec![T::zero(); p + 1]; 2];
+        for r in 0..=p {
+            let (mut s1, mut s2) = (0, 1);
+            a[0][0] = T::one();
+            for k in 1..=n.min(p) {
+                let mut d = T::zero();
+                let rk = r as isize - k as isize;
+                let pk = p - k;
+                if r >= k {
+                    a[s2][0] = a[s1][0] / ndu[pk + 1][rk as usize];
+                    d = a[s2][0] * ndu[rk as usize][pk];
+                }
+                let j1 = if rk >= -1 { 1 } else { (-rk) as usize };
+                let j2 = if r <= pk + 1 { k - 1 } else { p - r };
+                for j in j1..=j2 {
+                    let i = (rk + j as isize) as usize;
+                    a[s2][j] = (a[s1][j] - a[s1][j - 1]) / ndu[pk + 1][i];
+                    d = d + a[s2][j] * ndu[i][pk];
+                }
+                if r <= pk {
+                    a[s2][k] = -a[s1][k - 1] / ndu[pk + 1][r];
+                    d = d + a[s2][k] * ndu[r][pk];
+                }
+                ders[k][r] = d;
+                std::mem::swap(&mut s1, &mut s2);
+            }
+        }
+
+        // Multiply through by the correct factors
+        let mut factor = p as Float;
+        for (k, row) in ders.iter_mut().enumerate().take(n.min(p) + 1).skip(1) {
+            for x in row.iter_mut() {
+                *x = *x * T::from_f64(factor);
+            }
+            factor *= (p - k) as Float;
+        }
+        ders
+    }
+
+    // Returns the point on the curve at parameter t, followed by its derivatives up to order n
+    // (as ideal points)
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        // See "The NURBS Book", page 125, algorithm A4.2
+        let p = self.degree;
+        let span = self.find_span(t);
+        let ders = self.basis_function_derivatives(span, t, n);
+
+        // Derivatives of the homogeneous curve
+        let homogeneous: Vec<[T; 4]> = ders.iter().map(|row| {
+            row.iter().enumerate().fold([T::zero(); 4], |acc, (j, &basis)| {
+                let (x, y, z, w) = self.points[span - p + j].as_tuple();
+                [acc[0] + basis * x, acc[1] + basis * y, acc[2] + basis * z, acc[3] + basis * w]
+            })
+        }).collect();
+
+        rational_derivatives(&homogeneous)
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 0)[0]
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 1)[1]
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 2)[2]
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 3)[3]
+    }
+
+    pub fn closed(&self) -> bool {
+        let start = xyz(self.d0(self.knots[self.degree]));
+        let end = xyz(self.d0(self.knots[self.points.len()]));
+        length(sub(start, end)).to_f64() < EPSILON_COINCIDENT_DISTANCE
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        Some(self.knots[self.degree])
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        Some(self.knots[self.points.len()])
+    }
+}
+
+impl Nurbs {
+    pub fn reflect<T>(&self, entity: T) -> Nurbs
+    where Trivector: Reflect<T>, T: Copy {
+        // Reflection is linear in the homogeneous coordinates, so it commutes with the weighted sum
+        Nurbs {degree: self.degree, knots: self.knots.clone(), points: self.points.iter().map(|p| p.reflect(entity)).collect()}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Nurbs
+    where Trivector: Transform<T>, T: Copy {
+        Nurbs {degree: self.degree, knots: self.knots.clone(), points: self.points.iter().map(|p| p.transform(entity)).collect()}
+    }
+
+    pub fn reversed(&self) -> Nurbs {
+        // Mirror the knots within the curve's range, so that t maps to t_min + t_max - t
+        let sum = self.t_min().unwrap() + self.t_max().unwrap();
+        Nurbs {
+            degree: self.degree,
+            knots: self.knots.iter().rev().map(|&k| sum - k).collect(),
+            points: self.points.iter().rev().copied().collect(),
+        }
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // Find the closest of a set of samples on each span,
+        // then refine it with Newton's method on the squared distance.
+        // See "The NURBS Book", page 230
+        let target = xyz(p.hat());
+        let t_min = self.t_min().unwrap();
+        let t_max = self.t_max().unwrap();
+        let samples_per_span = 4 * (self.degree + 1);
+        let distance_to = |t: Float| length(sub(xyz(self.d0(t)), target));
+
+        let mut best_t = t_min;
+        let mut best_distance = distance_to(t_min);
+        for span in self.degree..self.points.len() {
+            let (k0, k1) = (self.knots[span], self.knots[span + 1]);
+            if k0 == k1 {
+                continue;
+            }
+            for i in 1..=samples_per_span {
+                let t = k0 + (k1 - k0) * i as Float / samples_per_span as Float;
+                let distance = distance_to(t);
+                if distance < best_distance {
+                    best_t = t;
+                    best_distance = distance;
+                }
+            }
+        }
+
+        let closed = self.closed();
+        let mut t = best_t;
+        for _ in 0..20 {
+            let ders = self.derivatives(t, 2);
+            let offset = sub(xyz(ders[0]), target);
+            let d1 = xyz(ders[1]);
+            let d2 = xyz(ders[2]);
+            let numerator = dot(d1, offset);
+            let denominator = dot(d2, offset) + dot(d1, d1);
+            if denominator.abs() < FLOAT_DIVISION_EPSILON {
+                break;
+            }
+            let mut next_t = t - numerator / denominator;
+            if next_t < t_min {
+                next_t = if closed { t_max - (t_min - next_t) } else { t_min };
+            } else if next_t > t_max {
+                next_t = if closed { t_min + (next_t - t_max) } else { t_max };
+            }
+            let step = length(scale(d1, next_t - t));
+            t = next_t;
+            if step < 0.01 * EPSILON_COINCIDENT_DISTANCE {
+                break;
+            }
+        }
+        t
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // The curve is only as smooth as its knots allow, so integrate each span separately
+        integrate_speed(|t| length(xyz(self.d1(t))), start_t, end_t, &self.knots)
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // With positive weights, a NURBS curve lies within the convex hull of its control points,
+        // so take the control points of the curve restricted to the given range
+        let (start_t, end_t) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        if end_t - start_t < FLOAT_DIVISION_EPSILON {
+            return vec![self.d0(start_t)];
+        }
+        self.to_nurbs(start_t, end_t).points.into_iter().map(dehomogenize).collect()
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        let mut curve = self.clamped();
+        if end_t < curve.t_max().unwrap() {
+            curve = curve.split(end_t).0;
+        }
+        if start_t > curve.t_min().unwrap() {
+            curve = curve.split(start_t).1;
+        }
+        curve
+    }
+
+    /// Returns an equivalent curve with the knot t inserted the given number of times.
+    /// The total multiplicity of t may not exceed the degree.
+    pub fn insert_knot(&self, t: Float, times: usize) -> Nurbs {
+        // See "The NURBS Book", page 151, algorithm A5.1
+        if times == 0 {
+            return self.clone();
+        }
+        let p = self.degree;
+        let s = self.multiplicity(t);
+        assert!(times + s <= p, "knot multiplicity may not exceed the degree");
+        assert!(t >= self.t_min().unwrap() && t <= self.t_max().unwrap(), "knot must lie within the curve's range");
+        // Unlike find_span, t = t_max falls within the span that starts at t_max
+        let k = self.knots.iter().rposition(|&knot| knot <= t).unwrap();
+        let n = self.points.len() - 1;
+
+        let mut knots = Vec::with_capacity(self.knots.len() + times);
+        knots.extend_from_slice(&self.knots[..=k]);
+        knots.extend(std::iter::repeat(t).take(times));
+        knots.extend_from_slice(&self.knots[k + 1..]);
+
+        // Save unaltered control points
+        let mut points = vec![Trivector::zero(); n + 1 + times];
+        points[..=k - p].copy_from_slice(&self.points[..=k - p]);
+        points[k - s + times..].copy_from_slice(&self.points[k - s..]);
+
+        let mut temp: Vec<Trivector> = self.points[k - p..=k - s].to_vec();
+        let mut l = k - p;
+        for j in 1..=times {
+            l = k - p + j;
+            for i in 0..=p - j - s {
+                let alpha = (t - self.knots[l + i]) / (self.knots[i + k + 1] - self.knots[l + i]);
+                temp[i] = alpha * temp[i + 1] + (1. - alpha) * temp[i];
+            }
+            points[l] = temp[0];
+            points[k + times - j - s] = temp[p - j - s];
+        }
+        if l + 1 < k - s {
+            points[l + 1..k - s].copy_from_slice(&temp[1..k - s - l]);
+        }
+
+        Nurbs {degree: p, knots, points}
+    }
+
+    /// Attempts to remove the knot t up to the given number of times,
+    /// as long as the shape of the curve does not change by more than the given tolerance.
+    /// Returns the new curve and the number of times the knot was actually removed.
+    pub fn remove_knot(&self, t: Float, times: usize, tolerance: Float) -> (Nurbs, usize) {
+        // See "The NURBS Book", page 185, algorithm A5.8
+        let p = self.degree as isize;
+        let s = self.multiplicity(t) as isize;
+        let times = times.min(s as usize) as isize;
+        if times == 0 || t <= self.t_min().unwrap() || t >= self.t_max().unwrap() {
+            return (self.clone(), 0);
+        }
+        let u = &self.knots;
+        let mut pw = self.points.clone();
+        let n = pw.len() as isize - 1;
+        let m = n + p + 1;
+        let r = self.knots.iter().rposition(|&k| k == t).unwrap() as isize;
+        let order = p + 1;
+
+        // Convert the euclidean tolerance to one on homogeneous points
+        let w_min = pw.iter().map(|pt| pt.as_tuple().3).fold(Float::INFINITY, Float::min);
+        let p_max = pw.iter().map(|&pt| length(xyz(dehomogenize(pt)))).fold(0., Float::max);
+        let tolerance = tolerance * w_min / (1. + p_max);
+        let distance_4d = |a: Trivector, b: Trivector| {
+            let (ax, ay, az, aw) = a.as_tuple();
+            let (bx, by, bz, bw) = b.as_tuple();
+            ((ax - bx).powi(2) + (ay - by).powi(2) + (az - bz).powi(2) + (aw - bw).powi(2)).sqrt()
+        };
+
+        let mut first = r - p;
+        let mut last = r - s;
+        let mut temp = vec![Trivector::zero(); (2 * p + 1) as usize];
+        let mut removed = 0;
+        while removed < times {
+            // Compute new control points for one removal step
+            let off = first - 1;
+            temp[0] = pw[off as usize];
+            temp[(last + 1 - off) as usize] = pw[(last + 1) as usize];
+            let (mut i, mut j) = (first, last);
+            let (mut ii, mut jj) = (1, last - off);
+            while j - i > removed {
+                let alpha_i = (t - u[i as usize]) / (u[(i + order + removed) as usize] - u[i as usize]);
+                let alpha_j = (t - u[(j - removed) as usize]) / (u[(j + order) as usize] - u[(j - removed) as usize]);
+                temp[ii as usize] = (pw[i as usize] - (1. - alpha_i) * temp[(ii - 1) as usize]) * (1. / alpha_i);
+                temp[jj as usize] = (pw[j as usize] - alpha_j * temp[(jj + 1) as usize]) * (1. / (1. - alpha_j));
+                i += 1;
+                ii += 1;
+                j -= 1;
+                jj -= 1;
+            }
+
+            // Check whether the knot is removable
+            let removable = if j - i < removed {
+                distance_4d(temp[(ii - 1) as usize], temp[(jj + 1) as usize]) <= tolerance
+            } else {
+                let alpha_i = (t - u[i as usize]) / (u[(i + order + removed) as usize] - u[i as usize]);
+                let blended = alpha_i * temp[(ii + removed + 1) as usize] + (1. - alpha_i) * temp[(ii - 1) as usize];
+                distance_4d(pw[i as usize], blended) <= tolerance
+            };
+            if !removable {
+                break;
+            }
+
+            // Save the new control points
+            let (mut i, mut j) = (first, last);
+            while j - i > removed {
+                pw[i as usize] = temp[(i - off) as usize];
+                pw[j as usize] = temp[(j - off) as usize];
+                i += 1;
+                j -= 1;
+            }
+            first -= 1;
+            last += 1;
+            removed += 1;
+        }
+
+        if removed == 0 {
+            return (self.clone(), 0);
+        }
+
+        // Shift the knots and control points down over the removed ones
+        let mut knots = u.clone();
+        for k in r + 1..=m {
+            knots[(k - removed) as usize] = knots[k as usize];
+        }
+        knots.truncate((m + 1 - removed) as usize);
+
+        let fout = (2 * r - s - p) / 2;
+        let (mut i, mut j) = (fout, fout);
+        for k in 1..removed {
+            if k % 2 == 1 {
+                i += 1;
+            } else {
+                j -= 1;
+            }
+        }
+        for k in i + 1..=n {
+            pw[j as usize] = pw[k as usize];
+            j += 1;
+        }
+        pw.truncate((n + 1 - removed) as usize);
+
+        (Nurbs {degree: self.degree, knots, points: pw}, removed as usize)
+    }
+
+    // Returns an equivalent curve whose knot vector starts and ends with knots of multiplicity p + 1,
+    // so that it starts and ends at its first and last control points
+    fn clamped(&self) -> Nurbs {
+        let p = self.degree;
+        let t_min = self.t_min().unwrap();
+        let t_max = self.t_max().unwrap();
+        let curve = self.insert_knot(t_min, p - self.multiplicity(t_min).min(p));
+        let curve = curve.insert_knot(t_max, p - curve.multiplicity(t_max).min(p));
+
+        // Drop the knots and control points that lie outside the range,
+        // keeping only the last p copies of t_min and the first p copies of t_max
+        let first = curve.knots.iter().rposition(|&k| k == t_min).unwrap() + 1 - p;
+        let last = curve.knots.iter().position(|&k| k == t_max).unwrap() + p - 1;
+        let mut knots = vec![t_min];
+        knots.extend_from_slice(&curve.knots[first..=last]);
+        knots.push(t_max);
+        let points = curve.points[first - 1..=last - p].to_vec();
+        Nurbs {degree: p, knots, points}
+    }
+
+    // Returns the distinct interior knots of a clamped curve, along with their multiplicities
+    fn interior_knots(&self) -> Vec<(Float, usize)> {
+        let mut interior: Vec<(Float, usize)> = vec![];
+        for &k in &self.knots[self.degree + 1..self.points.len()] {
+            match interior.last_mut() {
+                Some((value, count)) if *value == k => *count += 1,
+                _ => interior.push((k, 1)),
+            }
+        }
+        interior
+    }
+
+    /// Decomposes the curve into Bezier segments, one for each non-empty knot span.
+    /// Each segment covers its knot span, reparameterized to [0, 1].
+    pub fn to_bezier(&self) -> Vec<Bezier> {
+        // Raise the multiplicity of every interior knot to the degree,
+        // after which each span has its own p + 1 control points, sharing their ends
+        let p = self.degree;
+        let curve = self.clamped();
+        let curve = curve.interior_knots().iter().fold(curve.clone(), |c, &(k, count)| c.insert_knot(k, p - count.min(p)));
+        curve.points.windows(p + 1).step_by(p).map(|segment| Bezier {points: segment.to_vec()}).collect()
+    }
+
+    /// Returns an equivalent curve of a higher degree
+    pub fn elevate_degree(&self, times: usize) -> Nurbs {
+        if times == 0 {
+            return self.clone();
+        }
+        let p = self.degree;
+        let q = p + times;
+        let curve = self.clamped();
+        let interior = curve.interior_knots();
+
+        // Elevate each Bezier segment, then join them back together
+        let mut points = vec![];
+        for segment in curve.to_bezier() {
+            let elevated = segment.elevate_degree(times);
+            let start = if points.is_empty() { 0 } else { 1 };
+            points.extend_from_slice(&elevated.points[start..]);
+        }
+        let mut knots = vec![curve.knots[0]; q + 1];
+        for &(k, _) in &interior {
+            knots.extend(std::iter::repeat(k).take(q));
+        }
+        knots.extend(std::iter::repeat(curve.knots[curve.knots.len() - 1]).take(q + 1));
+        let elevated = Nurbs {degree: q, knots, points};
+
+        // Remove the knots that were added during decomposition,
+        // leaving each interior knot with its original multiplicity plus the elevation
+        interior.iter().fold(elevated, |c, &(k, count)| {
+            c.remove_knot(k, p - count.min(p), EPSILON_COINCIDENT_DISTANCE).0
+        })
+    }
+
+    /// Continues the curve past its ends, over start_delta more of parameter before t_min and end_delta more after t_max.
+    /// Each end carries on along the polynomial of its last knot span,
+    /// so the extensions join with continuous curvature (and higher derivatives.)
+    /// Rational curves can only be extended as far as the weights of the new control points stay positive.
+    pub fn extend(&self, start_delta: Float, end_delta: Float) -> Nurbs {
+        let p = self.degree;
+        let curve = self.clamped();
+        let interior = curve.interior_knots();
+        // Split off each end span, extend it, and join it back on,
+        // removing the knots that were added at the joint
+        let curve = match interior.last() {
+            _ if end_delta == 0. => curve,
+            None => curve.extend_span(0., end_delta),
+            Some(&(k, count)) => {
+                let (body, span) = curve.split(k);
+                body.join(&span.extend_span(0., end_delta)).remove_knot(k, p - count.min(p), EPSILON_COINCIDENT_DISTANCE).0
+            },
+        };
+        match interior.first() {
+            _ if start_delta == 0. => curve,
+            None => curve.extend_span(start_delta, 0.),
+            Some(&(k, count)) => {
+                let (span, body) = curve.split(k);
+                span.extend_span(start_delta, 0.).join(&body).remove_knot(k, p - count.min(p), EPSILON_COINCIDENT_DISTANCE).0
+            },
+        }
+    }
+
+    // Extends a clamped curve with a single knot span, by continuing its polynomial outside the span
+    fn extend_span(&self, start_delta: Float, end_delta: Float) -> Nurbs {
+        let p = self.degree;
+        let (t_min, t_max) = (self.t_min().unwrap(), self.t_max().unwrap());
+        let span = t_max - t_min;
+
+        // de Casteljau's algorithm also works outside [0, 1]:
+        // cut the span's Bezier curve off at the new end, then cut the result at the new start
+        let points: Vec<[Float; 4]> = self.points.iter().map(to_homogeneous).collect();
+        let end = 1. + end_delta / span;
+        let (_, left, _) = de_casteljau(&points, end);
+        let (_, _, points) = de_casteljau(&left, -start_delta / span / end);
+        assert!(points.iter().all(|p| p[3] > FLOAT_DIVISION_EPSILON), "extension would give the curve a non-positive weight");
+
+        let mut knots = vec![t_min - start_delta; p + 1];
+        knots.extend(std::iter::repeat(t_max + end_delta).take(p + 1));
+        Nurbs {degree: p, knots, points: points.into_iter().map(from_homogeneous).collect()}
+    }
+
+    /// Splits the curve at the given parameter value into two curves
+    /// covering the ranges [t_min, t] and [t, t_max]
+    pub fn split(&self, t: Float) -> (Nurbs, Nurbs) {
+        assert!(t > self.t_min().unwrap() && t < self.t_max().unwrap(), "split point must lie inside the curve's range");
+        let p = self.degree;
+        let curve = self.clamped();
+        let curve = curve.insert_knot(t, p - curve.multiplicity(t).min(p));
+
+        // The knot t now appears p times; the control point before the last occurrence lies on the curve
+        let a = curve.knots.iter().position(|&k| k == t).unwrap();
+        let mut left_knots = curve.knots[..a + p].to_vec();
+        left_knots.push(t);
+        let mut right_knots = vec![t];
+        right_knots.extend_from_slice(&curve.knots[a..]);
+        (
+            Nurbs {degree: p, knots: left_knots, points: curve.points[..a].to_vec()},
+            Nurbs {degree: p, knots: right_knots, points: curve.points[a - 1..].to_vec()},
+        )
+    }
+
+    // Joins a curve that starts where this one ends (both in space and in parameter) onto the end of this one.
+    // Both curves must be clamped and of the same degree.
+    fn join(&self, other: &Nurbs) -> Nurbs {
+        let p = self.degree;
+        assert!(other.degree == p, "curves must be of the same degree");
+
+        // Scale the homogeneous points of the second curve so the weights agree at the joint
+        let scale_factor = self.points[self.points.len() - 1].as_tuple().3 / other.points[0].as_tuple().3;
+        let mut points = self.points.clone();
+        points.extend(other.points[1..].iter().map(|&pt| scale_factor * pt));
+
+        // The joint becomes an interior knot of multiplicity p
+        let mut knots = self.knots[..self.knots.len() - 1].to_vec();
+        knots.extend_from_slice(&other.knots[p + 1..]);
+
+        Nurbs {degree: p, knots, points}
+    }
+}
+
+// A Bezier curve is parameterized by its control points in homogeneous form, as for Nurbs,
+// and has a degree of one less than the number of control points.
+// A curve whose control points all have unit weight is polynomial; otherwise it is rational.
+// The parametric equation for the point r at position t on the curve is:
+// r(t) = sum(B_i(t) * P_i) / sum(B_i(t) * w_i)
+// where B_i are the Bernstein polynomials, and t ranges over [0, 1].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bezier<T = Float> {
+    pub points: Vec<Trivector<T>>,
+}
+
+// Runs de Casteljau's algorithm on a list of homogeneous points,
+// returning the point at parameter t
+// along with the control points of the two halves of the curve on either side of it
+fn de_casteljau<T: Scalar>(points: &[[T; 4]], t: T) -> ([T; 4], Vec<[T; 4]>, Vec<[T; 4]>) {
+    let mut row = points.to_vec();
+    let mut left = vec![row[0]];
+    let mut right = vec![row[row.len() - 1]];
+    while row.len() > 1 {
+        row = row.windows(2).map(|pair| {
+            let mut p = [T::zero(); 4];
+            for (i, x) in p.iter_mut().enumerate() {
+                *x = (T::one() - t) * pair[0][i] + t * pair[1][i];
+            }
+            p
+        }).collect();
+        left.push(row[0]);
+        right.push(row[row.len() - 1]);
+    }
+    right.reverse();
+    (row[0], left, right)
+}
+
+fn to_homogeneous<T: Scalar>(p: &Trivector<T>) -> [T; 4] {
+    let (x, y, z, w) = p.as_tuple();
+    [x, y, z, w]
+}
+
+fn from_homogeneous<T: Scalar>([x, y, z, w]: [T; 4]) -> Trivector<T> {
+    Trivector::new(x, y, z, w)
+}
+
+impl<T: Scalar> Bezier<T> {
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    // Returns the point on the curve at parameter t, followed by its derivatives up to order n
+    // (as ideal points)
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        // The derivative of a Bezier curve of degree p (in homogeneous coordinates)
+        // is a Bezier curve of degree p - 1 whose control points are p times the differences of the originals
+        let mut points: Vec<[T; 4]> = self.points.iter().map(to_homogeneous).collect();
+        let mut homogeneous = Vec::with_capacity(n + 1);
+        for _ in 0..=n {
+            if points.is_empty() {
+                homogeneous.push([T::zero(); 4]);
+                continue;
+            }
+            homogeneous.push(de_casteljau(&points, t).0);
+            let degree = T::from_f64((points.len() - 1) as Float);
+            points = points.windows(2).map(|pair| {
+                let mut p = [T::zero(); 4];
+                for (i, x) in p.iter_mut().enumerate() {
+                    *x = degree * (pair[1][i] - pair[0][i]);
+                }
+                p
+            }).collect();
+        }
+        rational_derivatives(&homogeneous)
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        dehomogenize(from_homogeneous(de_casteljau(&self.points.iter().map(to_homogeneous).collect::<Vec<_>>(), t).0))
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 1)[1]
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 2)[2]
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 3)[3]
+    }
+
+    pub fn closed(&self) -> bool {
+        let start = xyz(dehomogenize(self.points[0]));
+        let end = xyz(dehomogenize(self.points[self.points.len() - 1]));
+        length(sub(start, end)).to_f64() < EPSILON_COINCIDENT_DISTANCE
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        Some(T::zero())
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        Some(T::one())
+    }
+}
+
+impl Bezier {
+    pub fn reflect<T>(&self, entity: T) -> Bezier
+    where Trivector: Reflect<T>, T: Copy {
+        Bezier {points: self.points.iter().map(|p| p.reflect(entity)).collect()}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Bezier
+    where Trivector: Transform<T>, T: Copy {
+        Bezier {points: self.points.iter().map(|p| p.transform(entity)).collect()}
+    }
+
+    pub fn reversed(&self) -> Bezier {
+        Bezier {points: self.points.iter().rev().copied().collect()}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // A single-span NURBS curve on [0, 1] has the same parameterization
+        self.to_nurbs(0., 1.).t(p)
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // With positive weights, the curve lies within the convex hull of its control polygon,
+        // so take the control polygon of the sub-range
+        let (start_t, end_t) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        self.segment(start_t, end_t).points.into_iter().map(dehomogenize).collect()
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // The segment's knots span [start_t, end_t], so the NURBS curve keeps this curve's parameterization
+        let p = self.degree();
+        let mut knots = vec![start_t; p + 1];
+        knots.extend(std::iter::repeat(end_t).take(p + 1));
+        Nurbs {degree: p, knots, points: self.segment(start_t, end_t).points}
+    }
+
+    /// Splits the curve at parameter t into two curves,
+    /// covering the ranges [0, t] and [t, 1], each reparameterized to [0, 1]
+    pub fn subdivide(&self, t: Float) -> (Bezier, Bezier) {
+        let (_, left, right) = de_casteljau(&self.points.iter().map(to_homogeneous).collect::<Vec<_>>(), t);
+        (
+            Bezier {points: left.into_iter().map(from_homogeneous).collect()},
+            Bezier {points: right.into_iter().map(from_homogeneous).collect()},
+        )
+    }
+
+    // Returns the curve on the range [start_t, end_t], reparameterized to [0, 1]
+    fn segment(&self, start_t: Float, end_t: Float) -> Bezier {
+        let curve = if end_t < 1. { self.subdivide(end_t).0 } else { self.clone() };
+        if start_t > 0. && end_t > 0. {
+            curve.subdivide(start_t / end_t).1
+        } else {
+            curve
+        }
+    }
+
+    /// Returns an equivalent curve of a higher degree
+    pub fn elevate_degree(&self, times: usize) -> Bezier {
+        // See "The NURBS Book", page 205, equation 5.36
+        let p = self.degree();
+        let q = p + times;
+        Bezier {points: (0..=q).map(|i| {
+            (i.saturating_sub(times)..=p.min(i)).fold(Trivector::zero(), |acc, j| {
+                acc + (binomial::<Float>(p, j) * binomial::<Float>(times, i - j) / binomial::<Float>(q, i)) * self.points[j]
+            })
+        }).collect()}
+    }
+}
+
+// A polyline is a chain of straight segments through a list of (euclidean, unit weight) points.
+// If it is closed, a final segment joins the last point back to the first.
+// It is parameterized by chord length: t is the distance travelled along the chain from the first point,
+// so t ranges over [0, L] where L is the total length of the segments.
+// Parameter values outside this range extend the first or last segment.
+// The parameter value at each corner is computed once, when the polyline is built,
+// so the fields are private: build a polyline with Polyline::new (or Curve::polyline.)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "PolylinePoints<T>", bound(deserialize = "T: Scalar + serde::Deserialize<'de>")))]
+pub struct Polyline<T = Float> {
+    points: Vec<Trivector<T>>,
+    closed: bool,
+    // The parameter value at each corner, repeating the first corner at the end if the polyline is closed
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    lengths: Vec<T>,
+}
+
+// The serialized form of a polyline, from which its lengths are recomputed
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PolylinePoints<T> {
+    points: Vec<Trivector<T>>,
+    closed: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar> From<PolylinePoints<T>> for Polyline<T> {
+    fn from(p: PolylinePoints<T>) -> Polyline<T> {
+        Polyline::new(p.points, p.closed)
+    }
+}
+
+impl<T: Scalar> Polyline<T> {
+    // Build a polyline through the given points, which must have unit weight.
+    // Points that coincide with the one before them are dropped, as is a last point that repeats the first if closed.
+    pub fn new(points: Vec<Trivector<T>>, closed: bool) -> Polyline<T> {
+        let coincident = |a: Trivector<T>, b: Trivector<T>| length(sub(xyz(a), xyz(b))).to_f64() < EPSILON_COINCIDENT_DISTANCE;
+        let mut distinct: Vec<Trivector<T>> = Vec::with_capacity(points.len());
+        for p in points {
+            if distinct.last().map_or(true, |&last| !coincident(last, p)) {
+                distinct.push(p);
+            }
+        }
+        if closed && distinct.len() > 1 && coincident(distinct[0], distinct[distinct.len() - 1]) {
+            distinct.pop();
+        }
+        assert!(distinct.len() >= if closed { 3 } else { 2 }, "there are too few distinct points");
+
+        let mut lengths = vec![T::zero()];
+        for i in 0..distinct.len() - 1 + closed as usize {
+            let segment = sub(xyz(distinct[(i + 1) % distinct.len()]), xyz(distinct[i]));
+            lengths.push(lengths[i] + length(segment));
+        }
+        Polyline {points: distinct, closed, lengths}
+    }
+
+    // Returns the points the polyline passes through, not repeating the first point if it is closed
+    pub fn points(&self) -> &[Trivector<T>] {
+        &self.points
+    }
+
+    // Returns corner i, counting the first point again at the end if the polyline is closed
+    fn corner(&self, i: usize) -> [T; 3] {
+        xyz(self.points[i % self.points.len()])
+    }
+
+    // Returns the corners of the polyline in order, repeating the first point at the end if it is closed
+    fn corners(&self) -> Vec<[T; 3]> {
+        (0..self.lengths.len()).map(|i| self.corner(i)).collect()
+    }
+
+    // Returns the start and end points of the segment containing parameter value t,
+    // along with their parameter values
+    fn segment(&self, t: T) -> ([T; 3], [T; 3], T, T) {
+        let ts = &self.lengths;
+        let i = ts[1..ts.len() - 1].partition_point(|&corner_t| corner_t <= t);
+        (self.corner(i), self.corner(i + 1), ts[i], ts[i + 1])
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        let (a, b, t_a, t_b) = self.segment(t);
+        let [x, y, z] = add(a, scale(sub(b, a), (t - t_a) / (t_b - t_a)));
+        Trivector::new(x, y, z, T::one())
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        // Each segment is traversed at unit speed
+        let (a, b, t_a, t_b) = self.segment(t);
+        let [x, y, z] = scale(sub(b, a), T::one() / (t_b - t_a));
+        Trivector::new(x, y, z, T::zero())
+    }
+
+    pub fn d2(&self, _t: T) -> Trivector<T> {
+        // Each segment is straight (the corners themselves are not differentiable)
+        Trivector::zero()
+    }
+
+    pub fn d3(&self, _t: T) -> Trivector<T> {
+        Trivector::zero()
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        (0..=n).map(|k| match k {
+            0 => self.d0(t),
+            1 => self.d1(t),
+            _ => Trivector::zero(),
+        }).collect()
+    }
+
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        Some(T::zero())
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        Some(self.lengths[self.lengths.len() - 1])
+    }
+}
+
+impl Polyline {
+    // Returns the parameter values and positions of the ends of the range [start_t, end_t]
+    // and of the corners strictly between them
+    fn corners_between(&self, start_t: Float, end_t: Float) -> Vec<(Float, Trivector)> {
+        let mut result = vec![(start_t, self.d0(start_t))];
+        result.extend(self.lengths.iter().copied().zip(self.corners()).filter(|&(t, _)| t > start_t && t < end_t).map(|(t, [x, y, z])| (t, point_from_xyz(x, y, z))));
+        result.push((end_t, self.d0(end_t)));
+        result
+    }
+
+    pub fn reflect<T>(&self, entity: T) -> Polyline
+    where Trivector: Reflect<T>, T: Copy {
+        // Reflection keeps the lengths of the segments
+        Polyline {points: self.points.iter().map(|p| dehomogenize(p.reflect(entity))).collect(), closed: self.closed, lengths: self.lengths.clone()}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Polyline
+    where Trivector: Transform<T>, T: Copy {
+        Polyline {points: self.points.iter().map(|p| dehomogenize(p.transform(entity))).collect(), closed: self.closed, lengths: self.lengths.clone()}
+    }
+
+    pub fn reversed(&self) -> Polyline {
+        // A closed polyline keeps its first point, so that it starts in the same place
+        let points = if self.closed {
+            std::iter::once(self.points[0]).chain(self.points[1..].iter().rev().copied()).collect()
+        } else {
+            self.points.iter().rev().copied().collect()
+        };
+        // Either way, the corners come in the opposite order
+        let total = self.lengths[self.lengths.len() - 1];
+        Polyline {points, closed: self.closed, lengths: self.lengths.iter().rev().map(|&t| total - t).collect()}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // Project p onto each segment, and take the closest
+        let p = xyz(dehomogenize(p));
+        let (corners, ts) = (self.corners(), &self.lengths);
+        let mut best_distance = Float::INFINITY;
+        let mut best_t = 0.;
+        for (i, pair) in corners.windows(2).enumerate() {
+            let d = sub(pair[1], pair[0]);
+            let along = (dot(sub(p, pair[0]), d) / dot(d, d)).clamp(0., 1.);
+            let distance = length(sub(p, add(pair[0], scale(d, along))));
+            if distance < best_distance {
+                best_distance = distance;
+                best_t = ts[i] + along * (ts[i + 1] - ts[i]);
+            }
+        }
+        best_t
+    }
+
+    // Returns the local minima of the distance from p, as for Curve::closest_points.
+    // These are the feet of the perpendiculars from p that land within a segment,
+    // along with any corners (or ends) that are closest to p on all of their segments.
+    pub fn closest_points(&self, p: Trivector) -> Vec<(Float, Float, Trivector)> {
+        let p = xyz(dehomogenize(p));
+        let (corners, ts) = (self.corners(), &self.lengths);
+        let along: Vec<Float> = corners.windows(2).map(|pair| {
+            let d = sub(pair[1], pair[0]);
+            (dot(sub(p, pair[0]), d) / dot(d, d)).clamp(0., 1.)
+        }).collect();
+        let segments = along.len();
+
+        let mut result = Vec::new();
+        for (i, &a) in along.iter().enumerate() {
+            let at_corner = if a <= 0. {
+                // The start of this segment only counts if the previous segment also ends closest to p
+                match (i, self.closed) {
+                    (0, false) => Some(0),
+                    (0, true) => (along[segments - 1] >= 1.).then(|| 0),
+                    _ => (along[i - 1] >= 1.).then(|| i),
+                }
+            } else if a >= 1. {
+                // The end of this segment is handled as the start of the next, unless this is an open polyline's last segment
+                (i == segments - 1 && !self.closed).then(|| i + 1)
+            } else {
+                let [x, y, z] = add(corners[i], scale(sub(corners[i + 1], corners[i]), a));
+                let t = ts[i] + a * (ts[i + 1] - ts[i]);
+                result.push((t, length(sub(p, [x, y, z])), point_from_xyz(x, y, z)));
+                None
+            };
+            if let Some(k) = at_corner {
+                let [x, y, z] = corners[k];
+                result.push((ts[k], length(sub(p, corners[k])), point_from_xyz(x, y, z)));
+            }
+        }
+        result.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        result
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // The parameter is the distance along the chain
+        end_t - start_t
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // A polyline is bounded exactly by the ends of the range and the corners in between
+        let (start_t, end_t) = if start_t <= end_t { (start_t, end_t) } else { (end_t, start_t) };
+        self.corners_between(start_t, end_t).into_iter().map(|(_, p)| p).collect()
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> Nurbs {
+        // A degree 1 curve interpolates linearly between its knots,
+        // so a knot at each corner keeps the chord length parameterization
+        let corners = self.corners_between(start_t, end_t);
+        let mut knots = vec![start_t];
+        knots.extend(corners.iter().map(|&(t, _)| t));
+        knots.push(end_t);
+        Nurbs {degree: 1, knots, points: corners.into_iter().map(|(_, p)| p).collect()}
+    }
+
+    /// Returns each segment of the polyline as a line, along with its start and end points,
+    /// e.g. for building a separate edge for each segment using Topo::edge
+    pub fn segments(&self) -> Vec<(Curve, (Trivector, Trivector))> {
+        let corners: Vec<Trivector> = self.corners().into_iter().map(|[x, y, z]| point_from_xyz(x, y, z)).collect();
+        corners.windows(2).map(|pair| (Curve::line_from_two_points(pair[0], pair[1]), (pair[0], pair[1]))).collect()
+    }
+}
+
+// A trimmed curve is a bounded sub-range [t_start, t_end] of a base curve,
+// and shares the base curve's parameterization.
+// If the base curve is closed, the range may wrap around past its t_max,
+// in which case t_end is stored unwrapped (i.e. t_start < t_end <= t_start + period)
+// and parameter values are wrapped back onto the base curve when evaluating it.
+// The base curve is never itself a TrimmedCurve: trims of trims collapse into a single trim.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "T: Scalar + serde::Deserialize<'de>")))]
+pub struct TrimmedCurve<T = Float> {
+    pub curve: Box<Curve<T>>,
+    pub t_start: T,
+    pub t_end: T,
+}
+
+impl<T: Scalar> TrimmedCurve<T> {
+    // Length of the base curve's parameter range, if it is closed
+    fn period(&self) -> Option<T> {
+        if self.curve.closed() {
+            Some(self.curve.t_max().unwrap() - self.curve.t_min().unwrap())
+        } else {
+            None
+        }
+    }
+
+    // Convert a parameter value in this trim's (unwrapped) range
+    // to one on the base curve
+    fn wrap_t(&self, t: T) -> T {
+        match self.period() {
+            Some(period) if t > self.curve.t_max().unwrap() => t - period,
+            _ => t,
+        }
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.curve.d0(self.wrap_t(t))
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.curve.d1(self.wrap_t(t))
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.curve.d2(self.wrap_t(t))
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.curve.d3(self.wrap_t(t))
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        self.curve.derivatives(self.wrap_t(t), n)
+    }
+
+    pub fn closed(&self) -> bool {
+        false
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        Some(self.t_start)
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        Some(self.t_end)
+    }
+}
+
+impl TrimmedCurve {
+    // Trim the given curve to the parameter range [t_start, t_end].
+    // If the curve is closed, t_end may be less than t_start,
+    // in which case the range wraps around through t_max.
+    pub fn new(curve: Curve, t_start: Float, t_end: Float) -> TrimmedCurve {
+        match curve {
+            Curve::TrimmedCurve(inner) => {
+                // Express the new range in the base curve's parameterization,
+                // unwrapping it into the inner trim's range
+                let t_start = inner.unwrap_t(t_start);
+                let t_end = inner.unwrap_t(t_end);
+                assert!(t_end > t_start, "t_end must be greater than t_start");
+                assert!(t_start >= inner.t_start && t_end <= inner.t_end, "Trim range must lie within the curve being trimmed");
+                TrimmedCurve {curve: inner.curve, t_start, t_end}
+            },
+            curve => {
+                if let Some(t_min) = curve.t_min() {
+                    assert!(t_start >= t_min && t_end >= t_min, "Trim range must lie within the curve being trimmed");
+                }
+                if let Some(t_max) = curve.t_max() {
+                    assert!(t_start <= t_max && t_end <= t_max, "Trim range must lie within the curve being trimmed");
+                }
+                let t_end = if t_end < t_start {
+                    assert!(curve.closed(), "Unless the curve is closed, t_end must be greater than t_start");
+                    t_end + (curve.t_max().unwrap() - curve.t_min().unwrap())
+                } else {
+                    t_end
+                };
+                TrimmedCurve {curve: Box::new(curve), t_start, t_end}
+            },
+        }
+    }
+
+    // Convert a parameter value on the base curve
+    // to the equivalent one nearest to this trim's (unwrapped) range
+    fn unwrap_t(&self, t: Float) -> Float {
+        match self.period() {
+            Some(period) => {
+                // Shift into [t_start, t_start + period)
+                let t = t - ((t - self.t_start) / period).floor() * period;
+                // A point past the end of the range may really be just before its start
+                if t > self.t_end && self.t_start - (t - period) < t - self.t_end {
+                    t - period
+                } else {
+                    t
+                }
+            },
+            None => t,
+        }
+    }
+
+    pub fn reflect<T>(&self, entity: T) -> TrimmedCurve
+    where Vector: Reflect<T>, Bivector: Reflect<T>, Trivector: Reflect<T>, FullMultivector: Reflect<T>, T: Copy {
+        TrimmedCurve {curve: Box::new(self.curve.reflect(entity)), t_start: self.t_start, t_end: self.t_end}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> TrimmedCurve
+    where Vector: Transform<T>, Bivector: Transform<T>, Trivector: Transform<T>, FullMultivector: Transform<T>, T: Copy {
+        TrimmedCurve {curve: Box::new(self.curve.transform(entity)), t_start: self.t_start, t_end: self.t_end}
+    }
+
+    // Trim the reversed base curve to the reversed range
+    pub fn reversed(&self) -> TrimmedCurve {
+        let remap = self.reversal_remap();
+        TrimmedCurve {curve: Box::new(self.curve.reversed()), t_start: remap.apply(self.t_end), t_end: remap.apply(self.t_start)}
+    }
+
+    // The base curve's reversal remap, shifted by whole periods of a closed base curve
+    // to keep the start of the reversed range within the base curve's range
+    // (which reversal leaves where it was)
+    pub fn reversal_remap(&self) -> CurveRemap {
+        let mut remap = self.curve.reversal_remap();
+        if let Some(period) = self.period() {
+            let t_start = remap.apply(self.t_end);
+            remap.offset -= ((t_start - self.curve.t_min().unwrap()) / period).floor() * period;
+        }
+        remap
+    }
+
+    // Widen the range, extending the base curve if the range reaches past its ends.
+    // A trim of a closed curve is widened at most until it comes back around to meet itself,
+    // with the ends sharing what is left in proportion to how far each was to be extended.
+    pub fn extend(&self, start_delta: Float, end_delta: Float) -> TrimmedCurve {
+        let (mut t_start, mut t_end) = (self.t_start - start_delta, self.t_end + end_delta);
+        let curve = match self.period() {
+            Some(period) => {
+                let excess = t_end - t_start - period;
+                if excess > 0. {
+                    t_start += excess * start_delta / (start_delta + end_delta);
+                    t_end -= excess * end_delta / (start_delta + end_delta);
+                }
+                if t_start < self.curve.t_min().unwrap() {
+                    t_start += period;
+                    t_end += period;
+                }
+                self.curve.as_ref().clone()
+            },
+            None => {
+                let before = self.curve.t_min().map_or(0., |t_min| (t_min - t_start).max(0.));
+                let after = self.curve.t_max().map_or(0., |t_max| (t_end - t_max).max(0.));
+                let curve = self.curve.extend(before, after);
+                // Analytic curves can't be extended past where they are defined
+                t_start = t_start.max(curve.t_min().unwrap_or(-Float::INFINITY));
+                t_end = t_end.min(curve.t_max().unwrap_or(Float::INFINITY));
+                curve
+            },
+        };
+        TrimmedCurve {curve: Box::new(curve), t_start, t_end}
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        self.unwrap_t(self.curve.t(p))
+    }
+
+    pub fn length(&self, start_t: Float, end_t: Float) -> Float {
+        // If the range crosses the base curve's seam, measure each side of it separately
+        if end_t < start_t {
+            return -self.length(end_t, start_t);
+        }
+        match self.period() {
+            Some(period) if end_t > self.curve.t_max().unwrap() && start_t < self.curve.t_max().unwrap() => {
+                self.curve.length(start_t, self.curve.t_max().unwrap())
+                    + self.curve.length(self.curve.t_min().unwrap(), end_t - period)
+            },
+            _ => self.curve.length(self.wrap_t(start_t), self.wrap_t(end_t)),
+        }
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // If the range crosses the base curve's seam, bound each side of it separately
+        match self.period() {
+            Some(period) if end_t > self.curve.t_max().unwrap() && start_t < self.curve.t_max().unwrap() => {
+                let mut hull = self.curve.hull(start_t, self.curve.t_max().unwrap());
+                hull.extend(self.curve.hull(self.curve.t_min().unwrap(), end_t - period));
+                hull
+            },
+            _ => self.curve.hull(self.wrap_t(start_t), self.wrap_t(end_t)),
+        }
+    }
+
+    pub fn bounding_box(&self, start_t: Float, end_t: Float) -> BoundingBox {
+        // If the range crosses the base curve's seam, bound each side of it separately
+        match self.period() {
+            Some(period) if end_t > self.curve.t_max().unwrap() && start_t < self.curve.t_max().unwrap() => {
+                self.curve.bounding_box(start_t, self.curve.t_max().unwrap())
+                    .union(&self.curve.bounding_box(self.curve.t_min().unwrap(), end_t - period))
+            },
+            _ => self.curve.bounding_box(self.wrap_t(start_t), self.wrap_t(end_t)),
+        }
+    }
+
+    pub fn to_nurbs(&self, start_t: Float, end_t: Float) -> (Nurbs, Float) {
+        // If the range crosses the base curve's seam, convert each side of it separately
+        // and join them, shifting the second part so that its parameter continues past t_max
+        match self.period() {
+            Some(period) if end_t > self.curve.t_max().unwrap() && start_t < self.curve.t_max().unwrap() => {
+                let (first, first_error) = self.curve.to_nurbs(start_t, self.curve.t_max().unwrap());
+                let (mut second, second_error) = self.curve.to_nurbs(self.curve.t_min().unwrap(), end_t - period);
+                for knot in second.knots.iter_mut() {
+                    *knot += period;
+                }
+                (first.join(&second), first_error.max(second_error))
+            },
+            _ => self.curve.to_nurbs(self.wrap_t(start_t), self.wrap_t(end_t)),
+        }
+    }
+}
+
+// An offset curve is parameterized by a base curve, a signed distance, and a unit ideal point v (the reference direction.)
+// Each point on the base curve is moved by the distance along the unit normal n(t) = T(t) x v,
+// where T is the base curve's tangent.
+// For a planar curve with v normal to its plane, this offsets the curve within the plane,
+// to the right of its direction of travel when viewed looking down v (i.e. outward for a circle that turns counterclockwise.)
+// The parametric equation for the point r at position t on the offset curve is:
+// r(t) = c(t) + distance * n(t)
+// where c is the base curve. The offset curve shares the base curve's parameterization.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "T: Scalar + serde::Deserialize<'de>")))]
+pub struct Offset<T = Float> {
+    pub curve: Box<Curve<T>>,
+    pub distance: T,
+    pub v: Trivector<T>,
+}
+
+impl<T: Scalar> Offset<T> {
+    // Returns the unit normal n(t) and its first n derivatives,
+    // given the base curve's point and its first n + 1 derivatives.
+    // With w = c'(t) x v, n = w / |w|, and writing s = |w| and q = w . w = s^2,
+    // differentiating s s = q and s n = w k times by the Leibniz rule gives
+    // s^(k) = (q^(k) - sum_{i=1}^{k-1} C(k, i) s^(i) s^(k-i)) / 2s and n^(k) = (w^(k) - sum_{i=1}^{k} C(k, i) s^(i) n^(k-i)) / s
+    fn normal(&self, base: &[Trivector<T>], n: usize) -> Vec<[T; 3]> {
+        let v = xyz(self.v);
+        let w: Vec<[T; 3]> = base[1..=n + 1].iter().map(|&d| cross(xyz(d), v)).collect();
+        let s0 = length(w[0]);
+        assert!(s0 > T::from_f64(FLOAT_DIVISION_EPSILON), "offset normal is undefined where the curve is stationary or parallel to the reference direction");
+        let mut s = vec![s0];
+        let mut normals = vec![scale(w[0], T::one() / s0)];
+        for k in 1..=n {
+            let q = (0..=k).fold(T::zero(), |acc, i| acc + binomial::<T>(k, i) * dot(w[i], w[k - i]));
+            let sk = (1..k).fold(q, |acc, i| acc - binomial::<T>(k, i) * s[i] * s[k - i]) / (T::from_f64(2.) * s0);
+            s.push(sk);
+            let nk = (1..=k).fold(w[k], |acc, i| sub(acc, scale(normals[k - i], binomial::<T>(k, i) * s[i])));
+            normals.push(scale(nk, T::one() / s0));
+        }
+        normals
+    }
+
+    // Converts an offset vector (or a derivative of one) to an ideal point
+    fn ideal(&self, n: [T; 3]) -> Trivector<T> {
+        let [x, y, z] = scale(n, self.distance);
+        Trivector::new(x, y, z, T::zero())
+    }
+
+    pub fn derivatives(&self, t: T, n: usize) -> Vec<Trivector<T>> {
+        let base = self.curve.derivatives(t, n + 1);
+        self.normal(&base, n).into_iter().enumerate().map(|(k, normal)| match k {
+            0 => base[0].hat() + self.ideal(normal),
+            k => base[k] + self.ideal(normal),
+        }).collect()
+    }
+
+    pub fn d0(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 0)[0]
+    }
+
+    pub fn d1(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 1)[1]
+    }
+
+    pub fn d2(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 2)[2]
+    }
+
+    pub fn d3(&self, t: T) -> Trivector<T> {
+        self.derivatives(t, 3)[3]
+    }
+
+    pub fn closed(&self) -> bool {
+        self.curve.closed()
+    }
+
+    pub fn t_min(&self) -> Option<T> {
+        self.curve.t_min()
+    }
+
+    pub fn t_max(&self) -> Option<T> {
+        self.curve.t_max()
+    }
+}
+
+impl Offset {
+    // Offset the given curve by the given distance, using the reference direction v
+    pub fn new(curve: Curve, distance: Float, v: Trivector) -> Offset {
+        assert!(v.is_infinite(), "reference direction must be an ideal point");
+        Offset {curve: Box::new(curve), distance, v: v.ihat()}
+    }
+
+    pub fn reflect<T>(&self, entity: T) -> Offset
+    where Vector: Reflect<T>, Bivector: Reflect<T>, Trivector: Reflect<T>, FullMultivector: Reflect<T>, T: Copy {
+        // A reflection flips the sign of cross products, so flip the reference direction to keep the offset on the same side
+        Offset {curve: Box::new(self.curve.reflect(entity)), distance: self.distance, v: -self.v.reflect(entity)}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Offset
+    where Vector: Transform<T>, Bivector: Transform<T>, Trivector: Transform<T>, FullMultivector: Transform<T>, T: Copy {
+        Offset {curve: Box::new(self.curve.transform(entity)), distance: self.distance, v: self.v.transform(entity)}
+    }
+
+    // Offset the reversed base curve
+    pub fn reversed(&self) -> Offset {
+        // Reversing the base curve flips its tangent, and so the side that the normal is on
+        Offset {curve: Box::new(self.curve.reversed()), distance: -self.distance, v: self.v}
+    }
+
+    // The offset shares the base curve's parameterization
+    pub fn reversal_remap(&self) -> CurveRemap {
+        self.curve.reversal_remap()
+    }
+
+    pub fn t(&self, p: Trivector) -> Float {
+        // Start from the parameter of the nearest point on the base curve,
+        // or from the closest of a set of samples if the curve is bounded and one of them is closer
+        // (an offset may fold back on itself, so the base curve's parameter can be far off),
+        // then refine it with Newton's method on the squared distance
+        const SAMPLES: usize = 64;
+        let target = xyz(p.hat());
+        let distance_to = |t: Float| length(sub(xyz(self.d0(t)), target));
+        let mut t = self.curve.t(p);
+        if let (Some(t_min), Some(t_max)) = (self.t_min(), self.t_max()) {
+            for i in 0..=SAMPLES {
+                let sample = t_min + (t_max - t_min) * i as Float / SAMPLES as Float;
+                if distance_to(sample) < distance_to(t) {
+                    t = sample;
+                }
+            }
+        }
+        for _ in 0..20 {
+            let offset = sub(xyz(self.d0(t)), target);
+            let d1 = xyz(self.d1(t));
+            let denominator = dot(xyz(self.d2(t)), offset) + dot(d1, d1);
+            if denominator.abs() < FLOAT_DIVISION_EPSILON {
+                break;
+            }
+            let step = dot(d1, offset) / denominator;
+            t -= step;
+            if (step * length(d1)).abs() < 0.01 * EPSILON_COINCIDENT_DISTANCE {
+                break;
+            }
+        }
+
+        // Newton's method may have wandered past the seam of a closed curve
+        match (self.closed(), self.t_min(), self.t_max()) {
+            (true, Some(t_min), Some(t_max)) => t_min + (t - t_min).rem_euclid(t_max - t_min),
+            _ => t,
+        }
+    }
+
+    pub fn hull(&self, start_t: Float, end_t: Float) -> Vec<Trivector> {
+        // The normal is always perpendicular to v, so the offset curve lies within
+        // the base curve's hull shifted by the normal in the middle of the range,
+        // and grown by a square perpendicular to v that covers how far the normal turns over the range.
+        // The rate of turning is estimated from samples, with some margin.
+        // Where the normal may turn a long way, the square instead has half-width |distance| around the unshifted hull,
+        // which covers every possible normal, so that the hull still shrinks when the range is subdivided.
+        let v = xyz(self.v);
+        let e1 = any_perpendicular(v);
+        let e2 = cross(v, e1);
+        let turn_rate = (0..=4).map(|i| {
+            let t = start_t + (end_t - start_t) * i as Float / 4.;
+            length(self.normal(&self.curve.derivatives(t, 2), 1)[1])
+        }).fold(0., Float::max);
+        let spread = 1.5 * turn_rate * 0.5 * (end_t - start_t);
+        let (shift, d) = if spread < 1. {
+            (scale(self.normal(&self.curve.derivatives(0.5 * (start_t + end_t), 1), 0)[0], self.distance), self.distance.abs() * spread)
+        } else {
+            ([0., 0., 0.], self.distance.abs())
+        };
+        self.curve.hull(start_t, end_t).into_iter().flat_map(|p| {
+            let p = add(xyz(p.hat()), shift);
+            [(1., 1.), (1., -1.), (-1., 1.), (-1., -1.)].iter().map(|&(a, b)| {
+                let q = add(p, add(scale(e1, a * d), scale(e2, b * d)));
+                point_from_xyz(q[0], q[1], q[2])
+            }).collect::<Vec<_>>()
+        }).collect()
+    }
+
+    /// Get the parameter values on the range [start_t, end_t] at which the offset curve has a cusp,
+    /// i.e. where its direction of travel reverses relative to the base curve.
+    /// For a planar curve, this is where the distance equals the radius of curvature on the inside of a bend.
+    pub fn cusps(&self, start_t: Float, end_t: Float) -> Vec<Float> {
+        const SAMPLES: usize = 256;
+        let g = |t: Float| dot(xyz(self.d1(t)), xyz(self.curve.d1(t)));
+
+        let ts: Vec<Float> = (0..=SAMPLES).map(|i| start_t + (end_t - start_t) * i as Float / SAMPLES as Float).collect();
+        let mut cusps = vec![];
+        for pair in ts.windows(2) {
+            let (mut a, mut b) = (pair[0], pair[1]);
+            let (mut ga, gb) = (g(a), g(b));
+            if ga * gb > 0. || (ga == 0. && a != start_t) {
+                continue;
+            }
+            // Bisect down to the sign change
+            if gb != 0. || ga == 0. {
+                for _ in 0..60 {
+                    let middle = 0.5 * (a + b);
+                    let gm = g(middle);
+                    if ga * gm <= 0. {
+                        b = middle;
+                    } else {
+                        a = middle;
+                        ga = gm;
+                    }
+                }
+            }
+            cusps.push(0.5 * (a + b));
+        }
+        cusps
+    }
+
+    /// Get the pairs of parameter values (t0, t1) with t0 < t1 on the range [start_t, end_t]
+    /// at which the offset curve crosses itself.
+    /// Offsetting a curve by more than its radius of curvature, or across a narrow gap, makes loops that intersect this way.
+    pub fn self_intersections(&self, start_t: Float, end_t: Float) -> Vec<(Float, Float)> {
+        const SAMPLES: usize = 256;
+
+        // Look for crossings in a polyline through the curve, as seen looking down v
+        let v = xyz(self.v);
+        let e1 = any_perpendicular(v);
+        let e2 = cross(v, e1);
+        let project = |p: [Float; 3]| [dot(p, e1), dot(p, e2)];
+        let ts: Vec<Float> = (0..=SAMPLES).map(|i| start_t + (end_t - start_t) * i as Float / SAMPLES as Float).collect();
+        let pts: Vec<[Float; 2]> = ts.iter().map(|&t| project(xyz(self.d0(t)))).collect();
+        let closed = self.closed() && self.curve.t_min() == Some(start_t) && self.curve.t_max() == Some(end_t);
+
+        let mut result: Vec<(Float, Float)> = vec![];
+        for i in 0..SAMPLES {
+            for j in i + 2..SAMPLES {
+                if closed && i == 0 && j == SAMPLES - 1 {
+                    // Adjacent across the seam
+                    continue;
+                }
+                let (a0, a1, b0, b1) = (pts[i], pts[i + 1], pts[j], pts[j + 1]);
+                let da = [a1[0] - a0[0], a1[1] - a0[1]];
+                let db = [b1[0] - b0[0], b1[1] - b0[1]];
+                let det = da[0] * db[1] - da[1] * db[0];
+                if det.abs() < FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON {
+                    continue;
+                }
+                let alpha = ((b0[0] - a0[0]) * db[1] - (b0[1] - a0[1]) * db[0]) / det;
+                let beta = ((b0[0] - a0[0]) * da[1] - (b0[1] - a0[1]) * da[0]) / det;
+                if !(0. ..=1.).contains(&alpha) || !(0. ..=1.).contains(&beta) {
+                    continue;
+                }
+
+                // Refine with Newton's method on the projected difference between the two points
+                let mut s = ts[i] + alpha * (ts[i + 1] - ts[i]);
+                let mut u = ts[j] + beta * (ts[j + 1] - ts[j]);
+                for _ in 0..20 {
+                    let f = project(sub(xyz(self.d0(s)), xyz(self.d0(u))));
+                    let ds = project(xyz(self.d1(s)));
+                    let du = project(xyz(self.d1(u)));
+                    let det = -ds[0] * du[1] + du[0] * ds[1];
+                    if det.abs() < FLOAT_DIVISION_EPSILON {
+                        break;
+                    }
+                    let step_s = (-f[0] * du[1] + du[0] * f[1]) / det;
+                    let step_u = (ds[0] * f[1] - ds[1] * f[0]) / det;
+                    s -= step_s;
+                    u -= step_u;
+                    if step_s.abs() + step_u.abs() < FLOAT_DIVISION_EPSILON {
+                        break;
+                    }
+                }
+
+                // For a space curve, the crossing seen looking down v may not be a real intersection
+                let gap = length(sub(xyz(self.d0(s)), xyz(self.d0(u))));
+                let duplicate = result.iter().any(|&(s0, u0)| (s0 - s).abs() < FLOAT_DIVISION_EPSILON.sqrt() && (u0 - u).abs() < FLOAT_DIVISION_EPSILON.sqrt());
+                if gap < EPSILON_COINCIDENT_DISTANCE && !duplicate && (u - s).abs() > FLOAT_DIVISION_EPSILON.sqrt() {
+                    result.push((s.min(u), s.max(u)));
+                }
+            }
+        }
+        result
+    }
+}
+
+/// A place where two curves meet, as found by intersect
+#[derive(Debug, Clone)]
+pub enum CurveIntersection {
+    /// The curves cross or touch at a single point,
+    /// which is at parameter t0 on the first curve and t1 on the second
+    Point {t0: Float, t1: Float, point: Trivector},
+    /// The curves coincide from parameter t0.0 to t0.1 on the first curve (where t0.0 < t0.1),
+    /// which correspond to parameters t1.0 and t1.1 on the second curve (which may run backwards.)
+    /// The ends are infinite where both curves are unbounded,
+    /// and t1 may run past the seam of a closed second curve.
+    Overlap {t0: (Float, Float), t1: (Float, Float)},
+}
+
+/// Find where two curves meet, in order of increasing parameter on the first curve.
+/// Lines and circles (or trims of them) are intersected exactly.
+/// Other curves are searched by subdividing wherever their hulls overlap, and refining with Newton's method.
+/// Where an unbounded curve meets a bounded one, only the part near the bounded curve is searched;
+/// where both are unbounded, only the parts near each other are searched.
+pub fn intersect(c0: &Curve, c1: &Curve) -> Vec<CurveIntersection> {
+    let mut result = match (exact_base(c0), exact_base(c1)) {
+        (Some(base0), Some(base1)) => intersect_exact(c0, base0, c1, base1),
+        _ => intersect_numeric(c0, c1),
+    };
+    result.sort_by(|a, b| {
+        let start = |x: &CurveIntersection| match x {
+            CurveIntersection::Point {t0, ..} => *t0,
+            CurveIntersection::Overlap {t0, ..} => t0.0,
+        };
+        start(a).partial_cmp(&start(b)).unwrap()
+    });
+    result
+}
+
+// Returns the line or circle underlying a curve, looking through a trim, if it is one
+fn exact_base(c: &Curve) -> Option<&Curve> {
+    match c {
+        Curve::Line(_) | Curve::Circle(_) => Some(c),
+        Curve::TrimmedCurve(x) => match x.curve.as_ref() {
+            Curve::Line(_) | Curve::Circle(_) => Some(x.curve.as_ref()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Converts a parameter value on a curve's line or circle (see exact_base) to one on the curve itself,
+// or None if it falls outside of the curve's range
+fn from_base_t(c: &Curve, t: Float) -> Option<Float> {
+    let t = match c {
+        Curve::TrimmedCurve(x) => x.unwrap_t(t),
+        _ => t,
+    };
+    let t = match c.t_min() {
+        Some(t_min) if t < t_min - FLOAT_DIVISION_EPSILON => return None,
+        Some(t_min) => t.max(t_min),
+        None => t,
+    };
+    match c.t_max() {
+        Some(t_max) if t > t_max + FLOAT_DIVISION_EPSILON => None,
+        Some(t_max) => Some(t.min(t_max)),
+        None => Some(t),
+    }
+}
+
+// How two lines or circles meet: at some points (given by the parameter on each and the position),
+// or everywhere, with parameters related by t1 = offset + scale * t0
+enum ExactIntersection {
+    Points(Vec<(Float, Float, Trivector)>),
+    Coincident {offset: Float, scale: Float},
+}
+
+// Intersects two curves whose underlying lines or circles are base0 and base1,
+// keeping only the parts of the result within the curves' ranges
+fn intersect_exact(c0: &Curve, base0: &Curve, c1: &Curve, base1: &Curve) -> Vec<CurveIntersection> {
+    let exact = match (base0, base1) {
+        (Curve::Line(l0), Curve::Line(l1)) => intersect_lines(l0, l1),
+        (Curve::Line(l), Curve::Circle(c)) => ExactIntersection::Points(intersect_line_circle(l, c)),
+        (Curve::Circle(c), Curve::Line(l)) => ExactIntersection::Points(
+            intersect_line_circle(l, c).into_iter().map(|(t_line, t_circle, p)| (t_circle, t_line, p)).collect()
+        ),
+        (Curve::Circle(c0), Curve::Circle(c1)) => intersect_circles(c0, c1),
+        _ => unreachable!(),
+    };
+
+    match exact {
+        ExactIntersection::Points(points) => points.into_iter().filter_map(|(t0, t1, point)| {
+            Some(CurveIntersection::Point {t0: from_base_t(c0, t0)?, t1: from_base_t(c1, t1)?, point})
+        }).collect(),
+        ExactIntersection::Coincident {offset, scale} => {
+            // Find where the mapped range of the first curve lies within the range of the second.
+            // Closed second curves can be followed past their seam, so only need their range to be checked if they are trimmed
+            let range0 = (c0.t_min().unwrap_or(-Float::INFINITY), c0.t_max().unwrap_or(Float::INFINITY));
+            let (range1, shifts) = match (c1.closed(), base1.closed()) {
+                (true, _) => ((-Float::INFINITY, Float::INFINITY), vec![0.]),
+                // A trim of a closed curve may be met at any number of turns away
+                (false, true) => {
+                    let period = base1.t_max().unwrap() - base1.t_min().unwrap();
+                    ((c1.t_min().unwrap(), c1.t_max().unwrap()), (-2..=2).map(|k| k as Float * period).collect())
+                },
+                (false, false) => ((c1.t_min().unwrap_or(-Float::INFINITY), c1.t_max().unwrap_or(Float::INFINITY)), vec![0.]),
+            };
+
+            let mut result = Vec::new();
+            for shift in shifts {
+                let offset = offset + shift;
+                let (a, b) = ((range1.0 - offset) / scale, (range1.1 - offset) / scale);
+                let start_t = range0.0.max(a.min(b));
+                let end_t = range0.1.min(a.max(b));
+                if end_t - start_t > FLOAT_DIVISION_EPSILON {
+                    result.push(CurveIntersection::Overlap {t0: (start_t, end_t), t1: (offset + scale * start_t, offset + scale * end_t)});
+                } else if end_t - start_t >= -FLOAT_DIVISION_EPSILON {
+                    // The ranges only touch at their ends
+                    result.push(CurveIntersection::Point {t0: start_t, t1: offset + scale * start_t, point: c0.d0(start_t)});
+                }
+            }
+            result
+        },
+    }
+}
+
+fn intersect_lines(l0: &Line, l1: &Line) -> ExactIntersection {
+    let (v0, v1) = (l0.d1(0.), l1.d1(0.));
+    let normal = cross(xyz(v0), xyz(v1));
+    if length(normal) <= FLOAT_DIVISION_EPSILON * length(xyz(v0)) * length(xyz(v1)) {
+        // Parallel lines either coincide everywhere or nowhere
+        let (offset, distance, _) = l1.closest_point(l0.p0);
+        if distance > EPSILON_COINCIDENT_DISTANCE {
+            return ExactIntersection::Points(vec![]);
+        }
+        return ExactIntersection::Coincident {offset, scale: dot(xyz(v0), xyz(v1)) / dot(xyz(v1), xyz(v1))};
+    }
+
+    // Meet the second line with the plane containing the first line and their common normal
+    let plane = l0.p0 & v0 & inf_point_from_xyz(normal[0], normal[1], normal[2]);
+    let p = plane ^ (l1.p0 & v1);
+    let (t0, distance, _) = l0.closest_point(p);
+    if distance > EPSILON_COINCIDENT_DISTANCE {
+        // The lines are skew
+        return ExactIntersection::Points(vec![]);
+    }
+    let (t1, _, point) = l1.closest_point(p);
+    ExactIntersection::Points(vec![(t0, t1, point)])
+}
+
+// Returns the center, unit axis direction, and radius of a circle
+fn circle_geometry(c: &Circle) -> ([Float; 3], [Float; 3], Float) {
+    let p0 = xyz(dehomogenize(c.p0));
+    let center = foot_on_line(c.a, p0);
+    (center, unit(xyz(c.a.direction())).unwrap(), length(sub(p0, center)))
+}
+
+// Returns the points where a line through p with direction v, lying in the plane of the given circle, meets the circle
+fn line_in_plane_meets_circle(p: [Float; 3], v: [Float; 3], center: [Float; 3], radius: Float) -> Vec<[Float; 3]> {
+    let v = unit(v).unwrap();
+    let foot = add(p, scale(v, dot(sub(center, p), v)));
+    let height = length(sub(foot, center));
+    if height > radius + EPSILON_COINCIDENT_DISTANCE {
+        vec![]
+    } else if (height - radius).abs() <= EPSILON_COINCIDENT_DISTANCE {
+        // The line is tangent to the circle
+        vec![foot]
+    } else {
+        let half_chord = (radius * radius - height * height).sqrt();
+        vec![sub(foot, scale(v, half_chord)), add(foot, scale(v, half_chord))]
+    }
+}
+
+fn intersect_line_circle(line: &Line, circle: &Circle) -> Vec<(Float, Float, Trivector)> {
+    let (center, axis, radius) = circle_geometry(circle);
+    let p = xyz(dehomogenize(line.p0));
+    let v = xyz(line.d1(0.));
+
+    let points = if dot(v, axis).abs() > FLOAT_DIVISION_EPSILON * length(v) {
+        // The line crosses the circle's plane at a single point, which must be on the circle
+        let plane = circle.a | point_from_xyz(center[0], center[1], center[2]);
+        let crossing = xyz(dehomogenize(plane ^ (line.p0 & line.d1(0.))));
+        if (length(sub(crossing, center)) - radius).abs() <= EPSILON_COINCIDENT_DISTANCE {
+            vec![crossing]
+        } else {
+            vec![]
+        }
+    } else if dot(sub(p, center), axis).abs() > EPSILON_COINCIDENT_DISTANCE {
+        // The line is parallel to the circle's plane, but not in it
+        vec![]
+    } else {
+        line_in_plane_meets_circle(p, v, center, radius)
+    };
+
+    points.into_iter().map(|[x, y, z]| {
+        let point = point_from_xyz(x, y, z);
+        (line.closest_point(point).0, circle.t(point), point)
+    }).collect()
+}
+
+fn intersect_circles(c0: &Circle, c1: &Circle) -> ExactIntersection {
+    let (center0, axis0, radius0) = circle_geometry(c0);
+    let (center1, axis1, radius1) = circle_geometry(c1);
+
+    let points = if length(cross(axis0, axis1)) <= FLOAT_DIVISION_EPSILON {
+        if dot(sub(center1, center0), axis0).abs() > EPSILON_COINCIDENT_DISTANCE {
+            // The circles lie in parallel planes
+            return ExactIntersection::Points(vec![]);
+        }
+        let distance = length(sub(center1, center0));
+        if distance <= EPSILON_COINCIDENT_DISTANCE {
+            if (radius0 - radius1).abs() > EPSILON_COINCIDENT_DISTANCE {
+                // The circles are concentric
+                return ExactIntersection::Points(vec![]);
+            }
+            // The circles coincide, turning in the same or opposite directions.
+            // Circle::t loses precision near a half turn, so measure the angle to c0's start with atan2
+            let u = sub(xyz(dehomogenize(c1.p0)), center1);
+            let w = xyz(c1.d1(0.));
+            let q = sub(xyz(dehomogenize(c0.p0)), center1);
+            let offset = (dot(q, w) / length(w)).atan2(dot(q, u) / length(u)).rem_euclid(2. * PI);
+            return ExactIntersection::Coincident {offset, scale: dot(axis0, axis1).signum()};
+        }
+
+        // The intersections lie on the radical line, which is perpendicular to the line between the centers
+        let u = scale(sub(center1, center0), 1. / distance);
+        let along = (radius0 * radius0 - radius1 * radius1 + distance * distance) / (2. * distance);
+        line_in_plane_meets_circle(add(center0, scale(u, along)), cross(axis0, u), center0, radius0)
+    } else {
+        // Any intersections lie on the line where the circles' planes meet
+        let plane0 = c0.a | point_from_xyz(center0[0], center0[1], center0[2]);
+        let plane1 = c1.a | point_from_xyz(center1[0], center1[1], center1[2]);
+        let meet = plane0 ^ plane1;
+        line_in_plane_meets_circle(foot_on_line(meet, center0), xyz(meet.direction()), center0, radius0)
+            .into_iter()
+            .filter(|&p| (length(sub(p, center1)) - radius1).abs() <= EPSILON_COINCIDENT_DISTANCE)
+            .collect()
+    };
+
+    ExactIntersection::Points(points.into_iter().map(|[x, y, z]| {
+        let point = point_from_xyz(x, y, z);
+        (c0.t(point), c1.t(point), point)
+    }).collect())
+}
+
+// Returns the corners of the axis-aligned box around a set of points
+fn points_box(points: &[Trivector]) -> ([Float; 3], [Float; 3]) {
+    points.iter().map(|&p| xyz(dehomogenize(p))).fold(
+        ([Float::INFINITY; 3], [-Float::INFINITY; 3]),
+        |(low, high), p| (
+            [low[0].min(p[0]), low[1].min(p[1]), low[2].min(p[2])],
+            [high[0].max(p[0]), high[1].max(p[1]), high[2].max(p[2])],
+        ),
+    )
+}
+
+fn boxes_overlap(a: ([Float; 3], [Float; 3]), b: ([Float; 3], [Float; 3]), tolerance: Float) -> bool {
+    (0..3).all(|i| a.0[i] <= b.1[i] + tolerance && b.0[i] <= a.1[i] + tolerance)
+}
+
+fn box_size(b: ([Float; 3], [Float; 3])) -> Float {
+    length(sub(b.1, b.0))
+}
+
+// Returns finite parameter ranges in which to look for intersections between two curves.
+// A bounded curve is searched over its whole range.
+// An unbounded curve starts from the part nearest the middle of the other curve,
+// and grows outwards for as long as its ends lie within a region around the other curve:
+// the bounding box of the other curve if it is bounded,
+// or else twice the size of the box around the starting parts of both curves.
+fn intersection_search_ranges(c0: &Curve, c1: &Curve) -> ((Float, Float), (Float, Float)) {
+    let initial_range = |c: &Curve, other: &Curve| -> (Float, Float) {
+        match (c.t_min(), c.t_max()) {
+            (Some(t_min), Some(t_max)) => (t_min, t_max),
+            (t_min, t_max) => {
+                let other_t = match (other.t_min(), other.t_max()) {
+                    (Some(t_min), Some(t_max)) => 0.5 * (t_min + t_max),
+                    (Some(t), None) | (None, Some(t)) => t,
+                    (None, None) => 0.,
+                };
+                let t = c.closest_point(other.d0(other_t)).map_or(0., |(t, _, _)| t);
+                match (t_min, t_max) {
+                    (Some(t_min), None) => (t_min, t.max(t_min) + 1.),
+                    (None, Some(t_max)) => (t.min(t_max) - 1., t_max),
+                    _ => (t - 1., t + 1.),
+                }
+            },
+        }
+    };
+    let curves = [c0, c1];
+    let mut ranges = [initial_range(c0, c1), initial_range(c1, c0)];
+    let boxes = [0, 1].map(|i| points_box(&curves[i].hull(ranges[i].0, ranges[i].1)));
+    let bounded = [0, 1].map(|i| curves[i].t_min().is_some() && curves[i].t_max().is_some());
+
+    let both = (
+        [0, 1, 2].map(|k| boxes[0].0[k].min(boxes[1].0[k])),
+        [0, 1, 2].map(|k| boxes[0].1[k].max(boxes[1].1[k])),
+    );
+    let center = scale(add(both.0, both.1), 0.5);
+    let surroundings = (sub(both.0, sub(center, both.0)), add(both.1, sub(both.1, center)));
+
+    for i in 0..2 {
+        let region = if bounded[1 - i] { boxes[1 - i] } else { surroundings };
+        let c = curves[i];
+        for (unbounded, sign) in [(c.t_min().is_none(), -1.), (c.t_max().is_none(), 1.)] {
+            if !unbounded {
+                continue;
+            }
+            let mut step = 1.;
+            for _ in 0..64 {
+                let t = if sign < 0. { ranges[i].0 } else { ranges[i].1 };
+                let end = xyz(c.d0(t));
+                let next = xyz(c.d0(t + sign * step));
+                if !boxes_overlap((end, end), region, EPSILON_COINCIDENT_DISTANCE) || !next.iter().all(|x| x.is_finite()) {
+                    break;
+                }
+                if sign < 0. { ranges[i].0 -= step } else { ranges[i].1 += step }
+                step *= 2.;
+            }
+        }
+    }
+    (ranges[0], ranges[1])
+}
+
+// Refines parameter values s on c0 and t on c1 towards a point where the curves meet,
+// using Gauss-Newton iterations to minimize |c0(s) - c1(t)|^2, within the given ranges.
+// Returns None if the curves don't meet there.
+fn refine_intersection(c0: &Curve, c1: &Curve, mut s: Float, mut t: Float, range0: (Float, Float), range1: (Float, Float)) -> Option<(Float, Float, Trivector)> {
+    for _ in 0..64 {
+        let f = sub(xyz(c0.d0(s)), xyz(c1.d0(t)));
+        let a = xyz(c0.d1(s));
+        let b = xyz(c1.d1(t));
+        // Solve the normal equations for the step (ds, dt) that minimizes |f + a * ds - b * dt|^2,
+        // with a little damping for when the curves are tangent
+        let (aa, ab, bb) = (dot(a, a), dot(a, b), dot(b, b));
+        let damping = FLOAT_DIVISION_EPSILON * (aa + bb);
+        let (aa, bb) = (aa + damping, bb + damping);
+        let det = aa * bb - ab * ab;
+        if det.abs() < Float::MIN_POSITIVE {
+            break;
+        }
+        let (rs, rt) = (-dot(a, f), dot(b, f));
+        let ds = (bb * rs + ab * rt) / det;
+        let dt = (ab * rs + aa * rt) / det;
+        let (next_s, next_t) = ((s + ds).clamp(range0.0, range0.1), (t + dt).clamp(range1.0, range1.1));
+        let converged = (next_s - s).abs() < FLOAT_DIVISION_EPSILON * 1e-3 && (next_t - t).abs() < FLOAT_DIVISION_EPSILON * 1e-3;
+        s = next_s;
+        t = next_t;
+        if converged {
+            break;
+        }
+    }
+
+    // Where the curves are tangent, the iterations above only locate the point of contact roughly,
+    // so instead find where the distance between them is least
+    let (a, b) = (xyz(c0.d1(s)), xyz(c1.d1(t)));
+    if length(cross(a, b)) < 1e-3 * length(a) * length(b) {
+        let reach = EPSILON_APPROXIMATION.sqrt() / length(a).max(FLOAT_DIVISION_EPSILON);
+        let distance = |s: Float| project_near(c1, xyz(c0.d0(s)), t, range1);
+        // Golden section search
+        let ratio = 0.5 * (5. as Float).sqrt() - 0.5;
+        let (mut low, mut high) = ((s - reach).max(range0.0), (s + reach).min(range0.1));
+        for _ in 0..64 {
+            let (x0, x1) = (high - ratio * (high - low), low + ratio * (high - low));
+            if distance(x0).1 < distance(x1).1 { high = x1 } else { low = x0 }
+        }
+        let polished = 0.5 * (low + high);
+        let (polished_t, polished_distance) = distance(polished);
+        if polished_distance < length(sub(xyz(c0.d0(s)), xyz(c1.d0(t)))) {
+            s = polished;
+            t = polished_t;
+        }
+    }
+
+    let point = c0.d0(s);
+    if vertices_coincident(point, c1.d0(t)) {
+        Some((s, t, point))
+    } else {
+        None
+    }
+}
+
+// Finds the parameter on a curve of the point nearest p, starting from t and staying within the given range
+fn project_near(c: &Curve, p: [Float; 3], mut t: Float, range: (Float, Float)) -> (Float, Float) {
+    for _ in 0..32 {
+        let r = sub(xyz(c.d0(t)), p);
+        let d1 = xyz(c.d1(t));
+        let df = dot(d1, d1) + dot(r, xyz(c.d2(t)));
+        if df <= FLOAT_DIVISION_EPSILON {
+            break;
+        }
+        let next_t = (t - dot(r, d1) / df).clamp(range.0, range.1);
+        let converged = (next_t - t).abs() < FLOAT_DIVISION_EPSILON * 1e-3;
+        t = next_t;
+        if converged {
+            break;
+        }
+    }
+    (t, length(sub(xyz(c.d0(t)), p)))
+}
+
+fn intersect_numeric(c0: &Curve, c1: &Curve) -> Vec<CurveIntersection> {
+    let (range0, range1) = intersection_search_ranges(c0, c1);
+    let whole0 = points_box(&c0.hull(range0.0, range0.1));
+    let whole1 = points_box(&c1.hull(range1.0, range1.1));
+    // Pieces are subdivided until they are this small
+    let leaf_size = 1e-3 * box_size(whole0).max(box_size(whole1));
+
+    // Finds the parameter on c1 of the point nearest c0(s), starting from the nearest of the given guesses
+    let on_c1 = |s: Float, guesses: &[Float]| {
+        let p = xyz(c0.d0(s));
+        guesses.iter().map(|&t| project_near(c1, p, t, range1)).min_by(|a, b| a.1.partial_cmp(&b.1).unwrap()).unwrap()
+    };
+
+    let mut points: Vec<(Float, Float, Trivector)> = Vec::new();
+    let mut overlaps: Vec<(Float, Float)> = Vec::new();
+    let mut stack = vec![(range0, range1, 0)];
+    while let Some(((a0, b0), (a1, b1), depth)) = stack.pop() {
+        let box0 = points_box(&c0.hull(a0, b0));
+        let box1 = points_box(&c1.hull(a1, b1));
+        if !boxes_overlap(box0, box1, EPSILON_COINCIDENT_DISTANCE) {
+            continue;
+        }
+        let (m0, m1) = (0.5 * (a0 + b0), 0.5 * (a1 + b1));
+        let (size0, size1) = (box_size(box0), box_size(box1));
+        if (size0 > leaf_size || size1 > leaf_size) && depth < 48 {
+            if size0 >= size1 {
+                stack.push(((a0, m0), (a1, b1), depth + 1));
+                stack.push(((m0, b0), (a1, b1), depth + 1));
+            } else {
+                stack.push(((a0, b0), (a1, m1), depth + 1));
+                stack.push(((a0, b0), (m1, b1), depth + 1));
+            }
+            continue;
+        }
+
+        // If this whole piece of c0 lies on c1, the curves overlap here
+        if b0 > a0 && [a0, m0, b0].iter().all(|&s| on_c1(s, &[a1, m1, b1]).1 <= EPSILON_COINCIDENT_DISTANCE) {
+            overlaps.push((a0, b0));
+            continue;
+        }
+        if let Some((s, t, point)) = refine_intersection(c0, c1, m0, m1, range0, range1) {
+            // Neighboring pieces find the same intersections,
+            // though tangent intersections can only be located to within about EPSILON_APPROXIMATION
+            if !points.iter().any(|&(_, _, other)| length(sub(xyz(point), xyz(other))) <= EPSILON_APPROXIMATION) {
+                points.push((s, t, point));
+            }
+        }
+    }
+
+    // Merge the overlapping pieces into ranges,
+    // and extend each range to where the curves actually part, which may be partway through a piece
+    // (each range also keeps the sizes of its first and last pieces, since that is as far as the curves can go before parting)
+    overlaps.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let mut merged: Vec<(Float, Float, Float, Float)> = Vec::new();
+    for (start_t, end_t) in overlaps {
+        match merged.last_mut() {
+            Some(last) if start_t <= last.1 => {
+                if end_t > last.1 {
+                    last.1 = end_t;
+                    last.3 = end_t - start_t;
+                }
+            },
+            _ => merged.push((start_t, end_t, end_t - start_t, end_t - start_t)),
+        }
+    }
+    let guesses = |s: Float| [range1.0, 0.5 * (range1.0 + range1.1), range1.1, c1.t(c0.d0(s)).clamp(range1.0, range1.1)];
+    let coincident = |s: Float| on_c1(s, &guesses(s)).1 <= EPSILON_COINCIDENT_DISTANCE;
+    let extend = |inside: Float, limit: Float| {
+        // Bisect between a parameter where the curves coincide and one where they may not
+        let (mut inside, mut outside) = (inside, limit);
+        if coincident(outside) {
+            return outside;
+        }
+        for _ in 0..48 {
+            let middle = 0.5 * (inside + outside);
+            if coincident(middle) { inside = middle } else { outside = middle }
+        }
+        inside
+    };
+    let ranges: Vec<(Float, Float)> = merged.into_iter().map(|(start_t, end_t, first_size, last_size)| {
+        (extend(start_t, (start_t - first_size).max(range0.0)), extend(end_t, (end_t + last_size).min(range0.1)))
+    }).collect();
+
+    let mut result: Vec<CurveIntersection> = ranges.iter().map(|&(start_t, end_t)| CurveIntersection::Overlap {
+        t0: (start_t, end_t),
+        t1: (on_c1(start_t, &guesses(start_t)).0, on_c1(end_t, &guesses(end_t)).0),
+    }).collect();
+    result.extend(points.into_iter()
+        .filter(|&(s, _, _)| !ranges.iter().any(|&(start_t, end_t)| s >= start_t - FLOAT_DIVISION_EPSILON && s <= end_t + FLOAT_DIVISION_EPSILON))
+        .map(|(t0, t1, point)| CurveIntersection::Point {t0, t1, point}));
+    result
+}
+
+/// How the parameter of a curve maps onto the parameter of a coincident curve: t1 = offset + scale * t0.
+/// On a closed second curve, t1 may need to be wrapped into its range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveRemap {
+    pub offset: Float,
+    pub scale: Float,
+}
+
+impl CurveRemap {
+    /// The remap of a curve onto itself
+    pub fn identity() -> CurveRemap {
+        CurveRemap {offset: 0., scale: 1.}
+    }
+
+    pub fn apply(&self, t: Float) -> Float {
+        self.offset + self.scale * t
+    }
+
+    pub fn direction(&self) -> Direction {
+        if self.scale < 0. { Direction::Reverse } else { Direction::Forward }
+    }
+}
+
+/// Determine whether two curves trace out the same points over their whole ranges.
+/// If so, returns whether they travel in the same direction,
+/// along with the map from parameters on the first curve to parameters on the second.
+/// Curves that coincide only under a parameterization that isn't linear (e.g. NURBS with different knots)
+/// are not considered coincident, since their parameters can't be remapped.
+pub fn curves_coincident(c0: &Curve, c1: &Curve) -> Option<(Direction, CurveRemap)> {
+    // Some((Forward, remap)) => Curves are coincident and have the same travel direction
+    // Some((Reverse, remap)) => Curves are coincident but have opposing travel directions
+    // None => Curves are not coincident
+
+    let bounded_ends = |c: &Curve| c.t_min().is_some() as u8 + c.t_max().is_some() as u8;
+    if c0.closed() != c1.closed() || bounded_ends(c0) != bounded_ends(c1) {
+        return None;
+    }
+
+    let candidates = match (c0, c1) {
+        (Curve::Line(l0), Curve::Line(l1)) => match intersect_lines(l0, l1) {
+            ExactIntersection::Coincident {offset, scale} => vec![CurveRemap {offset, scale}],
+            ExactIntersection::Points(_) => return None,
+        },
+        (Curve::Circle(c0), Curve::Circle(c1)) => match intersect_circles(c0, c1) {
+            ExactIntersection::Coincident {offset, scale} => vec![CurveRemap {offset, scale}],
+            ExactIntersection::Points(_) => return None,
+        },
+        _ => coincident_remap_candidates(c0, c1),
+    };
+
+    let remap = candidates.into_iter().find(|remap| remap_is_coincident(c0, c1, remap))?;
+    let remap = if c1.closed() {
+        // Report the offset within the range of the closed curve
+        let (t_min, t_max) = (c1.t_min().unwrap(), c1.t_max().unwrap());
+        CurveRemap {offset: t_min + (remap.offset - t_min).rem_euclid(t_max - t_min), ..remap}
+    } else {
+        remap
+    };
+    Some((remap.direction(), remap))
+}
+
+// Returns a finite parameter range over which to compare a curve against another.
+// This is the curve's own range if it is bounded; unbounded ends are cut off a short way out.
+fn coincidence_sample_range(c: &Curve) -> (Float, Float) {
+    (c.t_min().unwrap_or(c.t_max().map_or(-1., |t| t - 2.)), c.t_max().unwrap_or(c.t_min().map_or(1., |t| t + 2.)))
+}
+
+// Guesses linear maps from parameters on c0 to parameters on c1,
+// by finding where a point of c0 lies on c1 and comparing the velocities of the two curves there
+fn coincident_remap_candidates(c0: &Curve, c1: &Curve) -> Vec<CurveRemap> {
+    let (start_t, end_t) = coincidence_sample_range(c0);
+    // Probe at irregular fractions of the range, to avoid seams and symmetric points,
+    // trying another if the curve stalls (e.g. at a cusp)
+    for fraction in [0.382, 0.618, 0.159] {
+        let t0 = start_t + (end_t - start_t) * fraction;
+        let v0 = xyz(c0.d1(t0));
+        if length(v0) <= FLOAT_DIVISION_EPSILON {
+            continue;
+        }
+        let points = match c1.closest_points(c0.d0(t0)) {
+            Ok(points) => points,
+            Err(_) => return vec![],
+        };
+        return points.into_iter().filter(|&(_, distance, _)| distance <= EPSILON_COINCIDENT_DISTANCE).filter_map(|(t1, _, _)| {
+            let v1 = xyz(c1.d1(t1));
+            let speed_squared = dot(v1, v1);
+            if speed_squared <= FLOAT_DIVISION_EPSILON {
+                return None;
+            }
+            // The velocities must be parallel for the curves to coincide
+            if length(cross(v0, v1)) > EPSILON_COINCIDENT_DISTANCE * length(v0) * speed_squared.sqrt() {
+                return None;
+            }
+            let scale = dot(v0, v1) / speed_squared;
+            Some(CurveRemap {offset: t1 - scale * t0, scale})
+        }).collect();
+    }
+    vec![]
+}
+
+// Checks that the remap carries c0 onto c1: the points must agree along the whole of c0,
+// bounded ends must land on the corresponding ends of c1,
+// and closed curves must go around exactly once in the same span of parameter
+fn remap_is_coincident(c0: &Curve, c1: &Curve, remap: &CurveRemap) -> bool {
+    if remap.scale == 0. || !remap.scale.is_finite() {
+        return false;
+    }
+
+    let wrap = |t: Float| if c1.closed() {
+        let (t_min, t_max) = (c1.t_min().unwrap(), c1.t_max().unwrap());
+        t_min + (t - t_min).rem_euclid(t_max - t_min)
+    } else {
+        t
+    };
+
+    if c0.closed() {
+        let period0 = c0.t_max().unwrap() - c0.t_min().unwrap();
+        let period1 = c1.t_max().unwrap() - c1.t_min().unwrap();
+        if (remap.scale.abs() * period0 - period1).abs() > FLOAT_DIVISION_EPSILON * period1.max(1.) {
+            return false;
+        }
+    } else {
+        let ends1 = match remap.direction() {
+            Direction::Forward => [c1.t_min(), c1.t_max()],
+            Direction::Reverse => [c1.t_max(), c1.t_min()],
+        };
+        for (end0, end1) in [c0.t_min(), c0.t_max()].iter().zip(ends1.iter()) {
+            match (*end0, *end1) {
+                (None, None) => {},
+                (Some(t0), Some(t1)) => if !vertices_coincident(c0.d0(t0), c1.d0(t1)) {
+                    return false;
+                },
+                _ => return false,
+            }
+        }
+    }
+
+    let (start_t, end_t) = coincidence_sample_range(c0);
+    let samples = sample_count(c0, start_t, end_t);
+    (0..=samples).all(|i| {
+        let t0 = start_t + (end_t - start_t) * i as Float / samples as Float;
+        let t1 = wrap(remap.apply(t0)).clamp(c1.t_min().unwrap_or(-Float::INFINITY), c1.t_max().unwrap_or(Float::INFINITY));
+        vertices_coincident(c0.d0(t0), c1.d0(t1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::*;
+    use crate::scalar::DualNumber;
+
+    fn circle(radius: Float) -> Curve {
+        Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), inf_point_from_xyz(1., 1., 1.), radius).unwrap()
+    }
+
+    // Central difference of a point-valued function, as a 3-vector
+    fn difference<F: Fn(Float) -> [Float; 3]>(f: F, t: Float) -> [Float; 3] {
+        let h = 1e-5;
+        scale(sub(f(t + h), f(t - h)), 0.5 / h)
+    }
+
+    #[test]
+    fn derivatives_match_differences() {
+        let curves = [
+            Curve::line_from_two_points(point_from_xyz(1., 0., 2.), point_from_xyz(-1., 3., 0.)),
+            circle(2.),
+        ];
+        for c in curves.iter() {
+            for &t in &[-0.7, 0., 1.3] {
+                let d1 = difference(|t| xyz(c.d0(t).hat()), t);
+                let d2 = difference(|t| xyz(c.d1(t)), t);
+                let d3 = difference(|t| xyz(c.d2(t)), t);
+                assert!(length(sub(d1, xyz(c.d1(t)))) < 1e-6);
+                assert!(length(sub(d2, xyz(c.d2(t)))) < 1e-6);
+                assert!(length(sub(d3, xyz(c.d3(t)))) < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn curvature_and_torsion() {
+        let line = Curve::line_from_two_points(point_from_xyz(1., 0., 2.), point_from_xyz(-1., 3., 0.));
+        assert!(line.curvature(0.5).abs() < 1e-12);
+        assert_eq!(line.torsion(0.5), 0.);
+        assert!(line.frenet_frame(0.5).is_none());
+
+        let c = circle(2.);
+        assert!((c.curvature(0.3) - 0.5).abs() < 1e-9);
+        assert!(c.torsion(0.3).abs() < 1e-9);
+        assert!((length(xyz(c.tangent(0.3))) - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn frenet_normal_points_to_center() {
+        let c = circle(2.);
+        let frame = c.frenet_frame(0.8).unwrap();
+        // The frame's origin is on the curve, and its Y axis (the normal) points at the center
+        assert!(point_from_xyz(0., 0., 0.).transform(frame).hat().approx_eq(&c.d0(0.8).hat()));
+        assert!(point_from_xyz(0., 2., 0.).transform(frame).hat().approx_eq(&point_from_xyz(1., 2., 3.)));
+    }
+
+    // Lifts a point to dual numbers, as a constant
+    fn constant(p: Trivector) -> Trivector<DualNumber> {
+        let (x, y, z, w) = p.as_tuple();
+        Trivector::new(DualNumber::constant(x), DualNumber::constant(y), DualNumber::constant(z), DualNumber::constant(w))
+    }
+
+    fn constant_line(l: Bivector) -> Bivector<DualNumber> {
+        let (a5, a6, a7, a8, a9, a10) = l.as_tuple();
+        let c = DualNumber::constant;
+        Bivector::new(c(a5), c(a6), c(a7), c(a8), c(a9), c(a10))
+    }
+
+    fn constant_motor(m: Motor) -> Motor<DualNumber> {
+        let (a0, a5, a6, a7, a8, a9, a10, a15) = m.as_tuple();
+        let c = DualNumber::constant;
+        Motor::new(c(a0), c(a5), c(a6), c(a7), c(a8), c(a9), c(a10), c(a15))
+    }
+
+    fn value(p: Trivector<DualNumber>) -> Trivector {
+        let (x, y, z, w) = p.as_tuple();
+        Trivector::new(x.value, y.value, z.value, w.value)
+    }
+
+    fn derivative(p: Trivector<DualNumber>) -> Trivector {
+        let (x, y, z, w) = p.as_tuple();
+        Trivector::new(x.derivative, y.derivative, z.derivative, w.derivative)
+    }
+
+    #[test]
+    fn dual_number_derivative_with_respect_to_radius() {
+        // A circle of radius r about the Z axis, starting on the X axis
+        let r = DualNumber::variable(2.);
+        let zero = DualNumber::constant(0.);
+        let one = DualNumber::constant(1.);
+        let c = Circle {
+            p0: Trivector::new(r, zero, zero, one),
+            a: constant(point_from_xyz(0., 0., 0.)) & constant(point_from_xyz(0., 0., 1.)),
+        };
+        for &t in &[0., 0.4, 2.5] {
+            // Moving the start point outwards scales the whole circle, so the point moves along its radius
+            let p = c.d0(DualNumber::constant(t));
+            assert!(value(p).approx_eq(&point_from_xyz(2. * t.cos(), 2. * t.sin(), 0.)));
+            assert!(derivative(p).approx_eq(&inf_point_from_xyz(t.cos(), t.sin(), 0.)));
+        }
+    }
+
+    #[test]
+    fn dual_number_derivative_with_respect_to_parameter() {
+        // Differentiating d0 with respect to t gives d1
+        let frame = frame_from_three_points(point_from_xyz(1., 2., 3.), point_from_xyz(2., 2., 3.), point_from_xyz(1., 2., 4.));
+        let e = Ellipse {frame, a: 3., b: 2.};
+        let e_dual = Ellipse {frame: constant_motor(frame), a: DualNumber::constant(3.), b: DualNumber::constant(2.)};
+        let h = Hyperbola {frame, a: e.a, b: e.b};
+        let h_dual = Hyperbola {frame: e_dual.frame, a: e_dual.a, b: e_dual.b};
+        let l = match Curve::line_from_two_points(point_from_xyz(1., 0., 2.), point_from_xyz(-1., 3., 0.)) {
+            Curve::Line(x) => x,
+            _ => unreachable!(),
+        };
+        let l_dual = Line {p0: constant(l.p0), d: constant_line(l.d)};
+        for &t in &[-0.7, 0., 1.3] {
+            let t_dual = DualNumber::variable(t);
+            assert!(derivative(e_dual.d0(t_dual)).approx_eq(&e.d1(t)));
+            assert!(derivative(e_dual.d1(t_dual)).approx_eq(&e.d2(t)));
+            assert!(derivative(h_dual.d0(t_dual)).approx_eq(&h.d1(t)));
+            assert!(derivative(l_dual.d0(t_dual)).approx_eq(&l.d1(t)));
+        }
+    }
+
+    // A curve of each kind whose shape depends on the parameter x
+    fn parameterized_curves<T: Scalar>(x: T) -> Vec<Curve<T>> {
+        let c = T::from_f64;
+        let point = |x: T, y: Float, z: Float| Trivector::new(x, c(y), c(z), T::one());
+        let z_axis = point(c(0.), 0., 0.) & point(c(0.), 0., 1.);
+        let nurbs = Nurbs {
+            degree: 2,
+            knots: [0., 0., 0., 1., 2., 2., 2.].iter().map(|&k| c(k)).collect(),
+            points: vec![point(c(0.), 0., 0.), point(x, 2., 0.), point(c(3.), 2., 1.) * c(2.), point(c(4.), 0., 1.)],
+        };
+        let circle = Curve::Circle(Circle {p0: point(c(2.), 0., 0.), a: z_axis});
+        // A turn about the Z axis after a slide, which is the exponential of an ideal line
+        let ideal = |x: Float, y: Float| Trivector::new(c(x), c(y), c(0.), c(0.));
+        let frame = (z_axis * c(0.3)).exp() * ((ideal(1., 0.) & ideal(0., 1.)) * c(0.5)).exp();
+        vec![
+            Curve::Circle(Circle {p0: point(x, 0., 0.), a: z_axis}),
+            Curve::Ellipse(Ellipse {frame, a: x, b: c(2.)}),
+            Curve::Hyperbola(Hyperbola {frame, a: c(2.), b: x}),
+            Curve::Parabola(Parabola {frame, focal_length: x}),
+            Curve::Nurbs(nurbs.clone()),
+            Curve::Bezier(Bezier {points: nurbs.points.clone()}),
+            Curve::Helix(Helix {p0: point(c(2.), 0., 0.), s: z_axis, taper: x}),
+            Curve::Spiral(Spiral {center: point(c(0.), 0., 0.), a: z_axis, u: Trivector::new(c(1.), c(0.), c(0.), c(0.)), radius: c(1.), growth: x}),
+            Curve::Offset(Offset {curve: Box::new(circle), distance: x, v: Trivector::new(c(0.), c(0.), c(1.), c(0.))}),
+            Curve::Polyline(Polyline::new(vec![point(c(0.), 0., 0.), point(x, 1., 0.), point(c(3.), 1., 2.)], false)),
+            Curve::TrimmedCurve(TrimmedCurve {curve: Box::new(Curve::Nurbs(nurbs)), t_start: c(0.5), t_end: c(1.5)}),
+        ]
+    }
+
+    #[test]
+    fn derivatives_of_any_order() {
+        let mut curves = parameterized_curves(1.2);
+        curves.push(Curve::line_from_two_points(point_from_xyz(1., 0., 2.), point_from_xyz(-1., 3., 0.)));
+        // An offset of an offset needs the fourth and fifth derivatives of the innermost curve
+        let offset = curves.iter().find(|c| matches!(c, Curve::Offset(_))).unwrap().clone();
+        curves.push(offset.offset(0.1, inf_point_from_xyz(0., 0., 1.)));
+        for c in curves.iter() {
+            for &t in &[0.6, 0.9, 1.3] {
+                let ders = c.derivatives(t, 4);
+                assert!(ders[0].hat().approx_eq(&c.d0(t).hat()), "{:?}", c);
+                assert!(ders[1].approx_eq(&c.d1(t)) && ders[2].approx_eq(&c.d2(t)) && ders[3].approx_eq(&c.d3(t)), "{:?}", c);
+                let d4 = difference(|t| xyz(c.d3(t)), t);
+                assert!(length(sub(d4, xyz(ders[4]))) < 1e-5 * length(d4).max(1.), "{:?}", c);
+            }
+        }
+    }
+
+    #[test]
+    fn curves_differentiate_over_dual_numbers() {
+        // The derivative of each point with respect to x matches a central difference in x
+        let (x, h) = (1.2, 1e-6);
+        let curves = parameterized_curves(DualNumber::variable(x));
+        let (exact, below, above) = (parameterized_curves(x), parameterized_curves(x - h), parameterized_curves(x + h));
+        for (i, c) in curves.iter().enumerate() {
+            assert_eq!(c.closed(), exact[i].closed());
+            assert_eq!(c.t_max().map(|t| t.value), exact[i].t_max());
+            for &t in &[0.6, 0.9, 1.3] {
+                let p = c.d0(DualNumber::constant(t));
+                let expected = scale(sub(xyz(above[i].d0(t).hat()), xyz(below[i].d0(t).hat())), 0.5 / h);
+                assert!(value(p).approx_eq(&exact[i].d0(t).hat()));
+                assert!(length(sub(xyz(derivative(p)), expected)) < 1e-6, "{:?}", c);
+            }
+        }
+    }
+
+    #[test]
+    fn bishop_frames_follow_tangent() {
+        let c = circle(2.);
+        let frames = c.bishop_frames(0., 2., 9);
+        assert_eq!(frames.len(), 9);
+        for (i, &frame) in frames.iter().enumerate() {
+            let t = 2. * i as Float / 8.;
+            assert!(point_from_xyz(0., 0., 0.).transform(frame).hat().approx_eq(&c.d0(t).hat()));
+            assert!(inf_point_from_xyz(1., 0., 0.).transform(frame).approx_eq(&c.tangent(t)));
+        }
+    }
+
+    #[test]
+    fn trim_wraps_around_closed_curves() {
+        let c = circle(2.);
+        let trim = match c.clone().trim(1.5 * PI, 0.5 * PI) {
+            Curve::TrimmedCurve(x) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!((trim.t_start, trim.t_end), (1.5 * PI, 2.5 * PI));
+        assert!(!trim.closed());
+
+        // Past the seam, the trim continues onto the start of the circle
+        assert!(trim.d0(2. * PI + 0.2).approx_eq(&c.d0(0.2)));
+        assert!(trim.d1(2. * PI + 0.2).approx_eq(&c.d1(0.2)));
+        assert!((trim.t(c.d0(0.2)) - (2. * PI + 0.2)).abs() < 1e-9);
+        assert!((trim.t(c.d0(1.6 * PI)) - 1.6 * PI).abs() < 1e-9);
+        assert!((trim.length(1.5 * PI, 2.5 * PI) - 2. * PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nested_trims_collapse() {
+        let line = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.));
+        let trim = match line.trim(1., 5.).trim(2., 3.) {
+            Curve::TrimmedCurve(x) => x,
+            _ => unreachable!(),
+        };
+        assert!(matches!(*trim.curve, Curve::Line(_)));
+        assert_eq!((trim.t_start, trim.t_end), (2., 3.));
+
+        // A trim of a wrapped trim stays in the unwrapped range
+        let trim = match circle(1.).trim(1.5 * PI, 0.5 * PI).trim(1.9 * PI, 0.1 * PI) {
+            Curve::TrimmedCurve(x) => x,
+            _ => unreachable!(),
+        };
+        assert!(matches!(*trim.curve, Curve::Circle(_)));
+        assert!((trim.t_start - 1.9 * PI).abs() < 1e-12 && (trim.t_end - 2.1 * PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn trim_moves_with_its_curve() {
+        let trim = circle(2.).trim(0.5, 2.);
+        let m = motor_from_translation(1., 2., 3.) * motor_from_rotation(point_from_xyz(0., 0., 0.) & point_from_xyz(0., 1., 0.), 0.7);
+        let moved = trim.transform(m);
+        assert_eq!((moved.t_min(), moved.t_max()), (Some(0.5), Some(2.)));
+        for &t in &[0.5, 1.2, 2.] {
+            assert!(moved.d0(t).hat().approx_eq(&trim.d0(t).transform(m).hat()));
+        }
+    }
+
+    #[test]
+    fn conics_move_and_reflect() {
+        let center = point_from_xyz(1., 2., 3.);
+        let conics = [
+            Curve::ellipse_from_center_and_axes(center, inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 2., 0.)),
+            Curve::hyperbola_from_center_and_axes(center, inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 2., 0.)),
+            Curve::parabola_from_focus_and_directrix(center, point_from_xyz(0., 0., 3.) & point_from_xyz(0., 1., 3.)),
+        ];
+        let m = motor_from_translation(1., 2., 3.) * motor_from_rotation(point_from_xyz(0., 0., 0.) & point_from_xyz(0., 1., 0.), 0.7);
+        let mirror = plane_from_three_points(point_from_xyz(5., 0., 0.), point_from_xyz(5., 1., 0.), point_from_xyz(5., 0., 1.));
+        for c in conics.iter() {
+            let moved = c.transform(m);
+            let reflected = c.reflect(mirror);
+            for &t in &[0.2, 1.5, 2.5] {
+                assert!(moved.d0(t).hat().approx_eq(&c.d0(t).transform(m).hat()));
+                assert!(reflected.d0(t).hat().approx_eq(&c.d0(t).reflect(mirror).hat()));
+                assert!((moved.t(moved.d0(t)) - t).abs() < 1e-9);
+            }
+        }
+    }
+
+    // A rational cubic through a twisted set of control points, with an interior double knot
+    fn sample_nurbs() -> Nurbs {
+        let points = [
+            ([0., 0., 0.], 1.),
+            ([1., 2., 0.], 0.5),
+            ([3., 2., 1.], 2.),
+            ([4., 0., 1.], 1.),
+            ([5., -1., 3.], 0.8),
+            ([7., 1., 2.], 1.5),
+            ([8., 0., 0.], 1.),
+        ].iter().map(|&([x, y, z], w)| w * point_from_xyz(x, y, z)).collect();
+        match Curve::nurbs(3, vec![0., 0., 0., 0., 1., 2., 2., 3., 3., 3., 3.], points) {
+            Curve::Nurbs(x) => x,
+            _ => unreachable!(),
+        }
+    }
+
+    // Whether two curves trace the same points over the given parameter range
+    fn same_shape(a: &Nurbs, b: &Nurbs, start_t: Float, end_t: Float) -> bool {
+        (0..=20).map(|i| start_t + (end_t - start_t) * i as Float / 20.)
+            .all(|t| a.d0(t).hat().approx_eq_tol(&b.d0(t).hat(), 1e-9))
+    }
+
+    #[test]
+    fn nurbs_represents_circle_exactly() {
+        // A quarter of the unit circle as a rational quadratic
+        let w = 0.5_f64.sqrt();
+        let n = match Curve::nurbs(2, vec![0., 0., 0., 1., 1., 1.], vec![
+            point_from_xyz(1., 0., 0.),
+            w * point_from_xyz(1., 1., 0.),
+            point_from_xyz(0., 1., 0.),
+        ]) {
+            Curve::Nurbs(x) => x,
+            _ => unreachable!(),
+        };
+        for i in 0..=10 {
+            let (x, y, z, _) = n.d0(i as Float / 10.).hat().as_tuple();
+            assert!((x * x + y * y - 1.).abs() < 1e-12 && z == 0.);
+        }
+    }
+
+    #[test]
+    fn nurbs_derivatives_match_differences() {
+        let n = sample_nurbs();
+        for &t in &[0.3, 1.5, 2.7] {
+            assert!(length(sub(difference(|t| xyz(n.d0(t).hat()), t), xyz(n.d1(t)))) < 1e-6);
+            assert!(length(sub(difference(|t| xyz(n.d1(t)), t), xyz(n.d2(t)))) < 1e-5);
+            assert!(length(sub(difference(|t| xyz(n.d2(t)), t), xyz(n.d3(t)))) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn nurbs_knot_insertion_and_removal() {
+        let n = sample_nurbs();
+        let inserted = n.insert_knot(0.5, 2);
+        assert_eq!(inserted.points.len(), n.points.len() + 2);
+        assert_eq!(inserted.multiplicity(0.5), 2);
+        assert!(same_shape(&n, &inserted, 0., 3.));
+
+        let (removed, count) = inserted.remove_knot(0.5, 3, 1e-9);
+        assert_eq!(count, 2);
+        assert_eq!(removed.points.len(), n.points.len());
+        assert!(same_shape(&n, &removed, 0., 3.));
+
+        // Removing a knot that the shape depends on is refused
+        let (unchanged, count) = n.remove_knot(1., 1, 1e-9);
+        assert_eq!(count, 0);
+        assert_eq!(unchanged.points.len(), n.points.len());
+    }
+
+    #[test]
+    fn nurbs_degree_elevation_and_splitting() {
+        let n = sample_nurbs();
+        let elevated = n.elevate_degree(2);
+        assert_eq!(elevated.degree, 5);
+        assert!(same_shape(&n, &elevated, 0., 3.));
+
+        let (left, right) = n.split(1.2);
+        assert_eq!((left.t_min(), left.t_max()), (Some(0.), Some(1.2)));
+        assert_eq!((right.t_min(), right.t_max()), (Some(1.2), Some(3.)));
+        assert!(same_shape(&n, &left, 0., 1.2));
+        assert!(same_shape(&n, &right, 1.2, 3.));
+
+        let beziers = n.to_bezier();
+        assert_eq!(beziers.len(), 3);
+        assert!(beziers[2].d0(0.5).hat().approx_eq(&n.d0(2.5).hat()));
+    }
+
+    #[test]
+    fn nurbs_inversion_and_hull() {
+        let n = sample_nurbs();
+        let hull = BoundingBox::from_points(&n.hull(0., 3.));
+        for &t in &[0., 0.4, 1.7, 2.2, 3.] {
+            assert!((n.t(n.d0(t)) - t).abs() < 1e-7);
+            assert!(hull.contains(n.d0(t).hat(), 1e-12));
+        }
+    }
+
+    // The distance from each of a handful of points along the NURBS curve to the closest point on the curve
+    fn distances_from_curve(curve: &Curve, n: &Nurbs) -> Vec<Float> {
+        let (start_t, end_t) = (n.t_min().unwrap(), n.t_max().unwrap());
+        (0..=32).map(|i| {
+            let p = n.d0(start_t + (end_t - start_t) * i as Float / 32.);
+            length(sub(xyz(p.hat()), xyz(curve.d0(curve.t(p)).hat())))
+        }).collect()
+    }
+
+    #[test]
+    fn analytic_curves_convert_exactly() {
+        let curves = [
+            Curve::line_from_two_points(point_from_xyz(1., 2., 3.), point_from_xyz(-1., 0., 4.)),
+            circle(2.),
+            Curve::ellipse_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.)),
+            Curve::hyperbola_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(0., 2., 0.), inf_point_from_xyz(1., 0., 0.)),
+            Curve::parabola_from_focus_and_directrix(point_from_xyz(0., 1., 0.), point_from_xyz(0., -1., 0.) & point_from_xyz(1., -1., 0.)),
+        ];
+        for curve in curves.iter() {
+            let (start_t, end_t) = match curve {
+                Curve::Circle(_) | Curve::Ellipse(_) => (0.5, 5.5),
+                _ => (-1.5, 2.),
+            };
+            let (n, error) = curve.to_nurbs(start_t, end_t);
+            assert_eq!(error, 0.);
+            assert_eq!((n.t_min(), n.t_max()), (Some(start_t), Some(end_t)));
+            assert!(n.d0(start_t).hat().approx_eq(&curve.d0(start_t).hat()));
+            assert!(n.d0(end_t).hat().approx_eq(&curve.d0(end_t).hat()));
+            assert!(distances_from_curve(curve, &n).into_iter().all(|d| d < 1e-9), "{:?} is not exact", curve);
+        }
+
+        // Arcs of a circle are split every 90 degrees, so five radians take four quadratic spans
+        let (n, _) = circle(2.).to_nurbs(0.5, 5.5);
+        assert_eq!(n.degree, 2);
+        assert_eq!(n.points.len(), 9);
+    }
+
+    #[test]
+    fn other_curves_convert_within_bound() {
+        let helix = Curve::helix(point_from_xyz(2., 0., 0.), point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.), 1.5, 0.1);
+        let (n, error) = helix.to_nurbs(0., 10.);
+        assert!(error > 0. && error <= EPSILON_APPROXIMATION);
+        assert!(n.d0(0.).hat().approx_eq(&helix.d0(0.).hat()));
+        assert!(n.d0(10.).hat().approx_eq(&helix.d0(10.).hat()));
+        assert!(distances_from_curve(&helix, &n).into_iter().all(|d| d <= error + 1e-9));
+    }
+
+    // A rational cubic Bezier curve
+    fn sample_bezier() -> Bezier {
+        let b = match Curve::bezier(vec![
+            point_from_xyz(0., 0., 0.),
+            2. * point_from_xyz(1., 3., 0.),
+            0.5 * point_from_xyz(3., 3., 1.),
+            point_from_xyz(4., 0., 2.),
+        ]) {
+            Curve::Bezier(x) => x,
+            _ => unreachable!(),
+        };
+        b
+    }
+
+    #[test]
+    fn bezier_matches_bernstein_form() {
+        let b = sample_bezier();
+        for &t in &[0., 0.25, 0.6, 1.] {
+            let sum = b.points.iter().enumerate().fold(Trivector::zero(), |acc, (i, &p)| {
+                acc + (binomial::<Float>(3, i) * t.powi(i as i32) * (1. - t).powi(3 - i as i32)) * p
+            });
+            assert!(b.d0(t).hat().approx_eq(&sum.hat()));
+        }
+        for &t in &[0.25, 0.6] {
+            assert!(length(sub(difference(|t| xyz(b.d0(t).hat()), t), xyz(b.d1(t)))) < 1e-6);
+            assert!(length(sub(difference(|t| xyz(b.d1(t)), t), xyz(b.d2(t)))) < 1e-5);
+        }
+    }
+
+    #[test]
+    fn bezier_subdivision_and_elevation() {
+        let b = sample_bezier();
+        let (left, right) = b.subdivide(0.3);
+        for &t in &[0., 0.5, 1.] {
+            assert!(left.d0(t).hat().approx_eq(&b.d0(0.3 * t).hat()));
+            assert!(right.d0(t).hat().approx_eq(&b.d0(0.3 + 0.7 * t).hat()));
+        }
+
+        let elevated = b.elevate_degree(2);
+        assert_eq!(elevated.degree(), 5);
+        for &t in &[0., 0.3, 0.8, 1.] {
+            assert!(elevated.d0(t).hat().approx_eq(&b.d0(t).hat()));
+        }
+    }
+
+    #[test]
+    fn bezier_converts_losslessly() {
+        let b = sample_bezier();
+        let n = b.to_nurbs(0., 1.);
+        let segments = n.to_bezier();
+        assert_eq!(segments.len(), 1);
+        assert!(segments[0].points.iter().zip(b.points.iter()).all(|(p, q)| p.approx_eq(q)));
+
+        // A sub-range keeps the curve's parameterization, and converts back to its subdivision
+        let n = b.to_nurbs(0.2, 0.7);
+        assert!(n.d0(0.45).hat().approx_eq(&b.d0(0.45).hat()));
+        let middle = b.subdivide(0.7).0.subdivide(0.2 / 0.7).1;
+        assert!(n.to_bezier()[0].points.iter().zip(middle.points.iter()).all(|(p, q)| p.approx_eq(q)));
+
+        // The hull of a sub-range contains it
+        let hull = BoundingBox::from_points(&b.hull(0.2, 0.7));
+        assert!((0..=10).all(|i| hull.contains(b.d0(0.2 + 0.05 * i as Float), 1e-12)));
+    }
+
+    #[test]
+    fn helix_screws_about_axis() {
+        let z_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.);
+        let helix = Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.);
+        let tapered = Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.1);
+        for &t in &[0., 1., 0.5 * PI, 2. * PI, 9.] {
+            // Each full turn advances by the pitch, and the taper moves the point away from the axis
+            let [x, y, z] = xyz(helix.d0(t).hat());
+            assert!((x * x + y * y - 4.).abs() < 1e-9 && (z - 1.5 * t / (2. * PI)).abs() < 1e-9);
+            let [x, y, z] = xyz(tapered.d0(t).hat());
+            assert!(((x * x + y * y).sqrt() - (2. + 0.1 * t)).abs() < 1e-9 && (z - 1.5 * t / (2. * PI)).abs() < 1e-9);
+        }
+        assert!(helix.d0(0.5 * PI).hat().approx_eq(&point_from_xyz(0., 2., 0.375)));
+
+        for curve in [&helix, &tapered] {
+            for &t in &[0.3, 4., 11.] {
+                assert!(length(sub(difference(|t| xyz(curve.d0(t).hat()), t), xyz(curve.d1(t)))) < 1e-6);
+                assert!(length(sub(difference(|t| xyz(curve.d1(t)), t), xyz(curve.d2(t)))) < 1e-5);
+                assert!(length(sub(difference(|t| xyz(curve.d2(t)), t), xyz(curve.d3(t)))) < 1e-4);
+                // Points on later turns are told apart by their height
+                assert!((curve.t(curve.d0(t)) - t).abs() < 1e-7);
+            }
+            let hull = BoundingBox::from_points(&curve.hull(-1., 11.));
+            assert!((0..=24).all(|i| hull.contains(curve.d0(-1. + 0.5 * i as Float), 1e-9)));
+        }
+    }
+
+    #[test]
+    fn spiral_grows_in_its_plane() {
+        let z_axis = point_from_xyz(1., 2., 3.) & point_from_xyz(1., 2., 4.);
+        let spiral = Curve::spiral(point_from_xyz(1., 2., 3.), z_axis, inf_point_from_xyz(1., 0., 0.), 1., 0.5);
+        let archimedean = Curve::spiral(point_from_xyz(1., 2., 3.), z_axis, inf_point_from_xyz(1., 0., 0.), 0., 0.5);
+        for &t in &[0., 1., 0.5 * PI, 7., 15.] {
+            let [x, y, z] = xyz(spiral.d0(t).hat());
+            assert!((((x - 1.).powi(2) + (y - 2.).powi(2)).sqrt() - (1. + 0.5 * t)).abs() < 1e-9 && (z - 3.).abs() < 1e-12);
+        }
+        assert!(archimedean.d0(0.).hat().approx_eq(&point_from_xyz(1., 2., 3.)));
+        assert!(spiral.d0(0.5 * PI).hat().approx_eq(&point_from_xyz(1., 2. + 1. + 0.25 * PI, 3.)));
+
+        for curve in [&spiral, &archimedean] {
+            for &t in &[0.3, 4., 11.] {
+                assert!(length(sub(difference(|t| xyz(curve.d0(t).hat()), t), xyz(curve.d1(t)))) < 1e-6);
+                assert!(length(sub(difference(|t| xyz(curve.d1(t)), t), xyz(curve.d2(t)))) < 1e-5);
+                assert!(length(sub(difference(|t| xyz(curve.d2(t)), t), xyz(curve.d3(t)))) < 1e-4);
+                // Points on later turns are told apart by their distance from the center
+                assert!((curve.t(curve.d0(t)) - t).abs() < 1e-7);
+            }
+            let hull = BoundingBox::from_points(&curve.hull(0., 12.));
+            assert!((0..=24).all(|i| hull.contains(curve.d0(0.5 * i as Float), 1e-9)));
+        }
+    }
+
+    #[test]
+    fn helix_and_spiral_move_and_reflect() {
+        let z_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.);
+        let curves = [
+            Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.1),
+            Curve::spiral(point_from_xyz(0., 0., 0.), z_axis, inf_point_from_xyz(0., 1., 0.), 1., 0.5),
+        ];
+        let motor = motor_from_rotation((point_from_xyz(0., 0., 0.) & point_from_xyz(1., 1., 0.)).hat(), 1.2)
+            * motor_from_translation(3., -1., 2.);
+        let mirror = plane_from_three_points(point_from_xyz(5., 0., 0.), point_from_xyz(5., 1., 0.), point_from_xyz(5., 0., 1.)).hat();
+        for curve in curves.iter() {
+            let (moved, reflected) = (curve.transform(motor), curve.reflect(mirror));
+            for &t in &[0., 2., 7.5] {
+                assert!(moved.d0(t).hat().approx_eq(&curve.d0(t).transform(motor).hat()));
+                assert!(reflected.d0(t).hat().approx_eq(&curve.d0(t).reflect(mirror).hat()));
+            }
+        }
+    }
+
+    #[test]
+    fn offsets_of_lines_and_circles_are_exact() {
+        let up = inf_point_from_xyz(0., 0., 1.);
+        // Travelling along X in the XY plane, the right-hand side looking down Z is towards -Y
+        let line = Curve::line_from_two_points(point_from_xyz(1., 2., 0.), point_from_xyz(4., 2., 0.));
+        let offset = match line.clone().offset(0.5, up) {
+            Curve::Line(x) => x,
+            _ => panic!("offset of a line is not a line"),
+        };
+        for &t in &[-1., 0., 2.5] {
+            assert!(offset.d0(t).hat().approx_eq(&(line.d0(t).hat() + inf_point_from_xyz(0., -0.5, 0.))));
+        }
+
+        // A circle turning counterclockwise about the reference direction grows when offset to its right
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 0.), up, 2.).unwrap();
+        let radius = |x: Curve| length(sub(xyz(x.d0(0.3).hat()), [1., 2., 0.]));
+        let offset_circle = |distance: Float, v: Trivector| match c.clone().offset(distance, v) {
+            Curve::Circle(x) => Curve::Circle(x),
+            _ => panic!("offset of a circle about its axis is not a circle"),
+        };
+        assert!((radius(offset_circle(0.5, up)) - 2.5).abs() < 1e-9);
+        assert!((radius(offset_circle(-0.5, up)) - 1.5).abs() < 1e-9);
+        // Flipping both the distance and the reference direction gives the same normal
+        assert!((radius(offset_circle(-0.5, -up)) - 2.5).abs() < 1e-9);
+
+        // Offsets share the base curve's parameterization
+        assert!(offset_circle(0.5, up).d0(1.).hat().approx_eq(&Curve::Offset(Offset::new(c, 0.5, up)).d0(1.).hat()));
+    }
+
+    #[test]
+    fn offset_of_ellipse() {
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 0.));
+        let offset = ellipse.clone().offset(0.2, up);
+        let o = match &offset {
+            Curve::Offset(x) => x,
+            _ => panic!("offset of an ellipse is not an Offset"),
+        };
+        for &t in &[0.3, 2., 4.5] {
+            // Each point is the given distance from the base curve, along its normal
+            let d = sub(xyz(offset.d0(t).hat()), xyz(ellipse.d0(t).hat()));
+            assert!((length(d) - 0.2).abs() < 1e-12);
+            assert!(dot(d, xyz(ellipse.d1(t))).abs() < 1e-12);
+
+            assert!(length(sub(difference(|t| xyz(offset.d0(t).hat()), t), xyz(offset.d1(t)))) < 1e-6);
+            assert!(length(sub(difference(|t| xyz(offset.d1(t)), t), xyz(offset.d2(t)))) < 1e-5);
+            assert!(length(sub(difference(|t| xyz(offset.d2(t)), t), xyz(offset.d3(t)))) < 1e-6);
+            assert!((offset.t(offset.d0(t)) - t).abs() < 1e-7);
+        }
+        let hull = BoundingBox::from_points(&offset.hull(0., 2. * PI));
+        assert!((0..=32).all(|i| hull.contains(offset.d0(2. * PI * i as Float / 32.), 1e-9)));
+
+        // A small offset has no cusps and doesn't cross itself
+        assert!(o.cusps(0., 2. * PI).is_empty());
+        assert!(o.self_intersections(0., 2. * PI).is_empty());
+    }
+
+    #[test]
+    fn offset_cusps_and_self_intersections() {
+        // The ellipse's radius of curvature is 1/3 at the ends of its major axis,
+        // so offsetting it inwards by 1/2 makes a swallowtail at each end
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 0.));
+        let inward = Offset::new(ellipse.clone(), -0.5, up);
+        let outward = Offset::new(ellipse.clone(), 0.5, up);
+        assert!(length(xyz(inward.d0(0.5 * PI).hat())) < 1.);
+
+        assert!(outward.cusps(0., 2. * PI).is_empty());
+        assert!(outward.self_intersections(0., 2. * PI).is_empty());
+
+        // Two cusps either side of each end, where the radius of curvature equals the distance
+        let cusps = inward.cusps(0., 2. * PI);
+        assert_eq!(cusps.len(), 4);
+        for &t in cusps.iter() {
+            assert!((1. / ellipse.curvature(t) - 0.5).abs() < 1e-6);
+            assert!(length(xyz(inward.d1(t))) < 1e-6);
+        }
+
+        // Each swallowtail crosses itself on the major axis
+        let crossings = inward.self_intersections(0., 2. * PI);
+        assert_eq!(crossings.len(), 2);
+        for &(t0, t1) in crossings.iter() {
+            assert!(inward.d0(t0).hat().approx_eq(&inward.d0(t1).hat()));
+            assert!(xyz(inward.d0(t0).hat())[1].abs() < 1e-9);
+        }
+    }
+
+    // A 3-4-5 triangle's corners, with the right angle at (3, 0, 0)
+    fn triangle_corners() -> Vec<Trivector> {
+        vec![point_from_xyz(0., 0., 0.), point_from_xyz(3., 0., 0.), point_from_xyz(3., 4., 0.)]
+    }
+
+    #[test]
+    fn polyline_uses_chord_length() {
+        let open = Curve::polyline(triangle_corners(), false);
+        let closed = Curve::polyline(triangle_corners(), true);
+        assert_eq!((open.t_min(), open.t_max()), (Some(0.), Some(7.)));
+        assert_eq!((closed.t_min(), closed.t_max()), (Some(0.), Some(12.)));
+
+        assert!(open.d0(3.).approx_eq(&point_from_xyz(3., 0., 0.)));
+        assert!(open.d0(5.).approx_eq(&point_from_xyz(3., 2., 0.)));
+        assert!(open.d1(5.).approx_eq(&inf_point_from_xyz(0., 1., 0.)));
+        assert!(closed.d0(10.).approx_eq(&point_from_xyz(1.2, 1.6, 0.)));
+        // Parameters beyond the ends extend the end segments
+        assert!(open.d0(-1.).approx_eq(&point_from_xyz(-1., 0., 0.)));
+        assert!(open.d0(8.).approx_eq(&point_from_xyz(3., 5., 0.)));
+
+        // The closest segment wins
+        assert!((open.t(point_from_xyz(4., 2., 1.)) - 5.).abs() < 1e-12);
+        assert!((closed.t(point_from_xyz(1., 2., 0.)) - 9.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polyline_drops_coincident_points() {
+        // Repeated corners, and a closing point that repeats the first, would make zero-length segments
+        let mut repeated = triangle_corners();
+        repeated.insert(1, point_from_xyz(0., 0., 1e-12));
+        repeated.insert(3, 2. * point_from_xyz(3., 0., 0.));
+        for &closed in &[false, true] {
+            let expected = Curve::polyline(triangle_corners(), closed);
+            let mut points = repeated.clone();
+            if closed {
+                points.push(point_from_xyz(0., 0., 0.));
+            }
+            let curve = Curve::polyline(points, closed);
+            assert_eq!(curve.t_max(), expected.t_max());
+            assert!((0..=24).all(|i| curve.d0(0.5 * i as Float).approx_eq(&expected.d0(0.5 * i as Float))));
+            assert!((0..=24).all(|i| curve.d1(0.5 * i as Float).approx_eq(&expected.d1(0.5 * i as Float))));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn polyline_lengths_are_rebuilt_when_deserialized() {
+        let curve = Curve::polyline(triangle_corners(), true);
+        let json = serde_json::to_string(&curve).unwrap();
+        assert!(!json.contains("lengths"));
+        let back: Curve = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.t_max(), Some(12.));
+        assert!(back.d0(10.).approx_eq(&curve.d0(10.)));
+    }
+
+    #[test]
+    fn polyline_hull_is_exact() {
+        let open = Curve::polyline(triangle_corners(), false);
+        let hull = open.hull(1., 5.);
+        let expected = [point_from_xyz(1., 0., 0.), point_from_xyz(3., 0., 0.), point_from_xyz(3., 2., 0.)];
+        assert_eq!(hull.len(), 3);
+        assert!(hull.iter().zip(expected.iter()).all(|(p, q)| p.approx_eq(q)));
+
+        let (n, error) = open.to_nurbs(1., 5.);
+        assert_eq!((n.degree, error), (1, 0.));
+        assert!((0..=8).all(|i| n.d0(1. + 0.5 * i as Float).hat().approx_eq(&open.d0(1. + 0.5 * i as Float))));
+    }
+
+    #[test]
+    fn polyline_moves_reverses_and_splits() {
+        let open = match Curve::polyline(triangle_corners(), false) {
+            Curve::Polyline(x) => x,
+            _ => unreachable!(),
+        };
+        let closed = match Curve::polyline(triangle_corners(), true) {
+            Curve::Polyline(x) => x,
+            _ => unreachable!(),
+        };
+        let motor = motor_from_rotation((point_from_xyz(0., 0., 0.) & point_from_xyz(1., 1., 0.)).hat(), 1.2)
+            * motor_from_translation(3., -1., 2.);
+        let mirror = plane_from_three_points(point_from_xyz(5., 0., 0.), point_from_xyz(5., 1., 0.), point_from_xyz(5., 0., 1.)).hat();
+        for &t in &[0., 2., 6.5] {
+            assert!(open.transform(motor).d0(t).approx_eq(&open.d0(t).transform(motor).hat()));
+            assert!(open.reflect(mirror).d0(t).approx_eq(&open.d0(t).reflect(mirror).hat()));
+            assert!(open.reversed().d0(t).approx_eq(&open.d0(7. - t)));
+        }
+        // A closed polyline reverses about its first point
+        assert!(closed.reversed().d0(0.).approx_eq(&closed.d0(0.)));
+        assert!(closed.reversed().d0(2.).approx_eq(&closed.d0(10.)));
+
+        let segments = closed.segments();
+        assert_eq!(open.segments().len(), 2);
+        assert_eq!(segments.len(), 3);
+        for (i, (line, (start, end))) in segments.iter().enumerate() {
+            assert!(start.approx_eq(&triangle_corners()[i]) && end.approx_eq(&triangle_corners()[(i + 1) % 3]));
+            assert!(line.d0(line.t(*start)).hat().approx_eq(start) && line.d0(line.t(*end)).hat().approx_eq(end));
+        }
+    }
+
+    #[test]
+    fn closest_point_on_lines_and_circles() {
+        let line = Curve::line_from_two_points(point_from_xyz(1., 0., 0.), point_from_xyz(3., 0., 0.));
+        // Lines built from two points move at unit speed
+        let (t, distance, q) = line.closest_point(point_from_xyz(4., 3., 4.)).unwrap();
+        assert!((t - 3.).abs() < 1e-12 && (distance - 5.).abs() < 1e-12);
+        assert!(q.approx_eq(&point_from_xyz(4., 0., 0.)));
+
+        // Points off the circle's plane, inside it and outside it all find the nearest point on the rim
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), inf_point_from_xyz(0., 0., 1.), 2.).unwrap();
+        for &([x, y, z], expected) in &[([3., 0., 0.], 1.), ([0., 1., 0.], 1.), ([-3., 0., 4.], 17_f64.sqrt())] {
+            let (t, distance, q) = c.closest_point(point_from_xyz(x, y, z)).unwrap();
+            assert!((distance - expected).abs() < 1e-12);
+            assert!(q.approx_eq(&c.d0(t).hat()));
+            assert!((length(xyz(q)) - 2.).abs() < 1e-12);
+        }
+
+        // Every point on the circle is as close as any other to a point on its axis
+        assert_eq!(c.closest_point(point_from_xyz(0., 0., 5.)).unwrap_err(), ClosestPointError::Equidistant);
+        assert_eq!(c.closest_point(inf_point_from_xyz(1., 0., 0.)).unwrap_err(), ClosestPointError::IdealPoint);
+        assert_eq!(line.closest_point(inf_point_from_xyz(1., 0., 0.)).unwrap_err(), ClosestPointError::IdealPoint);
+    }
+
+    #[test]
+    fn closest_points_on_other_curves() {
+        // From just above the center of an ellipse, the ends of the minor axis are both local minima
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 0.));
+        let minima = ellipse.closest_points(point_from_xyz(0., 0.5, 0.)).unwrap();
+        assert_eq!(minima.len(), 2);
+        assert!((minima[0].0 - 0.5 * PI).abs() < 1e-9 && (minima[0].1 - 0.5).abs() < 1e-9);
+        assert!((minima[1].0 - 1.5 * PI).abs() < 1e-9 && (minima[1].1 - 1.5).abs() < 1e-9);
+
+        // Newton refinement lands where the offset from p is perpendicular to the curve
+        let p = point_from_xyz(2., 3., 1.);
+        let (t, distance, q) = ellipse.closest_point(p).unwrap();
+        assert!(dot(sub(xyz(p), xyz(q)), xyz(ellipse.d1(t))).abs() < 1e-9);
+        assert!((0..100).all(|i| length(sub(xyz(p), xyz(ellipse.d0(0.02 * PI * i as Float)))) >= distance));
+
+        // A bounded curve can be closest at one of its ends
+        let bezier = Curve::bezier(vec![point_from_xyz(0., 0., 0.), point_from_xyz(1., 2., 0.), point_from_xyz(2., 0., 0.)]);
+        let (t, distance, _) = bezier.closest_point(point_from_xyz(4., -1., 0.)).unwrap();
+        assert!((t - 1.).abs() < 1e-12 && (distance - 5_f64.sqrt()).abs() < 1e-12);
+
+        // The same circle as a spline is searched numerically, and still sees that its center is equidistant
+        let (n, _) = circle(2.).to_nurbs(0., 2. * PI);
+        assert_eq!(Curve::Nurbs(n).closest_point(point_from_xyz(1., 2., 3.)).unwrap_err(), ClosestPointError::Equidistant);
+    }
+
+    // The length of a curve measured along a fine polyline through it
+    fn chord_length(curve: &Curve, start_t: Float, end_t: Float) -> Float {
+        let n = 20000;
+        (0..n).map(|i| {
+            let t = |i: usize| start_t + (end_t - start_t) * i as Float / n as Float;
+            length(sub(xyz(curve.d0(t(i + 1)).hat()), xyz(curve.d0(t(i)).hat())))
+        }).sum()
+    }
+
+    #[test]
+    fn lengths_match_closed_forms_and_chords() {
+        let line = Curve::line_from_two_points(point_from_xyz(1., 0., 0.), point_from_xyz(1., 3., 4.));
+        assert!((line.length(-1., 2.) - 3.).abs() < 1e-12);
+        assert!((circle(2.).length(0.5, 2.) - 3.).abs() < 1e-12);
+        // Running backwards gives a negative length
+        assert!((circle(2.).length(2., 0.5) + 3.).abs() < 1e-12);
+
+        // A helix of radius 2 rising 1.5 per turn unrolls to a line of slope 1.5 / (4 pi)
+        let z_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.);
+        let helix = Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.);
+        assert!((helix.length(0., 3.) - 3. * (4. + (1.5 / (2. * PI)).powi(2)).sqrt()).abs() < 1e-9);
+
+        let curves = [
+            helix,
+            Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.3),
+            Curve::spiral(point_from_xyz(0., 0., 0.), z_axis, inf_point_from_xyz(1., 0., 0.), 0., 0.5),
+            Curve::parabola_from_focus_and_directrix(point_from_xyz(0., 1., 0.), point_from_xyz(0., -1., 0.) & point_from_xyz(1., -1., 0.)),
+            Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.)),
+            Curve::Nurbs(sample_nurbs()),
+            Curve::Bezier(sample_bezier()),
+        ];
+        for curve in curves.iter() {
+            let (start_t, end_t) = match curve {
+                Curve::Nurbs(_) => (0.2, 2.9),
+                Curve::Bezier(_) => (0.1, 0.8),
+                _ => (0.2, 5.),
+            };
+            let expected = chord_length(curve, start_t, end_t);
+            assert!((curve.length(start_t, end_t) - expected).abs() < 1e-6 * expected, "length of {:?}", curve);
+        }
+    }
+
+    #[test]
+    fn t_at_length_inverts_length() {
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.));
+        for &(start_t, distance) in &[(0.5, 2.), (0.5, -0.3), (4., 1.5)] {
+            let t = ellipse.t_at_length(start_t, distance).unwrap();
+            assert!((ellipse.length(start_t, t) - distance).abs() < 1e-9);
+        }
+        // On a closed curve, distances wrap through the seam
+        let circumference = ellipse.length(0., 2. * PI);
+        let t = ellipse.t_at_length(6., 1.).unwrap();
+        assert!((ellipse.length(6., 2. * PI) + ellipse.length(0., t) - 1.).abs() < 1e-9);
+        assert!((ellipse.t_at_length(1., 2. * circumference + 0.5).unwrap() - ellipse.t_at_length(1., 0.5).unwrap()).abs() < 1e-9);
+
+        // An open curve runs out, unless it is unbounded
+        let bezier = Curve::Bezier(sample_bezier());
+        let total = bezier.length(0., 1.);
+        assert!((bezier.length(0.3, bezier.t_at_length(0.3, 0.25 * total).unwrap()) - 0.25 * total).abs() < 1e-9);
+        assert!(bezier.t_at_length(0.3, total).is_none());
+        assert!(bezier.t_at_length(0.3, -total).is_none());
+        let parabola = Curve::parabola_from_focus_and_directrix(point_from_xyz(0., 1., 0.), point_from_xyz(0., -1., 0.) & point_from_xyz(1., -1., 0.));
+        let t = parabola.t_at_length(0., -100.).unwrap();
+        assert!((parabola.length(0., t) + 100.).abs() < 1e-9);
+    }
+
+    // The points at which two curves cross, checking that each lies on both curves at its parameters
+    fn crossings(c0: &Curve, c1: &Curve) -> Vec<(Float, Float, [Float; 3])> {
+        intersect(c0, c1).into_iter().map(|x| match x {
+            CurveIntersection::Point {t0, t1, point} => {
+                assert!(c0.d0(t0).hat().approx_eq_tol(&point.hat(), 1e-9) && c1.d0(t1).hat().approx_eq_tol(&point.hat(), 1e-9));
+                (t0, t1, xyz(point.hat()))
+            },
+            CurveIntersection::Overlap {..} => panic!("curves unexpectedly overlap"),
+        }).collect()
+    }
+
+    fn close(a: [Float; 3], b: [Float; 3]) -> bool {
+        length(sub(a, b)) < 1e-9
+    }
+
+    #[test]
+    fn intersect_lines_and_circles() {
+        let line = |x0: Float, y0: Float, x1: Float, y1: Float| Curve::line_from_two_points(point_from_xyz(x0, y0, 0.), point_from_xyz(x1, y1, 0.));
+        let x_axis = line(0., 0., 1., 0.);
+
+        let found = crossings(&x_axis, &line(2., -1., 2., 1.));
+        assert_eq!(found.len(), 1);
+        assert!((found[0].0 - 2.).abs() < 1e-12 && (found[0].1 - 1.).abs() < 1e-12);
+        assert!(crossings(&x_axis, &line(0., 1., 1., 1.)).is_empty());
+        let skew = Curve::line_from_two_points(point_from_xyz(2., 0., 1.), point_from_xyz(2., 1., 1.));
+        assert!(crossings(&x_axis, &skew).is_empty());
+
+        // A circle of radius 2 about the origin, crossed twice, touched once and missed
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), inf_point_from_xyz(0., 0., 1.), 2.).unwrap();
+        let found = crossings(&line(-5., 1., 5., 1.), &c);
+        assert_eq!(found.len(), 2);
+        assert!(close(found[0].2, [-(3_f64.sqrt()), 1., 0.]) && close(found[1].2, [3_f64.sqrt(), 1., 0.]));
+        let found = crossings(&line(-5., 2., 5., 2.), &c);
+        assert_eq!(found.len(), 1);
+        assert!(close(found[0].2, [0., 2., 0.]));
+        assert!(crossings(&line(-5., 3., 5., 3.), &c).is_empty());
+        // Swapping the curves swaps the parameters
+        let found = crossings(&c, &line(-5., 1., 5., 1.));
+        assert_eq!(found.len(), 2);
+        assert!(found[0].0 < found[1].0);
+
+        // Two circles of radius 2 whose centers are 2 apart
+        let other = Curve::circle_from_center_axis_radius(point_from_xyz(2., 0., 0.), inf_point_from_xyz(0., 0., 1.), 2.).unwrap();
+        let found = crossings(&c, &other);
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|x| close(x.2, [1., 3_f64.sqrt(), 0.]) || close(x.2, [1., -(3_f64.sqrt()), 0.])));
+
+        // A trim only keeps the crossings within its range
+        let segment = line(-5., 1., 5., 1.).trim(0., 5.);
+        let found = crossings(&segment, &c);
+        assert_eq!(found.len(), 1);
+        assert!(close(found[0].2, [-(3_f64.sqrt()), 1., 0.]));
+    }
+
+    #[test]
+    fn intersect_overlapping_curves() {
+        let x_axis = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.));
+        let backwards = Curve::line_from_two_points(point_from_xyz(3., 0., 0.), point_from_xyz(2., 0., 0.));
+        match intersect(&x_axis, &backwards).as_slice() {
+            [CurveIntersection::Overlap {t0, t1}] => {
+                assert_eq!(*t0, (-Float::INFINITY, Float::INFINITY));
+                assert_eq!(*t1, (Float::INFINITY, -Float::INFINITY));
+            },
+            x => panic!("expected the lines to overlap, found {:?}", x),
+        }
+
+        // Segments of the same line overlap where their ranges do
+        match intersect(&x_axis.clone().trim(0., 3.), &backwards.clone().trim(0., 2.)).as_slice() {
+            [CurveIntersection::Overlap {t0, t1}] => {
+                assert!((t0.0 - 1.).abs() < 1e-12 && (t0.1 - 3.).abs() < 1e-12);
+                assert!(close(xyz(x_axis.d0(t0.0).hat()), xyz(backwards.d0(t1.0).hat())));
+                assert!(close(xyz(x_axis.d0(t0.1).hat()), xyz(backwards.d0(t1.1).hat())));
+            },
+            x => panic!("expected the segments to overlap, found {:?}", x),
+        }
+
+        // The same circle, running the other way
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), inf_point_from_xyz(0., 0., 1.), 2.).unwrap();
+        let reversed = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), inf_point_from_xyz(0., 0., -1.), 2.).unwrap();
+        match intersect(&c, &reversed).as_slice() {
+            [CurveIntersection::Overlap {t0, t1}] => {
+                assert!(t0.0.abs() < 1e-12 && (t0.1 - 2. * PI).abs() < 1e-12);
+                assert!((t1.1 - t1.0 + 2. * PI).abs() < 1e-12);
+                assert!(close(xyz(c.d0(1.).hat()), xyz(reversed.d0(t1.0 - 1.).hat())));
+            },
+            x => panic!("expected the circles to overlap, found {:?}", x),
+        }
+    }
+
+    #[test]
+    fn intersect_general_curves() {
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(0., 0., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 0.));
+        let line = Curve::line_from_two_points(point_from_xyz(-5., 0.5, 0.), point_from_xyz(5., 0.5, 0.));
+        let found = crossings(&line, &ellipse);
+        let x = 3. * 0.75_f64.sqrt();
+        assert_eq!(found.len(), 2);
+        assert!(close(found[0].2, [-x, 0.5, 0.]) && close(found[1].2, [x, 0.5, 0.]));
+
+        // x^2 / 9 + y^2 = 1 and x^2 + y^2 = 4 meet where x^2 = 27 / 8
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), inf_point_from_xyz(0., 0., 1.), 2.).unwrap();
+        let found = crossings(&ellipse, &c);
+        assert_eq!(found.len(), 4);
+        assert!(found.iter().all(|x| (x.2[0].powi(2) - 27. / 8.).abs() < 1e-9 && (x.2[1].powi(2) - 5. / 8.).abs() < 1e-9));
+        assert!(found.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        // A curve that passes near the ellipse without meeting it, and one that crosses it
+        // (the first dips to y = 1.05 just above the top of the ellipse, and the second to y = 0.5)
+        let bezier = Curve::bezier(vec![point_from_xyz(-1., 3., 0.), point_from_xyz(0., -0.9, 0.), point_from_xyz(1., 3., 0.)]);
+        assert!(crossings(&bezier, &ellipse).is_empty());
+        let bezier = Curve::bezier(vec![point_from_xyz(-1., 3., 0.), point_from_xyz(0., -2., 0.), point_from_xyz(1., 3., 0.)]);
+        assert_eq!(crossings(&bezier, &ellipse).len(), 2);
+    }
+
+    // Whether the remap takes points on c0 to the same points on c1
+    fn remap_matches(c0: &Curve, c1: &Curve, remap: &CurveRemap, ts: &[Float]) -> bool {
+        ts.iter().all(|&t| c0.d0(t).hat().approx_eq_tol(&c1.d0(remap.apply(t)).hat(), 1e-9))
+    }
+
+    #[test]
+    fn coincident_lines_and_circles() {
+        let ts = [-2., 0., 0.7, 3.];
+        let line = Curve::line_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.));
+        // The same line, starting somewhere else, and then running the other way
+        let shifted = Curve::line_from_two_points(point_from_xyz(5., 0., 0.), point_from_xyz(6., 0., 0.));
+        let (direction, remap) = curves_coincident(&line, &shifted).unwrap();
+        assert_eq!(direction, Direction::Forward);
+        assert!((remap.offset + 5.).abs() < 1e-12 && (remap.scale - 1.).abs() < 1e-12);
+        assert!(remap_matches(&line, &shifted, &remap, &ts));
+        let backwards = Curve::line_from_two_points(point_from_xyz(6., 0., 0.), point_from_xyz(5., 0., 0.));
+        let (direction, remap) = curves_coincident(&line, &backwards).unwrap();
+        assert_eq!(direction, Direction::Reverse);
+        assert!((remap.scale + 1.).abs() < 1e-12);
+        assert!(remap_matches(&line, &backwards, &remap, &ts));
+
+        let parallel = Curve::line_from_two_points(point_from_xyz(0., 1., 0.), point_from_xyz(1., 1., 0.));
+        assert!(curves_coincident(&line, &parallel).is_none());
+        // A segment is not the whole line
+        assert!(curves_coincident(&line, &shifted.clone().trim(0., 1.)).is_none());
+
+        // The same circle with a reversed axis, and then rotated about its axis so that it starts elsewhere
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), inf_point_from_xyz(1., 1., 1.), 2.).unwrap();
+        let reversed = Curve::circle_from_center_axis_radius(point_from_xyz(1., 2., 3.), inf_point_from_xyz(-1., -1., -1.), 2.).unwrap();
+        let (direction, remap) = curves_coincident(&c, &reversed).unwrap();
+        assert_eq!(direction, Direction::Reverse);
+        assert!((remap.scale + 1.).abs() < 1e-12 && (0. ..2. * PI).contains(&remap.offset));
+        assert!(remap_matches(&c, &reversed, &remap, &ts));
+        let circle_data = match &c {
+            Curve::Circle(x) => x,
+            _ => unreachable!(),
+        };
+        let turned = c.transform(motor_from_rotation(circle_data.a.hat(), 1.));
+        let (direction, remap) = curves_coincident(&c, &turned).unwrap();
+        assert_eq!(direction, Direction::Forward);
+        assert!((remap.scale - 1.).abs() < 1e-12 && (0. ..2. * PI).contains(&remap.offset));
+        assert!(remap_matches(&c, &turned, &remap, &ts));
+
+        assert!(curves_coincident(&c, &circle(2.5)).is_none());
+    }
+
+    #[test]
+    fn coincident_general_curves() {
+        let ellipse = Curve::ellipse_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.));
+        let reversed = ellipse.reversed();
+        let (direction, remap) = curves_coincident(&ellipse, &reversed).unwrap();
+        assert_eq!(direction, Direction::Reverse);
+        assert!(remap_matches(&ellipse, &reversed, &remap, &[0., 1., 4.]));
+
+        // A curve of higher degree can trace the same points
+        let bezier = Curve::Bezier(sample_bezier());
+        let elevated = Curve::Bezier(sample_bezier().elevate_degree(1));
+        let (direction, remap) = curves_coincident(&bezier, &elevated).unwrap();
+        assert_eq!(direction, Direction::Forward);
+        assert!(remap.offset.abs() < 1e-9 && (remap.scale - 1.).abs() < 1e-9);
+        let reversed = bezier.reversed();
+        let (direction, remap) = curves_coincident(&bezier, &reversed).unwrap();
+        assert_eq!(direction, Direction::Reverse);
+        assert!((remap.offset - 1.).abs() < 1e-9 && (remap.scale + 1.).abs() < 1e-9);
+
+        // Only part of the same curve is not coincident
+        assert!(curves_coincident(&bezier, &Curve::Bezier(sample_bezier().subdivide(0.5).0)).is_none());
+        assert!(curves_coincident(&ellipse, &bezier).is_none());
+    }
+
+    // The points at the ends of a bounded curve
+    fn ends(c: &Curve) -> (Trivector, Trivector) {
+        (c.d0(c.t_min().unwrap()).hat(), c.d0(c.t_max().unwrap()).hat())
+    }
+
+    #[test]
+    fn split_keeps_parameterization() {
+        let arc = circle(2.).trim(0.5, 2.);
+        let (first, second) = arc.split_at(1.);
+        assert_eq!((first.t_min(), first.t_max()), (Some(0.5), Some(1.)));
+        assert_eq!((second.t_min(), second.t_max()), (Some(1.), Some(2.)));
+        assert!(first.d0(0.7).hat().approx_eq(&arc.d0(0.7).hat()));
+        assert!(second.d0(1.6).hat().approx_eq(&arc.d0(1.6).hat()));
+        assert!(ends(&first).1.approx_eq(&ends(&second).0));
+
+        let bezier = Curve::Bezier(sample_bezier());
+        let (first, second) = bezier.split_at(0.3);
+        assert!(ends(&first).0.approx_eq(&bezier.d0(0.).hat()) && ends(&second).1.approx_eq(&bezier.d0(1.).hat()));
+        assert!(second.d0(0.65).hat().approx_eq(&bezier.d0(0.65).hat()));
+    }
+
+    #[test]
+    fn reversal_flips_direction() {
+        let line = Curve::line_from_two_points(point_from_xyz(1., 2., 3.), point_from_xyz(2., 2., 5.));
+        let curves = [
+            line.clone(),
+            circle(2.),
+            circle(2.).trim(5., 1.),
+            Curve::Bezier(sample_bezier()),
+            Curve::Nurbs(sample_nurbs()),
+            Curve::polyline(vec![point_from_xyz(0., 0., 0.), point_from_xyz(3., 0., 0.), point_from_xyz(3., 4., 0.)], false),
+            Curve::ellipse_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.)),
+            circle(2.).trim(5., 1.).offset(0.5, inf_point_from_xyz(1., 1., 1.)),
+        ];
+        for curve in curves.iter() {
+            let (reversed, remap) = (curve.reversed(), curve.reversal_remap());
+            assert_eq!(remap.direction(), Direction::Reverse);
+            let (start_t, end_t) = (curve.t_min().unwrap_or(-1.), curve.t_max().unwrap_or(1.));
+            for i in 0..=4 {
+                let t = start_t + (end_t - start_t) * i as Float / 4.;
+                let t1 = remap.apply(t);
+                assert!(reversed.d0(t1).hat().approx_eq(&curve.d0(t).hat()), "{:?} reversed at {}", curve, t);
+                assert!(reversed.d1(t1).approx_eq(&-curve.d1(t)));
+            }
+            // Reversing twice is the same curve
+            assert!(curves_coincident(curve, &reversed.reversed()).unwrap().0 == Direction::Forward);
+        }
+
+        // Lines and circles reverse their direction and axis
+        let (l, reversed) = match (line.clone(), line.reversed()) {
+            (Curve::Line(x), Curve::Line(y)) => (x, y),
+            _ => unreachable!(),
+        };
+        assert!(reversed.d.approx_eq(&-l.d));
+        let (c, reversed) = match (circle(2.), circle(2.).reversed()) {
+            (Curve::Circle(x), Curve::Circle(y)) => (x, y),
+            _ => unreachable!(),
+        };
+        assert!(reversed.a.approx_eq(&-c.a));
+    }
+
+    #[test]
+    fn extension_continues_curves() {
+        // Segments of lines and arcs are continued exactly
+        let line = Curve::line_from_two_points(point_from_xyz(1., 2., 3.), point_from_xyz(2., 2., 5.));
+        let segment = line.clone().trim(0., 1.).extend(1., 2.);
+        assert_eq!((segment.t_min(), segment.t_max()), (Some(-1.), Some(3.)));
+        let (a, b) = ends(&segment);
+        assert!(a.approx_eq(&line.d0(-1.).hat()) && b.approx_eq(&line.d0(3.).hat()));
+
+        let arc = circle(2.).trim(0.5, 2.).extend(1., 0.5);
+        let (a, b) = ends(&arc);
+        assert!(a.approx_eq(&circle(2.).d0(-0.5).hat()) && b.approx_eq(&circle(2.).d0(2.5).hat()));
+        assert!((arc.length(arc.t_min().unwrap(), arc.t_max().unwrap()) - 2. * 3.).abs() < 1e-9);
+        // An arc stops growing when it comes back around to meet itself
+        let arc = circle(2.).trim(0.5, 2.).extend(10., 10.);
+        assert!((arc.length(arc.t_min().unwrap(), arc.t_max().unwrap()) - 2. * 2. * PI).abs() < 1e-9);
+
+        // A Bezier curve carries on along its polynomial, which de Casteljau's algorithm also evaluates outside [0, 1]
+        // (with weights that vary gently, so that they stay positive)
+        let bezier = Bezier {points: vec![
+            point_from_xyz(0., 0., 0.),
+            1.2 * point_from_xyz(1., 3., 0.),
+            0.9 * point_from_xyz(3., 3., 1.),
+            point_from_xyz(4., 0., 2.),
+        ]};
+        let extended = Curve::Bezier(bezier.clone()).extend(0.2, 0.5);
+        assert_eq!((extended.t_min(), extended.t_max()), (Some(-0.2), Some(1.5)));
+        for &t in &[-0.2, 0.4, 1.2, 1.5] {
+            assert!(extended.d0(t).hat().approx_eq(&bezier.d0(t).hat()));
+        }
+
+        // A spline's extension joins it with continuous curvature
+        let n = sample_nurbs();
+        let extended = Curve::Nurbs(n.clone()).extend(0., 0.3);
+        let h = 1e-7;
+        assert!(extended.d0(2.5).hat().approx_eq(&n.d0(2.5).hat()));
+        assert!(extended.d1(3. + h).approx_eq_tol(&n.d1(3.), 1e-5));
+        assert!(extended.d2(3. + h).approx_eq_tol(&n.d2(3.), 1e-4));
+
+        // Closed and unbounded curves are left as they are
+        assert!(circle(2.).extend(1., 1.).approx_eq(&circle(2.)));
+        assert!(line.extend(1., 1.).approx_eq(&line));
+    }
+
+    // The box around many points along a curve, which is just inside its true bounding box
+    fn sampled_box(c: &Curve, start_t: Float, end_t: Float) -> BoundingBox {
+        BoundingBox::from_points(&(0..=4000).map(|i| c.d0(start_t + (end_t - start_t) * i as Float / 4000.).hat()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn bounding_boxes_are_tight() {
+        // A circle of radius 2 about the origin in the XY plane reaches exactly to 2 on each side
+        let up = inf_point_from_xyz(0., 0., 1.);
+        let c = Curve::circle_from_center_axis_radius(point_from_xyz(0., 0., 0.), up, 2.).unwrap();
+        let b = c.bounding_box(0., 2. * PI);
+        assert!((0..3).all(|i| (b.min[i] - [-2., -2., 0.][i]).abs() < 1e-12 && (b.max[i] - [2., 2., 0.][i]).abs() < 1e-12));
+        // A quarter arc from (2, 0) to (0, 2) only covers one quadrant
+        let arc = Curve::arc_from_center_start_angle(point_from_xyz(0., 0., 0.), up, point_from_xyz(2., 0., 0.), 0.5 * PI).unwrap();
+        let b = arc.bounding_box(arc.t_min().unwrap(), arc.t_max().unwrap());
+        assert!((0..3).all(|i| (b.min[i] - [0., 0., 0.][i]).abs() < 1e-12 && (b.max[i] - [2., 2., 0.][i]).abs() < 1e-12));
+
+        let z_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(0., 0., 1.);
+        let curves = [
+            (circle(2.), 0.3, 4.),
+            (Curve::line_from_two_points(point_from_xyz(1., 2., 3.), point_from_xyz(2., 0., 5.)), -1., 2.),
+            (Curve::ellipse_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 1.)), 1., 5.),
+            (Curve::hyperbola_from_center_and_axes(point_from_xyz(1., 2., 3.), inf_point_from_xyz(0., 2., 0.), inf_point_from_xyz(1., 0., 1.)), -1.5, 1.),
+            (Curve::parabola_from_focus_and_directrix(point_from_xyz(0., 1., 0.), point_from_xyz(0., -1., 0.) & point_from_xyz(1., -1., 1.)), -2., 1.),
+            (Curve::helix(point_from_xyz(2., 0., 0.), z_axis, 1.5, 0.1), 0.5, 9.),
+            (Curve::spiral(point_from_xyz(0., 0., 0.), z_axis, inf_point_from_xyz(1., 0., 0.), 1., 0.5), 0., 8.),
+            (Curve::Bezier(sample_bezier()), 0.1, 0.9),
+            (Curve::Nurbs(sample_nurbs()), 0.2, 2.8),
+            (Curve::Bezier(sample_bezier()).offset(0.3, up), 0., 1.),
+            (Curve::polyline(vec![point_from_xyz(0., 0., 0.), point_from_xyz(3., 0., 0.), point_from_xyz(3., 4., 1.)], false), 1., 6.),
+            (circle(2.).trim(5., 1.), 5., 7.),
+        ];
+        for (c, start_t, end_t) in curves.iter() {
+            // The box contains the curve, and reaches no farther than its extremes
+            let b = c.bounding_box(*start_t, *end_t);
+            let sampled = sampled_box(c, *start_t, *end_t);
+            assert!(b.corners().iter().all(|&p| sampled.contains(p, 1e-5)), "{:?} has a loose box {:?}", c, b);
+            assert!(sampled.corners().iter().all(|&p| b.contains(p, 1e-9)), "{:?} has a box {:?} that misses part of it", c, b);
+
+            let obb = c.oriented_bounding_box(*start_t, *end_t);
+            assert!((0..=100).all(|i| obb.contains(c.d0(start_t + (end_t - start_t) * i as Float / 100.).hat(), 1e-9)));
+        }
+    }
 }
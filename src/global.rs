@@ -6,8 +6,10 @@ pub type Float = f64;
 pub const FLOAT_DIVISION_EPSILON: Float = 1e-9;
 pub const PI: Float = f64::consts::PI;
 pub const EPSILON_COINCIDENT_DISTANCE: f64 = 1e-9; // Distance between two coincident objects (like vertices or a vertex and a plane) must not be farther than this value
+pub const EPSILON_APPROXIMATION: f64 = 1e-6; // Distance between a curve and an approximation of it (for curves without an exact representation) must not be farther than this value
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Forward,
     Reverse,
@@ -14,26 +14,11 @@ pub fn interpolate_curve_subset_fixed(c: &Curve, t_start: Float, t_end: Float, n
 
     let mut result = vec![Trivector::zero(); n];
 
-    match c.t_min() {
-        Some(t_min) => {
-            assert!(t_start >= t_min, "t_start must be at least t_min");
-            assert!(t_end >= t_min, "t_end must be at least t_min");
-        },
-        None => {},
-    }
-
-    match c.t_max() {
-        Some(t_max) => {
-            assert!(t_start <= t_max, "t_start must be at most t_max");
-            assert!(t_end <= t_max, "t_end must be at most t_max");
-        },
-        None => {},
-    }
+    check_subset(c, t_start, t_end);
 
     if t_end < t_start {
         // Interpolate through the parameter discontinuity
         // on a closed curve
-        assert!(c.closed(), "Unless the curve is closed, t_end must be greater than t_start");
         let t_min = c.t_min().unwrap();
         let t_max = c.t_max().unwrap();
         let new_t_end = t_end + (t_max - t_min);
@@ -76,3 +61,124 @@ pub fn interpolate_closed_curve_fixed(c: &Curve, n: usize) -> Vec<Trivector> {
 
     result
 }
+
+// Interpolates n points on a curve evenly spaced by arc length,
+// starting at t_start and ending at t_end.
+// The result is a vector of the interpolated points.
+// Preconditions are the same as for interpolate_curve_subset_fixed.
+pub fn interpolate_curve_subset_arc_length(c: &Curve, t_start: Float, t_end: Float, n: usize) -> Vec<Trivector> {
+    assert!(n >= 2);
+
+    check_subset(c, t_start, t_end);
+
+    let length = if t_end < t_start {
+        // Measure through the parameter discontinuity
+        // on a closed curve
+        c.length(t_start, c.t_max().unwrap()) + c.length(c.t_min().unwrap(), t_end)
+    } else {
+        c.length(t_start, t_end)
+    };
+
+    // Step along the curve from each point to the next
+    let step = length / (n - 1) as Float;
+    let mut t = t_start;
+    let mut result = vec![c.d0(t_start)];
+    for _ in 1..n - 1 {
+        t = c.t_at_length(t, step).expect("the curve ended before its subset did");
+        result.push(c.d0(t));
+    }
+    result.push(c.d0(t_end));
+
+    result
+}
+
+// Interpolates n points on a closed curve evenly spaced by arc length.
+// The result is a vector of the interpolated points.
+// Precondition: n >= 2.
+// Precondition: the curve is closed.
+pub fn interpolate_closed_curve_arc_length(c: &Curve, n: usize) -> Vec<Trivector> {
+    assert!(n >= 2);
+    assert!(c.closed(), "The curve must be closed");
+
+    let t_min = c.t_min().unwrap();
+    let step = c.length(t_min, c.t_max().unwrap()) / n as Float;
+
+    let mut t = t_min;
+    let mut result = vec![c.d0(t_min)];
+    for _ in 1..n {
+        t = c.t_at_length(t, step).unwrap();
+        result.push(c.d0(t));
+    }
+
+    result
+}
+
+// Checks the preconditions on the range of a subset of a curve to interpolate
+fn check_subset(c: &Curve, t_start: Float, t_end: Float) {
+    if let Some(t_min) = c.t_min() {
+        assert!(t_start >= t_min, "t_start must be at least t_min");
+        assert!(t_end >= t_min, "t_end must be at least t_min");
+    }
+
+    if let Some(t_max) = c.t_max() {
+        assert!(t_start <= t_max, "t_start must be at most t_max");
+        assert!(t_end <= t_max, "t_end must be at most t_max");
+    }
+
+    if t_end < t_start {
+        assert!(c.closed(), "Unless the curve is closed, t_end must be greater than t_start");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::construct::*;
+
+    // An ellipse in the XY plane, whose speed varies around it
+    fn ellipse() -> Curve {
+        Curve::ellipse_from_center_and_axes(point_from_xyz(1., 2., 0.), inf_point_from_xyz(3., 0., 0.), inf_point_from_xyz(0., 1., 0.))
+    }
+
+    // The lengths along the curve between consecutive points
+    fn gaps(c: &Curve, points: &[Trivector], closed: bool) -> Vec<Float> {
+        let ts: Vec<Float> = points.iter().map(|&p| c.t(p)).collect();
+        let mut gaps: Vec<Float> = ts.windows(2).map(|pair| c.length(pair[0], pair[1]).rem_euclid(c.length(0., 2. * PI))).collect();
+        if closed {
+            gaps.push(c.length(ts[ts.len() - 1], 2. * PI) + c.length(0., ts[0]));
+        }
+        gaps
+    }
+
+    #[test]
+    fn fixed_spacing_follows_parameter() {
+        let c = ellipse();
+        let points = interpolate_curve_subset_fixed(&c, 0.5, 2.5, 5);
+        assert!(points.iter().enumerate().all(|(i, p)| (c.t(*p) - (0.5 + 0.5 * i as Float)).abs() < 1e-9));
+        // Through the seam of a closed curve
+        let points = interpolate_curve_subset_fixed(&c, 6., 1., 3);
+        assert!((c.t(points[1]) - (3.5 - PI)).abs() < 1e-9);
+        assert_eq!(interpolate_closed_curve_fixed(&c, 8).len(), 8);
+    }
+
+    #[test]
+    fn arc_length_spacing_is_even() {
+        let c = ellipse();
+        let points = interpolate_curve_subset_arc_length(&c, 0.5, 2.5, 6);
+        let expected = c.length(0.5, 2.5) / 5.;
+        assert!(gaps(&c, &points, false).iter().all(|gap| (gap - expected).abs() < 1e-6));
+
+        let points = interpolate_curve_subset_arc_length(&c, 6., 1., 4);
+        let expected = (c.length(6., 2. * PI) + c.length(0., 1.)) / 3.;
+        assert!(gaps(&c, &points, false).iter().all(|gap| (gap - expected).abs() < 1e-6));
+
+        let points = interpolate_closed_curve_arc_length(&c, 7);
+        let expected = c.length(0., 2. * PI) / 7.;
+        assert_eq!(points.len(), 7);
+        assert!(gaps(&c, &points, true).iter().all(|gap| (gap - expected).abs() < 1e-6));
+
+        // Evenly spaced in t is not evenly spaced along an ellipse
+        let points = interpolate_closed_curve_fixed(&c, 7);
+        assert!(gaps(&c, &points, true).iter().any(|gap| (gap - expected).abs() > 1e-2));
+    }
+}
@@ -1,8 +1,15 @@
 pub mod global;
+pub mod scalar;
 pub mod pga;
+pub mod predicates;
 pub mod construct;
+mod vec3;
 pub mod vertex;
 pub mod curve;
 pub mod surface;
 pub mod topo;
 pub mod interpolate;
+pub mod approx;
+pub mod bounds;
+#[cfg(any(feature = "nalgebra", feature = "mint"))]
+pub mod convert;
@@ -24,7 +24,11 @@
 // so there is good interoperability.
 // One exception is that using ! to take the dual has been removed (use .dual())
 
+// All types are generic over their scalar type (see the scalar module),
+// which defaults to Float.
+
 use crate::global::{Float, FLOAT_DIVISION_EPSILON};
+use crate::scalar::{Scalar, DualNumber};
 use std::ops::{Add, Sub, Mul, Neg, BitXor, BitAnd, BitOr};
 
 pub trait Reverse {
@@ -40,31 +44,31 @@ pub trait Conjugate {
     fn conjugate(self) -> Self;
 }
 
-pub trait Normalize {
-    fn norm(self) -> Float;
+pub trait Normalize<T: Scalar = Float> {
+    fn norm(self) -> T;
 
     // Return a normalized copy
-    fn hat(self) -> Self where Self: Copy + Mul<Float, Output=Self> {
+    fn hat(self) -> Self where Self: Copy + Mul<T, Output=Self> {
         assert!(self.is_finite(), "norm is zero");
-        self * (1. / self.norm())
+        self * (T::one() / self.norm())
     }
 
     fn is_finite(self) -> bool where Self: Copy {
-        return self.norm() > FLOAT_DIVISION_EPSILON;
+        return self.norm() > T::from_f64(FLOAT_DIVISION_EPSILON);
     }
 }
 
-pub trait NormalizeInfinite {
-    fn inorm(self) -> Float;
+pub trait NormalizeInfinite<T: Scalar = Float> {
+    fn inorm(self) -> T;
 
     // Return a infinite-normalized copy
-    fn ihat(self) -> Self where Self: Copy + Mul<Float, Output=Self> {
+    fn ihat(self) -> Self where Self: Copy + Mul<T, Output=Self> {
         assert!(self.is_infinite(), "inorm is zero");
-        self * (1. / self.inorm())
+        self * (T::one() / self.inorm())
     }
 
     fn is_infinite(self) -> bool where Self: Copy {
-        return self.inorm() > FLOAT_DIVISION_EPSILON;
+        return self.inorm() > T::from_f64(FLOAT_DIVISION_EPSILON);
     }
 }
 
@@ -84,157 +88,1091 @@ pub trait Transform<Entity> {
 // Float
 // ===========================================================================
 
-impl Reverse for Float {
-    fn reverse(self) -> Float {
-        self
+// Operations with a scalar on the left-hand side
+// can't be implemented generically over the scalar type,
+// so this macro implements them for one concrete scalar type.
+macro_rules! impl_scalar_ops {
+    ($t:ty) => {
+        impl Reverse for $t {
+            fn reverse(self) -> $t {
+                self
+            }
+        }
+
+        impl Dual for $t {
+            type Output = Pseudoscalar<$t>;
+
+            fn dual(self) -> Pseudoscalar<$t> {
+                Pseudoscalar {
+                    a15: self,
+                }
+            }
+        }
+
+        impl Conjugate for $t {
+            fn conjugate(self) -> $t {
+                self
+            }
+        }
+
+        impl Normalize<$t> for $t {
+            fn norm(self) -> $t {
+                self.abs()
+            }
+        }
+
+        impl NormalizeInfinite<$t> for $t {
+            fn inorm(self) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        impl Add<Vector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn add(self, r: Vector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self,
+                    a1: r.a1,
+                    a2: r.a2,
+                    a3: r.a3,
+                    a4: r.a4,
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a11: <$t>::zero(),
+                    a12: <$t>::zero(),
+                    a13: <$t>::zero(),
+                    a14: <$t>::zero(),
+                    a15: <$t>::zero(),
+                }
+            }
+        }
+
+        impl Add<Bivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn add(self, r: Bivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self,
+                    a5: r.a5,
+                    a6: r.a6,
+                    a7: r.a7,
+                    a8: r.a8,
+                    a9: r.a9,
+                    a10: r.a10,
+                }
+            }
+        }
+
+        impl Add<Trivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn add(self, r: Trivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self,
+                    a1: <$t>::zero(),
+                    a2: <$t>::zero(),
+                    a3: <$t>::zero(),
+                    a4: <$t>::zero(),
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a11: r.a11,
+                    a12: r.a12,
+                    a13: r.a13,
+                    a14: r.a14,
+                    a15: <$t>::zero(),
+                }
+            }
+        }
+
+        impl Add<Pseudoscalar<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn add(self, r: Pseudoscalar<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self,
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a15: r.a15,
+                }
+            }
+        }
+
+        impl Add<ScalarAndBivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn add(self, r: ScalarAndBivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self + r.a0,
+                    a5: r.a5,
+                    a6: r.a6,
+                    a7: r.a7,
+                    a8: r.a8,
+                    a9: r.a9,
+                    a10: r.a10,
+                }
+            }
+        }
+
+        impl Add<Motor<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn add(self, r: Motor<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self + r.a0,
+                    a5: r.a5,
+                    a6: r.a6,
+                    a7: r.a7,
+                    a8: r.a8,
+                    a9: r.a9,
+                    a10: r.a10,
+                    a15: r.a15,
+                }
+            }
+        }
+
+        impl Add<FullMultivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn add(self, r: FullMultivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self + r.a0,
+                    a1: r.a1,
+                    a2: r.a2,
+                    a3: r.a3,
+                    a4: r.a4,
+                    a5: r.a5,
+                    a6: r.a6,
+                    a7: r.a7,
+                    a8: r.a8,
+                    a9: r.a9,
+                    a10: r.a10,
+                    a11: r.a11,
+                    a12: r.a12,
+                    a13: r.a13,
+                    a14: r.a14,
+                    a15: r.a15,
+                }
+            }
+        }
+
+        impl Sub<Vector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn sub(self, r: Vector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self,
+                    a1: -r.a1,
+                    a2: -r.a2,
+                    a3: -r.a3,
+                    a4: -r.a4,
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a11: <$t>::zero(),
+                    a12: <$t>::zero(),
+                    a13: <$t>::zero(),
+                    a14: <$t>::zero(),
+                    a15: <$t>::zero(),
+                }
+            }
+        }
+
+        impl Sub<Bivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn sub(self, r: Bivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self,
+                    a5: -r.a5,
+                    a6: -r.a6,
+                    a7: -r.a7,
+                    a8: -r.a8,
+                    a9: -r.a9,
+                    a10: -r.a10,
+                }
+            }
+        }
+
+        impl Sub<Trivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn sub(self, r: Trivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self,
+                    a1: <$t>::zero(),
+                    a2: <$t>::zero(),
+                    a3: <$t>::zero(),
+                    a4: <$t>::zero(),
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a11: -r.a11,
+                    a12: -r.a12,
+                    a13: -r.a13,
+                    a14: -r.a14,
+                    a15: <$t>::zero(),
+                }
+            }
+        }
+
+        impl Sub<Pseudoscalar<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn sub(self, r: Pseudoscalar<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self,
+                    a5: <$t>::zero(),
+                    a6: <$t>::zero(),
+                    a7: <$t>::zero(),
+                    a8: <$t>::zero(),
+                    a9: <$t>::zero(),
+                    a10: <$t>::zero(),
+                    a15: -r.a15,
+                }
+            }
+        }
+
+        impl Sub<ScalarAndBivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn sub(self, r: ScalarAndBivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self - r.a0,
+                    a5: -r.a5,
+                    a6: -r.a6,
+                    a7: -r.a7,
+                    a8: -r.a8,
+                    a9: -r.a9,
+                    a10: -r.a10,
+                }
+            }
+        }
+
+        impl Sub<Motor<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn sub(self, r: Motor<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self - r.a0,
+                    a5: -r.a5,
+                    a6: -r.a6,
+                    a7: -r.a7,
+                    a8: -r.a8,
+                    a9: -r.a9,
+                    a10: -r.a10,
+                    a15: -r.a15,
+                }
+            }
+        }
+
+        impl Sub<FullMultivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn sub(self, r: FullMultivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self - r.a0,
+                    a1: -r.a1,
+                    a2: -r.a2,
+                    a3: -r.a3,
+                    a4: -r.a4,
+                    a5: -r.a5,
+                    a6: -r.a6,
+                    a7: -r.a7,
+                    a8: -r.a8,
+                    a9: -r.a9,
+                    a10: -r.a10,
+                    a11: -r.a11,
+                    a12: -r.a12,
+                    a13: -r.a13,
+                    a14: -r.a14,
+                    a15: -r.a15,
+                }
+            }
+        }
+
+        impl Mul<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn mul(self, r: Vector<$t>) -> Vector<$t> {
+                Vector {
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                }
+            }
+        }
+
+        impl Mul<Bivector<$t>> for $t {
+            type Output = Bivector<$t>;
+
+            fn mul(self, r: Bivector<$t>) -> Bivector<$t> {
+                Bivector {
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        impl Mul<Trivector<$t>> for $t {
+            type Output = Trivector<$t>;
+
+            fn mul(self, r: Trivector<$t>) -> Trivector<$t> {
+                Trivector {
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                }
+            }
+        }
+
+        impl Mul<Pseudoscalar<$t>> for $t {
+            type Output = Pseudoscalar<$t>;
+
+            fn mul(self, r: Pseudoscalar<$t>) -> Pseudoscalar<$t> {
+                Pseudoscalar {
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        impl Mul<ScalarAndBivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn mul(self, r: ScalarAndBivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        impl Mul<Motor<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn mul(self, r: Motor<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        impl Mul<FullMultivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn mul(self, r: FullMultivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self * r.a0,
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn bitxor(self, r: Vector<$t>) -> Vector<$t> {
+                Vector {
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<Bivector<$t>> for $t {
+            type Output = Bivector<$t>;
+
+            fn bitxor(self, r: Bivector<$t>) -> Bivector<$t> {
+                Bivector {
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<Trivector<$t>> for $t {
+            type Output = Trivector<$t>;
+
+            fn bitxor(self, r: Trivector<$t>) -> Trivector<$t> {
+                Trivector {
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<Pseudoscalar<$t>> for $t {
+            type Output = Pseudoscalar<$t>;
+
+            fn bitxor(self, r: Pseudoscalar<$t>) -> Pseudoscalar<$t> {
+                Pseudoscalar {
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<ScalarAndBivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn bitxor(self, r: ScalarAndBivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<Motor<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn bitxor(self, r: Motor<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitXor<FullMultivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn bitxor(self, r: FullMultivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self * r.a0,
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<Vector<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, _r: Vector<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<Bivector<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, _r: Bivector<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<Trivector<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, _r: Trivector<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<Pseudoscalar<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, r: Pseudoscalar<$t>) -> $t {
+                self * r.a15
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<ScalarAndBivector<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, _r: ScalarAndBivector<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<Motor<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, r: Motor<$t>) -> $t {
+                self * r.a15
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitAnd<FullMultivector<$t>> for $t {
+            type Output = $t;
+
+            fn bitand(self, r: FullMultivector<$t>) -> $t {
+                self * r.a15
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn bitor(self, r: Vector<$t>) -> Vector<$t> {
+                Vector {
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<Bivector<$t>> for $t {
+            type Output = Bivector<$t>;
+
+            fn bitor(self, r: Bivector<$t>) -> Bivector<$t> {
+                Bivector {
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<Trivector<$t>> for $t {
+            type Output = Trivector<$t>;
+
+            fn bitor(self, r: Trivector<$t>) -> Trivector<$t> {
+                Trivector {
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<Pseudoscalar<$t>> for $t {
+            type Output = Pseudoscalar<$t>;
+
+            fn bitor(self, r: Pseudoscalar<$t>) -> Pseudoscalar<$t> {
+                Pseudoscalar {
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<ScalarAndBivector<$t>> for $t {
+            type Output = ScalarAndBivector<$t>;
+
+            fn bitor(self, r: ScalarAndBivector<$t>) -> ScalarAndBivector<$t> {
+                ScalarAndBivector {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<Motor<$t>> for $t {
+            type Output = Motor<$t>;
+
+            fn bitor(self, r: Motor<$t>) -> Motor<$t> {
+                Motor {
+                    a0: self * r.a0,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl BitOr<FullMultivector<$t>> for $t {
+            type Output = FullMultivector<$t>;
+
+            fn bitor(self, r: FullMultivector<$t>) -> FullMultivector<$t> {
+                FullMultivector {
+                    a0: self * r.a0,
+                    a1: self * r.a1,
+                    a2: self * r.a2,
+                    a3: self * r.a3,
+                    a4: self * r.a4,
+                    a5: self * r.a5,
+                    a6: self * r.a6,
+                    a7: self * r.a7,
+                    a8: self * r.a8,
+                    a9: self * r.a9,
+                    a10: self * r.a10,
+                    a11: self * r.a11,
+                    a12: self * r.a12,
+                    a13: self * r.a13,
+                    a14: self * r.a14,
+                    a15: self * r.a15,
+                }
+            }
+        }
+
+        impl Project<$t> for $t {
+            fn project(self, r: $t) -> $t {
+                self * r.powi(2)
+            }
+        }
+
+        impl Project<Vector<$t>> for $t {
+            fn project(self, r: Vector<$t>) -> $t {
+                self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+            }
+        }
+
+        impl Project<Trivector<$t>> for $t {
+            fn project(self, r: Trivector<$t>) -> $t {
+                -self * r.a14.powi(2)
+            }
+        }
+
+        impl Project<Pseudoscalar<$t>> for $t {
+            fn project(self, _r: Pseudoscalar<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        impl Reflect<$t> for $t {
+            fn reflect(self, r: $t) -> $t {
+                self * r.powi(2)
+            }
+        }
+
+        impl Reflect<Vector<$t>> for $t {
+            fn reflect(self, r: Vector<$t>) -> $t {
+                self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+            }
+        }
+
+        impl Reflect<Trivector<$t>> for $t {
+            fn reflect(self, r: Trivector<$t>) -> $t {
+                -self * r.a14.powi(2)
+            }
+        }
+
+        impl Reflect<Pseudoscalar<$t>> for $t {
+            fn reflect(self, _r: Pseudoscalar<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+
+        impl Transform<$t> for $t {
+            fn transform(self, r: $t) -> $t {
+                self * r.powi(2)
+            }
+        }
+
+        impl Transform<Vector<$t>> for $t {
+            fn transform(self, r: Vector<$t>) -> $t {
+                self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+            }
+        }
+
+        impl Transform<Trivector<$t>> for $t {
+            fn transform(self, r: Trivector<$t>) -> $t {
+                self * r.a14.powi(2)
+            }
+        }
+
+        impl Transform<Pseudoscalar<$t>> for $t {
+            fn transform(self, _r: Pseudoscalar<$t>) -> $t {
+                <$t>::zero()
+            }
+        }
+    };
+}
+
+impl_scalar_ops!(f64);
+impl_scalar_ops!(f32);
+impl_scalar_ops!(DualNumber);
+
+// ===========================================================================
+// Vector
+// ===========================================================================
+
+
+#[derive(Default,Debug,Clone,Copy,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector<T = Float> {
+    a1: T,
+    a2: T,
+    a3: T,
+    a4: T,
+}
+
+impl<T: Scalar> Reverse for Vector<T> {
+    fn reverse(self) -> Vector<T> {
+        Vector {
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+        }
     }
 }
 
-impl Dual for Float {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Dual for Vector<T> {
+    type Output = Trivector<T>;
 
-    fn dual(self) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self,
+    fn dual(self) -> Trivector<T> {
+        Trivector {
+            a11: self.a4,
+            a12: self.a3,
+            a13: self.a2,
+            a14: self.a1,
+        }
+    }
+}
+
+impl<T: Scalar> Conjugate for Vector<T> {
+    fn conjugate(self) -> Vector<T> {
+        Vector {
+            a1: -self.a1,
+            a2: -self.a2,
+            a3: -self.a3,
+            a4: -self.a4,
         }
     }
 }
 
-impl Conjugate for Float {
-    fn conjugate(self) -> Float {
-        self
+impl<T: Scalar> Normalize<T> for Vector<T> {
+    fn norm(self) -> T {
+        (self.a2.powi(2) + self.a3.powi(2) + self.a4.powi(2)).sqrt()
+    }
+}
+
+impl<T: Scalar> NormalizeInfinite<T> for Vector<T> {
+    fn inorm(self) -> T {
+        self.a1.abs()
     }
 }
 
-impl Normalize for Float {
-    fn norm(self) -> Float {
-        self.abs()
+impl<T: Scalar> Vector<T> {
+    pub fn new(a1: T, a2: T, a3: T, a4: T) -> Vector<T> {
+        Vector {a1, a2, a3, a4}
+    }
+
+    pub fn zero() -> Vector<T> {
+        Default::default()
+    }
+
+    pub fn as_tuple(&self) -> (T, T, T, T) {
+        (self.a1, self.a2, self.a3, self.a4)
+    }
+
+    pub fn full_multivector(self) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
     }
 }
 
-impl NormalizeInfinite for Float {
-    fn inorm(self) -> Float {
-        0.
+impl<T: Scalar> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector {
+            a1: -self.a1,
+            a2: -self.a2,
+            a3: -self.a3,
+            a4: -self.a4,
+        }
     }
 }
 
-impl Add<Vector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<T> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: T) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: r.a1,
-            a2: r.a2,
-            a3: r.a3,
-            a4: r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: r,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Bivector> for Float {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn add(self, r: Bivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self,
+    fn add(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 + r.a1,
+            a2: self.a2 + r.a2,
+            a3: self.a3 + r.a3,
+            a4: self.a4 + r.a4,
+        }
+    }
+}
+
+impl<T: Scalar> Add<Bivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn add(self, r: Bivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
             a5: r.a5,
             a6: r.a6,
             a7: r.a7,
             a8: r.a8,
             a9: r.a9,
             a10: r.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Trivector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: r.a11,
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Pseudoscalar> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
+    fn add(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
             a15: r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for Float {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self + r.a0,
+    fn add(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
             a5: r.a5,
             a6: r.a6,
             a7: r.a7,
             a8: r.a8,
             a9: r.a9,
             a10: r.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<FullMultivector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Motor<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: Motor<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self + r.a0,
-            a1: r.a1,
-            a2: r.a2,
-            a3: r.a3,
-            a4: r.a4,
+            a0: r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: r.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Add<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: r.a0,
+            a1: self.a1 + r.a1,
+            a2: self.a2 + r.a2,
+            a3: self.a3 + r.a3,
+            a4: self.a4 + r.a4,
             a5: r.a5,
             a6: r.a6,
             a7: r.a7,
@@ -250,796 +1188,1182 @@ impl Add<FullMultivector> for Float {
     }
 }
 
-impl Sub<Vector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<T> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: T) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: -r.a1,
-            a2: -r.a2,
-            a3: -r.a3,
-            a4: -r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: -r,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Bivector> for Float {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, r: Bivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self,
+    fn sub(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 - r.a1,
+            a2: self.a2 - r.a2,
+            a3: self.a3 - r.a3,
+            a4: self.a4 - r.a4,
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Bivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn sub(self, r: Bivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
             a5: -r.a5,
             a6: -r.a6,
             a7: -r.a7,
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Trivector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Trivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: -r.a11,
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Pseudoscalar> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
+    fn sub(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+            a0: T::zero(),
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
             a15: -r.a15,
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for Float {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self - r.a0,
+    fn sub(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: -r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
             a5: -r.a5,
             a6: -r.a6,
             a7: -r.a7,
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<FullMultivector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Motor<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: Motor<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self - r.a0,
-            a1: -r.a1,
-            a2: -r.a2,
-            a3: -r.a3,
-            a4: -r.a4,
+            a0: -r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
             a5: -r.a5,
             a6: -r.a6,
             a7: -r.a7,
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
-            a11: -r.a11,
-            a12: -r.a12,
-            a13: -r.a13,
-            a14: -r.a14,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
             a15: -r.a15,
         }
     }
 }
 
-impl Mul<Vector> for Float {
-    type Output = Vector;
+impl<T: Scalar> Sub<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Vector) -> Vector {
-        Vector {
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: -r.a0,
+            a1: self.a1 - r.a1,
+            a2: self.a2 - r.a2,
+            a3: self.a3 - r.a3,
+            a4: self.a4 - r.a4,
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a11: -r.a11,
+            a12: -r.a12,
+            a13: -r.a13,
+            a14: -r.a14,
+            a15: -r.a15,
         }
     }
 }
 
-impl Mul<Bivector> for Float {
-    type Output = Bivector;
+impl<T: Scalar> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
+    fn mul(self, r: T) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r,
+            a2: self.a2 * r,
+            a3: self.a3 * r,
+            a4: self.a4 * r,
         }
     }
 }
 
-impl Mul<Trivector> for Float {
-    type Output = Trivector;
+impl<T: Scalar> Mul<Vector<T>> for Vector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn mul(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
+    fn mul(self, r: Vector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
+            a5: self.a1 * r.a2 - self.a2 * r.a1,
+            a6: self.a1 * r.a3 - self.a3 * r.a1,
+            a7: self.a1 * r.a4 - self.a4 * r.a1,
+            a8: self.a2 * r.a3 - self.a3 * r.a2,
+            a9: -self.a2 * r.a4 + self.a4 * r.a2,
+            a10: self.a3 * r.a4 - self.a4 * r.a3,
         }
     }
 }
 
-impl Mul<Pseudoscalar> for Float {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Mul<Bivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self * r.a15,
+    fn mul(self, r: Bivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: -self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for Float {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Mul<Trivector<T>> for Vector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self * r.a0,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
+    fn mul(self, r: Trivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a3 * r.a11 + self.a4 * r.a12,
+            a6: self.a2 * r.a11 - self.a4 * r.a13,
+            a7: -self.a2 * r.a12 + self.a3 * r.a13,
+            a8: self.a4 * r.a14,
+            a9: self.a3 * r.a14,
+            a10: self.a2 * r.a14,
+            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Pseudoscalar<T>> for Vector<T> {
+    type Output = Trivector<T>;
+
+    fn mul(self, r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a4 * r.a15,
+            a12: self.a3 * r.a15,
+            a13: self.a2 * r.a15,
+            a14: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn mul(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Motor<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn mul(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5 + self.a4 * r.a15,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a3 * r.a15 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a2 * r.a15 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<FullMultivector> for Float {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self * r.a0,
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
-            a15: self * r.a15,
+            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+            a5: self.a1 * r.a2 - self.a2 * r.a1 - self.a3 * r.a11 + self.a4 * r.a12,
+            a6: self.a1 * r.a3 + self.a2 * r.a11 - self.a3 * r.a1 - self.a4 * r.a13,
+            a7: self.a1 * r.a4 - self.a2 * r.a12 + self.a3 * r.a13 - self.a4 * r.a1,
+            a8: self.a2 * r.a3 - self.a3 * r.a2 + self.a4 * r.a14,
+            a9: -self.a2 * r.a4 + self.a3 * r.a14 + self.a4 * r.a2,
+            a10: self.a2 * r.a14 + self.a3 * r.a4 - self.a4 * r.a3,
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5 + self.a4 * r.a15,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a3 * r.a15 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a2 * r.a15 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
         }
     }
 }
 
-impl BitXor<Vector> for Float {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn bitxor(self, r: Vector) -> Vector {
+    fn bitxor(self, r: T) -> Vector<T> {
         Vector {
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
+            a1: self.a1 * r,
+            a2: self.a2 * r,
+            a3: self.a3 * r,
+            a4: self.a4 * r,
         }
     }
 }
 
-impl BitXor<Bivector> for Float {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for Vector<T> {
+    type Output = Bivector<T>;
 
-    fn bitxor(self, r: Bivector) -> Bivector {
+    fn bitxor(self, r: Vector<T>) -> Bivector<T> {
         Bivector {
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
+            a5: self.a1 * r.a2 - self.a2 * r.a1,
+            a6: self.a1 * r.a3 - self.a3 * r.a1,
+            a7: self.a1 * r.a4 - self.a4 * r.a1,
+            a8: self.a2 * r.a3 - self.a3 * r.a2,
+            a9: -self.a2 * r.a4 + self.a4 * r.a2,
+            a10: self.a3 * r.a4 - self.a4 * r.a3,
         }
     }
 }
 
-impl BitXor<Trivector> for Float {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for Vector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, r: Trivector) -> Trivector {
+    fn bitxor(self, r: Bivector<T>) -> Trivector<T> {
         Trivector {
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
         }
     }
 }
 
-impl BitXor<Pseudoscalar> for Float {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for Vector<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: Pseudoscalar) -> Pseudoscalar {
+    fn bitxor(self, r: Trivector<T>) -> Pseudoscalar<T> {
         Pseudoscalar {
-            a15: self * r.a15,
+            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
         }
     }
 }
 
-impl BitXor<ScalarAndBivector> for Float {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitxor(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self * r.a0,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
-        }
+    fn bitxor(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<FullMultivector> for Float {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
+    fn bitxor(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self * r.a0,
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
-            a15: self * r.a15,
+            a0: T::zero(),
+            a1: self.a1 * r.a0,
+            a2: self.a2 * r.a0,
+            a3: self.a3 * r.a0,
+            a4: self.a4 * r.a0,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitAnd<Vector> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, _r: Vector) -> Float {
-        0.
+    fn bitxor(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1 * r.a0,
+            a2: self.a2 * r.a0,
+            a3: self.a3 * r.a0,
+            a4: self.a4 * r.a0,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: T::zero(),
+        }
     }
 }
 
-impl BitAnd<Bivector> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, _r: Bivector) -> Float {
-        0.
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1 * r.a0,
+            a2: self.a2 * r.a0,
+            a3: self.a3 * r.a0,
+            a4: self.a4 * r.a0,
+            a5: self.a1 * r.a2 - self.a2 * r.a1,
+            a6: self.a1 * r.a3 - self.a3 * r.a1,
+            a7: self.a1 * r.a4 - self.a4 * r.a1,
+            a8: self.a2 * r.a3 - self.a3 * r.a2,
+            a9: -self.a2 * r.a4 + self.a4 * r.a2,
+            a10: self.a3 * r.a4 - self.a4 * r.a3,
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
+        }
     }
 }
 
-impl BitAnd<Trivector> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for Vector<T> {
+    type Output = T;
 
-    fn bitand(self, _r: Trivector) -> Float {
-        0.
+    fn bitand(self, _r: T) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<Pseudoscalar> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Pseudoscalar) -> Float {
-        self * r.a15
+    fn bitand(self, _r: Vector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<ScalarAndBivector> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitand(self, _r: ScalarAndBivector) -> Float {
-        0.
+    fn bitand(self, _r: Bivector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<FullMultivector> for Float {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitand(self, r: FullMultivector) -> Float {
-        self * r.a15
+    fn bitand(self, r: Trivector<T>) -> T {
+        -self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11
     }
 }
 
-impl BitOr<Vector> for Float {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Vector) -> Vector {
+    fn bitand(self, r: Pseudoscalar<T>) -> Vector<T> {
         Vector {
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
+            a1: self.a1 * r.a15,
+            a2: self.a2 * r.a15,
+            a3: self.a3 * r.a15,
+            a4: self.a4 * r.a15,
         }
     }
 }
 
-impl BitOr<Bivector> for Float {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitor(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
-        }
+    fn bitand(self, _r: ScalarAndBivector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitOr<Trivector> for Float {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
+    fn bitand(self, r: Motor<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.a15,
+            a2: self.a2 * r.a15,
+            a3: self.a3 * r.a15,
+            a4: self.a4 * r.a15,
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for Float {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self * r.a15,
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: -self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11,
+            a1: self.a1 * r.a15,
+            a2: self.a2 * r.a15,
+            a3: self.a3 * r.a15,
+            a4: self.a4 * r.a15,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for Float {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self * r.a0,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
+    fn bitor(self, r: T) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r,
+            a2: self.a2 * r,
+            a3: self.a3 * r,
+            a4: self.a4 * r,
         }
     }
 }
 
-impl BitOr<FullMultivector> for Float {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for Vector<T> {
+    type Output = T;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: self * r.a0,
-            a1: self * r.a1,
-            a2: self * r.a2,
-            a3: self * r.a3,
-            a4: self * r.a4,
-            a5: self * r.a5,
-            a6: self * r.a6,
-            a7: self * r.a7,
-            a8: self * r.a8,
-            a9: self * r.a9,
-            a10: self * r.a10,
-            a11: self * r.a11,
-            a12: self * r.a12,
-            a13: self * r.a13,
-            a14: self * r.a14,
-            a15: self * r.a15,
-        }
+    fn bitor(self, r: Vector<T>) -> T {
+        self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4
     }
 }
 
-impl Project<Float> for Float {
-    fn project(self, r: Float) -> Float {
-        self * r.powi(2)
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-impl Project<Vector> for Float {
-    fn project(self, r: Vector) -> Float {
-        self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+    fn bitor(self, r: Bivector<T>) -> Vector<T> {
+        Vector {
+            a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: -self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10,
+        }
     }
 }
 
-impl Project<Trivector> for Float {
-    fn project(self, r: Trivector) -> Float {
-        -self * r.a14.powi(2)
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for Vector<T> {
+    type Output = Bivector<T>;
 
-impl Project<Pseudoscalar> for Float {
-    fn project(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitor(self, r: Trivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a3 * r.a11 + self.a4 * r.a12,
+            a6: self.a2 * r.a11 - self.a4 * r.a13,
+            a7: -self.a2 * r.a12 + self.a3 * r.a13,
+            a8: self.a4 * r.a14,
+            a9: self.a3 * r.a14,
+            a10: self.a2 * r.a14,
+        }
     }
 }
 
-impl Reflect<Float> for Float {
-    fn reflect(self, r: Float) -> Float {
-        self * r.powi(2)
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for Vector<T> {
+    type Output = Trivector<T>;
 
-impl Reflect<Vector> for Float {
-    fn reflect(self, r: Vector) -> Float {
-        self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+    fn bitor(self, r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a4 * r.a15,
+            a12: self.a3 * r.a15,
+            a13: self.a2 * r.a15,
+            a14: T::zero(),
+        }
     }
 }
 
-impl Reflect<Trivector> for Float {
-    fn reflect(self, r: Trivector) -> Float {
-        -self * r.a14.powi(2)
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-impl Reflect<Pseudoscalar> for Float {
-    fn reflect(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitor(self, r: ScalarAndBivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+        }
     }
 }
 
-impl Transform<Float> for Float {
-    fn transform(self, r: Float) -> Float {
-        self * r.powi(2)
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
 
-impl Transform<Vector> for Float {
-    fn transform(self, r: Vector) -> Float {
-        self * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2))
+    fn bitor(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a4 * r.a15,
+            a12: self.a3 * r.a15,
+            a13: self.a2 * r.a15,
+            a14: T::zero(),
+            a15: T::zero(),
+        }
     }
 }
 
-impl Transform<Trivector> for Float {
-    fn transform(self, r: Trivector) -> Float {
-        self * r.a14.powi(2)
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for Vector<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+            a5: -self.a3 * r.a11 + self.a4 * r.a12,
+            a6: self.a2 * r.a11 - self.a4 * r.a13,
+            a7: -self.a2 * r.a12 + self.a3 * r.a13,
+            a8: self.a4 * r.a14,
+            a9: self.a3 * r.a14,
+            a10: self.a2 * r.a14,
+            a11: self.a4 * r.a15,
+            a12: self.a3 * r.a15,
+            a13: self.a2 * r.a15,
+            a14: T::zero(),
+            a15: T::zero(),
+        }
     }
 }
 
-impl Transform<Pseudoscalar> for Float {
-    fn transform(self, _r: Pseudoscalar) -> Float {
-        0.
+impl<T: Scalar> Project<T> for Vector<T> {
+    fn project(self, r: T) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.powi(2),
+            a2: self.a2 * r.powi(2),
+            a3: self.a3 * r.powi(2),
+            a4: self.a4 * r.powi(2),
+        }
     }
 }
 
-// ===========================================================================
-// Vector
-// ===========================================================================
-
-
-#[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct Vector {
-    a1: Float,
-    a2: Float,
-    a3: Float,
-    a4: Float,
+impl<T: Scalar> Project<Vector<T>> for Vector<T> {
+    fn project(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: r.a1 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+            a3: r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+            a4: r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+        }
+    }
 }
 
-impl Reverse for Vector {
-    fn reverse(self) -> Vector {
+impl<T: Scalar> Project<Trivector<T>> for Vector<T> {
+    fn project(self, r: Trivector<T>) -> Vector<T> {
         Vector {
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
+            a1: r.a14 * (self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11),
+            a2: -self.a2 * r.a14.powi(2),
+            a3: -self.a3 * r.a14.powi(2),
+            a4: -self.a4 * r.a14.powi(2),
         }
     }
 }
 
-impl Dual for Vector {
-    type Output = Trivector;
-
-    fn dual(self) -> Trivector {
-        Trivector {
-            a11: self.a4,
-            a12: self.a3,
-            a13: self.a2,
-            a14: self.a1,
+impl<T: Scalar> Project<Pseudoscalar<T>> for Vector<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> Vector<T> {
+        Vector {
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
         }
     }
 }
 
-impl Conjugate for Vector {
-    fn conjugate(self) -> Vector {
+impl<T: Scalar> Reflect<T> for Vector<T> {
+    fn reflect(self, r: T) -> Vector<T> {
         Vector {
-            a1: -self.a1,
-            a2: -self.a2,
-            a3: -self.a3,
-            a4: -self.a4,
+            a1: self.a1 * r.powi(2),
+            a2: self.a2 * r.powi(2),
+            a3: self.a3 * r.powi(2),
+            a4: self.a4 * r.powi(2),
         }
     }
 }
 
-impl Normalize for Vector {
-    fn norm(self) -> Float {
-        (self.a2.powi(2) + self.a3.powi(2) + self.a4.powi(2)).sqrt()
+impl<T: Scalar> Reflect<Vector<T>> for Vector<T> {
+    fn reflect(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 + T::from_f64(2.) * self.a4 * r.a1 * r.a4,
+            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a3 * (self.a2 * r.a3 - self.a3 * r.a2) - r.a4 * (self.a2 * r.a4 - self.a4 * r.a2),
+            a3: r.a2 * (self.a2 * r.a3 - self.a3 * r.a2) + r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a4 * (self.a3 * r.a4 - self.a4 * r.a3),
+            a4: r.a2 * (self.a2 * r.a4 - self.a4 * r.a2) + r.a3 * (self.a3 * r.a4 - self.a4 * r.a3) + r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+        }
     }
 }
 
-impl NormalizeInfinite for Vector {
-    fn inorm(self) -> Float {
-        self.a1.abs()
+impl<T: Scalar> Reflect<Bivector<T>> for Vector<T> {
+    fn reflect(self, r: Bivector<T>) -> Vector<T> {
+        Vector {
+            a1: -self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a6 * r.a8 - T::from_f64(2.) * self.a2 * r.a7 * r.a9 - T::from_f64(2.) * self.a3 * r.a5 * r.a8 + T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a5 * r.a9 - T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: r.a8 * (self.a2 * r.a8 - self.a4 * r.a10) + r.a9 * (self.a2 * r.a9 - self.a3 * r.a10) - r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
+            a3: r.a8 * (self.a3 * r.a8 - self.a4 * r.a9) - r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) - r.a10 * (self.a2 * r.a9 - self.a3 * r.a10),
+            a4: -T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a8 * r.a9 - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2),
+        }
     }
 }
 
-impl Vector {
-    pub fn new(a1: Float, a2: Float, a3: Float, a4: Float) -> Vector {
-        Vector {a1, a2, a3, a4}
+impl<T: Scalar> Reflect<Trivector<T>> for Vector<T> {
+    fn reflect(self, r: Trivector<T>) -> Vector<T> {
+        Vector {
+            a1: r.a14 * (self.a1 * r.a14 + T::from_f64(2.) * self.a2 * r.a13 + T::from_f64(2.) * self.a3 * r.a12 + T::from_f64(2.) * self.a4 * r.a11),
+            a2: -self.a2 * r.a14.powi(2),
+            a3: -self.a3 * r.a14.powi(2),
+            a4: -self.a4 * r.a14.powi(2),
+        }
     }
+}
 
-    pub fn zero() -> Vector {
-        Default::default()
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for Vector<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> Vector<T> {
+        Vector {
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+        }
     }
+}
 
-    pub fn as_tuple(&self) -> (Float, Float, Float, Float) {
-        (self.a1, self.a2, self.a3, self.a4)
+impl<T: Scalar> Transform<T> for Vector<T> {
+    fn transform(self, r: T) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.powi(2),
+            a2: self.a2 * r.powi(2),
+            a3: self.a3 * r.powi(2),
+            a4: self.a4 * r.powi(2),
+        }
     }
+}
 
-    pub fn full_multivector(self) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+impl<T: Scalar> Transform<Vector<T>> for Vector<T> {
+    fn transform(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 + T::from_f64(2.) * self.a4 * r.a1 * r.a4,
+            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a3 * (self.a2 * r.a3 - self.a3 * r.a2) - r.a4 * (self.a2 * r.a4 - self.a4 * r.a2),
+            a3: r.a2 * (self.a2 * r.a3 - self.a3 * r.a2) + r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a4 * (self.a3 * r.a4 - self.a4 * r.a3),
+            a4: r.a2 * (self.a2 * r.a4 - self.a4 * r.a2) + r.a3 * (self.a3 * r.a4 - self.a4 * r.a3) + r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
         }
     }
 }
 
-impl Neg for Vector {
-    type Output = Vector;
+impl<T: Scalar> Transform<Bivector<T>> for Vector<T> {
+    fn transform(self, r: Bivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: -r.a8 * (self.a2 * r.a8 - self.a4 * r.a10) - r.a9 * (self.a2 * r.a9 - self.a3 * r.a10) + r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
+            a3: -r.a8 * (self.a3 * r.a8 - self.a4 * r.a9) + r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a10 * (self.a2 * r.a9 - self.a3 * r.a10),
+            a4: r.a8 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a9 * (self.a3 * r.a8 - self.a4 * r.a9) + r.a10 * (self.a2 * r.a8 - self.a4 * r.a10),
+        }
+    }
+}
 
-    fn neg(self) -> Vector {
+impl<T: Scalar> Transform<Trivector<T>> for Vector<T> {
+    fn transform(self, r: Trivector<T>) -> Vector<T> {
         Vector {
-            a1: -self.a1,
-            a2: -self.a2,
-            a3: -self.a3,
-            a4: -self.a4,
+            a1: r.a14 * (-self.a1 * r.a14 - T::from_f64(2.) * self.a2 * r.a13 - T::from_f64(2.) * self.a3 * r.a12 - T::from_f64(2.) * self.a4 * r.a11),
+            a2: self.a2 * r.a14.powi(2),
+            a3: self.a3 * r.a14.powi(2),
+            a4: self.a4 * r.a14.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<Pseudoscalar<T>> for Vector<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> Vector<T> {
+        Vector {
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for Vector<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a5 - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a3 * r.a0 * r.a6 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a0 * r.a7 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: r.a0 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a8 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a9 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
+            a3: r.a0 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a8 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a10 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0),
+            a4: r.a0 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a8 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a9 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) - r.a10 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<Motor<T>> for Vector<T> {
+    fn transform(self, r: Motor<T>) -> Vector<T> {
+        Vector {
+            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a5 - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a2 * r.a10 * r.a15 + T::from_f64(2.) * self.a3 * r.a0 * r.a6 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a3 * r.a9 * r.a15 + T::from_f64(2.) * self.a4 * r.a0 * r.a7 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a15,
+            a2: r.a0 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a8 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a9 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
+            a3: r.a0 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a8 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a10 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0),
+            a4: r.a0 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a8 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a9 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) - r.a10 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10),
+        }
+    }
+}
+
+// ===========================================================================
+// Bivector
+// ===========================================================================
+
+
+#[derive(Default,Debug,Clone,Copy,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bivector<T = Float> {
+    a5: T,
+    a6: T,
+    a7: T,
+    a8: T,
+    a9: T,
+    a10: T,
+}
+
+impl<T: Scalar> Reverse for Bivector<T> {
+    fn reverse(self) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Dual for Bivector<T> {
+    type Output = Bivector<T>;
+
+    fn dual(self) -> Bivector<T> {
+        Bivector {
+            a5: self.a10,
+            a6: self.a9,
+            a7: self.a8,
+            a8: self.a7,
+            a9: self.a6,
+            a10: self.a5,
         }
     }
 }
 
-impl Add<Float> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Conjugate for Bivector<T> {
+    fn conjugate(self) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Normalize<T> for Bivector<T> {
+    fn norm(self) -> T {
+        (self.a8.powi(2) + self.a9.powi(2) + self.a10.powi(2)).sqrt()
+    }
+}
+
+impl<T: Scalar> NormalizeInfinite<T> for Bivector<T> {
+    fn inorm(self) -> T {
+        (self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2)).sqrt()
+    }
+}
 
-    fn add(self, r: Float) -> FullMultivector {
+impl<T: Scalar> Bivector<T> {
+    pub fn new(a5: T, a6: T, a7: T, a8: T, a9: T, a10: T) -> Bivector<T> {
+        Bivector {a5, a6, a7, a8, a9, a10}
+    }
+
+    pub fn zero() -> Bivector<T> {
+        Default::default()
+    }
+
+    pub fn as_tuple(&self) -> (T, T, T, T, T, T) {
+        (self.a5, self.a6, self.a7, self.a8, self.a9, self.a10)
+    }
+
+    pub fn motor(self) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: T::zero(),
+        }
+    }
+
+    pub fn full_multivector(self) -> FullMultivector<T> {
         FullMultivector {
-            a0: r,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Vector> for Vector {
-    type Output = Vector;
+impl<T: Scalar> Neg for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn add(self, r: Vector) -> Vector {
-        Vector {
-            a1: self.a1 + r.a1,
-            a2: self.a2 + r.a2,
-            a3: self.a3 + r.a3,
-            a4: self.a4 + r.a4,
+    fn neg(self) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Add<T> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
+
+    fn add(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: r,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
         }
     }
 }
 
-impl Add<Bivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Bivector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: T::zero(),
+            a1: r.a1,
+            a2: r.a2,
+            a3: r.a3,
+            a4: r.a4,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Add<Bivector<T>> for Bivector<T> {
+    type Output = Bivector<T>;
+
+    fn add(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
         }
     }
 }
 
-impl Add<Trivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
             a11: r.a11,
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Pseudoscalar> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for Bivector<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+    fn add(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
             a15: r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn add(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn add(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
             a0: r.a0,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Add<Motor<T>> for Bivector<T> {
+    type Output = Motor<T>;
+
+    fn add(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: r.a0,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+            a15: r.a15,
         }
     }
 }
 
-impl Add<FullMultivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: r.a0,
-            a1: self.a1 + r.a1,
-            a2: self.a2 + r.a2,
-            a3: self.a3 + r.a3,
-            a4: self.a4 + r.a4,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
+            a1: r.a1,
+            a2: r.a2,
+            a3: r.a3,
+            a4: r.a4,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
             a11: r.a11,
             a12: r.a12,
             a13: r.a13,
@@ -1049,160 +2373,153 @@ impl Add<FullMultivector> for Vector {
     }
 }
 
-impl Sub<Float> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<T> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn sub(self, r: Float) -> FullMultivector {
-        FullMultivector {
+    fn sub(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
             a0: -r,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
         }
     }
 }
 
-impl Sub<Vector> for Vector {
-    type Output = Vector;
+impl<T: Scalar> Sub<Vector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> Vector {
-        Vector {
-            a1: self.a1 - r.a1,
-            a2: self.a2 - r.a2,
-            a3: self.a3 - r.a3,
-            a4: self.a4 - r.a4,
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: -r.a1,
+            a2: -r.a2,
+            a3: -r.a3,
+            a4: -r.a4,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Bivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Bivector<T>> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn sub(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+    fn sub(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
         }
     }
 }
 
-impl Sub<Trivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Trivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
             a11: -r.a11,
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: 0.,
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Pseudoscalar<T>> for Bivector<T> {
+    type Output = Motor<T>;
+
+    fn sub(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<Pseudoscalar> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: -r.a15,
+    fn sub(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -r.a0,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Motor<T>> for Bivector<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn sub(self, r: Motor<T>) -> Motor<T> {
+        Motor {
             a0: -r.a0,
-            a1: self.a1,
-            a2: self.a2,
-            a3: self.a3,
-            a4: self.a4,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<FullMultivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -r.a0,
-            a1: self.a1 - r.a1,
-            a2: self.a2 - r.a2,
-            a3: self.a3 - r.a3,
-            a4: self.a4 - r.a4,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
+            a1: -r.a1,
+            a2: -r.a2,
+            a3: -r.a3,
+            a4: -r.a4,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
             a11: -r.a11,
             a12: -r.a12,
             a13: -r.a13,
@@ -1212,1919 +2529,2178 @@ impl Sub<FullMultivector> for Vector {
     }
 }
 
-impl Mul<Float> for Vector {
-    type Output = Vector;
+impl<T: Scalar> Mul<T> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn mul(self, r: Float) -> Vector {
-        Vector {
-            a1: self.a1 * r,
-            a2: self.a2 * r,
-            a3: self.a3 * r,
-            a4: self.a4 * r,
+    fn mul(self, r: T) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl Mul<Vector> for Vector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Mul<Vector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Vector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
-            a5: self.a1 * r.a2 - self.a2 * r.a1,
-            a6: self.a1 * r.a3 - self.a3 * r.a1,
-            a7: self.a1 * r.a4 - self.a4 * r.a1,
-            a8: self.a2 * r.a3 - self.a3 * r.a2,
-            a9: -self.a2 * r.a4 + self.a4 * r.a2,
-            a10: self.a3 * r.a4 - self.a4 * r.a3,
+    fn mul(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
+            a2: self.a8 * r.a3 - self.a9 * r.a4,
+            a3: -self.a8 * r.a2 + self.a10 * r.a4,
+            a4: self.a9 * r.a2 - self.a10 * r.a3,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Bivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Bivector<T>> for Bivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: -self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
-            a15: 0.,
+    fn mul(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: -self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
+            a6: self.a5 * r.a8 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
+            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a9 * r.a10 - self.a10 * r.a9,
+            a9: -self.a8 * r.a10 + self.a10 * r.a8,
+            a10: self.a8 * r.a9 - self.a9 * r.a8,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl Mul<Trivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Trivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Trivector) -> FullMultivector {
+    fn mul(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a3 * r.a11 + self.a4 * r.a12,
-            a6: self.a2 * r.a11 - self.a4 * r.a13,
-            a7: -self.a2 * r.a12 + self.a3 * r.a13,
-            a8: self.a4 * r.a14,
-            a9: self.a3 * r.a14,
-            a10: self.a2 * r.a14,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
+            a0: T::zero(),
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: -self.a10 * r.a14,
+            a3: -self.a9 * r.a14,
+            a4: -self.a8 * r.a14,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+            a12: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a13: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Pseudoscalar> for Vector {
-    type Output = Trivector;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn mul(self, r: Pseudoscalar) -> Trivector {
-        Trivector {
-            a11: self.a4 * r.a15,
-            a12: self.a3 * r.a15,
-            a13: self.a2 * r.a15,
-            a14: 0.,
+    fn mul(self, r: Pseudoscalar<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a10 * r.a15,
+            a6: -self.a9 * r.a15,
+            a7: -self.a8 * r.a15,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
-            a15: 0.,
+    fn mul(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
+            a6: self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
+            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: -self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl Mul<FullMultivector> for Vector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Motor<T>> for Bivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
-            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
-            a5: self.a1 * r.a2 - self.a2 * r.a1 - self.a3 * r.a11 + self.a4 * r.a12,
-            a6: self.a1 * r.a3 + self.a2 * r.a11 - self.a3 * r.a1 - self.a4 * r.a13,
-            a7: self.a1 * r.a4 - self.a2 * r.a12 + self.a3 * r.a13 - self.a4 * r.a1,
-            a8: self.a2 * r.a3 - self.a3 * r.a2 + self.a4 * r.a14,
-            a9: -self.a2 * r.a4 + self.a3 * r.a14 + self.a4 * r.a2,
-            a10: self.a2 * r.a14 + self.a3 * r.a4 - self.a4 * r.a3,
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5 + self.a4 * r.a15,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a3 * r.a15 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a2 * r.a15 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
-            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
+    fn mul(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
+            a6: self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
+            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: -self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<Float> for Vector {
-    type Output = Vector;
+impl<T: Scalar> Mul<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Float) -> Vector {
-        Vector {
-            a1: self.a1 * r,
-            a2: self.a2 * r,
-            a3: self.a3 * r,
-            a4: self.a4 * r,
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
+            a3: -self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
+            a4: -self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
+            a6: self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
+            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: -self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a7 * r.a14 - self.a8 * r.a1 + self.a9 * r.a13 - self.a10 * r.a12,
+            a12: self.a5 * r.a4 - self.a6 * r.a14 - self.a7 * r.a2 - self.a8 * r.a13 - self.a9 * r.a1 + self.a10 * r.a11,
+            a13: -self.a5 * r.a14 - self.a6 * r.a4 + self.a7 * r.a3 + self.a8 * r.a12 - self.a9 * r.a11 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<Vector> for Vector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitxor(self, r: Vector) -> Bivector {
+    fn bitxor(self, r: T) -> Bivector<T> {
         Bivector {
-            a5: self.a1 * r.a2 - self.a2 * r.a1,
-            a6: self.a1 * r.a3 - self.a3 * r.a1,
-            a7: self.a1 * r.a4 - self.a4 * r.a1,
-            a8: self.a2 * r.a3 - self.a3 * r.a2,
-            a9: -self.a2 * r.a4 + self.a4 * r.a2,
-            a10: self.a3 * r.a4 - self.a4 * r.a3,
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl BitXor<Bivector> for Vector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for Bivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, r: Bivector) -> Trivector {
+    fn bitxor(self, r: Vector<T>) -> Trivector<T> {
         Trivector {
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
         }
     }
 }
 
-impl BitXor<Trivector> for Vector {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for Bivector<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: Trivector) -> Pseudoscalar {
+    fn bitxor(self, r: Bivector<T>) -> Pseudoscalar<T> {
         Pseudoscalar {
-            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for Bivector<T> {
+    type Output = T;
+
+    fn bitxor(self, _r: Trivector<T>) -> T {
+        T::zero()
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for Bivector<T> {
+    type Output = T;
+
+    fn bitxor(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = Motor<T>;
+
+    fn bitxor(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a5 * r.a0,
+            a6: self.a6 * r.a0,
+            a7: self.a7 * r.a0,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for Bivector<T> {
+    type Output = Motor<T>;
+
+    fn bitxor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a5 * r.a0,
+            a6: self.a6 * r.a0,
+            a7: self.a7 * r.a0,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<Pseudoscalar> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5 * r.a0,
+            a6: self.a6 * r.a0,
+            a7: self.a7 * r.a0,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
     }
 }
 
-impl BitXor<ScalarAndBivector> for Vector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for Bivector<T> {
+    type Output = T;
 
-    fn bitxor(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1 * r.a0,
-            a2: self.a2 * r.a0,
-            a3: self.a3 * r.a0,
-            a4: self.a4 * r.a0,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
-            a15: 0.,
-        }
+    fn bitand(self, _r: T) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<FullMultivector> for Vector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for Bivector<T> {
+    type Output = T;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a1 * r.a0,
-            a2: self.a2 * r.a0,
-            a3: self.a3 * r.a0,
-            a4: self.a4 * r.a0,
-            a5: self.a1 * r.a2 - self.a2 * r.a1,
-            a6: self.a1 * r.a3 - self.a3 * r.a1,
-            a7: self.a1 * r.a4 - self.a4 * r.a1,
-            a8: self.a2 * r.a3 - self.a3 * r.a2,
-            a9: -self.a2 * r.a4 + self.a4 * r.a2,
-            a10: self.a3 * r.a4 - self.a4 * r.a3,
-            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5,
-            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5,
-            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6,
-            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8,
-            a15: self.a1 * r.a14 + self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11,
-        }
+    fn bitand(self, _r: Vector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<Float> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for Bivector<T> {
+    type Output = T;
 
-    fn bitand(self, _r: Float) -> Float {
-        0.
+    fn bitand(self, r: Bivector<T>) -> T {
+        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
     }
 }
 
-impl BitAnd<Vector> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for Bivector<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, _r: Vector) -> Float {
-        0.
+    fn bitand(self, r: Trivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
+            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+        }
     }
 }
 
-impl BitAnd<Bivector> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitand(self, _r: Bivector) -> Float {
-        0.
+    fn bitand(self, r: Pseudoscalar<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
+        }
     }
 }
 
-impl BitAnd<Trivector> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Trivector) -> Float {
-        -self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11
+    fn bitand(self, r: ScalarAndBivector<T>) -> T {
+        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
     }
 }
 
-impl BitAnd<Pseudoscalar> for Vector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: self.a1 * r.a15,
-            a2: self.a2 * r.a15,
-            a3: self.a3 * r.a15,
-            a4: self.a4 * r.a15,
+    fn bitand(self, r: Motor<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
         }
     }
 }
 
-impl BitAnd<ScalarAndBivector> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, _r: ScalarAndBivector) -> Float {
-        0.
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
+            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
     }
 }
 
-impl BitAnd<FullMultivector> for Vector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: -self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11,
-            a1: self.a1 * r.a15,
-            a2: self.a2 * r.a15,
-            a3: self.a3 * r.a15,
-            a4: self.a4 * r.a15,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+    fn bitor(self, r: T) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl BitOr<Float> for Vector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for Bivector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Float) -> Vector {
+    fn bitor(self, r: Vector<T>) -> Vector<T> {
         Vector {
-            a1: self.a1 * r,
-            a2: self.a2 * r,
-            a3: self.a3 * r,
-            a4: self.a4 * r,
+            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
+            a2: self.a8 * r.a3 - self.a9 * r.a4,
+            a3: -self.a8 * r.a2 + self.a10 * r.a4,
+            a4: self.a9 * r.a2 - self.a10 * r.a3,
         }
     }
 }
 
-impl BitOr<Vector> for Vector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for Bivector<T> {
+    type Output = T;
 
-    fn bitor(self, r: Vector) -> Float {
-        self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4
+    fn bitor(self, r: Bivector<T>) -> T {
+        -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10
     }
 }
 
-impl BitOr<Bivector> for Vector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for Bivector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Bivector) -> Vector {
+    fn bitor(self, r: Trivector<T>) -> Vector<T> {
         Vector {
-            a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: -self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10,
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: -self.a10 * r.a14,
+            a3: -self.a9 * r.a14,
+            a4: -self.a8 * r.a14,
         }
     }
 }
 
-impl BitOr<Trivector> for Vector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for Bivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitor(self, r: Trivector) -> Bivector {
+    fn bitor(self, r: Pseudoscalar<T>) -> Bivector<T> {
         Bivector {
-            a5: -self.a3 * r.a11 + self.a4 * r.a12,
-            a6: self.a2 * r.a11 - self.a4 * r.a13,
-            a7: -self.a2 * r.a12 + self.a3 * r.a13,
-            a8: self.a4 * r.a14,
-            a9: self.a3 * r.a14,
-            a10: self.a2 * r.a14,
+            a5: -self.a10 * r.a15,
+            a6: -self.a9 * r.a15,
+            a7: -self.a8 * r.a15,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for Vector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> Trivector {
-        Trivector {
-            a11: self.a4 * r.a15,
-            a12: self.a3 * r.a15,
-            a13: self.a2 * r.a15,
-            a14: 0.,
+    fn bitor(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a5 * r.a0,
+            a6: self.a6 * r.a0,
+            a7: self.a7 * r.a0,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for Vector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for Bivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> Vector {
-        Vector {
-            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
+    fn bitor(self, r: Motor<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a5 * r.a0 - self.a10 * r.a15,
+            a6: self.a6 * r.a0 - self.a9 * r.a15,
+            a7: self.a7 * r.a0 - self.a8 * r.a15,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
         }
     }
 }
 
-impl BitOr<FullMultivector> for Vector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for Bivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
-            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7,
-            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9,
-            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10,
-            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0,
-            a5: -self.a3 * r.a11 + self.a4 * r.a12,
-            a6: self.a2 * r.a11 - self.a4 * r.a13,
-            a7: -self.a2 * r.a12 + self.a3 * r.a13,
-            a8: self.a4 * r.a14,
-            a9: self.a3 * r.a14,
-            a10: self.a2 * r.a14,
-            a11: self.a4 * r.a15,
-            a12: self.a3 * r.a15,
-            a13: self.a2 * r.a15,
-            a14: 0.,
-            a15: 0.,
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
+            a3: -self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
+            a4: -self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: self.a5 * r.a0 - self.a10 * r.a15,
+            a6: self.a6 * r.a0 - self.a9 * r.a15,
+            a7: self.a7 * r.a0 - self.a8 * r.a15,
+            a8: self.a8 * r.a0,
+            a9: self.a9 * r.a0,
+            a10: self.a10 * r.a0,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Project<Float> for Vector {
-    fn project(self, r: Float) -> Vector {
-        Vector {
-            a1: self.a1 * r.powi(2),
-            a2: self.a2 * r.powi(2),
-            a3: self.a3 * r.powi(2),
-            a4: self.a4 * r.powi(2),
+impl<T: Scalar> Project<T> for Bivector<T> {
+    fn project(self, r: T) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Project<Vector> for Vector {
-    fn project(self, r: Vector) -> Vector {
-        Vector {
-            a1: r.a1 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
-            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
-            a3: r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
-            a4: r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+impl<T: Scalar> Project<Vector<T>> for Bivector<T> {
+    fn project(self, r: Vector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -r.a1 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a2 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
+            a6: r.a1 * (self.a8 * r.a2 - self.a10 * r.a4) + r.a3 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
+            a7: -r.a1 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
+            a8: r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) + r.a3 * (self.a8 * r.a3 - self.a9 * r.a4),
+            a9: r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) - r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
+            a10: -r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) - r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
         }
     }
 }
 
-impl Project<Trivector> for Vector {
-    fn project(self, r: Trivector) -> Vector {
-        Vector {
-            a1: r.a14 * (self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11),
-            a2: -self.a2 * r.a14.powi(2),
-            a3: -self.a3 * r.a14.powi(2),
-            a4: -self.a4 * r.a14.powi(2),
+impl<T: Scalar> Project<Bivector<T>> for Bivector<T> {
+    fn project(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -r.a5 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a6: -r.a6 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a7: -r.a7 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a8: -r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a9: -r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a10: -r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
         }
     }
 }
 
-impl Project<Pseudoscalar> for Vector {
-    fn project(self, _r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+impl<T: Scalar> Project<Trivector<T>> for Bivector<T> {
+    fn project(self, r: Trivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: r.a14 * (-self.a8 * r.a12 + self.a9 * r.a11),
+            a6: r.a14 * (self.a8 * r.a13 - self.a10 * r.a11),
+            a7: r.a14 * (-self.a9 * r.a13 + self.a10 * r.a12),
+            a8: -self.a8 * r.a14.powi(2),
+            a9: -self.a9 * r.a14.powi(2),
+            a10: -self.a10 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Float> for Vector {
-    fn reflect(self, r: Float) -> Vector {
-        Vector {
-            a1: self.a1 * r.powi(2),
-            a2: self.a2 * r.powi(2),
-            a3: self.a3 * r.powi(2),
-            a4: self.a4 * r.powi(2),
+impl<T: Scalar> Project<Pseudoscalar<T>> for Bivector<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> Bivector<T> {
+        Bivector {
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Reflect<Vector> for Vector {
-    fn reflect(self, r: Vector) -> Vector {
-        Vector {
-            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a3 * r.a1 * r.a3 + 2. * self.a4 * r.a1 * r.a4,
-            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a3 * (self.a2 * r.a3 - self.a3 * r.a2) - r.a4 * (self.a2 * r.a4 - self.a4 * r.a2),
-            a3: r.a2 * (self.a2 * r.a3 - self.a3 * r.a2) + r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a4 * (self.a3 * r.a4 - self.a4 * r.a3),
-            a4: r.a2 * (self.a2 * r.a4 - self.a4 * r.a2) + r.a3 * (self.a3 * r.a4 - self.a4 * r.a3) + r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+impl<T: Scalar> Reflect<T> for Bivector<T> {
+    fn reflect(self, r: T) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Bivector> for Vector {
-    fn reflect(self, r: Bivector) -> Vector {
-        Vector {
-            a1: -self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + 2. * self.a2 * r.a6 * r.a8 - 2. * self.a2 * r.a7 * r.a9 - 2. * self.a3 * r.a5 * r.a8 + 2. * self.a3 * r.a7 * r.a10 + 2. * self.a4 * r.a5 * r.a9 - 2. * self.a4 * r.a6 * r.a10,
-            a2: r.a8 * (self.a2 * r.a8 - self.a4 * r.a10) + r.a9 * (self.a2 * r.a9 - self.a3 * r.a10) - r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
-            a3: r.a8 * (self.a3 * r.a8 - self.a4 * r.a9) - r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) - r.a10 * (self.a2 * r.a9 - self.a3 * r.a10),
-            a4: -2. * self.a2 * r.a8 * r.a10 - 2. * self.a3 * r.a8 * r.a9 - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2),
+impl<T: Scalar> Reflect<Vector<T>> for Bivector<T> {
+    fn reflect(self, r: Vector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) - r.a3 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a4 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2),
+            a9: -r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a3 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
+            a10: r.a2 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
         }
     }
 }
 
-impl Reflect<Trivector> for Vector {
-    fn reflect(self, r: Trivector) -> Vector {
-        Vector {
-            a1: r.a14 * (self.a1 * r.a14 + 2. * self.a2 * r.a13 + 2. * self.a3 * r.a12 + 2. * self.a4 * r.a11),
-            a2: -self.a2 * r.a14.powi(2),
-            a3: -self.a3 * r.a14.powi(2),
-            a4: -self.a4 * r.a14.powi(2),
+impl<T: Scalar> Reflect<Bivector<T>> for Bivector<T> {
+    fn reflect(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: -T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: -r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a9 * (self.a8 * r.a9 - self.a9 * r.a8) + r.a10 * (self.a8 * r.a10 - self.a10 * r.a8),
+            a9: -r.a8 * (self.a8 * r.a9 - self.a9 * r.a8) - r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a10 * (self.a9 * r.a10 - self.a10 * r.a9),
+            a10: -T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for Vector {
-    fn reflect(self, _r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+impl<T: Scalar> Reflect<Trivector<T>> for Bivector<T> {
+    fn reflect(self, r: Trivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: r.a14 * (self.a5 * r.a14 - T::from_f64(2.) * self.a8 * r.a12 + T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (self.a6 * r.a14 + T::from_f64(2.) * self.a8 * r.a13 - T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (self.a7 * r.a14 - T::from_f64(2.) * self.a9 * r.a13 + T::from_f64(2.) * self.a10 * r.a12),
+            a8: -self.a8 * r.a14.powi(2),
+            a9: -self.a9 * r.a14.powi(2),
+            a10: -self.a10 * r.a14.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for Bivector<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> Bivector<T> {
+        Bivector {
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<T> for Bivector<T> {
+    fn transform(self, r: T) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Transform<Float> for Vector {
-    fn transform(self, r: Float) -> Vector {
-        Vector {
-            a1: self.a1 * r.powi(2),
-            a2: self.a2 * r.powi(2),
-            a3: self.a3 * r.powi(2),
-            a4: self.a4 * r.powi(2),
+impl<T: Scalar> Transform<Vector<T>> for Bivector<T> {
+    fn transform(self, r: Vector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) - r.a3 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a4 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2),
+            a9: -r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a3 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
+            a10: r.a2 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
         }
     }
 }
 
-impl Transform<Vector> for Vector {
-    fn transform(self, r: Vector) -> Vector {
-        Vector {
-            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a3 * r.a1 * r.a3 + 2. * self.a4 * r.a1 * r.a4,
-            a2: r.a2 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a3 * (self.a2 * r.a3 - self.a3 * r.a2) - r.a4 * (self.a2 * r.a4 - self.a4 * r.a2),
-            a3: r.a2 * (self.a2 * r.a3 - self.a3 * r.a2) + r.a3 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4) - r.a4 * (self.a3 * r.a4 - self.a4 * r.a3),
-            a4: r.a2 * (self.a2 * r.a4 - self.a4 * r.a2) + r.a3 * (self.a3 * r.a4 - self.a4 * r.a3) + r.a4 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
+impl<T: Scalar> Transform<Bivector<T>> for Bivector<T> {
+    fn transform(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a9 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: T::from_f64(2.) * self.a5 * r.a9 * r.a10 - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a8 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) - r.a9 * (self.a8 * r.a9 - self.a9 * r.a8) - r.a10 * (self.a8 * r.a10 - self.a10 * r.a8),
+            a9: r.a8 * (self.a8 * r.a9 - self.a9 * r.a8) + r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) - r.a10 * (self.a9 * r.a10 - self.a10 * r.a9),
+            a10: r.a8 * (self.a8 * r.a10 - self.a10 * r.a8) + r.a9 * (self.a9 * r.a10 - self.a10 * r.a9) + r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
         }
     }
 }
 
-impl Transform<Bivector> for Vector {
-    fn transform(self, r: Bivector) -> Vector {
-        Vector {
-            a1: self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - 2. * self.a2 * r.a6 * r.a8 + 2. * self.a2 * r.a7 * r.a9 + 2. * self.a3 * r.a5 * r.a8 - 2. * self.a3 * r.a7 * r.a10 - 2. * self.a4 * r.a5 * r.a9 + 2. * self.a4 * r.a6 * r.a10,
-            a2: -r.a8 * (self.a2 * r.a8 - self.a4 * r.a10) - r.a9 * (self.a2 * r.a9 - self.a3 * r.a10) + r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
-            a3: -r.a8 * (self.a3 * r.a8 - self.a4 * r.a9) + r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a10 * (self.a2 * r.a9 - self.a3 * r.a10),
-            a4: r.a8 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a9 * (self.a3 * r.a8 - self.a4 * r.a9) + r.a10 * (self.a2 * r.a8 - self.a4 * r.a10),
+impl<T: Scalar> Transform<Trivector<T>> for Bivector<T> {
+    fn transform(self, r: Trivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: r.a14 * (-self.a5 * r.a14 + T::from_f64(2.) * self.a8 * r.a12 - T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (-self.a6 * r.a14 - T::from_f64(2.) * self.a8 * r.a13 + T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (-self.a7 * r.a14 + T::from_f64(2.) * self.a9 * r.a13 - T::from_f64(2.) * self.a10 * r.a12),
+            a8: self.a8 * r.a14.powi(2),
+            a9: self.a9 * r.a14.powi(2),
+            a10: self.a10 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Trivector> for Vector {
-    fn transform(self, r: Trivector) -> Vector {
-        Vector {
-            a1: r.a14 * (-self.a1 * r.a14 - 2. * self.a2 * r.a13 - 2. * self.a3 * r.a12 - 2. * self.a4 * r.a11),
-            a2: self.a2 * r.a14.powi(2),
-            a3: self.a3 * r.a14.powi(2),
-            a4: self.a4 * r.a14.powi(2),
+impl<T: Scalar> Transform<Pseudoscalar<T>> for Bivector<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> Bivector<T> {
+        Bivector {
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for Vector {
-    fn transform(self, _r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for Bivector<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: r.a0 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a9 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) - r.a10 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8),
+            a9: r.a0 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a8 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a10 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9),
+            a10: r.a0 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a8 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a9 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
         }
     }
 }
 
-impl Transform<ScalarAndBivector> for Vector {
-    fn transform(self, r: ScalarAndBivector) -> Vector {
-        Vector {
-            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + 2. * self.a2 * r.a0 * r.a5 - 2. * self.a2 * r.a6 * r.a8 + 2. * self.a2 * r.a7 * r.a9 + 2. * self.a3 * r.a0 * r.a6 + 2. * self.a3 * r.a5 * r.a8 - 2. * self.a3 * r.a7 * r.a10 + 2. * self.a4 * r.a0 * r.a7 - 2. * self.a4 * r.a5 * r.a9 + 2. * self.a4 * r.a6 * r.a10,
-            a2: r.a0 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a8 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a9 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a10 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8),
-            a3: r.a0 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10) - r.a8 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) + r.a9 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a10 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0),
-            a4: r.a0 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a4 * r.a0) + r.a8 * (self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8) + r.a9 * (self.a2 * r.a0 + self.a3 * r.a8 - self.a4 * r.a9) - r.a10 * (-self.a2 * r.a8 + self.a3 * r.a0 + self.a4 * r.a10),
+impl<T: Scalar> Transform<Motor<T>> for Bivector<T> {
+    fn transform(self, r: Motor<T>) -> Bivector<T> {
+        Bivector {
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a8 * r.a9 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a8 * r.a15,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a10 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a10 * r.a9 * r.a15,
+            a8: r.a0 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a9 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) - r.a10 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8),
+            a9: r.a0 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a8 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a10 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9),
+            a10: r.a0 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a8 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a9 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
         }
     }
 }
 
 // ===========================================================================
-// Bivector
+// Trivector
 // ===========================================================================
 
 
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct Bivector {
-    a5: Float,
-    a6: Float,
-    a7: Float,
-    a8: Float,
-    a9: Float,
-    a10: Float,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trivector<T = Float> {
+    a11: T,
+    a12: T,
+    a13: T,
+    a14: T,
 }
 
-impl Reverse for Bivector {
-    fn reverse(self) -> Bivector {
-        Bivector {
-            a5: -self.a5,
-            a6: -self.a6,
-            a7: -self.a7,
-            a8: -self.a8,
-            a9: -self.a9,
-            a10: -self.a10,
+impl<T: Scalar> Reverse for Trivector<T> {
+    fn reverse(self) -> Trivector<T> {
+        Trivector {
+            a11: -self.a11,
+            a12: -self.a12,
+            a13: -self.a13,
+            a14: -self.a14,
         }
     }
 }
 
-impl Dual for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Dual for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn dual(self) -> Bivector {
-        Bivector {
-            a5: self.a10,
-            a6: self.a9,
-            a7: self.a8,
-            a8: self.a7,
-            a9: self.a6,
-            a10: self.a5,
+    fn dual(self) -> Vector<T> {
+        Vector {
+            a1: self.a14,
+            a2: self.a13,
+            a3: self.a12,
+            a4: self.a11,
         }
     }
 }
 
-impl Conjugate for Bivector {
-    fn conjugate(self) -> Bivector {
-        Bivector {
-            a5: -self.a5,
-            a6: -self.a6,
-            a7: -self.a7,
-            a8: -self.a8,
-            a9: -self.a9,
-            a10: -self.a10,
+impl<T: Scalar> Conjugate for Trivector<T> {
+    fn conjugate(self) -> Trivector<T> {
+        Trivector {
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
         }
     }
 }
 
-impl Normalize for Bivector {
-    fn norm(self) -> Float {
-        (self.a8.powi(2) + self.a9.powi(2) + self.a10.powi(2)).sqrt()
+impl<T: Scalar> Normalize<T> for Trivector<T> {
+    fn norm(self) -> T {
+        self.a14.abs()
     }
 }
 
-impl NormalizeInfinite for Bivector {
-    fn inorm(self) -> Float {
-        (self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2)).sqrt()
+impl<T: Scalar> NormalizeInfinite<T> for Trivector<T> {
+    fn inorm(self) -> T {
+        (self.a11.powi(2) + self.a12.powi(2) + self.a13.powi(2)).sqrt()
     }
 }
 
-impl Bivector {
-    pub fn new(a5: Float, a6: Float, a7: Float, a8: Float, a9: Float, a10: Float) -> Bivector {
-        Bivector {a5, a6, a7, a8, a9, a10}
+impl<T: Scalar> Trivector<T> {
+    pub fn new(a11: T, a12: T, a13: T, a14: T) -> Trivector<T> {
+        Trivector {a11, a12, a13, a14}
     }
 
-    pub fn zero() -> Bivector {
+    pub fn zero() -> Trivector<T> {
         Default::default()
     }
 
-    pub fn as_tuple(&self) -> (Float, Float, Float, Float, Float, Float) {
-        (self.a5, self.a6, self.a7, self.a8, self.a9, self.a10)
+    pub fn as_tuple(&self) -> (T, T, T, T) {
+        (self.a11, self.a12, self.a13, self.a14)
     }
 
-    pub fn full_multivector(self) -> FullMultivector {
+    pub fn full_multivector(self) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Neg for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Neg for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn neg(self) -> Bivector {
-        Bivector {
-            a5: -self.a5,
-            a6: -self.a6,
-            a7: -self.a7,
-            a8: -self.a8,
-            a9: -self.a9,
-            a10: -self.a10,
+    fn neg(self) -> Trivector<T> {
+        Trivector {
+            a11: -self.a11,
+            a12: -self.a12,
+            a13: -self.a13,
+            a14: -self.a14,
         }
     }
 }
 
-impl Add<Float> for Bivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<T> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn add(self, r: T) -> FullMultivector<T> {
+        FullMultivector {
             a0: r,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Vector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: r.a1,
             a2: r.a2,
             a3: r.a3,
             a4: r.a4,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Bivector> for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Add<Bivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self.a5 + r.a5,
-            a6: self.a6 + r.a6,
-            a7: self.a7 + r.a7,
-            a8: self.a8 + r.a8,
-            a9: self.a9 + r.a9,
-            a10: self.a10 + r.a10,
+    fn add(self, r: Bivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Trivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: r.a11,
-            a12: r.a12,
-            a13: r.a13,
-            a14: r.a14,
-            a15: 0.,
+    fn add(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 + r.a11,
+            a12: self.a12 + r.a12,
+            a13: self.a13 + r.a13,
+            a14: self.a14 + r.a14,
         }
     }
 }
 
-impl Add<Pseudoscalar> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
+    fn add(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
             a15: r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for Bivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn add(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
+        FullMultivector {
             a0: r.a0,
-            a5: self.a5 + r.a5,
-            a6: self.a6 + r.a6,
-            a7: self.a7 + r.a7,
-            a8: self.a8 + r.a8,
-            a9: self.a9 + r.a9,
-            a10: self.a10 + r.a10,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Add<Motor<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn add(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: r.a0,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: r.a15,
         }
     }
 }
 
-impl Add<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: r.a0,
             a1: r.a1,
             a2: r.a2,
             a3: r.a3,
             a4: r.a4,
-            a5: self.a5 + r.a5,
-            a6: self.a6 + r.a6,
-            a7: self.a7 + r.a7,
-            a8: self.a8 + r.a8,
-            a9: self.a9 + r.a9,
-            a10: self.a10 + r.a10,
-            a11: r.a11,
-            a12: r.a12,
-            a13: r.a13,
-            a14: r.a14,
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a11: self.a11 + r.a11,
+            a12: self.a12 + r.a12,
+            a13: self.a13 + r.a13,
+            a14: self.a14 + r.a14,
             a15: r.a15,
         }
     }
 }
 
-impl Sub<Float> for Bivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<T> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn sub(self, r: T) -> FullMultivector<T> {
+        FullMultivector {
             a0: -r,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Vector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: -r.a1,
+            a2: -r.a2,
+            a3: -r.a3,
+            a4: -r.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Vector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Bivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: -r.a1,
-            a2: -r.a2,
-            a3: -r.a3,
-            a4: -r.a4,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Bivector> for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Sub<Trivector<T>> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn sub(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self.a5 - r.a5,
-            a6: self.a6 - r.a6,
-            a7: self.a7 - r.a7,
-            a8: self.a8 - r.a8,
-            a9: self.a9 - r.a9,
-            a10: self.a10 - r.a10,
+    fn sub(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 - r.a11,
+            a12: self.a12 - r.a12,
+            a13: self.a13 - r.a13,
+            a14: self.a14 - r.a14,
         }
     }
 }
 
-impl Sub<Trivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: -r.a11,
-            a12: -r.a12,
-            a13: -r.a13,
-            a14: -r.a14,
-            a15: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<Pseudoscalar> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
+    fn sub(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5,
-            a6: self.a6,
-            a7: self.a7,
-            a8: self.a8,
-            a9: self.a9,
-            a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: -r.a15,
+            a0: -r.a0,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for Bivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<Motor<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn sub(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
             a0: -r.a0,
-            a5: self.a5 - r.a5,
-            a6: self.a6 - r.a6,
-            a7: self.a7 - r.a7,
-            a8: self.a8 - r.a8,
-            a9: self.a9 - r.a9,
-            a10: self.a10 - r.a10,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -r.a0,
             a1: -r.a1,
             a2: -r.a2,
             a3: -r.a3,
             a4: -r.a4,
-            a5: self.a5 - r.a5,
-            a6: self.a6 - r.a6,
-            a7: self.a7 - r.a7,
-            a8: self.a8 - r.a8,
-            a9: self.a9 - r.a9,
-            a10: self.a10 - r.a10,
-            a11: -r.a11,
-            a12: -r.a12,
-            a13: -r.a13,
-            a14: -r.a14,
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a11: self.a11 - r.a11,
+            a12: self.a12 - r.a12,
+            a13: self.a13 - r.a13,
+            a14: self.a14 - r.a14,
             a15: -r.a15,
         }
     }
 }
 
-impl Mul<Float> for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Mul<T> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn mul(self, r: Float) -> Bivector {
-        Bivector {
-            a5: self.a5 * r,
-            a6: self.a6 * r,
-            a7: self.a7 * r,
-            a8: self.a8 * r,
-            a9: self.a9 * r,
-            a10: self.a10 * r,
+    fn mul(self, r: T) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r,
+            a12: self.a12 * r,
+            a13: self.a13 * r,
+            a14: self.a14 * r,
         }
     }
 }
 
-impl Mul<Vector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Vector<T>> for Trivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Vector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
-            a2: self.a8 * r.a3 - self.a9 * r.a4,
-            a3: -self.a8 * r.a2 + self.a10 * r.a4,
-            a4: self.a9 * r.a2 - self.a10 * r.a3,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
-            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
-            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
-            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: 0.,
+    fn mul(self, r: Vector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a11 * r.a3 + self.a12 * r.a4,
+            a6: self.a11 * r.a2 - self.a13 * r.a4,
+            a7: -self.a12 * r.a2 + self.a13 * r.a3,
+            a8: self.a14 * r.a4,
+            a9: self.a14 * r.a3,
+            a10: self.a14 * r.a2,
+            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
         }
     }
 }
 
-impl Mul<Bivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Bivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Bivector) -> FullMultivector {
+    fn mul(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
-            a6: self.a5 * r.a8 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
-            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a9 * r.a5 - self.a10 * r.a6,
-            a8: self.a9 * r.a10 - self.a10 * r.a9,
-            a9: -self.a8 * r.a10 + self.a10 * r.a8,
-            a10: self.a8 * r.a9 - self.a9 * r.a8,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a0: T::zero(),
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
+            a12: -self.a11 * r.a10 + self.a13 * r.a8 + self.a14 * r.a6,
+            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a14 * r.a5,
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Trivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Trivector<T>> for Trivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn mul(self, r: Trivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
-            a2: -self.a10 * r.a14,
-            a3: -self.a9 * r.a14,
-            a4: -self.a8 * r.a14,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
-            a12: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
-            a13: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
-            a14: 0.,
-            a15: 0.,
+    fn mul(self, r: Trivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a14 * r.a14,
+            a5: self.a13 * r.a14 - self.a14 * r.a13,
+            a6: self.a12 * r.a14 - self.a14 * r.a12,
+            a7: self.a11 * r.a14 - self.a14 * r.a11,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Mul<Pseudoscalar> for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: -self.a10 * r.a15,
-            a6: -self.a9 * r.a15,
-            a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+    fn mul(self, r: Pseudoscalar<T>) -> Vector<T> {
+        Vector {
+            a1: self.a14 * r.a15,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
+    fn mul(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
-            a6: self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
-            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 + self.a9 * r.a5 - self.a10 * r.a6,
-            a8: self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
-            a9: -self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
-            a10: self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a0: T::zero(),
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
+            a12: -self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
+            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
+            a14: self.a14 * r.a0,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Motor<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: Motor<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
-            a2: self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
-            a3: -self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
-            a4: -self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
-            a5: self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
-            a6: self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
-            a7: -self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
-            a8: self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
-            a9: -self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
-            a10: self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
-            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a7 * r.a14 - self.a8 * r.a1 + self.a9 * r.a13 - self.a10 * r.a12,
-            a12: self.a5 * r.a4 - self.a6 * r.a14 - self.a7 * r.a2 - self.a8 * r.a13 - self.a9 * r.a1 + self.a10 * r.a11,
-            a13: -self.a5 * r.a14 - self.a6 * r.a4 + self.a7 * r.a3 + self.a8 * r.a12 - self.a9 * r.a11 - self.a10 * r.a1,
-            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a0: T::zero(),
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
+            a12: -self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
+            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
+            a14: self.a14 * r.a0,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitXor<Float> for Bivector {
-    type Output = Bivector;
+impl<T: Scalar> Mul<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Float) -> Bivector {
-        Bivector {
-            a5: self.a5 * r,
-            a6: self.a6 * r,
-            a7: self.a7 * r,
-            a8: self.a8 * r,
-            a9: self.a9 * r,
-            a10: self.a10 * r,
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: -self.a14 * r.a14,
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: -self.a11 * r.a3 + self.a12 * r.a4 + self.a13 * r.a14 - self.a14 * r.a13,
+            a6: self.a11 * r.a2 + self.a12 * r.a14 - self.a13 * r.a4 - self.a14 * r.a12,
+            a7: self.a11 * r.a14 - self.a12 * r.a2 + self.a13 * r.a3 - self.a14 * r.a11,
+            a8: self.a14 * r.a4,
+            a9: self.a14 * r.a3,
+            a10: self.a14 * r.a2,
+            a11: self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
+            a12: -self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
+            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
+            a14: self.a14 * r.a0,
+            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
         }
     }
 }
 
-impl BitXor<Vector> for Bivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, r: Vector) -> Trivector {
+    fn bitxor(self, r: T) -> Trivector<T> {
         Trivector {
-            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
-            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
-            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
-            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a11: self.a11 * r,
+            a12: self.a12 * r,
+            a13: self.a13 * r,
+            a14: self.a14 * r,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for Trivector<T> {
+    type Output = Pseudoscalar<T>;
+
+    fn bitxor(self, r: Vector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
         }
     }
 }
 
-impl BitXor<Bivector> for Bivector {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for Trivector<T> {
+    type Output = T;
+
+    fn bitxor(self, _r: Bivector<T>) -> T {
+        T::zero()
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for Trivector<T> {
+    type Output = T;
+
+    fn bitxor(self, _r: Trivector<T>) -> T {
+        T::zero()
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for Trivector<T> {
+    type Output = T;
 
-    fn bitxor(self, r: Bivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
-        }
+    fn bitxor(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<Trivector> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, _r: Trivector) -> Float {
-        0.
+    fn bitxor(self, r: ScalarAndBivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+        }
     }
 }
 
-impl BitXor<Pseudoscalar> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitxor(self, r: Motor<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+        }
     }
 }
 
-impl BitXor<ScalarAndBivector> for Bivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: ScalarAndBivector) -> FullMultivector {
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5 * r.a0,
-            a6: self.a6 * r.a0,
-            a7: self.a7 * r.a0,
-            a8: self.a8 * r.a0,
-            a9: self.a9 * r.a0,
-            a10: self.a10 * r.a0,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
         }
     }
 }
 
-impl BitXor<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for Trivector<T> {
+    type Output = T;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a5 * r.a0,
-            a6: self.a6 * r.a0,
-            a7: self.a7 * r.a0,
-            a8: self.a8 * r.a0,
-            a9: self.a9 * r.a0,
-            a10: self.a10 * r.a0,
-            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
-            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
-            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
-            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
-        }
+    fn bitand(self, _r: T) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<Float> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for Trivector<T> {
+    type Output = T;
 
-    fn bitand(self, _r: Float) -> Float {
-        0.
+    fn bitand(self, r: Vector<T>) -> T {
+        self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1
     }
 }
 
-impl BitAnd<Vector> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, _r: Vector) -> Float {
-        0.
+    fn bitand(self, r: Bivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
+            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
+            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
+            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+        }
     }
 }
 
-impl BitAnd<Bivector> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for Trivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitand(self, r: Bivector) -> Float {
-        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
+    fn bitand(self, r: Trivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a11 * r.a12 + self.a12 * r.a11,
+            a6: self.a11 * r.a13 - self.a13 * r.a11,
+            a7: -self.a12 * r.a13 + self.a13 * r.a12,
+            a8: -self.a11 * r.a14 + self.a14 * r.a11,
+            a9: -self.a12 * r.a14 + self.a14 * r.a12,
+            a10: -self.a13 * r.a14 + self.a14 * r.a13,
+        }
     }
 }
 
-impl BitAnd<Trivector> for Bivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for Trivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitand(self, r: Trivector) -> Vector {
-        Vector {
-            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
-            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
-            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
-            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+    fn bitand(self, r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a15,
+            a12: self.a12 * r.a15,
+            a13: self.a13 * r.a15,
+            a14: self.a14 * r.a15,
         }
     }
 }
 
-impl BitAnd<Pseudoscalar> for Bivector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.a15,
-            a6: self.a6 * r.a15,
-            a7: self.a7 * r.a15,
-            a8: self.a8 * r.a15,
-            a9: self.a9 * r.a15,
-            a10: self.a10 * r.a15,
+    fn bitand(self, r: ScalarAndBivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
+            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
+            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
+            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
         }
     }
 }
 
-impl BitAnd<ScalarAndBivector> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: ScalarAndBivector) -> Float {
-        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
+    fn bitand(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
+            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
+            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
+            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a15,
+            a12: self.a12 * r.a15,
+            a13: self.a13 * r.a15,
+            a14: self.a14 * r.a15,
+            a15: T::zero(),
+        }
     }
 }
 
-impl BitAnd<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
-            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
-            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
-            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
-            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
-            a5: self.a5 * r.a15,
-            a6: self.a6 * r.a15,
-            a7: self.a7 * r.a15,
-            a8: self.a8 * r.a15,
-            a9: self.a9 * r.a15,
-            a10: self.a10 * r.a15,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1,
+            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
+            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
+            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
+            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+            a5: -self.a11 * r.a12 + self.a12 * r.a11,
+            a6: self.a11 * r.a13 - self.a13 * r.a11,
+            a7: -self.a12 * r.a13 + self.a13 * r.a12,
+            a8: -self.a11 * r.a14 + self.a14 * r.a11,
+            a9: -self.a12 * r.a14 + self.a14 * r.a12,
+            a10: -self.a13 * r.a14 + self.a14 * r.a13,
+            a11: self.a11 * r.a15,
+            a12: self.a12 * r.a15,
+            a13: self.a13 * r.a15,
+            a14: self.a14 * r.a15,
+            a15: T::zero(),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for Trivector<T> {
+    type Output = Trivector<T>;
+
+    fn bitor(self, r: T) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r,
+            a12: self.a12 * r,
+            a13: self.a13 * r,
+            a14: self.a14 * r,
         }
     }
 }
 
-impl BitOr<Float> for Bivector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for Trivector<T> {
+    type Output = Bivector<T>;
 
-    fn bitor(self, r: Float) -> Bivector {
+    fn bitor(self, r: Vector<T>) -> Bivector<T> {
         Bivector {
-            a5: self.a5 * r,
-            a6: self.a6 * r,
-            a7: self.a7 * r,
-            a8: self.a8 * r,
-            a9: self.a9 * r,
-            a10: self.a10 * r,
+            a5: -self.a11 * r.a3 + self.a12 * r.a4,
+            a6: self.a11 * r.a2 - self.a13 * r.a4,
+            a7: -self.a12 * r.a2 + self.a13 * r.a3,
+            a8: self.a14 * r.a4,
+            a9: self.a14 * r.a3,
+            a10: self.a14 * r.a2,
         }
     }
 }
 
-impl BitOr<Vector> for Bivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Vector) -> Vector {
+    fn bitor(self, r: Bivector<T>) -> Vector<T> {
         Vector {
-            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
-            a2: self.a8 * r.a3 - self.a9 * r.a4,
-            a3: -self.a8 * r.a2 + self.a10 * r.a4,
-            a4: self.a9 * r.a2 - self.a10 * r.a3,
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
         }
     }
 }
 
-impl BitOr<Bivector> for Bivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for Trivector<T> {
+    type Output = T;
 
-    fn bitor(self, r: Bivector) -> Float {
-        -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10
+    fn bitor(self, r: Trivector<T>) -> T {
+        -self.a14 * r.a14
     }
 }
 
-impl BitOr<Trivector> for Bivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for Trivector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Trivector) -> Vector {
+    fn bitor(self, r: Pseudoscalar<T>) -> Vector<T> {
         Vector {
-            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
-            a2: -self.a10 * r.a14,
-            a3: -self.a9 * r.a14,
-            a4: -self.a8 * r.a14,
+            a1: self.a14 * r.a15,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for Bivector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: -self.a10 * r.a15,
-            a6: -self.a9 * r.a15,
-            a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+    fn bitor(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for Bivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a5: self.a5 * r.a0,
-            a6: self.a6 * r.a0,
-            a7: self.a7 * r.a0,
-            a8: self.a8 * r.a0,
-            a9: self.a9 * r.a0,
-            a10: self.a10 * r.a0,
+    fn bitor(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<FullMultivector> for Bivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for Trivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
-            a2: self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
-            a3: -self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
-            a4: -self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
-            a5: self.a5 * r.a0 - self.a10 * r.a15,
-            a6: self.a6 * r.a0 - self.a9 * r.a15,
-            a7: self.a7 * r.a0 - self.a8 * r.a15,
-            a8: self.a8 * r.a0,
-            a9: self.a9 * r.a0,
-            a10: self.a10 * r.a0,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: -self.a14 * r.a14,
+            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: -self.a14 * r.a10,
+            a3: -self.a14 * r.a9,
+            a4: -self.a14 * r.a8,
+            a5: -self.a11 * r.a3 + self.a12 * r.a4,
+            a6: self.a11 * r.a2 - self.a13 * r.a4,
+            a7: -self.a12 * r.a2 + self.a13 * r.a3,
+            a8: self.a14 * r.a4,
+            a9: self.a14 * r.a3,
+            a10: self.a14 * r.a2,
+            a11: self.a11 * r.a0,
+            a12: self.a12 * r.a0,
+            a13: self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+            a15: T::zero(),
         }
     }
 }
 
-impl Project<Float> for Bivector {
-    fn project(self, r: Float) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.powi(2),
-            a6: self.a6 * r.powi(2),
-            a7: self.a7 * r.powi(2),
-            a8: self.a8 * r.powi(2),
-            a9: self.a9 * r.powi(2),
-            a10: self.a10 * r.powi(2),
+impl<T: Scalar> Project<T> for Trivector<T> {
+    fn project(self, r: T) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.powi(2),
+            a12: self.a12 * r.powi(2),
+            a13: self.a13 * r.powi(2),
+            a14: self.a14 * r.powi(2),
         }
     }
 }
 
-impl Project<Vector> for Bivector {
-    fn project(self, r: Vector) -> Bivector {
-        Bivector {
-            a5: -r.a1 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a2 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
-            a6: r.a1 * (self.a8 * r.a2 - self.a10 * r.a4) + r.a3 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
-            a7: -r.a1 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4),
-            a8: r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) + r.a3 * (self.a8 * r.a3 - self.a9 * r.a4),
-            a9: r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) - r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
-            a10: -r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) - r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
+impl<T: Scalar> Project<Vector<T>> for Trivector<T> {
+    fn project(self, r: Vector<T>) -> Trivector<T> {
+        Trivector {
+            a11: -self.a14 * r.a1 * r.a4 + r.a2 * (self.a11 * r.a2 - self.a13 * r.a4) + r.a3 * (self.a11 * r.a3 - self.a12 * r.a4),
+            a12: -self.a14 * r.a1 * r.a3 + r.a2 * (self.a12 * r.a2 - self.a13 * r.a3) - r.a4 * (self.a11 * r.a3 - self.a12 * r.a4),
+            a13: -self.a14 * r.a1 * r.a2 - r.a3 * (self.a12 * r.a2 - self.a13 * r.a3) - r.a4 * (self.a11 * r.a2 - self.a13 * r.a4),
+            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
         }
     }
 }
 
-impl Project<Bivector> for Bivector {
-    fn project(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: -r.a5 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
-            a6: -r.a6 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
-            a7: -r.a7 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
-            a8: -r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
-            a9: -r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
-            a10: -r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+impl<T: Scalar> Project<Trivector<T>> for Trivector<T> {
+    fn project(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: -self.a14 * r.a11 * r.a14,
+            a12: -self.a14 * r.a12 * r.a14,
+            a13: -self.a14 * r.a13 * r.a14,
+            a14: -self.a14 * r.a14.powi(2),
         }
     }
 }
 
-impl Project<Trivector> for Bivector {
-    fn project(self, r: Trivector) -> Bivector {
-        Bivector {
-            a5: r.a14 * (-self.a8 * r.a12 + self.a9 * r.a11),
-            a6: r.a14 * (self.a8 * r.a13 - self.a10 * r.a11),
-            a7: r.a14 * (-self.a9 * r.a13 + self.a10 * r.a12),
-            a8: -self.a8 * r.a14.powi(2),
-            a9: -self.a9 * r.a14.powi(2),
-            a10: -self.a10 * r.a14.powi(2),
+impl<T: Scalar> Project<Pseudoscalar<T>> for Trivector<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
         }
     }
 }
 
-impl Project<Pseudoscalar> for Bivector {
-    fn project(self, _r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Reflect<T> for Trivector<T> {
+    fn reflect(self, r: T) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.powi(2),
+            a12: self.a12 * r.powi(2),
+            a13: self.a13 * r.powi(2),
+            a14: self.a14 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Float> for Bivector {
-    fn reflect(self, r: Float) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.powi(2),
-            a6: self.a6 * r.powi(2),
-            a7: self.a7 * r.powi(2),
-            a8: self.a8 * r.powi(2),
-            a9: self.a9 * r.powi(2),
-            a10: self.a10 * r.powi(2),
+impl<T: Scalar> Reflect<Vector<T>> for Trivector<T> {
+    fn reflect(self, r: Vector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - T::from_f64(2.) * self.a12 * r.a3 * r.a4 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 - T::from_f64(2.) * self.a14 * r.a1 * r.a4,
+            a12: -T::from_f64(2.) * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a14 * r.a1 * r.a3,
+            a13: -T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - T::from_f64(2.) * self.a14 * r.a1 * r.a2,
+            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
         }
     }
 }
 
-impl Reflect<Vector> for Bivector {
-    fn reflect(self, r: Vector) -> Bivector {
-        Bivector {
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) - r.a3 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a4 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2),
-            a9: -r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a3 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
-            a10: r.a2 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
+impl<T: Scalar> Reflect<Bivector<T>> for Trivector<T> {
+    fn reflect(self, r: Bivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: -self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a8 * r.a9 - T::from_f64(2.) * self.a13 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a9 - T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: -T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a11 * r.a8 * r.a10 - T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + T::from_f64(2.) * self.a14 * r.a6 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: self.a14 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
         }
     }
 }
 
-impl Reflect<Bivector> for Bivector {
-    fn reflect(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a5 * r.a8 - 2. * self.a8 * r.a7 * r.a10 - 2. * self.a9 * r.a5 * r.a9 - 2. * self.a9 * r.a6 * r.a10 - 2. * self.a10 * r.a5 * r.a10 + 2. * self.a10 * r.a6 * r.a9 + 2. * self.a10 * r.a7 * r.a8,
-            a6: -2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - 2. * self.a7 * r.a8 * r.a9 - 2. * self.a8 * r.a6 * r.a8 - 2. * self.a8 * r.a7 * r.a9 + 2. * self.a9 * r.a5 * r.a10 - 2. * self.a9 * r.a6 * r.a9 + 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a5 * r.a9 - 2. * self.a10 * r.a6 * r.a10,
-            a7: -2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a8 * r.a9 - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + 2. * self.a8 * r.a5 * r.a10 + 2. * self.a8 * r.a6 * r.a9 - 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a6 * r.a8 - 2. * self.a9 * r.a7 * r.a9 - 2. * self.a10 * r.a5 * r.a8 - 2. * self.a10 * r.a7 * r.a10,
-            a8: -r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a9 * (self.a8 * r.a9 - self.a9 * r.a8) + r.a10 * (self.a8 * r.a10 - self.a10 * r.a8),
-            a9: -r.a8 * (self.a8 * r.a9 - self.a9 * r.a8) - r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a10 * (self.a9 * r.a10 - self.a10 * r.a9),
-            a10: -2. * self.a8 * r.a8 * r.a10 - 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+impl<T: Scalar> Reflect<Trivector<T>> for Trivector<T> {
+    fn reflect(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: r.a14 * (self.a11 * r.a14 - T::from_f64(2.) * self.a14 * r.a11),
+            a12: r.a14 * (self.a12 * r.a14 - T::from_f64(2.) * self.a14 * r.a12),
+            a13: r.a14 * (self.a13 * r.a14 - T::from_f64(2.) * self.a14 * r.a13),
+            a14: -self.a14 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Trivector> for Bivector {
-    fn reflect(self, r: Trivector) -> Bivector {
-        Bivector {
-            a5: r.a14 * (self.a5 * r.a14 - 2. * self.a8 * r.a12 + 2. * self.a9 * r.a11),
-            a6: r.a14 * (self.a6 * r.a14 + 2. * self.a8 * r.a13 - 2. * self.a10 * r.a11),
-            a7: r.a14 * (self.a7 * r.a14 - 2. * self.a9 * r.a13 + 2. * self.a10 * r.a12),
-            a8: -self.a8 * r.a14.powi(2),
-            a9: -self.a9 * r.a14.powi(2),
-            a10: -self.a10 * r.a14.powi(2),
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for Trivector<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for Bivector {
-    fn reflect(self, _r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Transform<T> for Trivector<T> {
+    fn transform(self, r: T) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.powi(2),
+            a12: self.a12 * r.powi(2),
+            a13: self.a13 * r.powi(2),
+            a14: self.a14 * r.powi(2),
         }
     }
 }
 
-impl Transform<Float> for Bivector {
-    fn transform(self, r: Float) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.powi(2),
-            a6: self.a6 * r.powi(2),
-            a7: self.a7 * r.powi(2),
-            a8: self.a8 * r.powi(2),
-            a9: self.a9 * r.powi(2),
-            a10: self.a10 * r.powi(2),
+impl<T: Scalar> Transform<Vector<T>> for Trivector<T> {
+    fn transform(self, r: Vector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - T::from_f64(2.) * self.a12 * r.a3 * r.a4 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 - T::from_f64(2.) * self.a14 * r.a1 * r.a4,
+            a12: -T::from_f64(2.) * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a14 * r.a1 * r.a3,
+            a13: -T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - T::from_f64(2.) * self.a14 * r.a1 * r.a2,
+            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
         }
     }
 }
 
-impl Transform<Vector> for Bivector {
-    fn transform(self, r: Vector) -> Bivector {
-        Bivector {
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -r.a2 * (self.a8 * r.a2 - self.a10 * r.a4) - r.a3 * (self.a8 * r.a3 - self.a9 * r.a4) + r.a4 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2),
-            a9: -r.a2 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a3 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a4 * (self.a8 * r.a3 - self.a9 * r.a4),
-            a10: r.a2 * (self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2) + r.a3 * (self.a9 * r.a2 - self.a10 * r.a3) + r.a4 * (self.a8 * r.a2 - self.a10 * r.a4),
+impl<T: Scalar> Transform<Bivector<T>> for Trivector<T> {
+    fn transform(self, r: Bivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: T::from_f64(2.) * self.a11 * r.a8 * r.a9 - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) + T::from_f64(2.) * self.a13 * r.a9 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: self.a14 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
-impl Transform<Bivector> for Bivector {
-    fn transform(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: -self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + 2. * self.a6 * r.a9 * r.a10 + 2. * self.a7 * r.a8 * r.a10 + 2. * self.a8 * r.a5 * r.a8 + 2. * self.a8 * r.a7 * r.a10 + 2. * self.a9 * r.a5 * r.a9 + 2. * self.a9 * r.a6 * r.a10 + 2. * self.a10 * r.a5 * r.a10 - 2. * self.a10 * r.a6 * r.a9 - 2. * self.a10 * r.a7 * r.a8,
-            a6: 2. * self.a5 * r.a9 * r.a10 - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + 2. * self.a7 * r.a8 * r.a9 + 2. * self.a8 * r.a6 * r.a8 + 2. * self.a8 * r.a7 * r.a9 - 2. * self.a9 * r.a5 * r.a10 + 2. * self.a9 * r.a6 * r.a9 - 2. * self.a9 * r.a7 * r.a8 + 2. * self.a10 * r.a5 * r.a9 + 2. * self.a10 * r.a6 * r.a10,
-            a7: 2. * self.a5 * r.a8 * r.a10 + 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - 2. * self.a8 * r.a5 * r.a10 - 2. * self.a8 * r.a6 * r.a9 + 2. * self.a8 * r.a7 * r.a8 + 2. * self.a9 * r.a6 * r.a8 + 2. * self.a9 * r.a7 * r.a9 + 2. * self.a10 * r.a5 * r.a8 + 2. * self.a10 * r.a7 * r.a10,
-            a8: r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) - r.a9 * (self.a8 * r.a9 - self.a9 * r.a8) - r.a10 * (self.a8 * r.a10 - self.a10 * r.a8),
-            a9: r.a8 * (self.a8 * r.a9 - self.a9 * r.a8) + r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) - r.a10 * (self.a9 * r.a10 - self.a10 * r.a9),
-            a10: r.a8 * (self.a8 * r.a10 - self.a10 * r.a8) + r.a9 * (self.a9 * r.a10 - self.a10 * r.a9) + r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+impl<T: Scalar> Transform<Trivector<T>> for Trivector<T> {
+    fn transform(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: r.a14 * (-self.a11 * r.a14 + T::from_f64(2.) * self.a14 * r.a11),
+            a12: r.a14 * (-self.a12 * r.a14 + T::from_f64(2.) * self.a14 * r.a12),
+            a13: r.a14 * (-self.a13 * r.a14 + T::from_f64(2.) * self.a14 * r.a13),
+            a14: self.a14 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Trivector> for Bivector {
-    fn transform(self, r: Trivector) -> Bivector {
-        Bivector {
-            a5: r.a14 * (-self.a5 * r.a14 + 2. * self.a8 * r.a12 - 2. * self.a9 * r.a11),
-            a6: r.a14 * (-self.a6 * r.a14 - 2. * self.a8 * r.a13 + 2. * self.a10 * r.a11),
-            a7: r.a14 * (-self.a7 * r.a14 + 2. * self.a9 * r.a13 - 2. * self.a10 * r.a12),
-            a8: self.a8 * r.a14.powi(2),
-            a9: self.a9 * r.a14.powi(2),
-            a10: self.a10 * r.a14.powi(2),
+impl<T: Scalar> Transform<Pseudoscalar<T>> for Trivector<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> Trivector<T> {
+        Trivector {
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for Bivector {
-    fn transform(self, _r: Pseudoscalar) -> Bivector {
-        Bivector {
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for Trivector<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a0 * r.a10 + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a7 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: T::from_f64(2.) * self.a11 * r.a0 * r.a10 + T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a0 * r.a8 + T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a6 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a11 * r.a0 * r.a9 + T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a5 - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: self.a14 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
-impl Transform<ScalarAndBivector> for Bivector {
-    fn transform(self, r: ScalarAndBivector) -> Bivector {
-        Bivector {
-            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + 2. * self.a6 * r.a0 * r.a8 + 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a0 * r.a9 + 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a0 * r.a6 + 2. * self.a8 * r.a5 * r.a8 + 2. * self.a8 * r.a7 * r.a10 + 2. * self.a9 * r.a0 * r.a7 + 2. * self.a9 * r.a5 * r.a9 + 2. * self.a9 * r.a6 * r.a10 + 2. * self.a10 * r.a5 * r.a10 - 2. * self.a10 * r.a6 * r.a9 - 2. * self.a10 * r.a7 * r.a8,
-            a6: -2. * self.a5 * r.a0 * r.a8 + 2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + 2. * self.a7 * r.a0 * r.a10 + 2. * self.a7 * r.a8 * r.a9 + 2. * self.a8 * r.a0 * r.a5 + 2. * self.a8 * r.a6 * r.a8 + 2. * self.a8 * r.a7 * r.a9 - 2. * self.a9 * r.a5 * r.a10 + 2. * self.a9 * r.a6 * r.a9 - 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a0 * r.a7 + 2. * self.a10 * r.a5 * r.a9 + 2. * self.a10 * r.a6 * r.a10,
-            a7: 2. * self.a5 * r.a0 * r.a9 + 2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a0 * r.a10 + 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - 2. * self.a8 * r.a5 * r.a10 - 2. * self.a8 * r.a6 * r.a9 + 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a0 * r.a5 + 2. * self.a9 * r.a6 * r.a8 + 2. * self.a9 * r.a7 * r.a9 + 2. * self.a10 * r.a0 * r.a6 + 2. * self.a10 * r.a5 * r.a8 + 2. * self.a10 * r.a7 * r.a10,
-            a8: r.a0 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a9 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) - r.a10 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8),
-            a9: r.a0 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a8 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10) + r.a10 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9),
-            a10: r.a0 * (-self.a8 * r.a9 + self.a9 * r.a8 + self.a10 * r.a0) + r.a8 * (self.a8 * r.a10 + self.a9 * r.a0 - self.a10 * r.a8) - r.a9 * (self.a8 * r.a0 - self.a9 * r.a10 + self.a10 * r.a9) + r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+impl<T: Scalar> Transform<Motor<T>> for Trivector<T> {
+    fn transform(self, r: Motor<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a0 * r.a10 + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a7 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a8 * r.a15,
+            a12: T::from_f64(2.) * self.a11 * r.a0 * r.a10 + T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a0 * r.a8 + T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a6 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a9 * r.a15,
+            a13: -T::from_f64(2.) * self.a11 * r.a0 * r.a9 + T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a5 - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a10 * r.a15,
+            a14: self.a14 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
 // ===========================================================================
-// Trivector
+// Pseudoscalar
 // ===========================================================================
 
 
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct Trivector {
-    a11: Float,
-    a12: Float,
-    a13: Float,
-    a14: Float,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pseudoscalar<T = Float> {
+    a15: T,
 }
 
-impl Reverse for Trivector {
-    fn reverse(self) -> Trivector {
-        Trivector {
-            a11: -self.a11,
-            a12: -self.a12,
-            a13: -self.a13,
-            a14: -self.a14,
+impl<T: Scalar> Reverse for Pseudoscalar<T> {
+    fn reverse(self) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15,
         }
     }
 }
 
-impl Dual for Trivector {
-    type Output = Vector;
+impl<T: Scalar> Dual for Pseudoscalar<T> {
+    type Output = T;
 
-    fn dual(self) -> Vector {
-        Vector {
-            a1: self.a14,
-            a2: self.a13,
-            a3: self.a12,
-            a4: self.a11,
-        }
+    fn dual(self) -> T {
+        self.a15
     }
 }
 
-impl Conjugate for Trivector {
-    fn conjugate(self) -> Trivector {
-        Trivector {
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
+impl<T: Scalar> Conjugate for Pseudoscalar<T> {
+    fn conjugate(self) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15,
         }
     }
 }
 
-impl Normalize for Trivector {
-    fn norm(self) -> Float {
-        self.a14.abs()
+impl<T: Scalar> Normalize<T> for Pseudoscalar<T> {
+    fn norm(self) -> T {
+        T::zero()
     }
 }
 
-impl NormalizeInfinite for Trivector {
-    fn inorm(self) -> Float {
-        (self.a11.powi(2) + self.a12.powi(2) + self.a13.powi(2)).sqrt()
+impl<T: Scalar> NormalizeInfinite<T> for Pseudoscalar<T> {
+    fn inorm(self) -> T {
+        self.a15.abs()
     }
 }
 
-impl Trivector {
-    pub fn new(a11: Float, a12: Float, a13: Float, a14: Float) -> Trivector {
-        Trivector {a11, a12, a13, a14}
+impl<T: Scalar> Pseudoscalar<T> {
+    pub fn new(a15: T) -> Pseudoscalar<T> {
+        Pseudoscalar {a15}
     }
 
-    pub fn zero() -> Trivector {
+    pub fn zero() -> Pseudoscalar<T> {
         Default::default()
     }
 
-    pub fn as_tuple(&self) -> (Float, Float, Float, Float) {
-        (self.a11, self.a12, self.a13, self.a14)
+    pub fn as_tuple(&self) -> T {
+        self.a15
     }
 
-    pub fn full_multivector(self) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+    pub fn motor(self) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15,
+        }
+    }
+
+    pub fn full_multivector(self) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Neg for Trivector {
-    type Output = Trivector;
+impl<T: Scalar> Neg for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn neg(self) -> Trivector {
-        Trivector {
-            a11: -self.a11,
-            a12: -self.a12,
-            a13: -self.a13,
-            a14: -self.a14,
+    fn neg(self) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: -self.a15,
         }
     }
 }
 
-impl Add<Float> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<T> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Float) -> FullMultivector {
-        FullMultivector {
+    fn add(self, r: T) -> Motor<T> {
+        Motor {
             a0: r,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Vector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: r.a1,
             a2: r.a2,
             a3: r.a3,
             a4: r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Bivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Bivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn add(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
             a5: r.a5,
             a6: r.a6,
             a7: r.a7,
             a8: r.a8,
             a9: r.a9,
             a10: r.a10,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Trivector> for Trivector {
-    type Output = Trivector;
+impl<T: Scalar> Add<Trivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: self.a11 + r.a11,
-            a12: self.a12 + r.a12,
-            a13: self.a13 + r.a13,
-            a14: self.a14 + r.a14,
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: r.a11,
+            a12: r.a12,
+            a13: r.a13,
+            a14: r.a14,
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Pseudoscalar> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: r.a15,
+    fn add(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn add(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
             a0: r.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
             a5: r.a5,
             a6: r.a6,
             a7: r.a7,
             a8: r.a8,
             a9: r.a9,
             a10: r.a10,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a15: self.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Add<Motor<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
+
+    fn add(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: r.a0,
+            a5: r.a5,
+            a6: r.a6,
+            a7: r.a7,
+            a8: r.a8,
+            a9: r.a9,
+            a10: r.a10,
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Add<FullMultivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: r.a0,
             a1: r.a1,
@@ -3137,157 +4713,147 @@ impl Add<FullMultivector> for Trivector {
             a8: r.a8,
             a9: r.a9,
             a10: r.a10,
-            a11: self.a11 + r.a11,
-            a12: self.a12 + r.a12,
-            a13: self.a13 + r.a13,
-            a14: self.a14 + r.a14,
-            a15: r.a15,
+            a11: r.a11,
+            a12: r.a12,
+            a13: r.a13,
+            a14: r.a14,
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Sub<Float> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<T> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Float) -> FullMultivector {
-        FullMultivector {
+    fn sub(self, r: T) -> Motor<T> {
+        Motor {
             a0: -r,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Vector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Vector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: -r.a1,
             a2: -r.a2,
             a3: -r.a3,
             a4: -r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Bivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Bivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn sub(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
             a5: -r.a5,
             a6: -r.a6,
             a7: -r.a7,
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Trivector> for Trivector {
-    type Output = Trivector;
+impl<T: Scalar> Sub<Trivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: self.a11 - r.a11,
-            a12: self.a12 - r.a12,
-            a13: self.a13 - r.a13,
-            a14: self.a14 - r.a14,
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -r.a11,
+            a12: -r.a12,
+            a13: -r.a13,
+            a14: -r.a14,
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Pseudoscalar> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: -r.a15,
+    fn sub(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn sub(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
             a0: -r.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
             a5: -r.a5,
             a6: -r.a6,
             a7: -r.a7,
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
-            a11: self.a11,
-            a12: self.a12,
-            a13: self.a13,
-            a14: self.a14,
-            a15: 0.,
+            a15: self.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Motor<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
+
+    fn sub(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: -r.a0,
+            a5: -r.a5,
+            a6: -r.a6,
+            a7: -r.a7,
+            a8: -r.a8,
+            a9: -r.a9,
+            a10: -r.a10,
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Sub<FullMultivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -r.a0,
             a1: -r.a1,
@@ -3300,1552 +4866,1826 @@ impl Sub<FullMultivector> for Trivector {
             a8: -r.a8,
             a9: -r.a9,
             a10: -r.a10,
-            a11: self.a11 - r.a11,
-            a12: self.a12 - r.a12,
-            a13: self.a13 - r.a13,
-            a14: self.a14 - r.a14,
-            a15: -r.a15,
+            a11: -r.a11,
+            a12: -r.a12,
+            a13: -r.a13,
+            a14: -r.a14,
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Mul<Float> for Trivector {
-    type Output = Trivector;
+impl<T: Scalar> Mul<T> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn mul(self, r: Float) -> Trivector {
-        Trivector {
-            a11: self.a11 * r,
-            a12: self.a12 * r,
-            a13: self.a13 * r,
-            a14: self.a14 * r,
+    fn mul(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Vector<T>> for Pseudoscalar<T> {
+    type Output = Trivector<T>;
+
+    fn mul(self, r: Vector<T>) -> Trivector<T> {
+        Trivector {
+            a11: -self.a15 * r.a4,
+            a12: -self.a15 * r.a3,
+            a13: -self.a15 * r.a2,
+            a14: T::zero(),
         }
     }
 }
 
-impl Mul<Vector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Bivector<T>> for Pseudoscalar<T> {
+    type Output = Bivector<T>;
 
-    fn mul(self, r: Vector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a11 * r.a3 + self.a12 * r.a4,
-            a6: self.a11 * r.a2 - self.a13 * r.a4,
-            a7: -self.a12 * r.a2 + self.a13 * r.a3,
-            a8: self.a14 * r.a4,
-            a9: self.a14 * r.a3,
-            a10: self.a14 * r.a2,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
+    fn mul(self, r: Bivector<T>) -> Bivector<T> {
+        Bivector {
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Mul<Bivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Trivector<T>> for Pseudoscalar<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
-            a12: -self.a11 * r.a10 + self.a13 * r.a8 + self.a14 * r.a6,
-            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a14 * r.a5,
-            a14: 0.,
-            a15: 0.,
+    fn mul(self, r: Trivector<T>) -> Vector<T> {
+        Vector {
+            a1: -self.a15 * r.a14,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
         }
     }
 }
 
-impl Mul<Trivector> for Trivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn mul(self, r: Trivector) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: -self.a14 * r.a14,
-            a5: self.a13 * r.a14 - self.a14 * r.a13,
-            a6: self.a12 * r.a14 - self.a14 * r.a12,
-            a7: self.a11 * r.a14 - self.a14 * r.a11,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-        }
+    fn mul(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
     }
 }
 
-impl Mul<Pseudoscalar> for Trivector {
-    type Output = Vector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: self.a14 * r.a15,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn mul(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Motor<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
-            a12: -self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
-            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
-            a14: self.a14 * r.a0,
-            a15: 0.,
+    fn mul(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl Mul<FullMultivector> for Trivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -self.a14 * r.a14,
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
-            a5: -self.a11 * r.a3 + self.a12 * r.a4 + self.a13 * r.a14 - self.a14 * r.a13,
-            a6: self.a11 * r.a2 + self.a12 * r.a14 - self.a13 * r.a4 - self.a14 * r.a12,
-            a7: self.a11 * r.a14 - self.a12 * r.a2 + self.a13 * r.a3 - self.a14 * r.a11,
-            a8: self.a14 * r.a4,
-            a9: self.a14 * r.a3,
-            a10: self.a14 * r.a2,
-            a11: self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
-            a12: -self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
-            a13: self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
-            a14: self.a14 * r.a0,
-            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
+            a0: T::zero(),
+            a1: -self.a15 * r.a14,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a15 * r.a4,
+            a12: -self.a15 * r.a3,
+            a13: -self.a15 * r.a2,
+            a14: T::zero(),
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl BitXor<Float> for Trivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: Float) -> Trivector {
-        Trivector {
-            a11: self.a11 * r,
-            a12: self.a12 * r,
-            a13: self.a13 * r,
-            a14: self.a14 * r,
+    fn bitxor(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r,
         }
     }
 }
 
-impl BitXor<Vector> for Trivector {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitxor(self, r: Vector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
-        }
+    fn bitxor(self, _r: Vector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<Bivector> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitxor(self, _r: Bivector) -> Float {
-        0.
+    fn bitxor(self, _r: Bivector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<Trivector> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitxor(self, _r: Trivector) -> Float {
-        0.
+    fn bitxor(self, _r: Trivector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<Pseudoscalar> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitxor(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitxor(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitXor<ScalarAndBivector> for Trivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: ScalarAndBivector) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.a0,
-            a12: self.a12 * r.a0,
-            a13: self.a13 * r.a0,
-            a14: self.a14 * r.a0,
+    fn bitxor(self, r: ScalarAndBivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl BitXor<FullMultivector> for Trivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11 * r.a0,
-            a12: self.a12 * r.a0,
-            a13: self.a13 * r.a0,
-            a14: self.a14 * r.a0,
-            a15: -self.a11 * r.a4 - self.a12 * r.a3 - self.a13 * r.a2 - self.a14 * r.a1,
+    fn bitxor(self, r: Motor<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl BitAnd<Float> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitand(self, _r: Float) -> Float {
-        0.
+    fn bitxor(self, r: FullMultivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.a0,
+        }
     }
 }
 
-impl BitAnd<Vector> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitand(self, r: Vector) -> Float {
-        self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1
+    fn bitand(self, r: T) -> T {
+        self.a15 * r
     }
 }
 
-impl BitAnd<Bivector> for Trivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for Pseudoscalar<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, r: Bivector) -> Vector {
+    fn bitand(self, r: Vector<T>) -> Vector<T> {
         Vector {
-            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
-            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
-            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
-            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+            a1: self.a15 * r.a1,
+            a2: self.a15 * r.a2,
+            a3: self.a15 * r.a3,
+            a4: self.a15 * r.a4,
         }
     }
 }
 
-impl BitAnd<Trivector> for Trivector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for Pseudoscalar<T> {
+    type Output = Bivector<T>;
 
-    fn bitand(self, r: Trivector) -> Bivector {
+    fn bitand(self, r: Bivector<T>) -> Bivector<T> {
         Bivector {
-            a5: -self.a11 * r.a12 + self.a12 * r.a11,
-            a6: self.a11 * r.a13 - self.a13 * r.a11,
-            a7: -self.a12 * r.a13 + self.a13 * r.a12,
-            a8: -self.a11 * r.a14 + self.a14 * r.a11,
-            a9: -self.a12 * r.a14 + self.a14 * r.a12,
-            a10: -self.a13 * r.a14 + self.a14 * r.a13,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
         }
     }
 }
 
-impl BitAnd<Pseudoscalar> for Trivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for Pseudoscalar<T> {
+    type Output = Trivector<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> Trivector {
+    fn bitand(self, r: Trivector<T>) -> Trivector<T> {
         Trivector {
-            a11: self.a11 * r.a15,
-            a12: self.a12 * r.a15,
-            a13: self.a13 * r.a15,
-            a14: self.a14 * r.a15,
+            a11: self.a15 * r.a11,
+            a12: self.a15 * r.a12,
+            a13: self.a15 * r.a13,
+            a14: self.a15 * r.a14,
         }
     }
 }
 
-impl BitAnd<ScalarAndBivector> for Trivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitand(self, r: ScalarAndBivector) -> Vector {
-        Vector {
-            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
-            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
-            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
-            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+    fn bitand(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.a15,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = ScalarAndBivector<T>;
+
+    fn bitand(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a15 * r.a0,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
+
+    fn bitand(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a15 * r.a0,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
+            a15: self.a15 * r.a15,
         }
     }
 }
 
-impl BitAnd<FullMultivector> for Trivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1,
-            a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
-            a2: -self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
-            a3: self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
-            a4: -self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
-            a5: -self.a11 * r.a12 + self.a12 * r.a11,
-            a6: self.a11 * r.a13 - self.a13 * r.a11,
-            a7: -self.a12 * r.a13 + self.a13 * r.a12,
-            a8: -self.a11 * r.a14 + self.a14 * r.a11,
-            a9: -self.a12 * r.a14 + self.a14 * r.a12,
-            a10: -self.a13 * r.a14 + self.a14 * r.a13,
-            a11: self.a11 * r.a15,
-            a12: self.a12 * r.a15,
-            a13: self.a13 * r.a15,
-            a14: self.a14 * r.a15,
-            a15: 0.,
+            a0: self.a15 * r.a0,
+            a1: self.a15 * r.a1,
+            a2: self.a15 * r.a2,
+            a3: self.a15 * r.a3,
+            a4: self.a15 * r.a4,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
+            a11: self.a15 * r.a11,
+            a12: self.a15 * r.a12,
+            a13: self.a15 * r.a13,
+            a14: self.a15 * r.a14,
+            a15: self.a15 * r.a15,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for Pseudoscalar<T> {
+    type Output = Pseudoscalar<T>;
+
+    fn bitor(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r,
         }
     }
 }
 
-impl BitOr<Float> for Trivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for Pseudoscalar<T> {
+    type Output = Trivector<T>;
 
-    fn bitor(self, r: Float) -> Trivector {
+    fn bitor(self, r: Vector<T>) -> Trivector<T> {
         Trivector {
-            a11: self.a11 * r,
-            a12: self.a12 * r,
-            a13: self.a13 * r,
-            a14: self.a14 * r,
+            a11: -self.a15 * r.a4,
+            a12: -self.a15 * r.a3,
+            a13: -self.a15 * r.a2,
+            a14: T::zero(),
         }
     }
 }
 
-impl BitOr<Vector> for Trivector {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for Pseudoscalar<T> {
+    type Output = Bivector<T>;
 
-    fn bitor(self, r: Vector) -> Bivector {
+    fn bitor(self, r: Bivector<T>) -> Bivector<T> {
         Bivector {
-            a5: -self.a11 * r.a3 + self.a12 * r.a4,
-            a6: self.a11 * r.a2 - self.a13 * r.a4,
-            a7: -self.a12 * r.a2 + self.a13 * r.a3,
-            a8: self.a14 * r.a4,
-            a9: self.a14 * r.a3,
-            a10: self.a14 * r.a2,
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl BitOr<Bivector> for Trivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for Pseudoscalar<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Bivector) -> Vector {
+    fn bitor(self, r: Trivector<T>) -> Vector<T> {
         Vector {
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
+            a1: -self.a15 * r.a14,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
         }
     }
 }
 
-impl BitOr<Trivector> for Trivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for Pseudoscalar<T> {
+    type Output = T;
 
-    fn bitor(self, r: Trivector) -> Float {
-        -self.a14 * r.a14
+    fn bitor(self, _r: Pseudoscalar<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitOr<Pseudoscalar> for Trivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> Vector {
-        Vector {
-            a1: self.a14 * r.a15,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn bitor(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for Pseudoscalar<T> {
+    type Output = Motor<T>;
+
+    fn bitor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for Pseudoscalar<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: -self.a15 * r.a14,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: -self.a15 * r.a10,
+            a6: -self.a15 * r.a9,
+            a7: -self.a15 * r.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a15 * r.a4,
+            a12: -self.a15 * r.a3,
+            a13: -self.a15 * r.a2,
+            a14: T::zero(),
+            a15: self.a15 * r.a0,
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for Trivector {
-    type Output = FullMultivector;
-
-    fn bitor(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: self.a11 * r.a0,
-            a12: self.a12 * r.a0,
-            a13: self.a13 * r.a0,
-            a14: self.a14 * r.a0,
-            a15: 0.,
+impl<T: Scalar> Project<T> for Pseudoscalar<T> {
+    fn project(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl BitOr<FullMultivector> for Trivector {
-    type Output = FullMultivector;
-
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: -self.a14 * r.a14,
-            a1: self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
-            a2: -self.a14 * r.a10,
-            a3: -self.a14 * r.a9,
-            a4: -self.a14 * r.a8,
-            a5: -self.a11 * r.a3 + self.a12 * r.a4,
-            a6: self.a11 * r.a2 - self.a13 * r.a4,
-            a7: -self.a12 * r.a2 + self.a13 * r.a3,
-            a8: self.a14 * r.a4,
-            a9: self.a14 * r.a3,
-            a10: self.a14 * r.a2,
-            a11: self.a11 * r.a0,
-            a12: self.a12 * r.a0,
-            a13: self.a13 * r.a0,
-            a14: self.a14 * r.a0,
-            a15: 0.,
+impl<T: Scalar> Project<Vector<T>> for Pseudoscalar<T> {
+    fn project(self, r: Vector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
         }
     }
 }
 
-impl Project<Float> for Trivector {
-    fn project(self, r: Float) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.powi(2),
-            a12: self.a12 * r.powi(2),
-            a13: self.a13 * r.powi(2),
-            a14: self.a14 * r.powi(2),
+impl<T: Scalar> Project<Bivector<T>> for Pseudoscalar<T> {
+    fn project(self, r: Bivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
         }
     }
 }
 
-impl Project<Vector> for Trivector {
-    fn project(self, r: Vector) -> Trivector {
-        Trivector {
-            a11: -self.a14 * r.a1 * r.a4 + r.a2 * (self.a11 * r.a2 - self.a13 * r.a4) + r.a3 * (self.a11 * r.a3 - self.a12 * r.a4),
-            a12: -self.a14 * r.a1 * r.a3 + r.a2 * (self.a12 * r.a2 - self.a13 * r.a3) - r.a4 * (self.a11 * r.a3 - self.a12 * r.a4),
-            a13: -self.a14 * r.a1 * r.a2 - r.a3 * (self.a12 * r.a2 - self.a13 * r.a3) - r.a4 * (self.a11 * r.a2 - self.a13 * r.a4),
-            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+impl<T: Scalar> Project<Trivector<T>> for Pseudoscalar<T> {
+    fn project(self, r: Trivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: -self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Project<Trivector> for Trivector {
-    fn project(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: -self.a14 * r.a11 * r.a14,
-            a12: -self.a14 * r.a12 * r.a14,
-            a13: -self.a14 * r.a13 * r.a14,
-            a14: -self.a14 * r.a14.powi(2),
+impl<T: Scalar> Project<Pseudoscalar<T>> for Pseudoscalar<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: T::zero(),
         }
     }
 }
 
-impl Project<Pseudoscalar> for Trivector {
-    fn project(self, _r: Pseudoscalar) -> Trivector {
-        Trivector {
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+impl<T: Scalar> Reflect<T> for Pseudoscalar<T> {
+    fn reflect(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Float> for Trivector {
-    fn reflect(self, r: Float) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.powi(2),
-            a12: self.a12 * r.powi(2),
-            a13: self.a13 * r.powi(2),
-            a14: self.a14 * r.powi(2),
+impl<T: Scalar> Reflect<Vector<T>> for Pseudoscalar<T> {
+    fn reflect(self, r: Vector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Reflect<Vector> for Trivector {
-    fn reflect(self, r: Vector) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - 2. * self.a12 * r.a3 * r.a4 - 2. * self.a13 * r.a2 * r.a4 - 2. * self.a14 * r.a1 * r.a4,
-            a12: -2. * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a14 * r.a1 * r.a3,
-            a13: -2. * self.a11 * r.a2 * r.a4 - 2. * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - 2. * self.a14 * r.a1 * r.a2,
-            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+impl<T: Scalar> Reflect<Bivector<T>> for Pseudoscalar<T> {
+    fn reflect(self, r: Bivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
         }
     }
 }
 
-impl Reflect<Bivector> for Trivector {
-    fn reflect(self, r: Bivector) -> Trivector {
-        Trivector {
-            a11: -self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - 2. * self.a12 * r.a8 * r.a9 - 2. * self.a13 * r.a8 * r.a10 + 2. * self.a14 * r.a5 * r.a9 - 2. * self.a14 * r.a6 * r.a10,
-            a12: -2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a5 * r.a8 + 2. * self.a14 * r.a7 * r.a10,
-            a13: -2. * self.a11 * r.a8 * r.a10 - 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + 2. * self.a14 * r.a6 * r.a8 - 2. * self.a14 * r.a7 * r.a9,
-            a14: self.a14 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
+impl<T: Scalar> Reflect<Trivector<T>> for Pseudoscalar<T> {
+    fn reflect(self, r: Trivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Trivector> for Trivector {
-    fn reflect(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: r.a14 * (self.a11 * r.a14 - 2. * self.a14 * r.a11),
-            a12: r.a14 * (self.a12 * r.a14 - 2. * self.a14 * r.a12),
-            a13: r.a14 * (self.a13 * r.a14 - 2. * self.a14 * r.a13),
-            a14: -self.a14 * r.a14.powi(2),
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for Pseudoscalar<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: T::zero(),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for Trivector {
-    fn reflect(self, _r: Pseudoscalar) -> Trivector {
-        Trivector {
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+impl<T: Scalar> Transform<T> for Pseudoscalar<T> {
+    fn transform(self, r: T) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl Transform<Float> for Trivector {
-    fn transform(self, r: Float) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.powi(2),
-            a12: self.a12 * r.powi(2),
-            a13: self.a13 * r.powi(2),
-            a14: self.a14 * r.powi(2),
+impl<T: Scalar> Transform<Vector<T>> for Pseudoscalar<T> {
+    fn transform(self, r: Vector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Transform<Vector> for Trivector {
-    fn transform(self, r: Vector) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - 2. * self.a12 * r.a3 * r.a4 - 2. * self.a13 * r.a2 * r.a4 - 2. * self.a14 * r.a1 * r.a4,
-            a12: -2. * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a14 * r.a1 * r.a3,
-            a13: -2. * self.a11 * r.a2 * r.a4 - 2. * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - 2. * self.a14 * r.a1 * r.a2,
-            a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+impl<T: Scalar> Transform<Bivector<T>> for Pseudoscalar<T> {
+    fn transform(self, r: Bivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
-impl Transform<Bivector> for Trivector {
-    fn transform(self, r: Bivector) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) + 2. * self.a12 * r.a8 * r.a9 + 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a5 * r.a9 + 2. * self.a14 * r.a6 * r.a10,
-            a12: 2. * self.a11 * r.a8 * r.a9 - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) + 2. * self.a13 * r.a9 * r.a10 + 2. * self.a14 * r.a5 * r.a8 - 2. * self.a14 * r.a7 * r.a10,
-            a13: 2. * self.a11 * r.a8 * r.a10 + 2. * self.a12 * r.a9 * r.a10 - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - 2. * self.a14 * r.a6 * r.a8 + 2. * self.a14 * r.a7 * r.a9,
-            a14: self.a14 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+impl<T: Scalar> Transform<Trivector<T>> for Pseudoscalar<T> {
+    fn transform(self, r: Trivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: -self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Trivector> for Trivector {
-    fn transform(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: r.a14 * (-self.a11 * r.a14 + 2. * self.a14 * r.a11),
-            a12: r.a14 * (-self.a12 * r.a14 + 2. * self.a14 * r.a12),
-            a13: r.a14 * (-self.a13 * r.a14 + 2. * self.a14 * r.a13),
-            a14: self.a14 * r.a14.powi(2),
+impl<T: Scalar> Transform<Pseudoscalar<T>> for Pseudoscalar<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: T::zero(),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for Trivector {
-    fn transform(self, _r: Pseudoscalar) -> Trivector {
-        Trivector {
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for Pseudoscalar<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
-impl Transform<ScalarAndBivector> for Trivector {
-    fn transform(self, r: ScalarAndBivector) -> Trivector {
-        Trivector {
-            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - 2. * self.a12 * r.a0 * r.a10 + 2. * self.a12 * r.a8 * r.a9 + 2. * self.a13 * r.a0 * r.a9 + 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a0 * r.a7 - 2. * self.a14 * r.a5 * r.a9 + 2. * self.a14 * r.a6 * r.a10,
-            a12: 2. * self.a11 * r.a0 * r.a10 + 2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - 2. * self.a13 * r.a0 * r.a8 + 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a0 * r.a6 + 2. * self.a14 * r.a5 * r.a8 - 2. * self.a14 * r.a7 * r.a10,
-            a13: -2. * self.a11 * r.a0 * r.a9 + 2. * self.a11 * r.a8 * r.a10 + 2. * self.a12 * r.a0 * r.a8 + 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - 2. * self.a14 * r.a0 * r.a5 - 2. * self.a14 * r.a6 * r.a8 + 2. * self.a14 * r.a7 * r.a9,
-            a14: self.a14 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+impl<T: Scalar> Transform<Motor<T>> for Pseudoscalar<T> {
+    fn transform(self, r: Motor<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a15 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
         }
     }
 }
 
 // ===========================================================================
-// Pseudoscalar
+// ScalarAndBivector
 // ===========================================================================
 
 
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct Pseudoscalar {
-    a15: Float,
-}
-
-impl Reverse for Pseudoscalar {
-    fn reverse(self) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScalarAndBivector<T = Float> {
+    a0: T,
+    a5: T,
+    a6: T,
+    a7: T,
+    a8: T,
+    a9: T,
+    a10: T,
+}
+
+impl<T: Scalar> Reverse for ScalarAndBivector<T> {
+    fn reverse(self) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0,
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
         }
     }
 }
 
-impl Dual for Pseudoscalar {
-    type Output = Float;
+impl<T: Scalar> Dual for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn dual(self) -> Float {
-        self.a15
+    fn dual(self) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a10,
+            a6: self.a9,
+            a7: self.a8,
+            a8: self.a7,
+            a9: self.a6,
+            a10: self.a5,
+            a15: self.a0,
+        }
     }
 }
 
-impl Conjugate for Pseudoscalar {
-    fn conjugate(self) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15,
+impl<T: Scalar> Conjugate for ScalarAndBivector<T> {
+    fn conjugate(self) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0,
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
         }
     }
 }
 
-impl Normalize for Pseudoscalar {
-    fn norm(self) -> Float {
-        0.
+impl<T: Scalar> Normalize<T> for ScalarAndBivector<T> {
+    fn norm(self) -> T {
+        (self.a0.powi(2) + self.a8.powi(2) + self.a9.powi(2) + self.a10.powi(2)).sqrt()
     }
 }
 
-impl NormalizeInfinite for Pseudoscalar {
-    fn inorm(self) -> Float {
-        self.a15.abs()
+impl<T: Scalar> NormalizeInfinite<T> for ScalarAndBivector<T> {
+    fn inorm(self) -> T {
+        (self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2)).sqrt()
     }
 }
 
-impl Pseudoscalar {
-    pub fn new(a15: Float) -> Pseudoscalar {
-        Pseudoscalar {a15}
+impl<T: Scalar> ScalarAndBivector<T> {
+    pub fn new(a0: T, a5: T, a6: T, a7: T, a8: T, a9: T, a10: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {a0, a5, a6, a7, a8, a9, a10}
     }
 
-    pub fn zero() -> Pseudoscalar {
+    pub fn zero() -> ScalarAndBivector<T> {
         Default::default()
     }
 
-    pub fn as_tuple(&self) -> Float {
-        self.a15
+    pub fn as_tuple(&self) -> (T, T, T, T, T, T, T) {
+        (self.a0, self.a5, self.a6, self.a7, self.a8, self.a9, self.a10)
     }
 
-    pub fn full_multivector(self) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    pub fn scalar(self) -> T {
+        self.a0
+    }
+
+    pub fn bivector(self) -> Bivector<T> {
+        Bivector {
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+        }
+    }
+
+    pub fn motor(self) -> Motor<T> {
+        Motor {
+            a0: self.a0,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: T::zero(),
+        }
+    }
+
+    pub fn full_multivector(self) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Neg for Pseudoscalar {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Neg for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn neg(self) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: -self.a15,
+    fn neg(self) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a0,
+            a5: -self.a5,
+            a6: -self.a6,
+            a7: -self.a7,
+            a8: -self.a8,
+            a9: -self.a9,
+            a10: -self.a10,
         }
     }
 }
 
-impl Add<Float> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<T> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn add(self, r: Float) -> FullMultivector {
-        FullMultivector {
-            a0: r,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn add(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 + r,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
         }
     }
 }
 
-impl Add<Vector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: self.a0,
             a1: r.a1,
             a2: r.a2,
             a3: r.a3,
             a4: r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Bivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn add(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn add(self, r: Bivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
         }
     }
 }
 
-impl Add<Trivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: self.a0,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
             a11: r.a11,
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: self.a15,
+            a15: T::zero(),
         }
     }
 }
 
-impl Add<Pseudoscalar> for Pseudoscalar {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Add<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 + r.a15,
+    fn add(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn add(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: r.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn add(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 + r.a0,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Add<Motor<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
+
+    fn add(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 + r.a0,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+            a15: r.a15,
         }
     }
 }
 
-impl Add<FullMultivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: r.a0,
+            a0: self.a0 + r.a0,
             a1: r.a1,
             a2: r.a2,
             a3: r.a3,
             a4: r.a4,
-            a5: r.a5,
-            a6: r.a6,
-            a7: r.a7,
-            a8: r.a8,
-            a9: r.a9,
-            a10: r.a10,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
             a11: r.a11,
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: self.a15 + r.a15,
+            a15: r.a15,
         }
     }
 }
 
-impl Sub<Float> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<T> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn sub(self, r: Float) -> FullMultivector {
-        FullMultivector {
-            a0: -r,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn sub(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 - r,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
         }
     }
 }
 
-impl Sub<Vector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Vector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: self.a0,
             a1: -r.a1,
             a2: -r.a2,
             a3: -r.a3,
             a4: -r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Bivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn sub(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn sub(self, r: Bivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
         }
     }
 }
 
-impl Sub<Trivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: self.a0,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
             a11: -r.a11,
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: self.a15,
+            a15: T::zero(),
         }
     }
 }
 
-impl Sub<Pseudoscalar> for Pseudoscalar {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 - r.a15,
+    fn sub(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: -r.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15,
+    fn sub(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 - r.a0,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Motor<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
+
+    fn sub(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 - r.a0,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
+            a15: -r.a15,
         }
     }
 }
 
-impl Sub<FullMultivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: -r.a0,
+            a0: self.a0 - r.a0,
             a1: -r.a1,
             a2: -r.a2,
             a3: -r.a3,
             a4: -r.a4,
-            a5: -r.a5,
-            a6: -r.a6,
-            a7: -r.a7,
-            a8: -r.a8,
-            a9: -r.a9,
-            a10: -r.a10,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
             a11: -r.a11,
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: self.a15 - r.a15,
+            a15: -r.a15,
         }
     }
 }
 
-impl Mul<Float> for Pseudoscalar {
-    type Output = Pseudoscalar;
+impl<T: Scalar> Mul<T> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn mul(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r,
+    fn mul(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r,
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl Mul<Vector> for Pseudoscalar {
-    type Output = Trivector;
+impl<T: Scalar> Mul<Vector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Vector) -> Trivector {
-        Trivector {
-            a11: -self.a15 * r.a4,
-            a12: -self.a15 * r.a3,
-            a13: -self.a15 * r.a2,
-            a14: 0.,
+    fn mul(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
+            a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4,
+            a3: self.a0 * r.a3 - self.a8 * r.a2 + self.a10 * r.a4,
+            a4: self.a0 * r.a4 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Bivector> for Pseudoscalar {
-    type Output = Bivector;
+impl<T: Scalar> Mul<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+    fn mul(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a0 * r.a8 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl Mul<Trivector> for Pseudoscalar {
-    type Output = Vector;
+impl<T: Scalar> Mul<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Trivector) -> Vector {
-        Vector {
-            a1: -self.a15 * r.a14,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn mul(self, r: Trivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: -self.a10 * r.a14,
+            a3: -self.a9 * r.a14,
+            a4: -self.a8 * r.a14,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a0 * r.a11 - self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+            a12: self.a0 * r.a12 - self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a13: self.a0 * r.a13 - self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a14: self.a0 * r.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Pseudoscalar> for Pseudoscalar {
-    type Output = Float;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn mul(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a10 * r.a15,
+            a6: -self.a9 * r.a15,
+            a7: -self.a8 * r.a15,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a0 * r.a15,
+        }
     }
 }
 
-impl Mul<ScalarAndBivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15 * r.a0,
+    fn mul(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Motor<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
+
+    fn mul(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl Mul<FullMultivector> for Pseudoscalar {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: -self.a15 * r.a14,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a15 * r.a4,
-            a12: -self.a15 * r.a3,
-            a13: -self.a15 * r.a2,
-            a14: 0.,
-            a15: self.a15 * r.a0,
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
+            a3: self.a0 * r.a3 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
+            a4: self.a0 * r.a4 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
+            a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a11: self.a0 * r.a11 - self.a5 * r.a3 + self.a6 * r.a2 - self.a7 * r.a14 - self.a8 * r.a1 + self.a9 * r.a13 - self.a10 * r.a12,
+            a12: self.a0 * r.a12 + self.a5 * r.a4 - self.a6 * r.a14 - self.a7 * r.a2 - self.a8 * r.a13 - self.a9 * r.a1 + self.a10 * r.a11,
+            a13: self.a0 * r.a13 - self.a5 * r.a14 - self.a6 * r.a4 + self.a7 * r.a3 + self.a8 * r.a12 - self.a9 * r.a11 - self.a10 * r.a1,
+            a14: self.a0 * r.a14 + self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<Float> for Pseudoscalar {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitxor(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r,
+    fn bitxor(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r,
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl BitXor<Vector> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, _r: Vector) -> Float {
-        0.
+    fn bitxor(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: self.a0 * r.a1,
+            a2: self.a0 * r.a2,
+            a3: self.a0 * r.a3,
+            a4: self.a0 * r.a4,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: T::zero(),
+        }
     }
 }
 
-impl BitXor<Bivector> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, _r: Bivector) -> Float {
-        0.
+    fn bitxor(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: self.a0 * r.a5,
+            a6: self.a0 * r.a6,
+            a7: self.a0 * r.a7,
+            a8: self.a0 * r.a8,
+            a9: self.a0 * r.a9,
+            a10: self.a0 * r.a10,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
     }
 }
 
-impl BitXor<Trivector> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, _r: Trivector) -> Float {
-        0.
+    fn bitxor(self, r: Trivector<T>) -> Trivector<T> {
+        Trivector {
+            a11: self.a0 * r.a11,
+            a12: self.a0 * r.a12,
+            a13: self.a0 * r.a13,
+            a14: self.a0 * r.a14,
+        }
     }
 }
 
-impl BitXor<Pseudoscalar> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitxor(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
+        Pseudoscalar {
+            a15: self.a0 * r.a15,
+        }
     }
 }
 
-impl BitXor<ScalarAndBivector> for Pseudoscalar {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, r: ScalarAndBivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.a0,
+    fn bitxor(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<FullMultivector> for Pseudoscalar {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, r: FullMultivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.a0,
+    fn bitxor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitAnd<Float> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Float) -> Float {
-        self.a15 * r
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0,
+            a1: self.a0 * r.a1,
+            a2: self.a0 * r.a2,
+            a3: self.a0 * r.a3,
+            a4: self.a0 * r.a4,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a11: self.a0 * r.a11 - self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
+            a12: self.a0 * r.a12 + self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
+            a13: self.a0 * r.a13 - self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a14: self.a0 * r.a14 + self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+        }
     }
 }
 
-impl BitAnd<Vector> for Pseudoscalar {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for ScalarAndBivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Vector) -> Vector {
-        Vector {
-            a1: self.a15 * r.a1,
-            a2: self.a15 * r.a2,
-            a3: self.a15 * r.a3,
-            a4: self.a15 * r.a4,
-        }
+    fn bitand(self, _r: T) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<Bivector> for Pseudoscalar {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for ScalarAndBivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: self.a15 * r.a5,
-            a6: self.a15 * r.a6,
-            a7: self.a15 * r.a7,
-            a8: self.a15 * r.a8,
-            a9: self.a15 * r.a9,
-            a10: self.a15 * r.a10,
-        }
+    fn bitand(self, _r: Vector<T>) -> T {
+        T::zero()
     }
 }
 
-impl BitAnd<Trivector> for Pseudoscalar {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Trivector) -> Trivector {
-        Trivector {
-            a11: self.a15 * r.a11,
-            a12: self.a15 * r.a12,
-            a13: self.a15 * r.a13,
-            a14: self.a15 * r.a14,
-        }
+    fn bitand(self, r: Bivector<T>) -> T {
+        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
     }
 }
 
-impl BitAnd<Pseudoscalar> for Pseudoscalar {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.a15,
+    fn bitand(self, r: Trivector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
+            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
         }
     }
 }
 
-impl BitAnd<ScalarAndBivector> for Pseudoscalar {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitand(self, r: ScalarAndBivector) -> ScalarAndBivector {
+    fn bitand(self, r: Pseudoscalar<T>) -> ScalarAndBivector<T> {
         ScalarAndBivector {
-            a0: self.a15 * r.a0,
-            a5: self.a15 * r.a5,
-            a6: self.a15 * r.a6,
-            a7: self.a15 * r.a7,
-            a8: self.a15 * r.a8,
-            a9: self.a15 * r.a9,
-            a10: self.a15 * r.a10,
+            a0: self.a0 * r.a15,
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
         }
     }
 }
 
-impl BitAnd<FullMultivector> for Pseudoscalar {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: self.a15 * r.a0,
-            a1: self.a15 * r.a1,
-            a2: self.a15 * r.a2,
-            a3: self.a15 * r.a3,
-            a4: self.a15 * r.a4,
-            a5: self.a15 * r.a5,
-            a6: self.a15 * r.a6,
-            a7: self.a15 * r.a7,
-            a8: self.a15 * r.a8,
-            a9: self.a15 * r.a9,
-            a10: self.a15 * r.a10,
-            a11: self.a15 * r.a11,
-            a12: self.a15 * r.a12,
-            a13: self.a15 * r.a13,
-            a14: self.a15 * r.a14,
-            a15: self.a15 * r.a15,
-        }
+    fn bitand(self, r: ScalarAndBivector<T>) -> T {
+        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
     }
 }
 
-impl BitOr<Float> for Pseudoscalar {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r,
+    fn bitand(self, r: Motor<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
         }
     }
 }
 
-impl BitOr<Vector> for Pseudoscalar {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Vector) -> Trivector {
-        Trivector {
-            a11: -self.a15 * r.a4,
-            a12: -self.a15 * r.a3,
-            a13: -self.a15 * r.a2,
-            a14: 0.,
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
+            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
+            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
+            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+            a5: self.a5 * r.a15,
+            a6: self.a6 * r.a15,
+            a7: self.a7 * r.a15,
+            a8: self.a8 * r.a15,
+            a9: self.a9 * r.a15,
+            a10: self.a10 * r.a15,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Bivector> for Pseudoscalar {
-    type Output = Bivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, r: Bivector) -> Bivector {
-        Bivector {
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+    fn bitor(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r,
+            a5: self.a5 * r,
+            a6: self.a6 * r,
+            a7: self.a7 * r,
+            a8: self.a8 * r,
+            a9: self.a9 * r,
+            a10: self.a10 * r,
         }
     }
 }
 
-impl BitOr<Trivector> for Pseudoscalar {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for ScalarAndBivector<T> {
+    type Output = Vector<T>;
 
-    fn bitor(self, r: Trivector) -> Vector {
+    fn bitor(self, r: Vector<T>) -> Vector<T> {
         Vector {
-            a1: -self.a15 * r.a14,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
+            a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4,
+            a3: self.a0 * r.a3 - self.a8 * r.a2 + self.a10 * r.a4,
+            a4: self.a0 * r.a4 + self.a9 * r.a2 - self.a10 * r.a3,
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for Pseudoscalar {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, _r: Pseudoscalar) -> Float {
-        0.
+    fn bitor(self, r: Bivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5,
+            a6: self.a0 * r.a6,
+            a7: self.a0 * r.a7,
+            a8: self.a0 * r.a8,
+            a9: self.a0 * r.a9,
+            a10: self.a0 * r.a10,
+        }
     }
 }
 
-impl BitOr<ScalarAndBivector> for Pseudoscalar {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> FullMultivector {
+    fn bitor(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a15 * r.a0,
+            a0: T::zero(),
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: -self.a10 * r.a14,
+            a3: -self.a9 * r.a14,
+            a4: -self.a8 * r.a14,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a0 * r.a11,
+            a12: self.a0 * r.a12,
+            a13: self.a0 * r.a13,
+            a14: self.a0 * r.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<FullMultivector> for Pseudoscalar {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: -self.a15 * r.a14,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: -self.a15 * r.a10,
-            a6: -self.a15 * r.a9,
-            a7: -self.a15 * r.a8,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a15 * r.a4,
-            a12: -self.a15 * r.a3,
-            a13: -self.a15 * r.a2,
-            a14: 0.,
-            a15: self.a15 * r.a0,
+    fn bitor(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: -self.a10 * r.a15,
+            a6: -self.a9 * r.a15,
+            a7: -self.a8 * r.a15,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: self.a0 * r.a15,
         }
     }
 }
 
-impl Project<Float> for Pseudoscalar {
-    fn project(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.powi(2),
-        }
-    }
-}
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for ScalarAndBivector<T> {
+    type Output = ScalarAndBivector<T>;
 
-impl Project<Vector> for Pseudoscalar {
-    fn project(self, r: Vector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+    fn bitor(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
         }
     }
 }
 
-impl Project<Bivector> for Pseudoscalar {
-    fn project(self, r: Bivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for ScalarAndBivector<T> {
+    type Output = Motor<T>;
+
+    fn bitor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a0 * r.a15,
         }
     }
 }
 
-impl Project<Trivector> for Pseudoscalar {
-    fn project(self, r: Trivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: -self.a15 * r.a14.powi(2),
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for ScalarAndBivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
+            a3: self.a0 * r.a3 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
+            a4: self.a0 * r.a4 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a11: self.a0 * r.a11,
+            a12: self.a0 * r.a12,
+            a13: self.a0 * r.a13,
+            a14: self.a0 * r.a14,
+            a15: self.a0 * r.a15,
         }
     }
 }
 
-impl Project<Pseudoscalar> for Pseudoscalar {
-    fn project(self, _r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: 0.,
+impl<T: Scalar> Project<T> for ScalarAndBivector<T> {
+    fn project(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.powi(2),
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Float> for Pseudoscalar {
-    fn reflect(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.powi(2),
+impl<T: Scalar> Project<Vector<T>> for ScalarAndBivector<T> {
+    fn project(self, r: Vector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+            a5: self.a5 * r.a2.powi(2) + self.a6 * r.a2 * r.a3 + self.a7 * r.a2 * r.a4 - self.a8 * r.a1 * r.a3 + self.a9 * r.a1 * r.a4,
+            a6: self.a5 * r.a2 * r.a3 + self.a6 * r.a3.powi(2) + self.a7 * r.a3 * r.a4 + self.a8 * r.a1 * r.a2 - self.a10 * r.a1 * r.a4,
+            a7: self.a5 * r.a2 * r.a4 + self.a6 * r.a3 * r.a4 + self.a7 * r.a4.powi(2) - self.a9 * r.a1 * r.a2 + self.a10 * r.a1 * r.a3,
+            a8: self.a8 * r.a2.powi(2) + self.a8 * r.a3.powi(2) - self.a9 * r.a3 * r.a4 - self.a10 * r.a2 * r.a4,
+            a9: -self.a8 * r.a3 * r.a4 + self.a9 * r.a2.powi(2) + self.a9 * r.a4.powi(2) - self.a10 * r.a2 * r.a3,
+            a10: -self.a8 * r.a2 * r.a4 - self.a9 * r.a2 * r.a3 + self.a10 * r.a3.powi(2) + self.a10 * r.a4.powi(2),
         }
     }
 }
 
-impl Reflect<Vector> for Pseudoscalar {
-    fn reflect(self, r: Vector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
+impl<T: Scalar> Project<Trivector<T>> for ScalarAndBivector<T> {
+    fn project(self, r: Trivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a0 * r.a14.powi(2),
+            a5: r.a14 * (-self.a8 * r.a12 + self.a9 * r.a11),
+            a6: r.a14 * (self.a8 * r.a13 - self.a10 * r.a11),
+            a7: r.a14 * (-self.a9 * r.a13 + self.a10 * r.a12),
+            a8: -self.a8 * r.a14.powi(2),
+            a9: -self.a9 * r.a14.powi(2),
+            a10: -self.a10 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Bivector> for Pseudoscalar {
-    fn reflect(self, r: Bivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
+impl<T: Scalar> Project<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Reflect<Trivector> for Pseudoscalar {
-    fn reflect(self, r: Trivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.a14.powi(2),
+impl<T: Scalar> Reflect<T> for ScalarAndBivector<T> {
+    fn reflect(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.powi(2),
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for Pseudoscalar {
-    fn reflect(self, _r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: 0.,
+impl<T: Scalar> Reflect<Vector<T>> for ScalarAndBivector<T> {
+    fn reflect(self, r: Vector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
         }
     }
 }
 
-impl Transform<Float> for Pseudoscalar {
-    fn transform(self, r: Float) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * r.powi(2),
+impl<T: Scalar> Reflect<Trivector<T>> for ScalarAndBivector<T> {
+    fn reflect(self, r: Trivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: -self.a0 * r.a14.powi(2),
+            a5: r.a14 * (self.a5 * r.a14 - T::from_f64(2.) * self.a8 * r.a12 + T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (self.a6 * r.a14 + T::from_f64(2.) * self.a8 * r.a13 - T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (self.a7 * r.a14 - T::from_f64(2.) * self.a9 * r.a13 + T::from_f64(2.) * self.a10 * r.a12),
+            a8: -self.a8 * r.a14.powi(2),
+            a9: -self.a9 * r.a14.powi(2),
+            a10: -self.a10 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Vector> for Pseudoscalar {
-    fn transform(self, r: Vector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
-impl Transform<Bivector> for Pseudoscalar {
-    fn transform(self, r: Bivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+impl<T: Scalar> Transform<T> for ScalarAndBivector<T> {
+    fn transform(self, r: T) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.powi(2),
+            a5: self.a5 * r.powi(2),
+            a6: self.a6 * r.powi(2),
+            a7: self.a7 * r.powi(2),
+            a8: self.a8 * r.powi(2),
+            a9: self.a9 * r.powi(2),
+            a10: self.a10 * r.powi(2),
         }
     }
 }
 
-impl Transform<Trivector> for Pseudoscalar {
-    fn transform(self, r: Trivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: -self.a15 * r.a14.powi(2),
+impl<T: Scalar> Transform<Vector<T>> for ScalarAndBivector<T> {
+    fn transform(self, r: Vector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for Pseudoscalar {
-    fn transform(self, _r: Pseudoscalar) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: 0.,
+impl<T: Scalar> Transform<Trivector<T>> for ScalarAndBivector<T> {
+    fn transform(self, r: Trivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a0 * r.a14.powi(2),
+            a5: r.a14 * (-self.a5 * r.a14 + T::from_f64(2.) * self.a8 * r.a12 - T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (-self.a6 * r.a14 - T::from_f64(2.) * self.a8 * r.a13 + T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (-self.a7 * r.a14 + T::from_f64(2.) * self.a9 * r.a13 - T::from_f64(2.) * self.a10 * r.a12),
+            a8: self.a8 * r.a14.powi(2),
+            a9: self.a9 * r.a14.powi(2),
+            a10: self.a10 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<ScalarAndBivector> for Pseudoscalar {
-    fn transform(self, r: ScalarAndBivector) -> Pseudoscalar {
-        Pseudoscalar {
-            a15: self.a15 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+impl<T: Scalar> Transform<Pseudoscalar<T>> for ScalarAndBivector<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
         }
     }
 }
 
 // ===========================================================================
-// ScalarAndBivector
+// Motor
 // ===========================================================================
 
 
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct ScalarAndBivector {
-    a0: Float,
-    a5: Float,
-    a6: Float,
-    a7: Float,
-    a8: Float,
-    a9: Float,
-    a10: Float,
-}
-
-impl Reverse for ScalarAndBivector {
-    fn reverse(self) -> ScalarAndBivector {
-        ScalarAndBivector {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Motor<T = Float> {
+    a0: T,
+    a5: T,
+    a6: T,
+    a7: T,
+    a8: T,
+    a9: T,
+    a10: T,
+    a15: T,
+}
+
+impl<T: Scalar> Reverse for Motor<T> {
+    fn reverse(self) -> Motor<T> {
+        Motor {
             a0: self.a0,
             a5: -self.a5,
             a6: -self.a6,
@@ -4853,38 +6693,31 @@ impl Reverse for ScalarAndBivector {
             a8: -self.a8,
             a9: -self.a9,
             a10: -self.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Dual for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Dual for Motor<T> {
+    type Output = Motor<T>;
 
-    fn dual(self) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn dual(self) -> Motor<T> {
+        Motor {
+            a0: self.a15,
             a5: self.a10,
             a6: self.a9,
             a7: self.a8,
             a8: self.a7,
             a9: self.a6,
             a10: self.a5,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
             a15: self.a0,
         }
     }
 }
 
-impl Conjugate for ScalarAndBivector {
-    fn conjugate(self) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Conjugate for Motor<T> {
+    fn conjugate(self) -> Motor<T> {
+        Motor {
             a0: self.a0,
             a5: -self.a5,
             a6: -self.a6,
@@ -4892,40 +6725,42 @@ impl Conjugate for ScalarAndBivector {
             a8: -self.a8,
             a9: -self.a9,
             a10: -self.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Normalize for ScalarAndBivector {
-    fn norm(self) -> Float {
+impl<T: Scalar> Normalize<T> for Motor<T> {
+    fn norm(self) -> T {
         (self.a0.powi(2) + self.a8.powi(2) + self.a9.powi(2) + self.a10.powi(2)).sqrt()
     }
 }
 
-impl NormalizeInfinite for ScalarAndBivector {
-    fn inorm(self) -> Float {
-        (self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2)).sqrt()
+impl<T: Scalar> NormalizeInfinite<T> for Motor<T> {
+    fn inorm(self) -> T {
+        (self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2) + self.a15.powi(2)).sqrt()
     }
 }
 
-impl ScalarAndBivector {
-    pub fn new(a0: Float, a5: Float, a6: Float, a7: Float, a8: Float, a9: Float, a10: Float) -> ScalarAndBivector {
-        ScalarAndBivector {a0, a5, a6, a7, a8, a9, a10}
+impl<T: Scalar> Motor<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(a0: T, a5: T, a6: T, a7: T, a8: T, a9: T, a10: T, a15: T) -> Motor<T> {
+        Motor {a0, a5, a6, a7, a8, a9, a10, a15}
     }
 
-    pub fn zero() -> ScalarAndBivector {
+    pub fn zero() -> Motor<T> {
         Default::default()
     }
 
-    pub fn as_tuple(&self) -> (Float, Float, Float, Float, Float, Float, Float) {
-        (self.a0, self.a5, self.a6, self.a7, self.a8, self.a9, self.a10)
+    pub fn as_tuple(&self) -> (T, T, T, T, T, T, T, T) {
+        (self.a0, self.a5, self.a6, self.a7, self.a8, self.a9, self.a10, self.a15)
     }
 
-    pub fn scalar(self) -> Float {
+    pub fn scalar(self) -> T {
         self.a0
     }
 
-    pub fn bivector(self) -> Bivector {
+    pub fn bivector(self) -> Bivector<T> {
         Bivector {
             a5: self.a5,
             a6: self.a6,
@@ -4936,33 +6771,33 @@ impl ScalarAndBivector {
         }
     }
 
-    pub fn full_multivector(self) -> FullMultivector {
+    pub fn full_multivector(self) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: self.a5,
             a6: self.a6,
             a7: self.a7,
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Neg for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Neg for Motor<T> {
+    type Output = Motor<T>;
 
-    fn neg(self) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn neg(self) -> Motor<T> {
+        Motor {
             a0: -self.a0,
             a5: -self.a5,
             a6: -self.a6,
@@ -4970,15 +6805,16 @@ impl Neg for ScalarAndBivector {
             a8: -self.a8,
             a9: -self.a9,
             a10: -self.a10,
+            a15: -self.a15,
         }
     }
 }
 
-impl Add<Float> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<T> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn add(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 + r,
             a5: self.a5,
             a6: self.a6,
@@ -4986,14 +6822,15 @@ impl Add<Float> for ScalarAndBivector {
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Vector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: r.a1,
@@ -5006,20 +6843,20 @@ impl Add<Vector> for ScalarAndBivector {
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Bivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<Bivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Bivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn add(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0,
             a5: self.a5 + r.a5,
             a6: self.a6 + r.a6,
@@ -5027,20 +6864,21 @@ impl Add<Bivector> for ScalarAndBivector {
             a8: self.a8 + r.a8,
             a9: self.a9 + r.a9,
             a10: self.a10 + r.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Trivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: self.a5,
             a6: self.a6,
             a7: self.a7,
@@ -5051,41 +6889,50 @@ impl Add<Trivector> for ScalarAndBivector {
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: 0.,
+            a15: self.a15,
         }
     }
 }
 
-impl Add<Pseudoscalar> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
+    fn add(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
             a0: self.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
             a5: self.a5,
             a6: self.a6,
             a7: self.a7,
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: r.a15,
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Add<ScalarAndBivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn add(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn add(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 + r.a0,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+            a15: self.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Add<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn add(self, r: Motor<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 + r.a0,
             a5: self.a5 + r.a5,
             a6: self.a6 + r.a6,
@@ -5093,14 +6940,15 @@ impl Add<ScalarAndBivector> for ScalarAndBivector {
             a8: self.a8 + r.a8,
             a9: self.a9 + r.a9,
             a10: self.a10 + r.a10,
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Add<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 + r.a0,
             a1: r.a1,
@@ -5117,16 +6965,16 @@ impl Add<FullMultivector> for ScalarAndBivector {
             a12: r.a12,
             a13: r.a13,
             a14: r.a14,
-            a15: r.a15,
+            a15: self.a15 + r.a15,
         }
     }
 }
 
-impl Sub<Float> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<T> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn sub(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 - r,
             a5: self.a5,
             a6: self.a6,
@@ -5134,14 +6982,15 @@ impl Sub<Float> for ScalarAndBivector {
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Vector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Vector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: -r.a1,
@@ -5154,20 +7003,20 @@ impl Sub<Vector> for ScalarAndBivector {
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Bivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<Bivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Bivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn sub(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0,
             a5: self.a5 - r.a5,
             a6: self.a6 - r.a6,
@@ -5175,20 +7024,21 @@ impl Sub<Bivector> for ScalarAndBivector {
             a8: self.a8 - r.a8,
             a9: self.a9 - r.a9,
             a10: self.a10 - r.a10,
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Trivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Trivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: self.a5,
             a6: self.a6,
             a7: self.a7,
@@ -5199,41 +7049,50 @@ impl Sub<Trivector> for ScalarAndBivector {
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: 0.,
+            a15: self.a15,
         }
     }
 }
 
-impl Sub<Pseudoscalar> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
+    fn sub(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
             a0: self.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
             a5: self.a5,
             a6: self.a6,
             a7: self.a7,
             a8: self.a8,
             a9: self.a9,
             a10: self.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: -r.a15,
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Sub<ScalarAndBivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn sub(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 - r.a0,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
+            a15: self.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Sub<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn sub(self, r: Motor<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 - r.a0,
             a5: self.a5 - r.a5,
             a6: self.a6 - r.a6,
@@ -5241,14 +7100,15 @@ impl Sub<ScalarAndBivector> for ScalarAndBivector {
             a8: self.a8 - r.a8,
             a9: self.a9 - r.a9,
             a10: self.a10 - r.a10,
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Sub<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 - r.a0,
             a1: -r.a1,
@@ -5265,16 +7125,16 @@ impl Sub<FullMultivector> for ScalarAndBivector {
             a12: -r.a12,
             a13: -r.a13,
             a14: -r.a14,
-            a15: -r.a15,
+            a15: self.a15 - r.a15,
         }
     }
 }
 
-impl Mul<Float> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+impl<T: Scalar> Mul<T> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn mul(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r,
             a5: self.a5 * r,
             a6: self.a6 * r,
@@ -5282,165 +7142,160 @@ impl Mul<Float> for ScalarAndBivector {
             a8: self.a8 * r,
             a9: self.a9 * r,
             a10: self.a10 * r,
+            a15: self.a15 * r,
         }
     }
 }
 
-impl Mul<Vector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Vector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Vector) -> FullMultivector {
+    fn mul(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
             a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4,
             a3: self.a0 * r.a3 - self.a8 * r.a2 + self.a10 * r.a4,
             a4: self.a0 * r.a4 + self.a9 * r.a2 - self.a10 * r.a3,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
-            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
-            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1 - self.a15 * r.a4,
+            a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1 - self.a15 * r.a3,
+            a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1 - self.a15 * r.a2,
             a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Bivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Bivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
+    fn mul(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
             a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a0 * r.a5 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
-            a6: self.a0 * r.a6 + self.a5 * r.a8 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
-            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a9 * r.a5 - self.a10 * r.a6,
+            a5: self.a0 * r.a5 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a9 * r.a5 - self.a10 * r.a6 - self.a15 * r.a8,
             a8: self.a0 * r.a8 + self.a9 * r.a10 - self.a10 * r.a9,
             a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a10 * r.a8,
             a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
             a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl Mul<Trivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Trivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Trivector) -> FullMultivector {
+    fn mul(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a0: T::zero(),
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
             a2: -self.a10 * r.a14,
             a3: -self.a9 * r.a14,
             a4: -self.a8 * r.a14,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: self.a0 * r.a11 - self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
             a12: self.a0 * r.a12 - self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
             a13: self.a0 * r.a13 - self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
             a14: self.a0 * r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl Mul<Pseudoscalar> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn mul(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
             a5: -self.a10 * r.a15,
             a6: -self.a9 * r.a15,
             a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn mul(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7,
-            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7,
-            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 + self.a9 * r.a5 - self.a10 * r.a6,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 + self.a10 * r.a7 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 + self.a9 * r.a5 - self.a10 * r.a6 - self.a15 * r.a8,
             a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
             a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
             a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn mul(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6 - self.a15 * r.a8,
+            a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
         }
     }
 }
 
-impl Mul<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
             a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
             a3: self.a0 * r.a3 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
             a4: self.a0 * r.a4 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
-            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15,
-            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7,
-            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6 - self.a15 * r.a8,
             a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
             a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
             a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
-            a11: self.a0 * r.a11 - self.a5 * r.a3 + self.a6 * r.a2 - self.a7 * r.a14 - self.a8 * r.a1 + self.a9 * r.a13 - self.a10 * r.a12,
-            a12: self.a0 * r.a12 + self.a5 * r.a4 - self.a6 * r.a14 - self.a7 * r.a2 - self.a8 * r.a13 - self.a9 * r.a1 + self.a10 * r.a11,
-            a13: self.a0 * r.a13 - self.a5 * r.a14 - self.a6 * r.a4 + self.a7 * r.a3 + self.a8 * r.a12 - self.a9 * r.a11 - self.a10 * r.a1,
+            a11: self.a0 * r.a11 - self.a5 * r.a3 + self.a6 * r.a2 - self.a7 * r.a14 - self.a8 * r.a1 + self.a9 * r.a13 - self.a10 * r.a12 - self.a15 * r.a4,
+            a12: self.a0 * r.a12 + self.a5 * r.a4 - self.a6 * r.a14 - self.a7 * r.a2 - self.a8 * r.a13 - self.a9 * r.a1 + self.a10 * r.a11 - self.a15 * r.a3,
+            a13: self.a0 * r.a13 - self.a5 * r.a14 - self.a6 * r.a4 + self.a7 * r.a3 + self.a8 * r.a12 - self.a9 * r.a11 - self.a10 * r.a1 - self.a15 * r.a2,
             a14: self.a0 * r.a14 + self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
         }
     }
 }
 
-impl BitXor<Float> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn bitxor(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r,
             a5: self.a5 * r,
             a6: self.a6 * r,
@@ -5448,64 +7303,60 @@ impl BitXor<Float> for ScalarAndBivector {
             a8: self.a8 * r,
             a9: self.a9 * r,
             a10: self.a10 * r,
+            a15: self.a15 * r,
         }
     }
 }
 
-impl BitXor<Vector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Vector) -> FullMultivector {
+    fn bitxor(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: self.a0 * r.a1,
             a2: self.a0 * r.a2,
             a3: self.a0 * r.a3,
             a4: self.a0 * r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: -self.a5 * r.a3 + self.a6 * r.a2 - self.a8 * r.a1,
             a12: self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
             a13: -self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
             a14: self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitXor<Bivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, r: Bivector) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn bitxor(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
             a5: self.a0 * r.a5,
             a6: self.a0 * r.a6,
             a7: self.a0 * r.a7,
             a8: self.a0 * r.a8,
             a9: self.a0 * r.a9,
             a10: self.a0 * r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
             a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
         }
     }
 }
 
-impl BitXor<Trivector> for ScalarAndBivector {
-    type Output = Trivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for Motor<T> {
+    type Output = Trivector<T>;
 
-    fn bitxor(self, r: Trivector) -> Trivector {
+    fn bitxor(self, r: Trivector<T>) -> Trivector<T> {
         Trivector {
             a11: self.a0 * r.a11,
             a12: self.a0 * r.a12,
@@ -5515,45 +7366,58 @@ impl BitXor<Trivector> for ScalarAndBivector {
     }
 }
 
-impl BitXor<Pseudoscalar> for ScalarAndBivector {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for Motor<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: Pseudoscalar) -> Pseudoscalar {
+    fn bitxor(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
         Pseudoscalar {
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl BitXor<ScalarAndBivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitxor(self, r: ScalarAndBivector) -> FullMultivector {
-        FullMultivector {
+    fn bitxor(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.a0,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
             a5: self.a0 * r.a5 + self.a5 * r.a0,
             a6: self.a0 * r.a6 + self.a6 * r.a0,
             a7: self.a0 * r.a7 + self.a7 * r.a0,
             a8: self.a0 * r.a8 + self.a8 * r.a0,
             a9: self.a0 * r.a9 + self.a9 * r.a0,
             a10: self.a0 * r.a10 + self.a10 * r.a0,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a15: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn bitxor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
         }
     }
 }
 
-impl BitXor<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0,
             a1: self.a0 * r.a1,
@@ -5570,53 +7434,83 @@ impl BitXor<FullMultivector> for ScalarAndBivector {
             a12: self.a0 * r.a12 + self.a5 * r.a4 - self.a7 * r.a2 - self.a9 * r.a1,
             a13: self.a0 * r.a13 - self.a6 * r.a4 + self.a7 * r.a3 - self.a10 * r.a1,
             a14: self.a0 * r.a14 + self.a8 * r.a4 + self.a9 * r.a3 + self.a10 * r.a2,
-            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
         }
     }
 }
 
-impl BitAnd<Float> for ScalarAndBivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for Motor<T> {
+    type Output = T;
 
-    fn bitand(self, _r: Float) -> Float {
-        0.
+    fn bitand(self, r: T) -> T {
+        self.a15 * r
     }
 }
 
-impl BitAnd<Vector> for ScalarAndBivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for Motor<T> {
+    type Output = Vector<T>;
 
-    fn bitand(self, _r: Vector) -> Float {
-        0.
+    fn bitand(self, r: Vector<T>) -> Vector<T> {
+        Vector {
+            a1: self.a15 * r.a1,
+            a2: self.a15 * r.a2,
+            a3: self.a15 * r.a3,
+            a4: self.a15 * r.a4,
+        }
     }
 }
 
-impl BitAnd<Bivector> for ScalarAndBivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for Motor<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitand(self, r: Bivector) -> Float {
-        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
+    fn bitand(self, r: Bivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
+        }
     }
 }
 
-impl BitAnd<Trivector> for ScalarAndBivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Trivector) -> Vector {
-        Vector {
+    fn bitand(self, r: Trivector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
             a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
             a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
             a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
             a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: self.a15 * r.a11,
+            a12: self.a15 * r.a12,
+            a13: self.a15 * r.a13,
+            a14: self.a15 * r.a14,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitAnd<Pseudoscalar> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn bitand(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.a15,
             a5: self.a5 * r.a15,
             a6: self.a6 * r.a15,
@@ -5624,48 +7518,78 @@ impl BitAnd<Pseudoscalar> for ScalarAndBivector {
             a8: self.a8 * r.a15,
             a9: self.a9 * r.a15,
             a10: self.a10 * r.a15,
+            a15: self.a15 * r.a15,
         }
     }
 }
 
-impl BitAnd<ScalarAndBivector> for ScalarAndBivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for Motor<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitand(self, r: ScalarAndBivector) -> Float {
-        self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5
+    fn bitand(self, r: ScalarAndBivector<T>) -> ScalarAndBivector<T> {
+        ScalarAndBivector {
+            a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+            a5: self.a15 * r.a5,
+            a6: self.a15 * r.a6,
+            a7: self.a15 * r.a7,
+            a8: self.a15 * r.a8,
+            a9: self.a15 * r.a9,
+            a10: self.a15 * r.a10,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn bitand(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+            a5: self.a5 * r.a15 + self.a15 * r.a5,
+            a6: self.a6 * r.a15 + self.a15 * r.a6,
+            a7: self.a7 * r.a15 + self.a15 * r.a7,
+            a8: self.a8 * r.a15 + self.a15 * r.a8,
+            a9: self.a9 * r.a15 + self.a15 * r.a9,
+            a10: self.a10 * r.a15 + self.a15 * r.a10,
+            a15: self.a15 * r.a15,
+        }
     }
 }
 
-impl BitAnd<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
-            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
-            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11,
-            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11,
-            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12,
-            a5: self.a5 * r.a15,
-            a6: self.a6 * r.a15,
-            a7: self.a7 * r.a15,
-            a8: self.a8 * r.a15,
-            a9: self.a9 * r.a15,
-            a10: self.a10 * r.a15,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+            a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11 + self.a15 * r.a1,
+            a2: -self.a5 * r.a14 + self.a8 * r.a12 - self.a9 * r.a11 + self.a15 * r.a2,
+            a3: -self.a6 * r.a14 - self.a8 * r.a13 + self.a10 * r.a11 + self.a15 * r.a3,
+            a4: -self.a7 * r.a14 + self.a9 * r.a13 - self.a10 * r.a12 + self.a15 * r.a4,
+            a5: self.a5 * r.a15 + self.a15 * r.a5,
+            a6: self.a6 * r.a15 + self.a15 * r.a6,
+            a7: self.a7 * r.a15 + self.a15 * r.a7,
+            a8: self.a8 * r.a15 + self.a15 * r.a8,
+            a9: self.a9 * r.a15 + self.a15 * r.a9,
+            a10: self.a10 * r.a15 + self.a15 * r.a10,
+            a11: self.a15 * r.a11,
+            a12: self.a15 * r.a12,
+            a13: self.a15 * r.a13,
+            a14: self.a15 * r.a14,
+            a15: self.a15 * r.a15,
         }
     }
 }
 
-impl BitOr<Float> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitor(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn bitor(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r,
             a5: self.a5 * r,
             a6: self.a6 * r,
@@ -5673,32 +7597,47 @@ impl BitOr<Float> for ScalarAndBivector {
             a8: self.a8 * r,
             a9: self.a9 * r,
             a10: self.a10 * r,
+            a15: self.a15 * r,
         }
     }
 }
 
-impl BitOr<Vector> for ScalarAndBivector {
-    type Output = Vector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Vector) -> Vector {
-        Vector {
+    fn bitor(self, r: Vector<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
             a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
             a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4,
             a3: self.a0 * r.a3 - self.a8 * r.a2 + self.a10 * r.a4,
             a4: self.a0 * r.a4 + self.a9 * r.a2 - self.a10 * r.a3,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: -self.a15 * r.a4,
+            a12: -self.a15 * r.a3,
+            a13: -self.a15 * r.a2,
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Bivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for Motor<T> {
+    type Output = ScalarAndBivector<T>;
 
-    fn bitor(self, r: Bivector) -> ScalarAndBivector {
+    fn bitor(self, r: Bivector<T>) -> ScalarAndBivector<T> {
         ScalarAndBivector {
             a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a5: self.a0 * r.a5,
-            a6: self.a0 * r.a6,
-            a7: self.a0 * r.a7,
+            a5: self.a0 * r.a5 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a15 * r.a8,
             a8: self.a0 * r.a8,
             a9: self.a0 * r.a9,
             a10: self.a0 * r.a10,
@@ -5706,100 +7645,115 @@ impl BitOr<Bivector> for ScalarAndBivector {
     }
 }
 
-impl BitOr<Trivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Trivector) -> FullMultivector {
+    fn bitor(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a0: T::zero(),
+            a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
             a2: -self.a10 * r.a14,
             a3: -self.a9 * r.a14,
             a4: -self.a8 * r.a14,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: self.a0 * r.a11,
             a12: self.a0 * r.a12,
             a13: self.a0 * r.a13,
             a14: self.a0 * r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> FullMultivector {
-        FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+    fn bitor(self, r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
             a5: -self.a10 * r.a15,
             a6: -self.a9 * r.a15,
             a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for ScalarAndBivector {
-    type Output = ScalarAndBivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for Motor<T> {
+    type Output = Motor<T>;
+
+    fn bitor(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a15 * r.a8,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for Motor<T> {
+    type Output = Motor<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+    fn bitor(self, r: Motor<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a5: self.a0 * r.a5 + self.a5 * r.a0,
-            a6: self.a0 * r.a6 + self.a6 * r.a0,
-            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15 - self.a15 * r.a8,
             a8: self.a0 * r.a8 + self.a8 * r.a0,
             a9: self.a0 * r.a9 + self.a9 * r.a0,
             a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a15: self.a0 * r.a15 + self.a15 * r.a0,
         }
     }
 }
 
-impl BitOr<FullMultivector> for ScalarAndBivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for Motor<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
-            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13,
+            a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
             a2: self.a0 * r.a2 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14,
             a3: self.a0 * r.a3 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4,
             a4: self.a0 * r.a4 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3,
-            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15,
-            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15,
-            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15 - self.a15 * r.a8,
             a8: self.a0 * r.a8 + self.a8 * r.a0,
             a9: self.a0 * r.a9 + self.a9 * r.a0,
             a10: self.a0 * r.a10 + self.a10 * r.a0,
-            a11: self.a0 * r.a11,
-            a12: self.a0 * r.a12,
-            a13: self.a0 * r.a13,
+            a11: self.a0 * r.a11 - self.a15 * r.a4,
+            a12: self.a0 * r.a12 - self.a15 * r.a3,
+            a13: self.a0 * r.a13 - self.a15 * r.a2,
             a14: self.a0 * r.a14,
-            a15: self.a0 * r.a15,
+            a15: self.a0 * r.a15 + self.a15 * r.a0,
         }
     }
 }
 
-impl Project<Float> for ScalarAndBivector {
-    fn project(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Project<T> for Motor<T> {
+    fn project(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.powi(2),
             a5: self.a5 * r.powi(2),
             a6: self.a6 * r.powi(2),
@@ -5807,13 +7761,14 @@ impl Project<Float> for ScalarAndBivector {
             a8: self.a8 * r.powi(2),
             a9: self.a9 * r.powi(2),
             a10: self.a10 * r.powi(2),
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl Project<Vector> for ScalarAndBivector {
-    fn project(self, r: Vector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Project<Vector<T>> for Motor<T> {
+    fn project(self, r: Vector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
             a5: self.a5 * r.a2.powi(2) + self.a6 * r.a2 * r.a3 + self.a7 * r.a2 * r.a4 - self.a8 * r.a1 * r.a3 + self.a9 * r.a1 * r.a4,
             a6: self.a5 * r.a2 * r.a3 + self.a6 * r.a3.powi(2) + self.a7 * r.a3 * r.a4 + self.a8 * r.a1 * r.a2 - self.a10 * r.a1 * r.a4,
@@ -5821,13 +7776,29 @@ impl Project<Vector> for ScalarAndBivector {
             a8: self.a8 * r.a2.powi(2) + self.a8 * r.a3.powi(2) - self.a9 * r.a3 * r.a4 - self.a10 * r.a2 * r.a4,
             a9: -self.a8 * r.a3 * r.a4 + self.a9 * r.a2.powi(2) + self.a9 * r.a4.powi(2) - self.a10 * r.a2 * r.a3,
             a10: -self.a8 * r.a2 * r.a4 - self.a9 * r.a2 * r.a3 + self.a10 * r.a3.powi(2) + self.a10 * r.a4.powi(2),
+            a15: self.a15 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
         }
     }
 }
 
-impl Project<Trivector> for ScalarAndBivector {
-    fn project(self, r: Trivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Project<Bivector<T>> for Motor<T> {
+    fn project(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
+            a5: r.a5 * (-self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10),
+            a6: r.a6 * (-self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10),
+            a7: r.a7 * (-self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10),
+            a8: -r.a8 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a9: -r.a9 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a10: -r.a10 * (self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Project<Trivector<T>> for Motor<T> {
+    fn project(self, r: Trivector<T>) -> Motor<T> {
+        Motor {
             a0: -self.a0 * r.a14.powi(2),
             a5: r.a14 * (-self.a8 * r.a12 + self.a9 * r.a11),
             a6: r.a14 * (self.a8 * r.a13 - self.a10 * r.a11),
@@ -5835,27 +7806,59 @@ impl Project<Trivector> for ScalarAndBivector {
             a8: -self.a8 * r.a14.powi(2),
             a9: -self.a9 * r.a14.powi(2),
             a10: -self.a10 * r.a14.powi(2),
+            a15: -self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Project<Pseudoscalar> for ScalarAndBivector {
-    fn project(self, _r: Pseudoscalar) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Project<Pseudoscalar<T>> for Motor<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Reflect<Float> for ScalarAndBivector {
-    fn reflect(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Project<ScalarAndBivector<T>> for Motor<T> {
+    fn project(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) - self.a6 * r.a0 * r.a8 + self.a7 * r.a0 * r.a9 + self.a8 * r.a0 * r.a6 - self.a8 * r.a5 * r.a8 - self.a9 * r.a0 * r.a7 - self.a9 * r.a5 * r.a9 - self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 + self.a5 * r.a0 * r.a8 + self.a6 * r.a0.powi(2) - self.a7 * r.a0 * r.a10 - self.a8 * r.a0 * r.a5 - self.a8 * r.a6 * r.a8 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - self.a5 * r.a0 * r.a9 + self.a6 * r.a0 * r.a10 + self.a7 * r.a0.powi(2) - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a7 * r.a9 - self.a10 * r.a0 * r.a6 - self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a9 * r.a0 * r.a10 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - self.a8 * r.a0 * r.a10 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a9.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + self.a8 * r.a0 * r.a9 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a10.powi(2),
+            a15: self.a15 * r.a0.powi(2) + r.a5 * (self.a0 * r.a10 + self.a10 * r.a0) + r.a6 * (self.a0 * r.a9 + self.a9 * r.a0) + r.a7 * (self.a0 * r.a8 + self.a8 * r.a0) + r.a8 * (self.a0 * r.a7 + self.a7 * r.a0 - self.a15 * r.a8) + r.a9 * (self.a0 * r.a6 + self.a6 * r.a0 - self.a15 * r.a9) + r.a10 * (self.a0 * r.a5 + self.a5 * r.a0 - self.a15 * r.a10),
+        }
+    }
+}
+
+impl<T: Scalar> Project<Motor<T>> for Motor<T> {
+    fn project(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + self.a5 * r.a0.powi(2) - self.a6 * r.a0 * r.a8 + self.a7 * r.a0 * r.a9 + self.a8 * r.a0 * r.a6 - self.a8 * r.a5 * r.a8 - self.a8 * r.a9 * r.a15 - self.a9 * r.a0 * r.a7 - self.a9 * r.a5 * r.a9 + self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 - self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 + self.a5 * r.a0 * r.a8 + self.a6 * r.a0.powi(2) - self.a7 * r.a0 * r.a10 - self.a8 * r.a0 * r.a5 - self.a8 * r.a6 * r.a8 + self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a6 * r.a10 - self.a10 * r.a8 * r.a15 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 - self.a5 * r.a0 * r.a9 + self.a6 * r.a0 * r.a10 + self.a7 * r.a0.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a7 * r.a9 - self.a9 * r.a10 * r.a15 - self.a10 * r.a0 * r.a6 - self.a10 * r.a7 * r.a10 + self.a10 * r.a9 * r.a15 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a9 * r.a0 * r.a10 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - self.a8 * r.a0 * r.a10 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a9.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + self.a8 * r.a0 * r.a9 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a10.powi(2),
+            a15: r.a0 * (self.a0 * r.a15 + self.a15 * r.a0) + r.a5 * (self.a0 * r.a10 + self.a10 * r.a0) + r.a6 * (self.a0 * r.a9 + self.a9 * r.a0) + r.a7 * (self.a0 * r.a8 + self.a8 * r.a0) + r.a8 * (self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15 - self.a15 * r.a8) + r.a9 * (self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15 - self.a15 * r.a9) + r.a10 * (self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15 - self.a15 * r.a10) - r.a15 * (-self.a0 * r.a0 + self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+        }
+    }
+}
+
+impl<T: Scalar> Reflect<T> for Motor<T> {
+    fn reflect(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.powi(2),
             a5: self.a5 * r.powi(2),
             a6: self.a6 * r.powi(2),
@@ -5863,55 +7866,104 @@ impl Reflect<Float> for ScalarAndBivector {
             a8: self.a8 * r.powi(2),
             a9: self.a9 * r.powi(2),
             a10: self.a10 * r.powi(2),
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl Reflect<Vector> for ScalarAndBivector {
-    fn reflect(self, r: Vector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Reflect<Vector<T>> for Motor<T> {
+    fn reflect(self, r: Vector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + 2. * self.a9 * r.a3 * r.a4 + 2. * self.a10 * r.a2 * r.a4,
-            a9: 2. * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + 2. * self.a10 * r.a2 * r.a3,
-            a10: 2. * self.a8 * r.a2 * r.a4 + 2. * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Reflect<Trivector> for ScalarAndBivector {
-    fn reflect(self, r: Trivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Reflect<Bivector<T>> for Motor<T> {
+    fn reflect(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
+            a5: self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: -T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: -self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: -T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Reflect<Trivector<T>> for Motor<T> {
+    fn reflect(self, r: Trivector<T>) -> Motor<T> {
+        Motor {
             a0: -self.a0 * r.a14.powi(2),
-            a5: r.a14 * (self.a5 * r.a14 - 2. * self.a8 * r.a12 + 2. * self.a9 * r.a11),
-            a6: r.a14 * (self.a6 * r.a14 + 2. * self.a8 * r.a13 - 2. * self.a10 * r.a11),
-            a7: r.a14 * (self.a7 * r.a14 - 2. * self.a9 * r.a13 + 2. * self.a10 * r.a12),
+            a5: r.a14 * (self.a5 * r.a14 - T::from_f64(2.) * self.a8 * r.a12 + T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (self.a6 * r.a14 + T::from_f64(2.) * self.a8 * r.a13 - T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (self.a7 * r.a14 - T::from_f64(2.) * self.a9 * r.a13 + T::from_f64(2.) * self.a10 * r.a12),
             a8: -self.a8 * r.a14.powi(2),
             a9: -self.a9 * r.a14.powi(2),
             a10: -self.a10 * r.a14.powi(2),
+            a15: self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for ScalarAndBivector {
-    fn reflect(self, _r: Pseudoscalar) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for Motor<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Transform<Float> for ScalarAndBivector {
-    fn transform(self, r: Float) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Reflect<ScalarAndBivector<T>> for Motor<T> {
+    fn reflect(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + T::from_f64(2.) * self.a5 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a0 * r.a8 + T::from_f64(2.) * self.a8 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a0 * r.a5 + self.a15 * r.a0.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Reflect<Motor<T>> for Motor<T> {
+    fn reflect(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + self.a5 * r.a0.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 - T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 - T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 + T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + T::from_f64(2.) * self.a5 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a0 * r.a8 + T::from_f64(2.) * self.a8 * r.a0 * r.a7 - T::from_f64(2.) * self.a8 * r.a8 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a6 - T::from_f64(2.) * self.a9 * r.a9 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a5 - T::from_f64(2.) * self.a10 * r.a10 * r.a15 + self.a15 * r.a0.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<T> for Motor<T> {
+    fn transform(self, r: T) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.powi(2),
             a5: self.a5 * r.powi(2),
             a6: self.a6 * r.powi(2),
@@ -5919,48 +7971,97 @@ impl Transform<Float> for ScalarAndBivector {
             a8: self.a8 * r.powi(2),
             a9: self.a9 * r.powi(2),
             a10: self.a10 * r.powi(2),
+            a15: self.a15 * r.powi(2),
         }
     }
 }
 
-impl Transform<Vector> for ScalarAndBivector {
-    fn transform(self, r: Vector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Transform<Vector<T>> for Motor<T> {
+    fn transform(self, r: Vector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + 2. * self.a9 * r.a3 * r.a4 + 2. * self.a10 * r.a2 * r.a4,
-            a9: 2. * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + 2. * self.a10 * r.a2 * r.a3,
-            a10: 2. * self.a8 * r.a2 * r.a4 + 2. * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Transform<Trivector> for ScalarAndBivector {
-    fn transform(self, r: Trivector) -> ScalarAndBivector {
-        ScalarAndBivector {
+impl<T: Scalar> Transform<Bivector<T>> for Motor<T> {
+    fn transform(self, r: Bivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+            a5: -self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a9 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: T::from_f64(2.) * self.a5 * r.a9 * r.a10 - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a8 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a8 * r.a9 - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a15: -T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<Trivector<T>> for Motor<T> {
+    fn transform(self, r: Trivector<T>) -> Motor<T> {
+        Motor {
             a0: self.a0 * r.a14.powi(2),
-            a5: r.a14 * (-self.a5 * r.a14 + 2. * self.a8 * r.a12 - 2. * self.a9 * r.a11),
-            a6: r.a14 * (-self.a6 * r.a14 - 2. * self.a8 * r.a13 + 2. * self.a10 * r.a11),
-            a7: r.a14 * (-self.a7 * r.a14 + 2. * self.a9 * r.a13 - 2. * self.a10 * r.a12),
+            a5: r.a14 * (-self.a5 * r.a14 + T::from_f64(2.) * self.a8 * r.a12 - T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (-self.a6 * r.a14 - T::from_f64(2.) * self.a8 * r.a13 + T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (-self.a7 * r.a14 + T::from_f64(2.) * self.a9 * r.a13 - T::from_f64(2.) * self.a10 * r.a12),
             a8: self.a8 * r.a14.powi(2),
             a9: self.a9 * r.a14.powi(2),
             a10: self.a10 * r.a14.powi(2),
+            a15: -self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for ScalarAndBivector {
-    fn transform(self, _r: Pseudoscalar) -> ScalarAndBivector {
-        ScalarAndBivector {
-            a0: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+impl<T: Scalar> Transform<Pseudoscalar<T>> for Motor<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> Motor<T> {
+        Motor {
+            a0: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for Motor<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: self.a8 * r.a0.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a0 * r.a10 + T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a0 * r.a8 + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a0 * r.a9 + T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a8 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a15: -T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a0.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<Motor<T>> for Motor<T> {
+    fn transform(self, r: Motor<T>) -> Motor<T> {
+        Motor {
+            a0: self.a0 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a8 * r.a9 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a8 * r.a15,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a10 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a10 * r.a9 * r.a15,
+            a8: self.a8 * r.a0.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a0 * r.a10 + T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a0 * r.a8 + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a0 * r.a9 + T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a8 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 - T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a0.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
         }
     }
 }
@@ -5971,27 +8072,28 @@ impl Transform<Pseudoscalar> for ScalarAndBivector {
 
 
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct FullMultivector {
-    a0: Float,
-    a1: Float,
-    a2: Float,
-    a3: Float,
-    a4: Float,
-    a5: Float,
-    a6: Float,
-    a7: Float,
-    a8: Float,
-    a9: Float,
-    a10: Float,
-    a11: Float,
-    a12: Float,
-    a13: Float,
-    a14: Float,
-    a15: Float,
-}
-
-impl Reverse for FullMultivector {
-    fn reverse(self) -> FullMultivector {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FullMultivector<T = Float> {
+    a0: T,
+    a1: T,
+    a2: T,
+    a3: T,
+    a4: T,
+    a5: T,
+    a6: T,
+    a7: T,
+    a8: T,
+    a9: T,
+    a10: T,
+    a11: T,
+    a12: T,
+    a13: T,
+    a14: T,
+    a15: T,
+}
+
+impl<T: Scalar> Reverse for FullMultivector<T> {
+    fn reverse(self) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6013,10 +8115,10 @@ impl Reverse for FullMultivector {
     }
 }
 
-impl Dual for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Dual for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn dual(self) -> FullMultivector {
+    fn dual(self) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a15,
             a1: self.a14,
@@ -6038,8 +8140,8 @@ impl Dual for FullMultivector {
     }
 }
 
-impl Conjugate for FullMultivector {
-    fn conjugate(self) -> FullMultivector {
+impl<T: Scalar> Conjugate for FullMultivector<T> {
+    fn conjugate(self) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: -self.a1,
@@ -6061,36 +8163,37 @@ impl Conjugate for FullMultivector {
     }
 }
 
-impl Normalize for FullMultivector {
-    fn norm(self) -> Float {
+impl<T: Scalar> Normalize<T> for FullMultivector<T> {
+    fn norm(self) -> T {
         (self.a0.powi(2) + self.a2.powi(2) + self.a3.powi(2) + self.a4.powi(2) + self.a8.powi(2) + self.a9.powi(2) + self.a10.powi(2) + self.a14.powi(2)).sqrt()
     }
 }
 
-impl NormalizeInfinite for FullMultivector {
-    fn inorm(self) -> Float {
+impl<T: Scalar> NormalizeInfinite<T> for FullMultivector<T> {
+    fn inorm(self) -> T {
         (self.a1.powi(2) + self.a5.powi(2) + self.a6.powi(2) + self.a7.powi(2) + self.a11.powi(2) + self.a12.powi(2) + self.a13.powi(2) + self.a15.powi(2)).sqrt()
     }
 }
 
-impl FullMultivector {
-    pub fn new(a0: Float, a1: Float, a2: Float, a3: Float, a4: Float, a5: Float, a6: Float, a7: Float, a8: Float, a9: Float, a10: Float, a11: Float, a12: Float, a13: Float, a14: Float, a15: Float) -> FullMultivector {
+impl<T: Scalar> FullMultivector<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(a0: T, a1: T, a2: T, a3: T, a4: T, a5: T, a6: T, a7: T, a8: T, a9: T, a10: T, a11: T, a12: T, a13: T, a14: T, a15: T) -> FullMultivector<T> {
         FullMultivector {a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15}
     }
 
-    pub fn zero() -> FullMultivector {
+    pub fn zero() -> FullMultivector<T> {
         Default::default()
     }
 
-    pub fn as_tuple(&self) -> (Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float) {
+    pub fn as_tuple(&self) -> (T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T) {
         (self.a0, self.a1, self.a2, self.a3, self.a4, self.a5, self.a6, self.a7, self.a8, self.a9, self.a10, self.a11, self.a12, self.a13, self.a14, self.a15)
     }
 
-    pub fn scalar(self) -> Float {
+    pub fn scalar(self) -> T {
         self.a0
     }
 
-    pub fn vector(self) -> Vector {
+    pub fn vector(self) -> Vector<T> {
         Vector {
             a1: self.a1,
             a2: self.a2,
@@ -6099,7 +8202,7 @@ impl FullMultivector {
         }
     }
 
-    pub fn bivector(self) -> Bivector {
+    pub fn bivector(self) -> Bivector<T> {
         Bivector {
             a5: self.a5,
             a6: self.a6,
@@ -6110,7 +8213,7 @@ impl FullMultivector {
         }
     }
 
-    pub fn trivector(self) -> Trivector {
+    pub fn trivector(self) -> Trivector<T> {
         Trivector {
             a11: self.a11,
             a12: self.a12,
@@ -6118,12 +8221,25 @@ impl FullMultivector {
             a14: self.a14,
         }
     }
+
+    pub fn motor(self) -> Motor<T> {
+        Motor {
+            a0: self.a0,
+            a5: self.a5,
+            a6: self.a6,
+            a7: self.a7,
+            a8: self.a8,
+            a9: self.a9,
+            a10: self.a10,
+            a15: self.a15,
+        }
+    }
 }
 
-impl Neg for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Neg for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn neg(self) -> FullMultivector {
+    fn neg(self) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a0,
             a1: -self.a1,
@@ -6145,10 +8261,10 @@ impl Neg for FullMultivector {
     }
 }
 
-impl Add<Float> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<T> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Float) -> FullMultivector {
+    fn add(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 + r,
             a1: self.a1,
@@ -6170,10 +8286,10 @@ impl Add<Float> for FullMultivector {
     }
 }
 
-impl Add<Vector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Vector) -> FullMultivector {
+    fn add(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1 + r.a1,
@@ -6195,10 +8311,10 @@ impl Add<Vector> for FullMultivector {
     }
 }
 
-impl Add<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Bivector) -> FullMultivector {
+    fn add(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6220,10 +8336,10 @@ impl Add<Bivector> for FullMultivector {
     }
 }
 
-impl Add<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Trivector) -> FullMultivector {
+    fn add(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6245,10 +8361,10 @@ impl Add<Trivector> for FullMultivector {
     }
 }
 
-impl Add<Pseudoscalar> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: Pseudoscalar) -> FullMultivector {
+    fn add(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6270,10 +8386,10 @@ impl Add<Pseudoscalar> for FullMultivector {
     }
 }
 
-impl Add<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: ScalarAndBivector) -> FullMultivector {
+    fn add(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 + r.a0,
             a1: self.a1,
@@ -6295,10 +8411,35 @@ impl Add<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl Add<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Add<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn add(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 + r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: self.a5 + r.a5,
+            a6: self.a6 + r.a6,
+            a7: self.a7 + r.a7,
+            a8: self.a8 + r.a8,
+            a9: self.a9 + r.a9,
+            a10: self.a10 + r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: self.a15 + r.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Add<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn add(self, r: FullMultivector) -> FullMultivector {
+    fn add(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 + r.a0,
             a1: self.a1 + r.a1,
@@ -6320,10 +8461,10 @@ impl Add<FullMultivector> for FullMultivector {
     }
 }
 
-impl Sub<Float> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<T> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Float) -> FullMultivector {
+    fn sub(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 - r,
             a1: self.a1,
@@ -6345,10 +8486,10 @@ impl Sub<Float> for FullMultivector {
     }
 }
 
-impl Sub<Vector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Vector) -> FullMultivector {
+    fn sub(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1 - r.a1,
@@ -6370,10 +8511,10 @@ impl Sub<Vector> for FullMultivector {
     }
 }
 
-impl Sub<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Bivector) -> FullMultivector {
+    fn sub(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6395,10 +8536,10 @@ impl Sub<Bivector> for FullMultivector {
     }
 }
 
-impl Sub<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Trivector) -> FullMultivector {
+    fn sub(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6420,10 +8561,10 @@ impl Sub<Trivector> for FullMultivector {
     }
 }
 
-impl Sub<Pseudoscalar> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: Pseudoscalar) -> FullMultivector {
+    fn sub(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0,
             a1: self.a1,
@@ -6445,10 +8586,10 @@ impl Sub<Pseudoscalar> for FullMultivector {
     }
 }
 
-impl Sub<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: ScalarAndBivector) -> FullMultivector {
+    fn sub(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 - r.a0,
             a1: self.a1,
@@ -6470,10 +8611,35 @@ impl Sub<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl Sub<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Sub<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn sub(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 - r.a0,
+            a1: self.a1,
+            a2: self.a2,
+            a3: self.a3,
+            a4: self.a4,
+            a5: self.a5 - r.a5,
+            a6: self.a6 - r.a6,
+            a7: self.a7 - r.a7,
+            a8: self.a8 - r.a8,
+            a9: self.a9 - r.a9,
+            a10: self.a10 - r.a10,
+            a11: self.a11,
+            a12: self.a12,
+            a13: self.a13,
+            a14: self.a14,
+            a15: self.a15 - r.a15,
+        }
+    }
+}
+
+impl<T: Scalar> Sub<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn sub(self, r: FullMultivector) -> FullMultivector {
+    fn sub(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 - r.a0,
             a1: self.a1 - r.a1,
@@ -6495,10 +8661,10 @@ impl Sub<FullMultivector> for FullMultivector {
     }
 }
 
-impl Mul<Float> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<T> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Float) -> FullMultivector {
+    fn mul(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r,
             a1: self.a1 * r,
@@ -6520,10 +8686,10 @@ impl Mul<Float> for FullMultivector {
     }
 }
 
-impl Mul<Vector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Vector) -> FullMultivector {
+    fn mul(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
             a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
@@ -6545,10 +8711,10 @@ impl Mul<Vector> for FullMultivector {
     }
 }
 
-impl Mul<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Bivector) -> FullMultivector {
+    fn mul(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
             a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
@@ -6570,10 +8736,10 @@ impl Mul<Bivector> for FullMultivector {
     }
 }
 
-impl Mul<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Trivector) -> FullMultivector {
+    fn mul(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a14 * r.a14,
             a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
@@ -6595,35 +8761,35 @@ impl Mul<Trivector> for FullMultivector {
     }
 }
 
-impl Mul<Pseudoscalar> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: Pseudoscalar) -> FullMultivector {
+    fn mul(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: self.a14 * r.a15,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: -self.a10 * r.a15,
             a6: -self.a9 * r.a15,
             a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: self.a4 * r.a15,
             a12: self.a3 * r.a15,
             a13: self.a2 * r.a15,
-            a14: 0.,
+            a14: T::zero(),
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl Mul<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: ScalarAndBivector) -> FullMultivector {
+    fn mul(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
             a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
@@ -6645,10 +8811,35 @@ impl Mul<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl Mul<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+impl<T: Scalar> Mul<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn mul(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0 - self.a14 * r.a8,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a6 * r.a8 + self.a7 * r.a9 + self.a8 * r.a6 - self.a9 * r.a7 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a5 * r.a8 + self.a6 * r.a0 - self.a7 * r.a10 - self.a8 * r.a5 - self.a9 * r.a15 + self.a10 * r.a7 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 - self.a5 * r.a9 + self.a6 * r.a10 + self.a7 * r.a0 - self.a8 * r.a15 + self.a9 * r.a5 - self.a10 * r.a6 - self.a15 * r.a8,
+            a8: self.a0 * r.a8 + self.a8 * r.a0 + self.a9 * r.a10 - self.a10 * r.a9,
+            a9: self.a0 * r.a9 - self.a8 * r.a10 + self.a9 * r.a0 + self.a10 * r.a8,
+            a10: self.a0 * r.a10 + self.a8 * r.a9 - self.a9 * r.a8 + self.a10 * r.a0,
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5 + self.a4 * r.a15 + self.a11 * r.a0 + self.a12 * r.a10 - self.a13 * r.a9 + self.a14 * r.a7,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a3 * r.a15 + self.a4 * r.a5 - self.a11 * r.a10 + self.a12 * r.a0 + self.a13 * r.a8 + self.a14 * r.a6,
+            a13: -self.a1 * r.a10 + self.a2 * r.a15 + self.a3 * r.a7 - self.a4 * r.a6 + self.a11 * r.a9 - self.a12 * r.a8 + self.a13 * r.a0 + self.a14 * r.a5,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8 + self.a14 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn mul(self, r: FullMultivector) -> FullMultivector {
+    fn mul(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 + self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10 - self.a14 * r.a14,
             a1: self.a0 * r.a1 + self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15 - self.a15 * r.a14,
@@ -6670,10 +8861,11 @@ impl Mul<FullMultivector> for FullMultivector {
     }
 }
 
-impl BitXor<Float> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<T> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Float) -> FullMultivector {
+    fn bitxor(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r,
             a1: self.a1 * r,
@@ -6695,12 +8887,13 @@ impl BitXor<Float> for FullMultivector {
     }
 }
 
-impl BitXor<Vector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Vector) -> FullMultivector {
+    fn bitxor(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: self.a0 * r.a1,
             a2: self.a0 * r.a2,
             a3: self.a0 * r.a3,
@@ -6720,16 +8913,17 @@ impl BitXor<Vector> for FullMultivector {
     }
 }
 
-impl BitXor<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Bivector) -> FullMultivector {
+    fn bitxor(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: self.a0 * r.a5,
             a6: self.a0 * r.a6,
             a7: self.a0 * r.a7,
@@ -6745,22 +8939,23 @@ impl BitXor<Bivector> for FullMultivector {
     }
 }
 
-impl BitXor<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: Trivector) -> FullMultivector {
+    fn bitxor(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: self.a0 * r.a11,
             a12: self.a0 * r.a12,
             a13: self.a0 * r.a13,
@@ -6770,20 +8965,22 @@ impl BitXor<Trivector> for FullMultivector {
     }
 }
 
-impl BitXor<Pseudoscalar> for FullMultivector {
-    type Output = Pseudoscalar;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = Pseudoscalar<T>;
 
-    fn bitxor(self, r: Pseudoscalar) -> Pseudoscalar {
+    fn bitxor(self, r: Pseudoscalar<T>) -> Pseudoscalar<T> {
         Pseudoscalar {
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl BitXor<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: ScalarAndBivector) -> FullMultivector {
+    fn bitxor(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0,
             a1: self.a1 * r.a0,
@@ -6805,10 +9002,37 @@ impl BitXor<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl BitXor<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitxor(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0,
+            a1: self.a1 * r.a0,
+            a2: self.a2 * r.a0,
+            a3: self.a3 * r.a0,
+            a4: self.a4 * r.a0,
+            a5: self.a0 * r.a5 + self.a5 * r.a0,
+            a6: self.a0 * r.a6 + self.a6 * r.a0,
+            a7: self.a0 * r.a7 + self.a7 * r.a0,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a11: -self.a1 * r.a8 + self.a2 * r.a6 - self.a3 * r.a5 + self.a11 * r.a0,
+            a12: -self.a1 * r.a9 - self.a2 * r.a7 + self.a4 * r.a5 + self.a12 * r.a0,
+            a13: -self.a1 * r.a10 + self.a3 * r.a7 - self.a4 * r.a6 + self.a13 * r.a0,
+            a14: self.a2 * r.a10 + self.a3 * r.a9 + self.a4 * r.a8 + self.a14 * r.a0,
+            a15: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitXor<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitxor(self, r: FullMultivector) -> FullMultivector {
+    fn bitxor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0,
             a1: self.a0 * r.a1 + self.a1 * r.a0,
@@ -6830,43 +9054,46 @@ impl BitXor<FullMultivector> for FullMultivector {
     }
 }
 
-impl BitAnd<Float> for FullMultivector {
-    type Output = Float;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<T> for FullMultivector<T> {
+    type Output = T;
 
-    fn bitand(self, r: Float) -> Float {
+    fn bitand(self, r: T) -> T {
         self.a15 * r
     }
 }
 
-impl BitAnd<Vector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Vector) -> FullMultivector {
+    fn bitand(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1,
             a1: self.a15 * r.a1,
             a2: self.a15 * r.a2,
             a3: self.a15 * r.a3,
             a4: self.a15 * r.a4,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitAnd<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Bivector) -> FullMultivector {
+    fn bitand(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5,
             a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
@@ -6879,19 +9106,20 @@ impl BitAnd<Bivector> for FullMultivector {
             a8: self.a15 * r.a8,
             a9: self.a15 * r.a9,
             a10: self.a15 * r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitAnd<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Trivector) -> FullMultivector {
+    fn bitand(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11,
             a1: self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11,
@@ -6908,15 +9136,16 @@ impl BitAnd<Trivector> for FullMultivector {
             a12: self.a15 * r.a12,
             a13: self.a15 * r.a13,
             a14: self.a15 * r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitAnd<Pseudoscalar> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: Pseudoscalar) -> FullMultivector {
+    fn bitand(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a15,
             a1: self.a1 * r.a15,
@@ -6938,10 +9167,11 @@ impl BitAnd<Pseudoscalar> for FullMultivector {
     }
 }
 
-impl BitAnd<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: ScalarAndBivector) -> FullMultivector {
+    fn bitand(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
             a1: self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
@@ -6954,19 +9184,46 @@ impl BitAnd<ScalarAndBivector> for FullMultivector {
             a8: self.a15 * r.a8,
             a9: self.a15 * r.a9,
             a10: self.a15 * r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitand(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a15 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a15 * r.a0,
+            a1: self.a1 * r.a15 + self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5,
+            a2: self.a2 * r.a15 - self.a11 * r.a9 + self.a12 * r.a8 - self.a14 * r.a5,
+            a3: self.a3 * r.a15 + self.a11 * r.a10 - self.a13 * r.a8 - self.a14 * r.a6,
+            a4: self.a4 * r.a15 - self.a12 * r.a10 + self.a13 * r.a9 - self.a14 * r.a7,
+            a5: self.a5 * r.a15 + self.a15 * r.a5,
+            a6: self.a6 * r.a15 + self.a15 * r.a6,
+            a7: self.a7 * r.a15 + self.a15 * r.a7,
+            a8: self.a8 * r.a15 + self.a15 * r.a8,
+            a9: self.a9 * r.a15 + self.a15 * r.a9,
+            a10: self.a10 * r.a15 + self.a15 * r.a10,
+            a11: self.a11 * r.a15,
+            a12: self.a12 * r.a15,
+            a13: self.a13 * r.a15,
+            a14: self.a14 * r.a15,
+            a15: self.a15 * r.a15,
         }
     }
 }
 
-impl BitAnd<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitAnd<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitand(self, r: FullMultivector) -> FullMultivector {
+    fn bitand(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a15 - self.a1 * r.a14 - self.a2 * r.a13 - self.a3 * r.a12 - self.a4 * r.a11 + self.a5 * r.a10 + self.a6 * r.a9 + self.a7 * r.a8 + self.a8 * r.a7 + self.a9 * r.a6 + self.a10 * r.a5 + self.a11 * r.a4 + self.a12 * r.a3 + self.a13 * r.a2 + self.a14 * r.a1 + self.a15 * r.a0,
             a1: self.a1 * r.a15 + self.a5 * r.a13 + self.a6 * r.a12 + self.a7 * r.a11 + self.a11 * r.a7 + self.a12 * r.a6 + self.a13 * r.a5 + self.a15 * r.a1,
@@ -6988,10 +9245,11 @@ impl BitAnd<FullMultivector> for FullMultivector {
     }
 }
 
-impl BitOr<Float> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<T> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Float) -> FullMultivector {
+    fn bitor(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r,
             a1: self.a1 * r,
@@ -7013,10 +9271,11 @@ impl BitOr<Float> for FullMultivector {
     }
 }
 
-impl BitOr<Vector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Vector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Vector) -> FullMultivector {
+    fn bitor(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4,
             a1: self.a0 * r.a1 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4,
@@ -7032,16 +9291,17 @@ impl BitOr<Vector> for FullMultivector {
             a11: -self.a15 * r.a4,
             a12: -self.a15 * r.a3,
             a13: -self.a15 * r.a2,
-            a14: 0.,
-            a15: 0.,
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Bivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Bivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Bivector) -> FullMultivector {
+    fn bitor(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
             a1: -self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
@@ -7054,19 +9314,20 @@ impl BitOr<Bivector> for FullMultivector {
             a8: self.a0 * r.a8,
             a9: self.a0 * r.a9,
             a10: self.a0 * r.a10,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Trivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Trivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Trivector) -> FullMultivector {
+    fn bitor(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a14 * r.a14,
             a1: self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 - self.a15 * r.a14,
@@ -7083,40 +9344,42 @@ impl BitOr<Trivector> for FullMultivector {
             a12: self.a0 * r.a12,
             a13: self.a0 * r.a13,
             a14: self.a0 * r.a14,
-            a15: 0.,
+            a15: T::zero(),
         }
     }
 }
 
-impl BitOr<Pseudoscalar> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Pseudoscalar<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: Pseudoscalar) -> FullMultivector {
+    fn bitor(self, r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
+            a0: T::zero(),
             a1: self.a14 * r.a15,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
             a5: -self.a10 * r.a15,
             a6: -self.a9 * r.a15,
             a7: -self.a8 * r.a15,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
             a11: self.a4 * r.a15,
             a12: self.a3 * r.a15,
             a13: self.a2 * r.a15,
-            a14: 0.,
+            a14: T::zero(),
             a15: self.a0 * r.a15,
         }
     }
 }
 
-impl BitOr<ScalarAndBivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<ScalarAndBivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: ScalarAndBivector) -> FullMultivector {
+    fn bitor(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
             a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10,
@@ -7138,10 +9401,37 @@ impl BitOr<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl BitOr<FullMultivector> for FullMultivector {
-    type Output = FullMultivector;
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<Motor<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
+
+    fn bitor(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10,
+            a1: self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15,
+            a2: self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10,
+            a3: self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9,
+            a4: -self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0 - self.a14 * r.a8,
+            a5: self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15 - self.a15 * r.a10,
+            a6: self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15 - self.a15 * r.a9,
+            a7: self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15 - self.a15 * r.a8,
+            a8: self.a0 * r.a8 + self.a8 * r.a0,
+            a9: self.a0 * r.a9 + self.a9 * r.a0,
+            a10: self.a0 * r.a10 + self.a10 * r.a0,
+            a11: self.a4 * r.a15 + self.a11 * r.a0,
+            a12: self.a3 * r.a15 + self.a12 * r.a0,
+            a13: self.a2 * r.a15 + self.a13 * r.a0,
+            a14: self.a14 * r.a0,
+            a15: self.a0 * r.a15 + self.a15 * r.a0,
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Scalar> BitOr<FullMultivector<T>> for FullMultivector<T> {
+    type Output = FullMultivector<T>;
 
-    fn bitor(self, r: FullMultivector) -> FullMultivector {
+    fn bitor(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a0 + self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10 - self.a14 * r.a14,
             a1: self.a0 * r.a1 + self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15 - self.a15 * r.a14,
@@ -7163,8 +9453,8 @@ impl BitOr<FullMultivector> for FullMultivector {
     }
 }
 
-impl Project<Float> for FullMultivector {
-    fn project(self, r: Float) -> FullMultivector {
+impl<T: Scalar> Project<T> for FullMultivector<T> {
+    fn project(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.powi(2),
             a1: self.a1 * r.powi(2),
@@ -7186,8 +9476,8 @@ impl Project<Float> for FullMultivector {
     }
 }
 
-impl Project<Vector> for FullMultivector {
-    fn project(self, r: Vector) -> FullMultivector {
+impl<T: Scalar> Project<Vector<T>> for FullMultivector<T> {
+    fn project(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
             a1: r.a1 * (self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4),
@@ -7209,8 +9499,8 @@ impl Project<Vector> for FullMultivector {
     }
 }
 
-impl Project<Bivector> for FullMultivector {
-    fn project(self, r: Bivector) -> FullMultivector {
+impl<T: Scalar> Project<Bivector<T>> for FullMultivector<T> {
+    fn project(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
             a1: r.a5 * (self.a3 * r.a8 - self.a4 * r.a9 + self.a14 * r.a10) + r.a6 * (-self.a2 * r.a8 + self.a4 * r.a10 + self.a14 * r.a9) + r.a7 * (self.a2 * r.a9 - self.a3 * r.a10 + self.a14 * r.a8),
@@ -7227,13 +9517,13 @@ impl Project<Bivector> for FullMultivector {
             a12: self.a3 * r.a5 * r.a10 + self.a3 * r.a6 * r.a9 + self.a3 * r.a7 * r.a8 - self.a11 * r.a8 * r.a9 - self.a12 * r.a9.powi(2) - self.a13 * r.a9 * r.a10 - self.a14 * r.a5 * r.a8 + self.a14 * r.a7 * r.a10,
             a13: self.a2 * r.a5 * r.a10 + self.a2 * r.a6 * r.a9 + self.a2 * r.a7 * r.a8 - self.a11 * r.a8 * r.a10 - self.a12 * r.a9 * r.a10 - self.a13 * r.a10.powi(2) + self.a14 * r.a6 * r.a8 - self.a14 * r.a7 * r.a9,
             a14: self.a14 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
-            a15: 2. * self.a0 * r.a5 * r.a10 + 2. * self.a0 * r.a6 * r.a9 + 2. * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Project<Trivector> for FullMultivector {
-    fn project(self, r: Trivector) -> FullMultivector {
+impl<T: Scalar> Project<Trivector<T>> for FullMultivector<T> {
+    fn project(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a0 * r.a14.powi(2),
             a1: r.a14 * (self.a2 * r.a13 + self.a3 * r.a12 + self.a4 * r.a11),
@@ -7255,43 +9545,43 @@ impl Project<Trivector> for FullMultivector {
     }
 }
 
-impl Project<Pseudoscalar> for FullMultivector {
-    fn project(self, _r: Pseudoscalar) -> FullMultivector {
+impl<T: Scalar> Project<Pseudoscalar<T>> for FullMultivector<T> {
+    fn project(self, _r: Pseudoscalar<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
         }
     }
 }
 
-impl Project<ScalarAndBivector> for FullMultivector {
-    fn project(self, r: ScalarAndBivector) -> FullMultivector {
+impl<T: Scalar> Project<ScalarAndBivector<T>> for FullMultivector<T> {
+    fn project(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - 2. * self.a8 * r.a0 * r.a8 - 2. * self.a9 * r.a0 * r.a9 - 2. * self.a10 * r.a0 * r.a10,
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
             a1: self.a11 * r.a0 * r.a8 + self.a12 * r.a0 * r.a9 + self.a13 * r.a0 * r.a10 + r.a0 * (self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10) - r.a5 * (self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10) - r.a6 * (self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9) + r.a7 * (self.a2 * r.a9 - self.a3 * r.a10 - self.a4 * r.a0 + self.a14 * r.a8),
-            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) - 2. * self.a3 * r.a0 * r.a8 + self.a3 * r.a9 * r.a10 + 2. * self.a4 * r.a0 * r.a9 + self.a4 * r.a8 * r.a10 - 2. * self.a14 * r.a0 * r.a10,
-            a3: 2. * self.a2 * r.a0 * r.a8 + self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) - self.a3 * r.a10.powi(2) - 2. * self.a4 * r.a0 * r.a10 + self.a4 * r.a8 * r.a9 - 2. * self.a14 * r.a0 * r.a9,
-            a4: -2. * self.a2 * r.a0 * r.a9 + self.a2 * r.a8 * r.a10 + 2. * self.a3 * r.a0 * r.a10 + self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) - 2. * self.a14 * r.a0 * r.a8,
-            a5: 2. * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) - self.a6 * r.a0 * r.a8 + self.a7 * r.a0 * r.a9 + self.a8 * r.a0 * r.a6 - self.a8 * r.a5 * r.a8 - self.a9 * r.a0 * r.a7 - self.a9 * r.a5 * r.a9 - self.a10 * r.a5 * r.a10 - 2. * self.a15 * r.a0 * r.a10,
-            a6: 2. * self.a0 * r.a0 * r.a6 + self.a5 * r.a0 * r.a8 + self.a6 * r.a0.powi(2) - self.a7 * r.a0 * r.a10 - self.a8 * r.a0 * r.a5 - self.a8 * r.a6 * r.a8 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a6 * r.a10 - 2. * self.a15 * r.a0 * r.a9,
-            a7: 2. * self.a0 * r.a0 * r.a7 - self.a5 * r.a0 * r.a9 + self.a6 * r.a0 * r.a10 + self.a7 * r.a0.powi(2) - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a7 * r.a9 - self.a10 * r.a0 * r.a6 - self.a10 * r.a7 * r.a10 - 2. * self.a15 * r.a0 * r.a8,
-            a8: 2. * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a9 * r.a0 * r.a10 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a8 * r.a10,
-            a9: 2. * self.a0 * r.a0 * r.a9 - self.a8 * r.a0 * r.a10 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a9.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a9 * r.a10,
-            a10: 2. * self.a0 * r.a0 * r.a10 + self.a8 * r.a0 * r.a9 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a10.powi(2),
+            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) - T::from_f64(2.) * self.a3 * r.a0 * r.a8 + self.a3 * r.a9 * r.a10 + T::from_f64(2.) * self.a4 * r.a0 * r.a9 + self.a4 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a10,
+            a3: T::from_f64(2.) * self.a2 * r.a0 * r.a8 + self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) - self.a3 * r.a10.powi(2) - T::from_f64(2.) * self.a4 * r.a0 * r.a10 + self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a14 * r.a0 * r.a9,
+            a4: -T::from_f64(2.) * self.a2 * r.a0 * r.a9 + self.a2 * r.a8 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a10 + self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a8,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) - self.a6 * r.a0 * r.a8 + self.a7 * r.a0 * r.a9 + self.a8 * r.a0 * r.a6 - self.a8 * r.a5 * r.a8 - self.a9 * r.a0 * r.a7 - self.a9 * r.a5 * r.a9 - self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 + self.a5 * r.a0 * r.a8 + self.a6 * r.a0.powi(2) - self.a7 * r.a0 * r.a10 - self.a8 * r.a0 * r.a5 - self.a8 * r.a6 * r.a8 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - self.a5 * r.a0 * r.a9 + self.a6 * r.a0 * r.a10 + self.a7 * r.a0.powi(2) - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a7 * r.a9 - self.a10 * r.a0 * r.a6 - self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a9 * r.a0 * r.a10 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - self.a8 * r.a0 * r.a10 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a9.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + self.a8 * r.a0 * r.a9 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a10.powi(2),
             a11: self.a11 * r.a0.powi(2) + self.a12 * r.a0 * r.a10 - self.a13 * r.a0 * r.a9 + self.a14 * r.a0 * r.a7 - r.a5 * (self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9) + r.a6 * (self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10) - r.a8 * (self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10),
             a12: -self.a11 * r.a0 * r.a10 + self.a12 * r.a0.powi(2) + self.a13 * r.a0 * r.a8 + self.a14 * r.a0 * r.a6 - r.a5 * (self.a2 * r.a9 - self.a3 * r.a10 - self.a4 * r.a0 + self.a14 * r.a8) - r.a7 * (self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10) - r.a9 * (self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10),
             a13: self.a11 * r.a0 * r.a9 - self.a12 * r.a0 * r.a8 + self.a13 * r.a0.powi(2) + self.a14 * r.a0 * r.a5 + r.a6 * (self.a2 * r.a9 - self.a3 * r.a10 - self.a4 * r.a0 + self.a14 * r.a8) + r.a7 * (self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9) - r.a10 * (self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10),
@@ -7301,31 +9591,54 @@ impl Project<ScalarAndBivector> for FullMultivector {
     }
 }
 
-impl Project<FullMultivector> for FullMultivector {
-    fn project(self, r: FullMultivector) -> FullMultivector {
+impl<T: Scalar> Project<Motor<T>> for FullMultivector<T> {
+    fn project(self, r: Motor<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a1: self.a14 * r.a0 * r.a15 + r.a0 * (self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15) - r.a5 * (self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 - self.a14 * r.a10) - r.a6 * (self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a14 * r.a9) + r.a7 * (self.a2 * r.a9 - self.a3 * r.a10 - self.a4 * r.a0 + self.a14 * r.a8) + r.a8 * (self.a4 * r.a15 + self.a11 * r.a0) + r.a9 * (self.a3 * r.a15 + self.a12 * r.a0) + r.a10 * (self.a2 * r.a15 + self.a13 * r.a0),
+            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) - T::from_f64(2.) * self.a3 * r.a0 * r.a8 + self.a3 * r.a9 * r.a10 + T::from_f64(2.) * self.a4 * r.a0 * r.a9 + self.a4 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a10,
+            a3: T::from_f64(2.) * self.a2 * r.a0 * r.a8 + self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) - self.a3 * r.a10.powi(2) - T::from_f64(2.) * self.a4 * r.a0 * r.a10 + self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a14 * r.a0 * r.a9,
+            a4: -T::from_f64(2.) * self.a2 * r.a0 * r.a9 + self.a2 * r.a8 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a10 + self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a8,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + self.a5 * r.a0.powi(2) - self.a6 * r.a0 * r.a8 + self.a7 * r.a0 * r.a9 + self.a8 * r.a0 * r.a6 - self.a8 * r.a5 * r.a8 - self.a8 * r.a9 * r.a15 - self.a9 * r.a0 * r.a7 - self.a9 * r.a5 * r.a9 + self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 - self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 + self.a5 * r.a0 * r.a8 + self.a6 * r.a0.powi(2) - self.a7 * r.a0 * r.a10 - self.a8 * r.a0 * r.a5 - self.a8 * r.a6 * r.a8 + self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a6 * r.a10 - self.a10 * r.a8 * r.a15 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 - self.a5 * r.a0 * r.a9 + self.a6 * r.a0 * r.a10 + self.a7 * r.a0.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a7 * r.a9 - self.a9 * r.a10 * r.a15 - self.a10 * r.a0 * r.a6 - self.a10 * r.a7 * r.a10 + self.a10 * r.a9 * r.a15 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a9 * r.a0 * r.a10 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - self.a8 * r.a0 * r.a10 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a9.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + self.a8 * r.a0 * r.a9 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a10.powi(2),
+            a11: -self.a1 * r.a0 * r.a8 + self.a2 * r.a0 * r.a6 - T::from_f64(2.) * self.a2 * r.a9 * r.a15 - self.a3 * r.a0 * r.a5 + T::from_f64(2.) * self.a3 * r.a10 * r.a15 + T::from_f64(2.) * self.a4 * r.a0 * r.a15 + self.a4 * r.a5 * r.a10 + self.a4 * r.a6 * r.a9 + self.a4 * r.a7 * r.a8 + self.a11 * r.a0.powi(2) - self.a11 * r.a8.powi(2) + self.a12 * r.a0 * r.a10 - self.a12 * r.a8 * r.a9 - self.a13 * r.a0 * r.a9 - self.a13 * r.a8 * r.a10 + self.a14 * r.a0 * r.a7 + self.a14 * r.a5 * r.a9 - self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a8 * r.a15,
+            a12: -self.a1 * r.a0 * r.a9 - self.a2 * r.a0 * r.a7 + T::from_f64(2.) * self.a2 * r.a8 * r.a15 + T::from_f64(2.) * self.a3 * r.a0 * r.a15 + self.a3 * r.a5 * r.a10 + self.a3 * r.a6 * r.a9 + self.a3 * r.a7 * r.a8 + self.a4 * r.a0 * r.a5 - T::from_f64(2.) * self.a4 * r.a10 * r.a15 - self.a11 * r.a0 * r.a10 - self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a9.powi(2) + self.a13 * r.a0 * r.a8 - self.a13 * r.a9 * r.a10 + self.a14 * r.a0 * r.a6 - self.a14 * r.a5 * r.a8 + self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a9 * r.a15,
+            a13: -self.a1 * r.a0 * r.a10 + T::from_f64(2.) * self.a2 * r.a0 * r.a15 + self.a2 * r.a5 * r.a10 + self.a2 * r.a6 * r.a9 + self.a2 * r.a7 * r.a8 + self.a3 * r.a0 * r.a7 - T::from_f64(2.) * self.a3 * r.a8 * r.a15 - self.a4 * r.a0 * r.a6 + T::from_f64(2.) * self.a4 * r.a9 * r.a15 + self.a11 * r.a0 * r.a9 - self.a11 * r.a8 * r.a10 - self.a12 * r.a0 * r.a8 - self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a10.powi(2) + self.a14 * r.a0 * r.a5 + self.a14 * r.a6 * r.a8 - self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a10 * r.a15,
+            a14: self.a2 * r.a0 * r.a10 + self.a3 * r.a0 * r.a9 + self.a4 * r.a0 * r.a8 + self.a14 * r.a0.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2),
+            a15: r.a0 * (self.a0 * r.a15 + self.a15 * r.a0) + r.a5 * (self.a0 * r.a10 + self.a10 * r.a0) + r.a6 * (self.a0 * r.a9 + self.a9 * r.a0) + r.a7 * (self.a0 * r.a8 + self.a8 * r.a0) + r.a8 * (self.a0 * r.a7 + self.a7 * r.a0 - self.a8 * r.a15 - self.a15 * r.a8) + r.a9 * (self.a0 * r.a6 + self.a6 * r.a0 - self.a9 * r.a15 - self.a15 * r.a9) + r.a10 * (self.a0 * r.a5 + self.a5 * r.a0 - self.a10 * r.a15 - self.a15 * r.a10) - r.a15 * (-self.a0 * r.a0 + self.a8 * r.a8 + self.a9 * r.a9 + self.a10 * r.a10),
+        }
+    }
+}
+
+impl<T: Scalar> Project<FullMultivector<T>> for FullMultivector<T> {
+    fn project(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: r.a0 * (self.a0 * r.a0 + self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10 - self.a14 * r.a14) + r.a2 * (self.a0 * r.a2 + self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14 - self.a14 * r.a10) + r.a3 * (self.a0 * r.a3 + self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4 - self.a14 * r.a9) + r.a4 * (self.a0 * r.a4 - self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3 - self.a14 * r.a8) - r.a8 * (self.a0 * r.a8 + self.a4 * r.a14 + self.a8 * r.a0 + self.a14 * r.a4) - r.a9 * (self.a0 * r.a9 + self.a3 * r.a14 + self.a9 * r.a0 + self.a14 * r.a3) - r.a10 * (self.a0 * r.a10 + self.a2 * r.a14 + self.a10 * r.a0 + self.a14 * r.a2) - r.a14 * (self.a0 * r.a14 + self.a14 * r.a0),
             a1: r.a0 * (self.a0 * r.a1 + self.a1 * r.a0 - self.a2 * r.a5 - self.a3 * r.a6 - self.a4 * r.a7 + self.a5 * r.a2 + self.a6 * r.a3 + self.a7 * r.a4 + self.a8 * r.a11 + self.a9 * r.a12 + self.a10 * r.a13 + self.a11 * r.a8 + self.a12 * r.a9 + self.a13 * r.a10 + self.a14 * r.a15 - self.a15 * r.a14) + r.a1 * (self.a0 * r.a0 + self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10 - self.a14 * r.a14) + r.a2 * (self.a0 * r.a5 - self.a3 * r.a11 + self.a4 * r.a12 + self.a5 * r.a0 - self.a10 * r.a15 - self.a11 * r.a3 + self.a12 * r.a4 - self.a15 * r.a10) + r.a3 * (self.a0 * r.a6 + self.a2 * r.a11 - self.a4 * r.a13 + self.a6 * r.a0 - self.a9 * r.a15 + self.a11 * r.a2 - self.a13 * r.a4 - self.a15 * r.a9) + r.a4 * (self.a0 * r.a7 - self.a2 * r.a12 + self.a3 * r.a13 + self.a7 * r.a0 - self.a8 * r.a15 - self.a12 * r.a2 + self.a13 * r.a3 - self.a15 * r.a8) - r.a5 * (self.a0 * r.a2 + self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14 - self.a14 * r.a10) - r.a6 * (self.a0 * r.a3 + self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4 - self.a14 * r.a9) - r.a7 * (self.a0 * r.a4 - self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3 - self.a14 * r.a8) + r.a8 * (self.a0 * r.a11 + self.a4 * r.a15 + self.a11 * r.a0 - self.a15 * r.a4) + r.a9 * (self.a0 * r.a12 + self.a3 * r.a15 + self.a12 * r.a0 - self.a15 * r.a3) + r.a10 * (self.a0 * r.a13 + self.a2 * r.a15 + self.a13 * r.a0 - self.a15 * r.a2) + r.a11 * (self.a0 * r.a8 + self.a4 * r.a14 + self.a8 * r.a0 + self.a14 * r.a4) + r.a12 * (self.a0 * r.a9 + self.a3 * r.a14 + self.a9 * r.a0 + self.a14 * r.a3) + r.a13 * (self.a0 * r.a10 + self.a2 * r.a14 + self.a10 * r.a0 + self.a14 * r.a2) - r.a14 * (self.a0 * r.a15 + self.a15 * r.a0) + r.a15 * (self.a0 * r.a14 + self.a14 * r.a0),
-            a2: 2. * self.a0 * r.a0 * r.a2 - 2. * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) - self.a2 * r.a14.powi(2) - 2. * self.a3 * r.a0 * r.a8 + self.a3 * r.a2 * r.a3 - self.a3 * r.a4 * r.a14 + self.a3 * r.a9 * r.a10 + 2. * self.a4 * r.a0 * r.a9 + self.a4 * r.a2 * r.a4 + self.a4 * r.a3 * r.a14 + self.a4 * r.a8 * r.a10 + 2. * self.a8 * r.a0 * r.a3 - self.a8 * r.a9 * r.a14 - 2. * self.a9 * r.a0 * r.a4 + self.a9 * r.a8 * r.a14 - 2. * self.a10 * r.a0 * r.a14 - self.a10 * r.a2 * r.a10 - self.a10 * r.a3 * r.a9 - self.a10 * r.a4 * r.a8 - 2. * self.a14 * r.a0 * r.a10 - 2. * self.a14 * r.a2 * r.a14,
-            a3: 2. * self.a0 * r.a0 * r.a3 - 2. * self.a0 * r.a9 * r.a14 + 2. * self.a2 * r.a0 * r.a8 + self.a2 * r.a2 * r.a3 + self.a2 * r.a4 * r.a14 + self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a8.powi(2) - self.a3 * r.a10.powi(2) - self.a3 * r.a14.powi(2) - 2. * self.a4 * r.a0 * r.a10 - self.a4 * r.a2 * r.a14 + self.a4 * r.a3 * r.a4 + self.a4 * r.a8 * r.a9 - 2. * self.a8 * r.a0 * r.a2 + self.a8 * r.a10 * r.a14 - 2. * self.a9 * r.a0 * r.a14 - self.a9 * r.a2 * r.a10 - self.a9 * r.a3 * r.a9 - self.a9 * r.a4 * r.a8 + 2. * self.a10 * r.a0 * r.a4 - self.a10 * r.a8 * r.a14 - 2. * self.a14 * r.a0 * r.a9 - 2. * self.a14 * r.a3 * r.a14,
-            a4: 2. * self.a0 * r.a0 * r.a4 - 2. * self.a0 * r.a8 * r.a14 - 2. * self.a2 * r.a0 * r.a9 + self.a2 * r.a2 * r.a4 - self.a2 * r.a3 * r.a14 + self.a2 * r.a8 * r.a10 + 2. * self.a3 * r.a0 * r.a10 + self.a3 * r.a2 * r.a14 + self.a3 * r.a3 * r.a4 + self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) + self.a4 * r.a4.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) - self.a4 * r.a14.powi(2) - 2. * self.a8 * r.a0 * r.a14 - self.a8 * r.a2 * r.a10 - self.a8 * r.a3 * r.a9 - self.a8 * r.a4 * r.a8 + 2. * self.a9 * r.a0 * r.a2 - self.a9 * r.a10 * r.a14 - 2. * self.a10 * r.a0 * r.a3 + self.a10 * r.a9 * r.a14 - 2. * self.a14 * r.a0 * r.a8 - 2. * self.a14 * r.a4 * r.a14,
-            a5: 2. * self.a0 * r.a0 * r.a5 - 2. * self.a0 * r.a3 * r.a11 + 2. * self.a0 * r.a4 * r.a12 - 2. * self.a0 * r.a10 * r.a15 + self.a1 * r.a0 * r.a2 - self.a2 * r.a0 * r.a1 - 2. * self.a2 * r.a8 * r.a11 - 2. * self.a2 * r.a9 * r.a12 - 2. * self.a3 * r.a0 * r.a11 + self.a3 * r.a1 * r.a8 - self.a3 * r.a2 * r.a6 + self.a3 * r.a3 * r.a5 + self.a3 * r.a4 * r.a15 - self.a3 * r.a7 * r.a14 + self.a3 * r.a9 * r.a13 + self.a3 * r.a10 * r.a12 + 2. * self.a4 * r.a0 * r.a12 - self.a4 * r.a1 * r.a9 - self.a4 * r.a2 * r.a7 - self.a4 * r.a3 * r.a15 + self.a4 * r.a4 * r.a5 + self.a4 * r.a6 * r.a14 + self.a4 * r.a8 * r.a13 + self.a4 * r.a10 * r.a11 + self.a5 * r.a0.powi(2) + self.a5 * r.a2.powi(2) - self.a6 * r.a0 * r.a8 + self.a6 * r.a2 * r.a3 + self.a7 * r.a0 * r.a9 + self.a7 * r.a2 * r.a4 + self.a8 * r.a0 * r.a6 - self.a8 * r.a1 * r.a3 + 2. * self.a8 * r.a2 * r.a11 - self.a8 * r.a5 * r.a8 - self.a8 * r.a9 * r.a15 - self.a8 * r.a12 * r.a14 - self.a9 * r.a0 * r.a7 + self.a9 * r.a1 * r.a4 + 2. * self.a9 * r.a2 * r.a12 - self.a9 * r.a5 * r.a9 + self.a9 * r.a8 * r.a15 + self.a9 * r.a11 * r.a14 - 2. * self.a10 * r.a0 * r.a15 + self.a10 * r.a1 * r.a14 + self.a10 * r.a2 * r.a13 - self.a10 * r.a3 * r.a12 - self.a10 * r.a4 * r.a11 - self.a10 * r.a5 * r.a10 - 2. * self.a11 * r.a0 * r.a3 + 2. * self.a12 * r.a0 * r.a4 + self.a13 * r.a0 * r.a14 + self.a13 * r.a2 * r.a10 + self.a13 * r.a3 * r.a9 + self.a13 * r.a4 * r.a8 - self.a14 * r.a0 * r.a13 + self.a14 * r.a1 * r.a10 - self.a14 * r.a3 * r.a7 + self.a14 * r.a4 * r.a6 - self.a14 * r.a5 * r.a14 - self.a14 * r.a8 * r.a12 + self.a14 * r.a9 * r.a11 - 2. * self.a15 * r.a0 * r.a10 - 2. * self.a15 * r.a2 * r.a14,
-            a6: 2. * self.a0 * r.a0 * r.a6 + 2. * self.a0 * r.a2 * r.a11 - 2. * self.a0 * r.a4 * r.a13 - 2. * self.a0 * r.a9 * r.a15 + self.a1 * r.a0 * r.a3 + 2. * self.a2 * r.a0 * r.a11 - self.a2 * r.a1 * r.a8 + self.a2 * r.a2 * r.a6 - self.a2 * r.a3 * r.a5 - self.a2 * r.a4 * r.a15 + self.a2 * r.a7 * r.a14 + self.a2 * r.a9 * r.a13 + self.a2 * r.a10 * r.a12 - self.a3 * r.a0 * r.a1 - 2. * self.a3 * r.a8 * r.a11 - 2. * self.a3 * r.a10 * r.a13 - 2. * self.a4 * r.a0 * r.a13 + self.a4 * r.a1 * r.a10 + self.a4 * r.a2 * r.a15 - self.a4 * r.a3 * r.a7 + self.a4 * r.a4 * r.a6 - self.a4 * r.a5 * r.a14 + self.a4 * r.a8 * r.a12 + self.a4 * r.a9 * r.a11 + self.a5 * r.a0 * r.a8 + self.a5 * r.a2 * r.a3 + self.a6 * r.a0.powi(2) + self.a6 * r.a3.powi(2) - self.a7 * r.a0 * r.a10 + self.a7 * r.a3 * r.a4 - self.a8 * r.a0 * r.a5 + self.a8 * r.a1 * r.a2 + 2. * self.a8 * r.a3 * r.a11 - self.a8 * r.a6 * r.a8 + self.a8 * r.a10 * r.a15 + self.a8 * r.a13 * r.a14 - 2. * self.a9 * r.a0 * r.a15 + self.a9 * r.a1 * r.a14 - self.a9 * r.a2 * r.a13 + self.a9 * r.a3 * r.a12 - self.a9 * r.a4 * r.a11 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a1 * r.a4 + 2. * self.a10 * r.a3 * r.a13 - self.a10 * r.a6 * r.a10 - self.a10 * r.a8 * r.a15 - self.a10 * r.a11 * r.a14 + 2. * self.a11 * r.a0 * r.a2 + self.a12 * r.a0 * r.a14 + self.a12 * r.a2 * r.a10 + self.a12 * r.a3 * r.a9 + self.a12 * r.a4 * r.a8 - 2. * self.a13 * r.a0 * r.a4 - self.a14 * r.a0 * r.a12 + self.a14 * r.a1 * r.a9 + self.a14 * r.a2 * r.a7 - self.a14 * r.a4 * r.a5 - self.a14 * r.a6 * r.a14 + self.a14 * r.a8 * r.a13 - self.a14 * r.a10 * r.a11 - 2. * self.a15 * r.a0 * r.a9 - 2. * self.a15 * r.a3 * r.a14,
-            a7: 2. * self.a0 * r.a0 * r.a7 - 2. * self.a0 * r.a2 * r.a12 + 2. * self.a0 * r.a3 * r.a13 - 2. * self.a0 * r.a8 * r.a15 + self.a1 * r.a0 * r.a4 - 2. * self.a2 * r.a0 * r.a12 + self.a2 * r.a1 * r.a9 + self.a2 * r.a2 * r.a7 + self.a2 * r.a3 * r.a15 - self.a2 * r.a4 * r.a5 - self.a2 * r.a6 * r.a14 + self.a2 * r.a8 * r.a13 + self.a2 * r.a10 * r.a11 + 2. * self.a3 * r.a0 * r.a13 - self.a3 * r.a1 * r.a10 - self.a3 * r.a2 * r.a15 + self.a3 * r.a3 * r.a7 - self.a3 * r.a4 * r.a6 + self.a3 * r.a5 * r.a14 + self.a3 * r.a8 * r.a12 + self.a3 * r.a9 * r.a11 - self.a4 * r.a0 * r.a1 - 2. * self.a4 * r.a9 * r.a12 - 2. * self.a4 * r.a10 * r.a13 - self.a5 * r.a0 * r.a9 + self.a5 * r.a2 * r.a4 + self.a6 * r.a0 * r.a10 + self.a6 * r.a3 * r.a4 + self.a7 * r.a0.powi(2) + self.a7 * r.a4.powi(2) - 2. * self.a8 * r.a0 * r.a15 + self.a8 * r.a1 * r.a14 - self.a8 * r.a2 * r.a13 - self.a8 * r.a3 * r.a12 + self.a8 * r.a4 * r.a11 - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a1 * r.a2 + 2. * self.a9 * r.a4 * r.a12 - self.a9 * r.a7 * r.a9 - self.a9 * r.a10 * r.a15 - self.a9 * r.a13 * r.a14 - self.a10 * r.a0 * r.a6 + self.a10 * r.a1 * r.a3 + 2. * self.a10 * r.a4 * r.a13 - self.a10 * r.a7 * r.a10 + self.a10 * r.a9 * r.a15 + self.a10 * r.a12 * r.a14 + self.a11 * r.a0 * r.a14 + self.a11 * r.a2 * r.a10 + self.a11 * r.a3 * r.a9 + self.a11 * r.a4 * r.a8 - 2. * self.a12 * r.a0 * r.a2 + 2. * self.a13 * r.a0 * r.a3 - self.a14 * r.a0 * r.a11 + self.a14 * r.a1 * r.a8 - self.a14 * r.a2 * r.a6 + self.a14 * r.a3 * r.a5 - self.a14 * r.a7 * r.a14 - self.a14 * r.a9 * r.a13 + self.a14 * r.a10 * r.a12 - 2. * self.a15 * r.a0 * r.a8 - 2. * self.a15 * r.a4 * r.a14,
-            a8: 2. * self.a0 * r.a0 * r.a8 + 2. * self.a0 * r.a4 * r.a14 + self.a2 * r.a0 * r.a3 - 2. * self.a2 * r.a9 * r.a14 - self.a3 * r.a0 * r.a2 + 2. * self.a3 * r.a10 * r.a14 + 2. * self.a4 * r.a0 * r.a14 + self.a4 * r.a2 * r.a10 + self.a4 * r.a3 * r.a9 + self.a4 * r.a4 * r.a8 + self.a8 * r.a0.powi(2) + self.a8 * r.a2.powi(2) + self.a8 * r.a3.powi(2) - self.a8 * r.a8.powi(2) - self.a8 * r.a14.powi(2) + self.a9 * r.a0 * r.a10 + 2. * self.a9 * r.a2 * r.a14 - self.a9 * r.a3 * r.a4 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a2 * r.a4 - 2. * self.a10 * r.a3 * r.a14 - self.a10 * r.a8 * r.a10 + 2. * self.a14 * r.a0 * r.a4 - 2. * self.a14 * r.a8 * r.a14,
-            a9: 2. * self.a0 * r.a0 * r.a9 + 2. * self.a0 * r.a3 * r.a14 - self.a2 * r.a0 * r.a4 + 2. * self.a2 * r.a8 * r.a14 + 2. * self.a3 * r.a0 * r.a14 + self.a3 * r.a2 * r.a10 + self.a3 * r.a3 * r.a9 + self.a3 * r.a4 * r.a8 + self.a4 * r.a0 * r.a2 - 2. * self.a4 * r.a10 * r.a14 - self.a8 * r.a0 * r.a10 - 2. * self.a8 * r.a2 * r.a14 - self.a8 * r.a3 * r.a4 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a2.powi(2) + self.a9 * r.a4.powi(2) - self.a9 * r.a9.powi(2) - self.a9 * r.a14.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a2 * r.a3 + 2. * self.a10 * r.a4 * r.a14 - self.a10 * r.a9 * r.a10 + 2. * self.a14 * r.a0 * r.a3 - 2. * self.a14 * r.a9 * r.a14,
-            a10: 2. * self.a0 * r.a0 * r.a10 + 2. * self.a0 * r.a2 * r.a14 + 2. * self.a2 * r.a0 * r.a14 + self.a2 * r.a2 * r.a10 + self.a2 * r.a3 * r.a9 + self.a2 * r.a4 * r.a8 + self.a3 * r.a0 * r.a4 - 2. * self.a3 * r.a8 * r.a14 - self.a4 * r.a0 * r.a3 + 2. * self.a4 * r.a9 * r.a14 + self.a8 * r.a0 * r.a9 - self.a8 * r.a2 * r.a4 + 2. * self.a8 * r.a3 * r.a14 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a2 * r.a3 - 2. * self.a9 * r.a4 * r.a14 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a3.powi(2) + self.a10 * r.a4.powi(2) - self.a10 * r.a10.powi(2) - self.a10 * r.a14.powi(2) + 2. * self.a14 * r.a0 * r.a2 - 2. * self.a14 * r.a10 * r.a14,
-            a11: 2. * self.a0 * r.a0 * r.a11 - 2. * self.a0 * r.a1 * r.a8 + 2. * self.a0 * r.a2 * r.a6 - 2. * self.a0 * r.a3 * r.a5 - self.a1 * r.a0 * r.a8 + self.a2 * r.a0 * r.a6 + 2. * self.a2 * r.a2 * r.a11 - 2. * self.a2 * r.a9 * r.a15 - self.a3 * r.a0 * r.a5 + 2. * self.a3 * r.a3 * r.a11 + 2. * self.a3 * r.a10 * r.a15 + 2. * self.a4 * r.a0 * r.a15 - self.a4 * r.a1 * r.a14 - self.a4 * r.a2 * r.a13 - self.a4 * r.a3 * r.a12 + self.a4 * r.a4 * r.a11 + self.a4 * r.a5 * r.a10 + self.a4 * r.a6 * r.a9 + self.a4 * r.a7 * r.a8 - self.a5 * r.a0 * r.a3 - self.a5 * r.a2 * r.a8 + self.a6 * r.a0 * r.a2 - self.a6 * r.a3 * r.a8 - self.a7 * r.a0 * r.a14 - self.a7 * r.a4 * r.a8 - self.a8 * r.a0 * r.a1 + self.a8 * r.a2 * r.a5 + self.a8 * r.a3 * r.a6 - 2. * self.a8 * r.a8 * r.a11 + self.a9 * r.a0 * r.a13 - self.a9 * r.a4 * r.a6 + self.a9 * r.a5 * r.a14 - self.a9 * r.a8 * r.a12 - self.a9 * r.a9 * r.a11 - self.a10 * r.a0 * r.a12 - self.a10 * r.a4 * r.a5 - self.a10 * r.a6 * r.a14 - self.a10 * r.a8 * r.a13 - self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a8.powi(2) + self.a12 * r.a0 * r.a10 + self.a12 * r.a2 * r.a14 - self.a12 * r.a3 * r.a4 - self.a12 * r.a8 * r.a9 - self.a13 * r.a0 * r.a9 - self.a13 * r.a2 * r.a4 - self.a13 * r.a3 * r.a14 - self.a13 * r.a8 * r.a10 + self.a14 * r.a0 * r.a7 - self.a14 * r.a1 * r.a4 - self.a14 * r.a2 * r.a12 + self.a14 * r.a3 * r.a13 + self.a14 * r.a5 * r.a9 - self.a14 * r.a6 * r.a10 - 2. * self.a14 * r.a8 * r.a15 - self.a14 * r.a11 * r.a14 - 2. * self.a15 * r.a0 * r.a4 + 2. * self.a15 * r.a8 * r.a14,
-            a12: 2. * self.a0 * r.a0 * r.a12 - 2. * self.a0 * r.a1 * r.a9 - 2. * self.a0 * r.a2 * r.a7 + 2. * self.a0 * r.a4 * r.a5 - self.a1 * r.a0 * r.a9 - self.a2 * r.a0 * r.a7 + 2. * self.a2 * r.a2 * r.a12 + 2. * self.a2 * r.a8 * r.a15 + 2. * self.a3 * r.a0 * r.a15 - self.a3 * r.a1 * r.a14 - self.a3 * r.a2 * r.a13 + self.a3 * r.a3 * r.a12 - self.a3 * r.a4 * r.a11 + self.a3 * r.a5 * r.a10 + self.a3 * r.a6 * r.a9 + self.a3 * r.a7 * r.a8 + self.a4 * r.a0 * r.a5 + 2. * self.a4 * r.a4 * r.a12 - 2. * self.a4 * r.a10 * r.a15 + self.a5 * r.a0 * r.a4 - self.a5 * r.a2 * r.a9 - self.a6 * r.a0 * r.a14 - self.a6 * r.a3 * r.a9 - self.a7 * r.a0 * r.a2 - self.a7 * r.a4 * r.a9 - self.a8 * r.a0 * r.a13 - self.a8 * r.a3 * r.a7 - self.a8 * r.a5 * r.a14 - self.a8 * r.a8 * r.a12 - self.a8 * r.a9 * r.a11 - self.a9 * r.a0 * r.a1 + self.a9 * r.a2 * r.a5 + self.a9 * r.a4 * r.a7 - 2. * self.a9 * r.a9 * r.a12 + self.a10 * r.a0 * r.a11 - self.a10 * r.a3 * r.a5 + self.a10 * r.a7 * r.a14 - self.a10 * r.a9 * r.a13 - self.a10 * r.a10 * r.a12 - self.a11 * r.a0 * r.a10 - self.a11 * r.a2 * r.a14 - self.a11 * r.a3 * r.a4 - self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) + self.a12 * r.a4.powi(2) - self.a12 * r.a9.powi(2) + self.a13 * r.a0 * r.a8 - self.a13 * r.a2 * r.a3 + self.a13 * r.a4 * r.a14 - self.a13 * r.a9 * r.a10 + self.a14 * r.a0 * r.a6 - self.a14 * r.a1 * r.a3 + self.a14 * r.a2 * r.a11 - self.a14 * r.a4 * r.a13 - self.a14 * r.a5 * r.a8 + self.a14 * r.a7 * r.a10 - 2. * self.a14 * r.a9 * r.a15 - self.a14 * r.a12 * r.a14 - 2. * self.a15 * r.a0 * r.a3 + 2. * self.a15 * r.a9 * r.a14,
-            a13: 2. * self.a0 * r.a0 * r.a13 - 2. * self.a0 * r.a1 * r.a10 + 2. * self.a0 * r.a3 * r.a7 - 2. * self.a0 * r.a4 * r.a6 - self.a1 * r.a0 * r.a10 + 2. * self.a2 * r.a0 * r.a15 - self.a2 * r.a1 * r.a14 + self.a2 * r.a2 * r.a13 - self.a2 * r.a3 * r.a12 - self.a2 * r.a4 * r.a11 + self.a2 * r.a5 * r.a10 + self.a2 * r.a6 * r.a9 + self.a2 * r.a7 * r.a8 + self.a3 * r.a0 * r.a7 + 2. * self.a3 * r.a3 * r.a13 - 2. * self.a3 * r.a8 * r.a15 - self.a4 * r.a0 * r.a6 + 2. * self.a4 * r.a4 * r.a13 + 2. * self.a4 * r.a9 * r.a15 - self.a5 * r.a0 * r.a14 - self.a5 * r.a2 * r.a10 - self.a6 * r.a0 * r.a4 - self.a6 * r.a3 * r.a10 + self.a7 * r.a0 * r.a3 - self.a7 * r.a4 * r.a10 + self.a8 * r.a0 * r.a12 - self.a8 * r.a2 * r.a7 + self.a8 * r.a6 * r.a14 - self.a8 * r.a8 * r.a13 - self.a8 * r.a10 * r.a11 - self.a9 * r.a0 * r.a11 - self.a9 * r.a2 * r.a6 - self.a9 * r.a7 * r.a14 - self.a9 * r.a9 * r.a13 - self.a9 * r.a10 * r.a12 - self.a10 * r.a0 * r.a1 + self.a10 * r.a3 * r.a6 + self.a10 * r.a4 * r.a7 - 2. * self.a10 * r.a10 * r.a13 + self.a11 * r.a0 * r.a9 - self.a11 * r.a2 * r.a4 + self.a11 * r.a3 * r.a14 - self.a11 * r.a8 * r.a10 - self.a12 * r.a0 * r.a8 - self.a12 * r.a2 * r.a3 - self.a12 * r.a4 * r.a14 - self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - self.a13 * r.a10.powi(2) + self.a14 * r.a0 * r.a5 - self.a14 * r.a1 * r.a2 - self.a14 * r.a3 * r.a11 + self.a14 * r.a4 * r.a12 + self.a14 * r.a6 * r.a8 - self.a14 * r.a7 * r.a9 - 2. * self.a14 * r.a10 * r.a15 - self.a14 * r.a13 * r.a14 - 2. * self.a15 * r.a0 * r.a2 + 2. * self.a15 * r.a10 * r.a14,
+            a2: T::from_f64(2.) * self.a0 * r.a0 * r.a2 - T::from_f64(2.) * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) - self.a2 * r.a14.powi(2) - T::from_f64(2.) * self.a3 * r.a0 * r.a8 + self.a3 * r.a2 * r.a3 - self.a3 * r.a4 * r.a14 + self.a3 * r.a9 * r.a10 + T::from_f64(2.) * self.a4 * r.a0 * r.a9 + self.a4 * r.a2 * r.a4 + self.a4 * r.a3 * r.a14 + self.a4 * r.a8 * r.a10 + T::from_f64(2.) * self.a8 * r.a0 * r.a3 - self.a8 * r.a9 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a4 + self.a9 * r.a8 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a14 - self.a10 * r.a2 * r.a10 - self.a10 * r.a3 * r.a9 - self.a10 * r.a4 * r.a8 - T::from_f64(2.) * self.a14 * r.a0 * r.a10 - T::from_f64(2.) * self.a14 * r.a2 * r.a14,
+            a3: T::from_f64(2.) * self.a0 * r.a0 * r.a3 - T::from_f64(2.) * self.a0 * r.a9 * r.a14 + T::from_f64(2.) * self.a2 * r.a0 * r.a8 + self.a2 * r.a2 * r.a3 + self.a2 * r.a4 * r.a14 + self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a8.powi(2) - self.a3 * r.a10.powi(2) - self.a3 * r.a14.powi(2) - T::from_f64(2.) * self.a4 * r.a0 * r.a10 - self.a4 * r.a2 * r.a14 + self.a4 * r.a3 * r.a4 + self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a0 * r.a2 + self.a8 * r.a10 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a14 - self.a9 * r.a2 * r.a10 - self.a9 * r.a3 * r.a9 - self.a9 * r.a4 * r.a8 + T::from_f64(2.) * self.a10 * r.a0 * r.a4 - self.a10 * r.a8 * r.a14 - T::from_f64(2.) * self.a14 * r.a0 * r.a9 - T::from_f64(2.) * self.a14 * r.a3 * r.a14,
+            a4: T::from_f64(2.) * self.a0 * r.a0 * r.a4 - T::from_f64(2.) * self.a0 * r.a8 * r.a14 - T::from_f64(2.) * self.a2 * r.a0 * r.a9 + self.a2 * r.a2 * r.a4 - self.a2 * r.a3 * r.a14 + self.a2 * r.a8 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a10 + self.a3 * r.a2 * r.a14 + self.a3 * r.a3 * r.a4 + self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) + self.a4 * r.a4.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) - self.a4 * r.a14.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a14 - self.a8 * r.a2 * r.a10 - self.a8 * r.a3 * r.a9 - self.a8 * r.a4 * r.a8 + T::from_f64(2.) * self.a9 * r.a0 * r.a2 - self.a9 * r.a10 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a3 + self.a10 * r.a9 * r.a14 - T::from_f64(2.) * self.a14 * r.a0 * r.a8 - T::from_f64(2.) * self.a14 * r.a4 * r.a14,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a3 * r.a11 + T::from_f64(2.) * self.a0 * r.a4 * r.a12 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + self.a1 * r.a0 * r.a2 - self.a2 * r.a0 * r.a1 - T::from_f64(2.) * self.a2 * r.a8 * r.a11 - T::from_f64(2.) * self.a2 * r.a9 * r.a12 - T::from_f64(2.) * self.a3 * r.a0 * r.a11 + self.a3 * r.a1 * r.a8 - self.a3 * r.a2 * r.a6 + self.a3 * r.a3 * r.a5 + self.a3 * r.a4 * r.a15 - self.a3 * r.a7 * r.a14 + self.a3 * r.a9 * r.a13 + self.a3 * r.a10 * r.a12 + T::from_f64(2.) * self.a4 * r.a0 * r.a12 - self.a4 * r.a1 * r.a9 - self.a4 * r.a2 * r.a7 - self.a4 * r.a3 * r.a15 + self.a4 * r.a4 * r.a5 + self.a4 * r.a6 * r.a14 + self.a4 * r.a8 * r.a13 + self.a4 * r.a10 * r.a11 + self.a5 * r.a0.powi(2) + self.a5 * r.a2.powi(2) - self.a6 * r.a0 * r.a8 + self.a6 * r.a2 * r.a3 + self.a7 * r.a0 * r.a9 + self.a7 * r.a2 * r.a4 + self.a8 * r.a0 * r.a6 - self.a8 * r.a1 * r.a3 + T::from_f64(2.) * self.a8 * r.a2 * r.a11 - self.a8 * r.a5 * r.a8 - self.a8 * r.a9 * r.a15 - self.a8 * r.a12 * r.a14 - self.a9 * r.a0 * r.a7 + self.a9 * r.a1 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a12 - self.a9 * r.a5 * r.a9 + self.a9 * r.a8 * r.a15 + self.a9 * r.a11 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 + self.a10 * r.a1 * r.a14 + self.a10 * r.a2 * r.a13 - self.a10 * r.a3 * r.a12 - self.a10 * r.a4 * r.a11 - self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a11 * r.a0 * r.a3 + T::from_f64(2.) * self.a12 * r.a0 * r.a4 + self.a13 * r.a0 * r.a14 + self.a13 * r.a2 * r.a10 + self.a13 * r.a3 * r.a9 + self.a13 * r.a4 * r.a8 - self.a14 * r.a0 * r.a13 + self.a14 * r.a1 * r.a10 - self.a14 * r.a3 * r.a7 + self.a14 * r.a4 * r.a6 - self.a14 * r.a5 * r.a14 - self.a14 * r.a8 * r.a12 + self.a14 * r.a9 * r.a11 - T::from_f64(2.) * self.a15 * r.a0 * r.a10 - T::from_f64(2.) * self.a15 * r.a2 * r.a14,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 + T::from_f64(2.) * self.a0 * r.a2 * r.a11 - T::from_f64(2.) * self.a0 * r.a4 * r.a13 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 + self.a1 * r.a0 * r.a3 + T::from_f64(2.) * self.a2 * r.a0 * r.a11 - self.a2 * r.a1 * r.a8 + self.a2 * r.a2 * r.a6 - self.a2 * r.a3 * r.a5 - self.a2 * r.a4 * r.a15 + self.a2 * r.a7 * r.a14 + self.a2 * r.a9 * r.a13 + self.a2 * r.a10 * r.a12 - self.a3 * r.a0 * r.a1 - T::from_f64(2.) * self.a3 * r.a8 * r.a11 - T::from_f64(2.) * self.a3 * r.a10 * r.a13 - T::from_f64(2.) * self.a4 * r.a0 * r.a13 + self.a4 * r.a1 * r.a10 + self.a4 * r.a2 * r.a15 - self.a4 * r.a3 * r.a7 + self.a4 * r.a4 * r.a6 - self.a4 * r.a5 * r.a14 + self.a4 * r.a8 * r.a12 + self.a4 * r.a9 * r.a11 + self.a5 * r.a0 * r.a8 + self.a5 * r.a2 * r.a3 + self.a6 * r.a0.powi(2) + self.a6 * r.a3.powi(2) - self.a7 * r.a0 * r.a10 + self.a7 * r.a3 * r.a4 - self.a8 * r.a0 * r.a5 + self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a8 * r.a3 * r.a11 - self.a8 * r.a6 * r.a8 + self.a8 * r.a10 * r.a15 + self.a8 * r.a13 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 + self.a9 * r.a1 * r.a14 - self.a9 * r.a2 * r.a13 + self.a9 * r.a3 * r.a12 - self.a9 * r.a4 * r.a11 - self.a9 * r.a6 * r.a9 + self.a10 * r.a0 * r.a7 - self.a10 * r.a1 * r.a4 + T::from_f64(2.) * self.a10 * r.a3 * r.a13 - self.a10 * r.a6 * r.a10 - self.a10 * r.a8 * r.a15 - self.a10 * r.a11 * r.a14 + T::from_f64(2.) * self.a11 * r.a0 * r.a2 + self.a12 * r.a0 * r.a14 + self.a12 * r.a2 * r.a10 + self.a12 * r.a3 * r.a9 + self.a12 * r.a4 * r.a8 - T::from_f64(2.) * self.a13 * r.a0 * r.a4 - self.a14 * r.a0 * r.a12 + self.a14 * r.a1 * r.a9 + self.a14 * r.a2 * r.a7 - self.a14 * r.a4 * r.a5 - self.a14 * r.a6 * r.a14 + self.a14 * r.a8 * r.a13 - self.a14 * r.a10 * r.a11 - T::from_f64(2.) * self.a15 * r.a0 * r.a9 - T::from_f64(2.) * self.a15 * r.a3 * r.a14,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a2 * r.a12 + T::from_f64(2.) * self.a0 * r.a3 * r.a13 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 + self.a1 * r.a0 * r.a4 - T::from_f64(2.) * self.a2 * r.a0 * r.a12 + self.a2 * r.a1 * r.a9 + self.a2 * r.a2 * r.a7 + self.a2 * r.a3 * r.a15 - self.a2 * r.a4 * r.a5 - self.a2 * r.a6 * r.a14 + self.a2 * r.a8 * r.a13 + self.a2 * r.a10 * r.a11 + T::from_f64(2.) * self.a3 * r.a0 * r.a13 - self.a3 * r.a1 * r.a10 - self.a3 * r.a2 * r.a15 + self.a3 * r.a3 * r.a7 - self.a3 * r.a4 * r.a6 + self.a3 * r.a5 * r.a14 + self.a3 * r.a8 * r.a12 + self.a3 * r.a9 * r.a11 - self.a4 * r.a0 * r.a1 - T::from_f64(2.) * self.a4 * r.a9 * r.a12 - T::from_f64(2.) * self.a4 * r.a10 * r.a13 - self.a5 * r.a0 * r.a9 + self.a5 * r.a2 * r.a4 + self.a6 * r.a0 * r.a10 + self.a6 * r.a3 * r.a4 + self.a7 * r.a0.powi(2) + self.a7 * r.a4.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 + self.a8 * r.a1 * r.a14 - self.a8 * r.a2 * r.a13 - self.a8 * r.a3 * r.a12 + self.a8 * r.a4 * r.a11 - self.a8 * r.a7 * r.a8 + self.a9 * r.a0 * r.a5 - self.a9 * r.a1 * r.a2 + T::from_f64(2.) * self.a9 * r.a4 * r.a12 - self.a9 * r.a7 * r.a9 - self.a9 * r.a10 * r.a15 - self.a9 * r.a13 * r.a14 - self.a10 * r.a0 * r.a6 + self.a10 * r.a1 * r.a3 + T::from_f64(2.) * self.a10 * r.a4 * r.a13 - self.a10 * r.a7 * r.a10 + self.a10 * r.a9 * r.a15 + self.a10 * r.a12 * r.a14 + self.a11 * r.a0 * r.a14 + self.a11 * r.a2 * r.a10 + self.a11 * r.a3 * r.a9 + self.a11 * r.a4 * r.a8 - T::from_f64(2.) * self.a12 * r.a0 * r.a2 + T::from_f64(2.) * self.a13 * r.a0 * r.a3 - self.a14 * r.a0 * r.a11 + self.a14 * r.a1 * r.a8 - self.a14 * r.a2 * r.a6 + self.a14 * r.a3 * r.a5 - self.a14 * r.a7 * r.a14 - self.a14 * r.a9 * r.a13 + self.a14 * r.a10 * r.a12 - T::from_f64(2.) * self.a15 * r.a0 * r.a8 - T::from_f64(2.) * self.a15 * r.a4 * r.a14,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + T::from_f64(2.) * self.a0 * r.a4 * r.a14 + self.a2 * r.a0 * r.a3 - T::from_f64(2.) * self.a2 * r.a9 * r.a14 - self.a3 * r.a0 * r.a2 + T::from_f64(2.) * self.a3 * r.a10 * r.a14 + T::from_f64(2.) * self.a4 * r.a0 * r.a14 + self.a4 * r.a2 * r.a10 + self.a4 * r.a3 * r.a9 + self.a4 * r.a4 * r.a8 + self.a8 * r.a0.powi(2) + self.a8 * r.a2.powi(2) + self.a8 * r.a3.powi(2) - self.a8 * r.a8.powi(2) - self.a8 * r.a14.powi(2) + self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a2 * r.a14 - self.a9 * r.a3 * r.a4 - self.a9 * r.a8 * r.a9 - self.a10 * r.a0 * r.a9 - self.a10 * r.a2 * r.a4 - T::from_f64(2.) * self.a10 * r.a3 * r.a14 - self.a10 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a4 - T::from_f64(2.) * self.a14 * r.a8 * r.a14,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 + T::from_f64(2.) * self.a0 * r.a3 * r.a14 - self.a2 * r.a0 * r.a4 + T::from_f64(2.) * self.a2 * r.a8 * r.a14 + T::from_f64(2.) * self.a3 * r.a0 * r.a14 + self.a3 * r.a2 * r.a10 + self.a3 * r.a3 * r.a9 + self.a3 * r.a4 * r.a8 + self.a4 * r.a0 * r.a2 - T::from_f64(2.) * self.a4 * r.a10 * r.a14 - self.a8 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a2 * r.a14 - self.a8 * r.a3 * r.a4 - self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a2.powi(2) + self.a9 * r.a4.powi(2) - self.a9 * r.a9.powi(2) - self.a9 * r.a14.powi(2) + self.a10 * r.a0 * r.a8 - self.a10 * r.a2 * r.a3 + T::from_f64(2.) * self.a10 * r.a4 * r.a14 - self.a10 * r.a9 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a3 - T::from_f64(2.) * self.a14 * r.a9 * r.a14,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + T::from_f64(2.) * self.a0 * r.a2 * r.a14 + T::from_f64(2.) * self.a2 * r.a0 * r.a14 + self.a2 * r.a2 * r.a10 + self.a2 * r.a3 * r.a9 + self.a2 * r.a4 * r.a8 + self.a3 * r.a0 * r.a4 - T::from_f64(2.) * self.a3 * r.a8 * r.a14 - self.a4 * r.a0 * r.a3 + T::from_f64(2.) * self.a4 * r.a9 * r.a14 + self.a8 * r.a0 * r.a9 - self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a3 * r.a14 - self.a8 * r.a8 * r.a10 - self.a9 * r.a0 * r.a8 - self.a9 * r.a2 * r.a3 - T::from_f64(2.) * self.a9 * r.a4 * r.a14 - self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a3.powi(2) + self.a10 * r.a4.powi(2) - self.a10 * r.a10.powi(2) - self.a10 * r.a14.powi(2) + T::from_f64(2.) * self.a14 * r.a0 * r.a2 - T::from_f64(2.) * self.a14 * r.a10 * r.a14,
+            a11: T::from_f64(2.) * self.a0 * r.a0 * r.a11 - T::from_f64(2.) * self.a0 * r.a1 * r.a8 + T::from_f64(2.) * self.a0 * r.a2 * r.a6 - T::from_f64(2.) * self.a0 * r.a3 * r.a5 - self.a1 * r.a0 * r.a8 + self.a2 * r.a0 * r.a6 + T::from_f64(2.) * self.a2 * r.a2 * r.a11 - T::from_f64(2.) * self.a2 * r.a9 * r.a15 - self.a3 * r.a0 * r.a5 + T::from_f64(2.) * self.a3 * r.a3 * r.a11 + T::from_f64(2.) * self.a3 * r.a10 * r.a15 + T::from_f64(2.) * self.a4 * r.a0 * r.a15 - self.a4 * r.a1 * r.a14 - self.a4 * r.a2 * r.a13 - self.a4 * r.a3 * r.a12 + self.a4 * r.a4 * r.a11 + self.a4 * r.a5 * r.a10 + self.a4 * r.a6 * r.a9 + self.a4 * r.a7 * r.a8 - self.a5 * r.a0 * r.a3 - self.a5 * r.a2 * r.a8 + self.a6 * r.a0 * r.a2 - self.a6 * r.a3 * r.a8 - self.a7 * r.a0 * r.a14 - self.a7 * r.a4 * r.a8 - self.a8 * r.a0 * r.a1 + self.a8 * r.a2 * r.a5 + self.a8 * r.a3 * r.a6 - T::from_f64(2.) * self.a8 * r.a8 * r.a11 + self.a9 * r.a0 * r.a13 - self.a9 * r.a4 * r.a6 + self.a9 * r.a5 * r.a14 - self.a9 * r.a8 * r.a12 - self.a9 * r.a9 * r.a11 - self.a10 * r.a0 * r.a12 - self.a10 * r.a4 * r.a5 - self.a10 * r.a6 * r.a14 - self.a10 * r.a8 * r.a13 - self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a8.powi(2) + self.a12 * r.a0 * r.a10 + self.a12 * r.a2 * r.a14 - self.a12 * r.a3 * r.a4 - self.a12 * r.a8 * r.a9 - self.a13 * r.a0 * r.a9 - self.a13 * r.a2 * r.a4 - self.a13 * r.a3 * r.a14 - self.a13 * r.a8 * r.a10 + self.a14 * r.a0 * r.a7 - self.a14 * r.a1 * r.a4 - self.a14 * r.a2 * r.a12 + self.a14 * r.a3 * r.a13 + self.a14 * r.a5 * r.a9 - self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a8 * r.a15 - self.a14 * r.a11 * r.a14 - T::from_f64(2.) * self.a15 * r.a0 * r.a4 + T::from_f64(2.) * self.a15 * r.a8 * r.a14,
+            a12: T::from_f64(2.) * self.a0 * r.a0 * r.a12 - T::from_f64(2.) * self.a0 * r.a1 * r.a9 - T::from_f64(2.) * self.a0 * r.a2 * r.a7 + T::from_f64(2.) * self.a0 * r.a4 * r.a5 - self.a1 * r.a0 * r.a9 - self.a2 * r.a0 * r.a7 + T::from_f64(2.) * self.a2 * r.a2 * r.a12 + T::from_f64(2.) * self.a2 * r.a8 * r.a15 + T::from_f64(2.) * self.a3 * r.a0 * r.a15 - self.a3 * r.a1 * r.a14 - self.a3 * r.a2 * r.a13 + self.a3 * r.a3 * r.a12 - self.a3 * r.a4 * r.a11 + self.a3 * r.a5 * r.a10 + self.a3 * r.a6 * r.a9 + self.a3 * r.a7 * r.a8 + self.a4 * r.a0 * r.a5 + T::from_f64(2.) * self.a4 * r.a4 * r.a12 - T::from_f64(2.) * self.a4 * r.a10 * r.a15 + self.a5 * r.a0 * r.a4 - self.a5 * r.a2 * r.a9 - self.a6 * r.a0 * r.a14 - self.a6 * r.a3 * r.a9 - self.a7 * r.a0 * r.a2 - self.a7 * r.a4 * r.a9 - self.a8 * r.a0 * r.a13 - self.a8 * r.a3 * r.a7 - self.a8 * r.a5 * r.a14 - self.a8 * r.a8 * r.a12 - self.a8 * r.a9 * r.a11 - self.a9 * r.a0 * r.a1 + self.a9 * r.a2 * r.a5 + self.a9 * r.a4 * r.a7 - T::from_f64(2.) * self.a9 * r.a9 * r.a12 + self.a10 * r.a0 * r.a11 - self.a10 * r.a3 * r.a5 + self.a10 * r.a7 * r.a14 - self.a10 * r.a9 * r.a13 - self.a10 * r.a10 * r.a12 - self.a11 * r.a0 * r.a10 - self.a11 * r.a2 * r.a14 - self.a11 * r.a3 * r.a4 - self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) + self.a12 * r.a4.powi(2) - self.a12 * r.a9.powi(2) + self.a13 * r.a0 * r.a8 - self.a13 * r.a2 * r.a3 + self.a13 * r.a4 * r.a14 - self.a13 * r.a9 * r.a10 + self.a14 * r.a0 * r.a6 - self.a14 * r.a1 * r.a3 + self.a14 * r.a2 * r.a11 - self.a14 * r.a4 * r.a13 - self.a14 * r.a5 * r.a8 + self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a9 * r.a15 - self.a14 * r.a12 * r.a14 - T::from_f64(2.) * self.a15 * r.a0 * r.a3 + T::from_f64(2.) * self.a15 * r.a9 * r.a14,
+            a13: T::from_f64(2.) * self.a0 * r.a0 * r.a13 - T::from_f64(2.) * self.a0 * r.a1 * r.a10 + T::from_f64(2.) * self.a0 * r.a3 * r.a7 - T::from_f64(2.) * self.a0 * r.a4 * r.a6 - self.a1 * r.a0 * r.a10 + T::from_f64(2.) * self.a2 * r.a0 * r.a15 - self.a2 * r.a1 * r.a14 + self.a2 * r.a2 * r.a13 - self.a2 * r.a3 * r.a12 - self.a2 * r.a4 * r.a11 + self.a2 * r.a5 * r.a10 + self.a2 * r.a6 * r.a9 + self.a2 * r.a7 * r.a8 + self.a3 * r.a0 * r.a7 + T::from_f64(2.) * self.a3 * r.a3 * r.a13 - T::from_f64(2.) * self.a3 * r.a8 * r.a15 - self.a4 * r.a0 * r.a6 + T::from_f64(2.) * self.a4 * r.a4 * r.a13 + T::from_f64(2.) * self.a4 * r.a9 * r.a15 - self.a5 * r.a0 * r.a14 - self.a5 * r.a2 * r.a10 - self.a6 * r.a0 * r.a4 - self.a6 * r.a3 * r.a10 + self.a7 * r.a0 * r.a3 - self.a7 * r.a4 * r.a10 + self.a8 * r.a0 * r.a12 - self.a8 * r.a2 * r.a7 + self.a8 * r.a6 * r.a14 - self.a8 * r.a8 * r.a13 - self.a8 * r.a10 * r.a11 - self.a9 * r.a0 * r.a11 - self.a9 * r.a2 * r.a6 - self.a9 * r.a7 * r.a14 - self.a9 * r.a9 * r.a13 - self.a9 * r.a10 * r.a12 - self.a10 * r.a0 * r.a1 + self.a10 * r.a3 * r.a6 + self.a10 * r.a4 * r.a7 - T::from_f64(2.) * self.a10 * r.a10 * r.a13 + self.a11 * r.a0 * r.a9 - self.a11 * r.a2 * r.a4 + self.a11 * r.a3 * r.a14 - self.a11 * r.a8 * r.a10 - self.a12 * r.a0 * r.a8 - self.a12 * r.a2 * r.a3 - self.a12 * r.a4 * r.a14 - self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - self.a13 * r.a10.powi(2) + self.a14 * r.a0 * r.a5 - self.a14 * r.a1 * r.a2 - self.a14 * r.a3 * r.a11 + self.a14 * r.a4 * r.a12 + self.a14 * r.a6 * r.a8 - self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a10 * r.a15 - self.a14 * r.a13 * r.a14 - T::from_f64(2.) * self.a15 * r.a0 * r.a2 + T::from_f64(2.) * self.a15 * r.a10 * r.a14,
             a14: r.a0 * (self.a0 * r.a14 + self.a14 * r.a0) + r.a2 * (self.a0 * r.a10 + self.a2 * r.a14 + self.a10 * r.a0 + self.a14 * r.a2) + r.a3 * (self.a0 * r.a9 + self.a3 * r.a14 + self.a9 * r.a0 + self.a14 * r.a3) + r.a4 * (self.a0 * r.a8 + self.a4 * r.a14 + self.a8 * r.a0 + self.a14 * r.a4) + r.a8 * (self.a0 * r.a4 - self.a2 * r.a9 + self.a3 * r.a10 + self.a4 * r.a0 - self.a8 * r.a14 + self.a9 * r.a2 - self.a10 * r.a3 - self.a14 * r.a8) + r.a9 * (self.a0 * r.a3 + self.a2 * r.a8 + self.a3 * r.a0 - self.a4 * r.a10 - self.a8 * r.a2 - self.a9 * r.a14 + self.a10 * r.a4 - self.a14 * r.a9) + r.a10 * (self.a0 * r.a2 + self.a2 * r.a0 - self.a3 * r.a8 + self.a4 * r.a9 + self.a8 * r.a3 - self.a9 * r.a4 - self.a10 * r.a14 - self.a14 * r.a10) + r.a14 * (self.a0 * r.a0 + self.a2 * r.a2 + self.a3 * r.a3 + self.a4 * r.a4 - self.a8 * r.a8 - self.a9 * r.a9 - self.a10 * r.a10 - self.a14 * r.a14),
-            a15: 2. * self.a0 * r.a0 * r.a15 + 2. * self.a0 * r.a5 * r.a10 + 2. * self.a0 * r.a6 * r.a9 + 2. * self.a0 * r.a7 * r.a8 + self.a1 * r.a0 * r.a14 + self.a2 * r.a0 * r.a13 + self.a3 * r.a0 * r.a12 + self.a4 * r.a0 * r.a11 + self.a5 * r.a0 * r.a10 + self.a5 * r.a2 * r.a14 + self.a6 * r.a0 * r.a9 + self.a6 * r.a3 * r.a14 + self.a7 * r.a0 * r.a8 + self.a7 * r.a4 * r.a14 + self.a8 * r.a0 * r.a7 - self.a8 * r.a2 * r.a12 + self.a8 * r.a3 * r.a13 - 2. * self.a8 * r.a8 * r.a15 + self.a9 * r.a0 * r.a6 + self.a9 * r.a2 * r.a11 - self.a9 * r.a4 * r.a13 - 2. * self.a9 * r.a9 * r.a15 + self.a10 * r.a0 * r.a5 - self.a10 * r.a3 * r.a11 + self.a10 * r.a4 * r.a12 - 2. * self.a10 * r.a10 * r.a15 - self.a11 * r.a0 * r.a4 + self.a11 * r.a2 * r.a9 - self.a11 * r.a3 * r.a10 + self.a11 * r.a8 * r.a14 - self.a12 * r.a0 * r.a3 - self.a12 * r.a2 * r.a8 + self.a12 * r.a4 * r.a10 + self.a12 * r.a9 * r.a14 - self.a13 * r.a0 * r.a2 + self.a13 * r.a3 * r.a8 - self.a13 * r.a4 * r.a9 + self.a13 * r.a10 * r.a14 - self.a14 * r.a0 * r.a1 + self.a14 * r.a2 * r.a5 + self.a14 * r.a3 * r.a6 + self.a14 * r.a4 * r.a7 - self.a14 * r.a8 * r.a11 - self.a14 * r.a9 * r.a12 - self.a14 * r.a10 * r.a13 + self.a15 * r.a0.powi(2) + self.a15 * r.a2.powi(2) + self.a15 * r.a3.powi(2) + self.a15 * r.a4.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2) - self.a15 * r.a14.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 + T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a1 * r.a0 * r.a14 + self.a2 * r.a0 * r.a13 + self.a3 * r.a0 * r.a12 + self.a4 * r.a0 * r.a11 + self.a5 * r.a0 * r.a10 + self.a5 * r.a2 * r.a14 + self.a6 * r.a0 * r.a9 + self.a6 * r.a3 * r.a14 + self.a7 * r.a0 * r.a8 + self.a7 * r.a4 * r.a14 + self.a8 * r.a0 * r.a7 - self.a8 * r.a2 * r.a12 + self.a8 * r.a3 * r.a13 - T::from_f64(2.) * self.a8 * r.a8 * r.a15 + self.a9 * r.a0 * r.a6 + self.a9 * r.a2 * r.a11 - self.a9 * r.a4 * r.a13 - T::from_f64(2.) * self.a9 * r.a9 * r.a15 + self.a10 * r.a0 * r.a5 - self.a10 * r.a3 * r.a11 + self.a10 * r.a4 * r.a12 - T::from_f64(2.) * self.a10 * r.a10 * r.a15 - self.a11 * r.a0 * r.a4 + self.a11 * r.a2 * r.a9 - self.a11 * r.a3 * r.a10 + self.a11 * r.a8 * r.a14 - self.a12 * r.a0 * r.a3 - self.a12 * r.a2 * r.a8 + self.a12 * r.a4 * r.a10 + self.a12 * r.a9 * r.a14 - self.a13 * r.a0 * r.a2 + self.a13 * r.a3 * r.a8 - self.a13 * r.a4 * r.a9 + self.a13 * r.a10 * r.a14 - self.a14 * r.a0 * r.a1 + self.a14 * r.a2 * r.a5 + self.a14 * r.a3 * r.a6 + self.a14 * r.a4 * r.a7 - self.a14 * r.a8 * r.a11 - self.a14 * r.a9 * r.a12 - self.a14 * r.a10 * r.a13 + self.a15 * r.a0.powi(2) + self.a15 * r.a2.powi(2) + self.a15 * r.a3.powi(2) + self.a15 * r.a4.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2) - self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Float> for FullMultivector {
-    fn reflect(self, r: Float) -> FullMultivector {
+impl<T: Scalar> Reflect<T> for FullMultivector<T> {
+    fn reflect(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.powi(2),
             a1: self.a1 * r.powi(2),
@@ -7347,146 +9660,169 @@ impl Reflect<Float> for FullMultivector {
     }
 }
 
-impl Reflect<Vector> for FullMultivector {
-    fn reflect(self, r: Vector) -> FullMultivector {
+impl<T: Scalar> Reflect<Vector<T>> for FullMultivector<T> {
+    fn reflect(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
-            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a3 * r.a1 * r.a3 + 2. * self.a4 * r.a1 * r.a4,
-            a2: self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + 2. * self.a3 * r.a2 * r.a3 + 2. * self.a4 * r.a2 * r.a4,
-            a3: 2. * self.a2 * r.a2 * r.a3 - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + 2. * self.a4 * r.a3 * r.a4,
-            a4: 2. * self.a2 * r.a2 * r.a4 + 2. * self.a3 * r.a3 * r.a4 - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2),
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + 2. * self.a9 * r.a3 * r.a4 + 2. * self.a10 * r.a2 * r.a4,
-            a9: 2. * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + 2. * self.a10 * r.a2 * r.a3,
-            a10: 2. * self.a8 * r.a2 * r.a4 + 2. * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
-            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - 2. * self.a12 * r.a3 * r.a4 - 2. * self.a13 * r.a2 * r.a4 - 2. * self.a14 * r.a1 * r.a4,
-            a12: -2. * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a14 * r.a1 * r.a3,
-            a13: -2. * self.a11 * r.a2 * r.a4 - 2. * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - 2. * self.a14 * r.a1 * r.a2,
+            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 + T::from_f64(2.) * self.a4 * r.a1 * r.a4,
+            a2: self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + T::from_f64(2.) * self.a3 * r.a2 * r.a3 + T::from_f64(2.) * self.a4 * r.a2 * r.a4,
+            a3: T::from_f64(2.) * self.a2 * r.a2 * r.a3 - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + T::from_f64(2.) * self.a4 * r.a3 * r.a4,
+            a4: T::from_f64(2.) * self.a2 * r.a2 * r.a4 + T::from_f64(2.) * self.a3 * r.a3 * r.a4 - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - T::from_f64(2.) * self.a12 * r.a3 * r.a4 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 - T::from_f64(2.) * self.a14 * r.a1 * r.a4,
+            a12: -T::from_f64(2.) * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a14 * r.a1 * r.a3,
+            a13: -T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - T::from_f64(2.) * self.a14 * r.a1 * r.a2,
             a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
             a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Reflect<Bivector> for FullMultivector {
-    fn reflect(self, r: Bivector) -> FullMultivector {
+impl<T: Scalar> Reflect<Bivector<T>> for FullMultivector<T> {
+    fn reflect(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
-            a1: -self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + 2. * self.a2 * r.a6 * r.a8 - 2. * self.a2 * r.a7 * r.a9 - 2. * self.a3 * r.a5 * r.a8 + 2. * self.a3 * r.a7 * r.a10 + 2. * self.a4 * r.a5 * r.a9 - 2. * self.a4 * r.a6 * r.a10,
-            a2: self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - 2. * self.a3 * r.a9 * r.a10 - 2. * self.a4 * r.a8 * r.a10,
-            a3: -2. * self.a2 * r.a9 * r.a10 + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - 2. * self.a4 * r.a8 * r.a9,
-            a4: -2. * self.a2 * r.a8 * r.a10 - 2. * self.a3 * r.a8 * r.a9 - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2),
-            a5: self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a5 * r.a8 - 2. * self.a8 * r.a7 * r.a10 - 2. * self.a9 * r.a5 * r.a9 - 2. * self.a9 * r.a6 * r.a10 - 2. * self.a10 * r.a5 * r.a10 + 2. * self.a10 * r.a6 * r.a9 + 2. * self.a10 * r.a7 * r.a8,
-            a6: -2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - 2. * self.a7 * r.a8 * r.a9 - 2. * self.a8 * r.a6 * r.a8 - 2. * self.a8 * r.a7 * r.a9 + 2. * self.a9 * r.a5 * r.a10 - 2. * self.a9 * r.a6 * r.a9 + 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a5 * r.a9 - 2. * self.a10 * r.a6 * r.a10,
-            a7: -2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a8 * r.a9 - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + 2. * self.a8 * r.a5 * r.a10 + 2. * self.a8 * r.a6 * r.a9 - 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a6 * r.a8 - 2. * self.a9 * r.a7 * r.a9 - 2. * self.a10 * r.a5 * r.a8 - 2. * self.a10 * r.a7 * r.a10,
-            a8: -self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - 2. * self.a9 * r.a8 * r.a9 - 2. * self.a10 * r.a8 * r.a10,
-            a9: -2. * self.a8 * r.a8 * r.a9 + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - 2. * self.a10 * r.a9 * r.a10,
-            a10: -2. * self.a8 * r.a8 * r.a10 - 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
-            a11: -self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - 2. * self.a12 * r.a8 * r.a9 - 2. * self.a13 * r.a8 * r.a10 + 2. * self.a14 * r.a5 * r.a9 - 2. * self.a14 * r.a6 * r.a10,
-            a12: -2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a5 * r.a8 + 2. * self.a14 * r.a7 * r.a10,
-            a13: -2. * self.a11 * r.a8 * r.a10 - 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + 2. * self.a14 * r.a6 * r.a8 - 2. * self.a14 * r.a7 * r.a9,
+            a1: -self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a6 * r.a8 - T::from_f64(2.) * self.a2 * r.a7 * r.a9 - T::from_f64(2.) * self.a3 * r.a5 * r.a8 + T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a5 * r.a9 - T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a8 * r.a10,
+            a3: -T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - T::from_f64(2.) * self.a4 * r.a8 * r.a9,
+            a4: -T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a8 * r.a9 - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2),
+            a5: self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: -T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: -self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: -T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a11: -self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a8 * r.a9 - T::from_f64(2.) * self.a13 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a9 - T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: -T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a11 * r.a8 * r.a10 - T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + T::from_f64(2.) * self.a14 * r.a6 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a9,
             a14: self.a14 * (-r.a8.powi(2) - r.a9.powi(2) - r.a10.powi(2)),
-            a15: 2. * self.a0 * r.a5 * r.a10 + 2. * self.a0 * r.a6 * r.a9 + 2. * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Reflect<Trivector> for FullMultivector {
-    fn reflect(self, r: Trivector) -> FullMultivector {
+impl<T: Scalar> Reflect<Trivector<T>> for FullMultivector<T> {
+    fn reflect(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: -self.a0 * r.a14.powi(2),
-            a1: r.a14 * (self.a1 * r.a14 + 2. * self.a2 * r.a13 + 2. * self.a3 * r.a12 + 2. * self.a4 * r.a11),
+            a1: r.a14 * (self.a1 * r.a14 + T::from_f64(2.) * self.a2 * r.a13 + T::from_f64(2.) * self.a3 * r.a12 + T::from_f64(2.) * self.a4 * r.a11),
             a2: -self.a2 * r.a14.powi(2),
             a3: -self.a3 * r.a14.powi(2),
             a4: -self.a4 * r.a14.powi(2),
-            a5: r.a14 * (self.a5 * r.a14 - 2. * self.a8 * r.a12 + 2. * self.a9 * r.a11),
-            a6: r.a14 * (self.a6 * r.a14 + 2. * self.a8 * r.a13 - 2. * self.a10 * r.a11),
-            a7: r.a14 * (self.a7 * r.a14 - 2. * self.a9 * r.a13 + 2. * self.a10 * r.a12),
+            a5: r.a14 * (self.a5 * r.a14 - T::from_f64(2.) * self.a8 * r.a12 + T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (self.a6 * r.a14 + T::from_f64(2.) * self.a8 * r.a13 - T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (self.a7 * r.a14 - T::from_f64(2.) * self.a9 * r.a13 + T::from_f64(2.) * self.a10 * r.a12),
             a8: -self.a8 * r.a14.powi(2),
             a9: -self.a9 * r.a14.powi(2),
             a10: -self.a10 * r.a14.powi(2),
-            a11: r.a14 * (self.a11 * r.a14 - 2. * self.a14 * r.a11),
-            a12: r.a14 * (self.a12 * r.a14 - 2. * self.a14 * r.a12),
-            a13: r.a14 * (self.a13 * r.a14 - 2. * self.a14 * r.a13),
+            a11: r.a14 * (self.a11 * r.a14 - T::from_f64(2.) * self.a14 * r.a11),
+            a12: r.a14 * (self.a12 * r.a14 - T::from_f64(2.) * self.a14 * r.a12),
+            a13: r.a14 * (self.a13 * r.a14 - T::from_f64(2.) * self.a14 * r.a13),
             a14: -self.a14 * r.a14.powi(2),
             a15: self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Reflect<Pseudoscalar> for FullMultivector {
-    fn reflect(self, _r: Pseudoscalar) -> FullMultivector {
+impl<T: Scalar> Reflect<Pseudoscalar<T>> for FullMultivector<T> {
+    fn reflect(self, _r: Pseudoscalar<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Reflect<ScalarAndBivector<T>> for FullMultivector<T> {
+    fn reflect(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a1: self.a1 * r.a0.powi(2) - self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a6 * r.a8 - T::from_f64(2.) * self.a2 * r.a7 * r.a9 - T::from_f64(2.) * self.a3 * r.a5 * r.a8 + T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a5 * r.a9 - T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a11 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a10,
+            a2: self.a2 * r.a0.powi(2) + self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a10,
+            a3: -T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - T::from_f64(2.) * self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a14 * r.a0 * r.a9,
+            a4: -T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a8,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a11: -T::from_f64(2.) * self.a1 * r.a0 * r.a8 + T::from_f64(2.) * self.a2 * r.a0 * r.a6 - T::from_f64(2.) * self.a3 * r.a0 * r.a5 + self.a11 * r.a0.powi(2) - self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a8 * r.a9 - T::from_f64(2.) * self.a13 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a9 - T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: -T::from_f64(2.) * self.a1 * r.a0 * r.a9 - T::from_f64(2.) * self.a2 * r.a0 * r.a7 + T::from_f64(2.) * self.a4 * r.a0 * r.a5 - T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a1 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a7 - T::from_f64(2.) * self.a4 * r.a0 * r.a6 - T::from_f64(2.) * self.a11 * r.a8 * r.a10 - T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + T::from_f64(2.) * self.a14 * r.a6 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: T::from_f64(2.) * self.a2 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a9 + T::from_f64(2.) * self.a4 * r.a0 * r.a8 + self.a14 * r.a0.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + T::from_f64(2.) * self.a5 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a0 * r.a8 + T::from_f64(2.) * self.a8 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a0 * r.a5 + self.a15 * r.a0.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Reflect<ScalarAndBivector> for FullMultivector {
-    fn reflect(self, r: ScalarAndBivector) -> FullMultivector {
+impl<T: Scalar> Reflect<Motor<T>> for FullMultivector<T> {
+    fn reflect(self, r: Motor<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - 2. * self.a8 * r.a0 * r.a8 - 2. * self.a9 * r.a0 * r.a9 - 2. * self.a10 * r.a0 * r.a10,
-            a1: self.a1 * r.a0.powi(2) - self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + 2. * self.a2 * r.a6 * r.a8 - 2. * self.a2 * r.a7 * r.a9 - 2. * self.a3 * r.a5 * r.a8 + 2. * self.a3 * r.a7 * r.a10 + 2. * self.a4 * r.a5 * r.a9 - 2. * self.a4 * r.a6 * r.a10 + 2. * self.a11 * r.a0 * r.a8 + 2. * self.a12 * r.a0 * r.a9 + 2. * self.a13 * r.a0 * r.a10,
-            a2: self.a2 * r.a0.powi(2) + self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - 2. * self.a3 * r.a9 * r.a10 - 2. * self.a4 * r.a8 * r.a10 - 2. * self.a14 * r.a0 * r.a10,
-            a3: -2. * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - 2. * self.a4 * r.a8 * r.a9 - 2. * self.a14 * r.a0 * r.a9,
-            a4: -2. * self.a2 * r.a8 * r.a10 - 2. * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2) - 2. * self.a14 * r.a0 * r.a8,
-            a5: 2. * self.a0 * r.a0 * r.a5 + self.a5 * r.a0.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a5 * r.a8 - 2. * self.a8 * r.a7 * r.a10 - 2. * self.a9 * r.a5 * r.a9 - 2. * self.a9 * r.a6 * r.a10 - 2. * self.a10 * r.a5 * r.a10 + 2. * self.a10 * r.a6 * r.a9 + 2. * self.a10 * r.a7 * r.a8 - 2. * self.a15 * r.a0 * r.a10,
-            a6: 2. * self.a0 * r.a0 * r.a6 - 2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - 2. * self.a7 * r.a8 * r.a9 - 2. * self.a8 * r.a6 * r.a8 - 2. * self.a8 * r.a7 * r.a9 + 2. * self.a9 * r.a5 * r.a10 - 2. * self.a9 * r.a6 * r.a9 + 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a5 * r.a9 - 2. * self.a10 * r.a6 * r.a10 - 2. * self.a15 * r.a0 * r.a9,
-            a7: 2. * self.a0 * r.a0 * r.a7 - 2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + 2. * self.a8 * r.a5 * r.a10 + 2. * self.a8 * r.a6 * r.a9 - 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a6 * r.a8 - 2. * self.a9 * r.a7 * r.a9 - 2. * self.a10 * r.a5 * r.a8 - 2. * self.a10 * r.a7 * r.a10 - 2. * self.a15 * r.a0 * r.a8,
-            a8: 2. * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - 2. * self.a9 * r.a8 * r.a9 - 2. * self.a10 * r.a8 * r.a10,
-            a9: 2. * self.a0 * r.a0 * r.a9 - 2. * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - 2. * self.a10 * r.a9 * r.a10,
-            a10: 2. * self.a0 * r.a0 * r.a10 - 2. * self.a8 * r.a8 * r.a10 - 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
-            a11: -2. * self.a1 * r.a0 * r.a8 + 2. * self.a2 * r.a0 * r.a6 - 2. * self.a3 * r.a0 * r.a5 + self.a11 * r.a0.powi(2) - self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - 2. * self.a12 * r.a8 * r.a9 - 2. * self.a13 * r.a8 * r.a10 + 2. * self.a14 * r.a5 * r.a9 - 2. * self.a14 * r.a6 * r.a10,
-            a12: -2. * self.a1 * r.a0 * r.a9 - 2. * self.a2 * r.a0 * r.a7 + 2. * self.a4 * r.a0 * r.a5 - 2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a5 * r.a8 + 2. * self.a14 * r.a7 * r.a10,
-            a13: -2. * self.a1 * r.a0 * r.a10 + 2. * self.a3 * r.a0 * r.a7 - 2. * self.a4 * r.a0 * r.a6 - 2. * self.a11 * r.a8 * r.a10 - 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + 2. * self.a14 * r.a6 * r.a8 - 2. * self.a14 * r.a7 * r.a9,
-            a14: 2. * self.a2 * r.a0 * r.a10 + 2. * self.a3 * r.a0 * r.a9 + 2. * self.a4 * r.a0 * r.a8 + self.a14 * r.a0.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2),
-            a15: 2. * self.a0 * r.a5 * r.a10 + 2. * self.a0 * r.a6 * r.a9 + 2. * self.a0 * r.a7 * r.a8 + 2. * self.a5 * r.a0 * r.a10 + 2. * self.a6 * r.a0 * r.a9 + 2. * self.a7 * r.a0 * r.a8 + 2. * self.a8 * r.a0 * r.a7 + 2. * self.a9 * r.a0 * r.a6 + 2. * self.a10 * r.a0 * r.a5 + self.a15 * r.a0.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
+            a0: self.a0 * r.a0.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a10 * r.a0 * r.a10,
+            a1: self.a1 * r.a0.powi(2) - self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a6 * r.a8 - T::from_f64(2.) * self.a2 * r.a7 * r.a9 - T::from_f64(2.) * self.a3 * r.a5 * r.a8 + T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a5 * r.a9 - T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a11 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a10,
+            a2: self.a2 * r.a0.powi(2) + self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a10,
+            a3: -T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - T::from_f64(2.) * self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a14 * r.a0 * r.a9,
+            a4: -T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a8,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + self.a5 * r.a0.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a15 * r.a0 * r.a10,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 - T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a9,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 - T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a15 * r.a0 * r.a8,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a8 * r.a9 - T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 - T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a8 * r.a10 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2),
+            a11: -T::from_f64(2.) * self.a1 * r.a0 * r.a8 + T::from_f64(2.) * self.a2 * r.a0 * r.a6 + T::from_f64(2.) * self.a2 * r.a9 * r.a15 - T::from_f64(2.) * self.a3 * r.a0 * r.a5 - T::from_f64(2.) * self.a3 * r.a10 * r.a15 + self.a11 * r.a0.powi(2) - self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a8 * r.a9 - T::from_f64(2.) * self.a13 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a9 - T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: -T::from_f64(2.) * self.a1 * r.a0 * r.a9 - T::from_f64(2.) * self.a2 * r.a0 * r.a7 - T::from_f64(2.) * self.a2 * r.a8 * r.a15 + T::from_f64(2.) * self.a4 * r.a0 * r.a5 + T::from_f64(2.) * self.a4 * r.a10 * r.a15 - T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a1 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a7 + T::from_f64(2.) * self.a3 * r.a8 * r.a15 - T::from_f64(2.) * self.a4 * r.a0 * r.a6 - T::from_f64(2.) * self.a4 * r.a9 * r.a15 - T::from_f64(2.) * self.a11 * r.a8 * r.a10 - T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + T::from_f64(2.) * self.a14 * r.a6 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: T::from_f64(2.) * self.a2 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a9 + T::from_f64(2.) * self.a4 * r.a0 * r.a8 + self.a14 * r.a0.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 + T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + T::from_f64(2.) * self.a5 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a0 * r.a8 + T::from_f64(2.) * self.a8 * r.a0 * r.a7 - T::from_f64(2.) * self.a8 * r.a8 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a6 - T::from_f64(2.) * self.a9 * r.a9 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a5 - T::from_f64(2.) * self.a10 * r.a10 * r.a15 + self.a15 * r.a0.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Reflect<FullMultivector> for FullMultivector {
-    fn reflect(self, r: FullMultivector) -> FullMultivector {
+impl<T: Scalar> Reflect<FullMultivector<T>> for FullMultivector<T> {
+    fn reflect(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a0 * r.a0.powi(2) + self.a0 * r.a2.powi(2) + self.a0 * r.a3.powi(2) + self.a0 * r.a4.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - self.a0 * r.a14.powi(2) + 2. * self.a2 * r.a0 * r.a2 - 2. * self.a2 * r.a10 * r.a14 + 2. * self.a3 * r.a0 * r.a3 - 2. * self.a3 * r.a9 * r.a14 + 2. * self.a4 * r.a0 * r.a4 - 2. * self.a4 * r.a8 * r.a14 - 2. * self.a8 * r.a0 * r.a8 - 2. * self.a8 * r.a4 * r.a14 - 2. * self.a9 * r.a0 * r.a9 - 2. * self.a9 * r.a3 * r.a14 - 2. * self.a10 * r.a0 * r.a10 - 2. * self.a10 * r.a2 * r.a14 - 2. * self.a14 * r.a0 * r.a14 - 2. * self.a14 * r.a2 * r.a10 - 2. * self.a14 * r.a3 * r.a9 - 2. * self.a14 * r.a4 * r.a8,
-            a1: 2. * self.a0 * r.a0 * r.a1 + 2. * self.a0 * r.a8 * r.a11 + 2. * self.a0 * r.a9 * r.a12 + 2. * self.a0 * r.a10 * r.a13 + self.a1 * r.a0.powi(2) - self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) - self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + self.a1 * r.a14.powi(2) + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a2 * r.a6 * r.a8 - 2. * self.a2 * r.a7 * r.a9 + 2. * self.a2 * r.a13 * r.a14 + 2. * self.a3 * r.a1 * r.a3 - 2. * self.a3 * r.a5 * r.a8 + 2. * self.a3 * r.a7 * r.a10 + 2. * self.a3 * r.a12 * r.a14 + 2. * self.a4 * r.a1 * r.a4 + 2. * self.a4 * r.a5 * r.a9 - 2. * self.a4 * r.a6 * r.a10 + 2. * self.a4 * r.a11 * r.a14 - 2. * self.a5 * r.a3 * r.a8 + 2. * self.a5 * r.a4 * r.a9 + 2. * self.a6 * r.a2 * r.a8 - 2. * self.a6 * r.a4 * r.a10 - 2. * self.a7 * r.a2 * r.a9 + 2. * self.a7 * r.a3 * r.a10 + 2. * self.a8 * r.a0 * r.a11 - 2. * self.a8 * r.a1 * r.a8 - 2. * self.a8 * r.a2 * r.a6 + 2. * self.a8 * r.a3 * r.a5 + 2. * self.a9 * r.a0 * r.a12 - 2. * self.a9 * r.a1 * r.a9 + 2. * self.a9 * r.a2 * r.a7 - 2. * self.a9 * r.a4 * r.a5 + 2. * self.a10 * r.a0 * r.a13 - 2. * self.a10 * r.a1 * r.a10 - 2. * self.a10 * r.a3 * r.a7 + 2. * self.a10 * r.a4 * r.a6 + 2. * self.a11 * r.a0 * r.a8 - 2. * self.a11 * r.a4 * r.a14 + 2. * self.a12 * r.a0 * r.a9 - 2. * self.a12 * r.a3 * r.a14 + 2. * self.a13 * r.a0 * r.a10 - 2. * self.a13 * r.a2 * r.a14 - 2. * self.a14 * r.a1 * r.a14 + 2. * self.a14 * r.a2 * r.a13 + 2. * self.a14 * r.a3 * r.a12 + 2. * self.a14 * r.a4 * r.a11,
-            a2: 2. * self.a0 * r.a0 * r.a2 - 2. * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - self.a2 * r.a14.powi(2) + 2. * self.a3 * r.a2 * r.a3 - 2. * self.a3 * r.a9 * r.a10 + 2. * self.a4 * r.a2 * r.a4 - 2. * self.a4 * r.a8 * r.a10 - 2. * self.a8 * r.a2 * r.a8 - 2. * self.a8 * r.a4 * r.a10 - 2. * self.a9 * r.a2 * r.a9 - 2. * self.a9 * r.a3 * r.a10 - 2. * self.a10 * r.a0 * r.a14 - 2. * self.a10 * r.a2 * r.a10 + 2. * self.a10 * r.a3 * r.a9 + 2. * self.a10 * r.a4 * r.a8 - 2. * self.a14 * r.a0 * r.a10 - 2. * self.a14 * r.a2 * r.a14,
-            a3: 2. * self.a0 * r.a0 * r.a3 - 2. * self.a0 * r.a9 * r.a14 + 2. * self.a2 * r.a2 * r.a3 - 2. * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - self.a3 * r.a14.powi(2) + 2. * self.a4 * r.a3 * r.a4 - 2. * self.a4 * r.a8 * r.a9 - 2. * self.a8 * r.a3 * r.a8 - 2. * self.a8 * r.a4 * r.a9 - 2. * self.a9 * r.a0 * r.a14 + 2. * self.a9 * r.a2 * r.a10 - 2. * self.a9 * r.a3 * r.a9 + 2. * self.a9 * r.a4 * r.a8 - 2. * self.a10 * r.a2 * r.a9 - 2. * self.a10 * r.a3 * r.a10 - 2. * self.a14 * r.a0 * r.a9 - 2. * self.a14 * r.a3 * r.a14,
-            a4: 2. * self.a0 * r.a0 * r.a4 - 2. * self.a0 * r.a8 * r.a14 + 2. * self.a2 * r.a2 * r.a4 - 2. * self.a2 * r.a8 * r.a10 + 2. * self.a3 * r.a3 * r.a4 - 2. * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2) - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2) - self.a4 * r.a14.powi(2) - 2. * self.a8 * r.a0 * r.a14 + 2. * self.a8 * r.a2 * r.a10 + 2. * self.a8 * r.a3 * r.a9 - 2. * self.a8 * r.a4 * r.a8 - 2. * self.a9 * r.a3 * r.a8 - 2. * self.a9 * r.a4 * r.a9 - 2. * self.a10 * r.a2 * r.a8 - 2. * self.a10 * r.a4 * r.a10 - 2. * self.a14 * r.a0 * r.a8 - 2. * self.a14 * r.a4 * r.a14,
-            a5: 2. * self.a0 * r.a0 * r.a5 - 2. * self.a0 * r.a3 * r.a11 + 2. * self.a0 * r.a4 * r.a12 - 2. * self.a0 * r.a10 * r.a15 + 2. * self.a1 * r.a3 * r.a8 - 2. * self.a1 * r.a4 * r.a9 + 2. * self.a2 * r.a2 * r.a5 - 2. * self.a2 * r.a3 * r.a6 - 2. * self.a2 * r.a4 * r.a7 - 2. * self.a2 * r.a14 * r.a15 - 2. * self.a3 * r.a0 * r.a11 - 2. * self.a3 * r.a1 * r.a8 + 2. * self.a3 * r.a2 * r.a6 + 2. * self.a3 * r.a3 * r.a5 + 2. * self.a4 * r.a0 * r.a12 + 2. * self.a4 * r.a1 * r.a9 + 2. * self.a4 * r.a2 * r.a7 + 2. * self.a4 * r.a4 * r.a5 + self.a5 * r.a0.powi(2) - self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) + self.a5 * r.a14.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a2 * r.a4 - 2. * self.a7 * r.a8 * r.a10 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a8 * r.a5 * r.a8 - 2. * self.a8 * r.a7 * r.a10 - 2. * self.a8 * r.a12 * r.a14 - 2. * self.a9 * r.a1 * r.a4 - 2. * self.a9 * r.a5 * r.a9 - 2. * self.a9 * r.a6 * r.a10 + 2. * self.a9 * r.a11 * r.a14 - 2. * self.a10 * r.a0 * r.a15 - 2. * self.a10 * r.a5 * r.a10 + 2. * self.a10 * r.a6 * r.a9 + 2. * self.a10 * r.a7 * r.a8 - 2. * self.a11 * r.a0 * r.a3 - 2. * self.a11 * r.a9 * r.a14 + 2. * self.a12 * r.a0 * r.a4 + 2. * self.a12 * r.a8 * r.a14 - 2. * self.a14 * r.a2 * r.a15 - 2. * self.a14 * r.a5 * r.a14 - 2. * self.a14 * r.a8 * r.a12 + 2. * self.a14 * r.a9 * r.a11 - 2. * self.a15 * r.a0 * r.a10 + 2. * self.a15 * r.a2 * r.a14,
-            a6: 2. * self.a0 * r.a0 * r.a6 + 2. * self.a0 * r.a2 * r.a11 - 2. * self.a0 * r.a4 * r.a13 - 2. * self.a0 * r.a9 * r.a15 - 2. * self.a1 * r.a2 * r.a8 + 2. * self.a1 * r.a4 * r.a10 + 2. * self.a2 * r.a0 * r.a11 + 2. * self.a2 * r.a1 * r.a8 + 2. * self.a2 * r.a2 * r.a6 + 2. * self.a2 * r.a3 * r.a5 - 2. * self.a3 * r.a2 * r.a5 + 2. * self.a3 * r.a3 * r.a6 - 2. * self.a3 * r.a4 * r.a7 - 2. * self.a3 * r.a14 * r.a15 - 2. * self.a4 * r.a0 * r.a13 - 2. * self.a4 * r.a1 * r.a10 + 2. * self.a4 * r.a3 * r.a7 + 2. * self.a4 * r.a4 * r.a6 - 2. * self.a5 * r.a2 * r.a3 - 2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) + self.a6 * r.a14.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a7 * r.a8 * r.a9 - 2. * self.a8 * r.a1 * r.a2 - 2. * self.a8 * r.a6 * r.a8 - 2. * self.a8 * r.a7 * r.a9 + 2. * self.a8 * r.a13 * r.a14 - 2. * self.a9 * r.a0 * r.a15 + 2. * self.a9 * r.a5 * r.a10 - 2. * self.a9 * r.a6 * r.a9 + 2. * self.a9 * r.a7 * r.a8 + 2. * self.a10 * r.a1 * r.a4 - 2. * self.a10 * r.a5 * r.a9 - 2. * self.a10 * r.a6 * r.a10 - 2. * self.a10 * r.a11 * r.a14 + 2. * self.a11 * r.a0 * r.a2 + 2. * self.a11 * r.a10 * r.a14 - 2. * self.a13 * r.a0 * r.a4 - 2. * self.a13 * r.a8 * r.a14 - 2. * self.a14 * r.a3 * r.a15 - 2. * self.a14 * r.a6 * r.a14 + 2. * self.a14 * r.a8 * r.a13 - 2. * self.a14 * r.a10 * r.a11 - 2. * self.a15 * r.a0 * r.a9 + 2. * self.a15 * r.a3 * r.a14,
-            a7: 2. * self.a0 * r.a0 * r.a7 - 2. * self.a0 * r.a2 * r.a12 + 2. * self.a0 * r.a3 * r.a13 - 2. * self.a0 * r.a8 * r.a15 + 2. * self.a1 * r.a2 * r.a9 - 2. * self.a1 * r.a3 * r.a10 - 2. * self.a2 * r.a0 * r.a12 - 2. * self.a2 * r.a1 * r.a9 + 2. * self.a2 * r.a2 * r.a7 + 2. * self.a2 * r.a4 * r.a5 + 2. * self.a3 * r.a0 * r.a13 + 2. * self.a3 * r.a1 * r.a10 + 2. * self.a3 * r.a3 * r.a7 + 2. * self.a3 * r.a4 * r.a6 - 2. * self.a4 * r.a2 * r.a5 - 2. * self.a4 * r.a3 * r.a6 + 2. * self.a4 * r.a4 * r.a7 - 2. * self.a4 * r.a14 * r.a15 - 2. * self.a5 * r.a2 * r.a4 - 2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a3 * r.a4 - 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + self.a7 * r.a14.powi(2) - 2. * self.a8 * r.a0 * r.a15 + 2. * self.a8 * r.a5 * r.a10 + 2. * self.a8 * r.a6 * r.a9 - 2. * self.a8 * r.a7 * r.a8 + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a9 * r.a6 * r.a8 - 2. * self.a9 * r.a7 * r.a9 - 2. * self.a9 * r.a13 * r.a14 - 2. * self.a10 * r.a1 * r.a3 - 2. * self.a10 * r.a5 * r.a8 - 2. * self.a10 * r.a7 * r.a10 + 2. * self.a10 * r.a12 * r.a14 - 2. * self.a12 * r.a0 * r.a2 - 2. * self.a12 * r.a10 * r.a14 + 2. * self.a13 * r.a0 * r.a3 + 2. * self.a13 * r.a9 * r.a14 - 2. * self.a14 * r.a4 * r.a15 - 2. * self.a14 * r.a7 * r.a14 - 2. * self.a14 * r.a9 * r.a13 + 2. * self.a14 * r.a10 * r.a12 - 2. * self.a15 * r.a0 * r.a8 + 2. * self.a15 * r.a4 * r.a14,
-            a8: 2. * self.a0 * r.a0 * r.a8 + 2. * self.a0 * r.a4 * r.a14 + 2. * self.a2 * r.a2 * r.a8 + 2. * self.a2 * r.a4 * r.a10 + 2. * self.a3 * r.a3 * r.a8 + 2. * self.a3 * r.a4 * r.a9 + 2. * self.a4 * r.a0 * r.a14 - 2. * self.a4 * r.a2 * r.a10 - 2. * self.a4 * r.a3 * r.a9 + 2. * self.a4 * r.a4 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - self.a8 * r.a14.powi(2) + 2. * self.a9 * r.a3 * r.a4 - 2. * self.a9 * r.a8 * r.a9 + 2. * self.a10 * r.a2 * r.a4 - 2. * self.a10 * r.a8 * r.a10 + 2. * self.a14 * r.a0 * r.a4 - 2. * self.a14 * r.a8 * r.a14,
-            a9: 2. * self.a0 * r.a0 * r.a9 + 2. * self.a0 * r.a3 * r.a14 + 2. * self.a2 * r.a2 * r.a9 + 2. * self.a2 * r.a3 * r.a10 + 2. * self.a3 * r.a0 * r.a14 - 2. * self.a3 * r.a2 * r.a10 + 2. * self.a3 * r.a3 * r.a9 - 2. * self.a3 * r.a4 * r.a8 + 2. * self.a4 * r.a3 * r.a8 + 2. * self.a4 * r.a4 * r.a9 + 2. * self.a8 * r.a3 * r.a4 - 2. * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - self.a9 * r.a14.powi(2) + 2. * self.a10 * r.a2 * r.a3 - 2. * self.a10 * r.a9 * r.a10 + 2. * self.a14 * r.a0 * r.a3 - 2. * self.a14 * r.a9 * r.a14,
-            a10: 2. * self.a0 * r.a0 * r.a10 + 2. * self.a0 * r.a2 * r.a14 + 2. * self.a2 * r.a0 * r.a14 + 2. * self.a2 * r.a2 * r.a10 - 2. * self.a2 * r.a3 * r.a9 - 2. * self.a2 * r.a4 * r.a8 + 2. * self.a3 * r.a2 * r.a9 + 2. * self.a3 * r.a3 * r.a10 + 2. * self.a4 * r.a2 * r.a8 + 2. * self.a4 * r.a4 * r.a10 + 2. * self.a8 * r.a2 * r.a4 - 2. * self.a8 * r.a8 * r.a10 + 2. * self.a9 * r.a2 * r.a3 - 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2) - self.a10 * r.a14.powi(2) + 2. * self.a14 * r.a0 * r.a2 - 2. * self.a14 * r.a10 * r.a14,
-            a11: 2. * self.a0 * r.a0 * r.a11 - 2. * self.a0 * r.a1 * r.a8 + 2. * self.a0 * r.a2 * r.a6 - 2. * self.a0 * r.a3 * r.a5 - 2. * self.a1 * r.a0 * r.a8 + 2. * self.a1 * r.a4 * r.a14 + 2. * self.a2 * r.a0 * r.a6 + 2. * self.a2 * r.a2 * r.a11 + 2. * self.a2 * r.a4 * r.a13 + 2. * self.a2 * r.a9 * r.a15 - 2. * self.a3 * r.a0 * r.a5 + 2. * self.a3 * r.a3 * r.a11 + 2. * self.a3 * r.a4 * r.a12 - 2. * self.a3 * r.a10 * r.a15 - 2. * self.a4 * r.a1 * r.a14 - 2. * self.a4 * r.a2 * r.a13 - 2. * self.a4 * r.a3 * r.a12 + 2. * self.a4 * r.a4 * r.a11 - 2. * self.a5 * r.a0 * r.a3 - 2. * self.a5 * r.a9 * r.a14 + 2. * self.a6 * r.a0 * r.a2 + 2. * self.a6 * r.a10 * r.a14 - 2. * self.a8 * r.a0 * r.a1 - 2. * self.a8 * r.a8 * r.a11 + 2. * self.a8 * r.a9 * r.a12 + 2. * self.a8 * r.a10 * r.a13 - 2. * self.a9 * r.a2 * r.a15 + 2. * self.a9 * r.a5 * r.a14 - 2. * self.a9 * r.a8 * r.a12 - 2. * self.a9 * r.a9 * r.a11 + 2. * self.a10 * r.a3 * r.a15 - 2. * self.a10 * r.a6 * r.a14 - 2. * self.a10 * r.a8 * r.a13 - 2. * self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) + self.a11 * r.a14.powi(2) - 2. * self.a12 * r.a3 * r.a4 - 2. * self.a12 * r.a8 * r.a9 - 2. * self.a13 * r.a2 * r.a4 - 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a1 * r.a4 + 2. * self.a14 * r.a5 * r.a9 - 2. * self.a14 * r.a6 * r.a10 - 2. * self.a14 * r.a11 * r.a14 - 2. * self.a15 * r.a2 * r.a9 + 2. * self.a15 * r.a3 * r.a10,
-            a12: 2. * self.a0 * r.a0 * r.a12 - 2. * self.a0 * r.a1 * r.a9 - 2. * self.a0 * r.a2 * r.a7 + 2. * self.a0 * r.a4 * r.a5 - 2. * self.a1 * r.a0 * r.a9 + 2. * self.a1 * r.a3 * r.a14 - 2. * self.a2 * r.a0 * r.a7 + 2. * self.a2 * r.a2 * r.a12 + 2. * self.a2 * r.a3 * r.a13 - 2. * self.a2 * r.a8 * r.a15 - 2. * self.a3 * r.a1 * r.a14 - 2. * self.a3 * r.a2 * r.a13 + 2. * self.a3 * r.a3 * r.a12 - 2. * self.a3 * r.a4 * r.a11 + 2. * self.a4 * r.a0 * r.a5 + 2. * self.a4 * r.a3 * r.a11 + 2. * self.a4 * r.a4 * r.a12 + 2. * self.a4 * r.a10 * r.a15 + 2. * self.a5 * r.a0 * r.a4 + 2. * self.a5 * r.a8 * r.a14 - 2. * self.a7 * r.a0 * r.a2 - 2. * self.a7 * r.a10 * r.a14 + 2. * self.a8 * r.a2 * r.a15 - 2. * self.a8 * r.a5 * r.a14 - 2. * self.a8 * r.a8 * r.a12 - 2. * self.a8 * r.a9 * r.a11 - 2. * self.a9 * r.a0 * r.a1 + 2. * self.a9 * r.a8 * r.a11 - 2. * self.a9 * r.a9 * r.a12 + 2. * self.a9 * r.a10 * r.a13 - 2. * self.a10 * r.a4 * r.a15 + 2. * self.a10 * r.a7 * r.a14 - 2. * self.a10 * r.a9 * r.a13 - 2. * self.a10 * r.a10 * r.a12 - 2. * self.a11 * r.a3 * r.a4 - 2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) + self.a12 * r.a14.powi(2) - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a1 * r.a3 - 2. * self.a14 * r.a5 * r.a8 + 2. * self.a14 * r.a7 * r.a10 - 2. * self.a14 * r.a12 * r.a14 + 2. * self.a15 * r.a2 * r.a8 - 2. * self.a15 * r.a4 * r.a10,
-            a13: 2. * self.a0 * r.a0 * r.a13 - 2. * self.a0 * r.a1 * r.a10 + 2. * self.a0 * r.a3 * r.a7 - 2. * self.a0 * r.a4 * r.a6 - 2. * self.a1 * r.a0 * r.a10 + 2. * self.a1 * r.a2 * r.a14 - 2. * self.a2 * r.a1 * r.a14 + 2. * self.a2 * r.a2 * r.a13 - 2. * self.a2 * r.a3 * r.a12 - 2. * self.a2 * r.a4 * r.a11 + 2. * self.a3 * r.a0 * r.a7 + 2. * self.a3 * r.a2 * r.a12 + 2. * self.a3 * r.a3 * r.a13 + 2. * self.a3 * r.a8 * r.a15 - 2. * self.a4 * r.a0 * r.a6 + 2. * self.a4 * r.a2 * r.a11 + 2. * self.a4 * r.a4 * r.a13 - 2. * self.a4 * r.a9 * r.a15 - 2. * self.a6 * r.a0 * r.a4 - 2. * self.a6 * r.a8 * r.a14 + 2. * self.a7 * r.a0 * r.a3 + 2. * self.a7 * r.a9 * r.a14 - 2. * self.a8 * r.a3 * r.a15 + 2. * self.a8 * r.a6 * r.a14 - 2. * self.a8 * r.a8 * r.a13 - 2. * self.a8 * r.a10 * r.a11 + 2. * self.a9 * r.a4 * r.a15 - 2. * self.a9 * r.a7 * r.a14 - 2. * self.a9 * r.a9 * r.a13 - 2. * self.a9 * r.a10 * r.a12 - 2. * self.a10 * r.a0 * r.a1 + 2. * self.a10 * r.a8 * r.a11 + 2. * self.a10 * r.a9 * r.a12 - 2. * self.a10 * r.a10 * r.a13 - 2. * self.a11 * r.a2 * r.a4 - 2. * self.a11 * r.a8 * r.a10 - 2. * self.a12 * r.a2 * r.a3 - 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + self.a13 * r.a14.powi(2) - 2. * self.a14 * r.a1 * r.a2 + 2. * self.a14 * r.a6 * r.a8 - 2. * self.a14 * r.a7 * r.a9 - 2. * self.a14 * r.a13 * r.a14 - 2. * self.a15 * r.a3 * r.a8 + 2. * self.a15 * r.a4 * r.a9,
-            a14: 2. * self.a0 * r.a0 * r.a14 + 2. * self.a0 * r.a2 * r.a10 + 2. * self.a0 * r.a3 * r.a9 + 2. * self.a0 * r.a4 * r.a8 + 2. * self.a2 * r.a0 * r.a10 + 2. * self.a2 * r.a2 * r.a14 + 2. * self.a3 * r.a0 * r.a9 + 2. * self.a3 * r.a3 * r.a14 + 2. * self.a4 * r.a0 * r.a8 + 2. * self.a4 * r.a4 * r.a14 + 2. * self.a8 * r.a0 * r.a4 - 2. * self.a8 * r.a8 * r.a14 + 2. * self.a9 * r.a0 * r.a3 - 2. * self.a9 * r.a9 * r.a14 + 2. * self.a10 * r.a0 * r.a2 - 2. * self.a10 * r.a10 * r.a14 + self.a14 * r.a0.powi(2) + self.a14 * r.a2.powi(2) + self.a14 * r.a3.powi(2) + self.a14 * r.a4.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2) - self.a14 * r.a14.powi(2),
-            a15: 2. * self.a0 * r.a0 * r.a15 + 2. * self.a0 * r.a5 * r.a10 + 2. * self.a0 * r.a6 * r.a9 + 2. * self.a0 * r.a7 * r.a8 + 2. * self.a2 * r.a2 * r.a15 + 2. * self.a2 * r.a5 * r.a14 - 2. * self.a2 * r.a8 * r.a12 + 2. * self.a2 * r.a9 * r.a11 + 2. * self.a3 * r.a3 * r.a15 + 2. * self.a3 * r.a6 * r.a14 + 2. * self.a3 * r.a8 * r.a13 - 2. * self.a3 * r.a10 * r.a11 + 2. * self.a4 * r.a4 * r.a15 + 2. * self.a4 * r.a7 * r.a14 - 2. * self.a4 * r.a9 * r.a13 + 2. * self.a4 * r.a10 * r.a12 + 2. * self.a5 * r.a0 * r.a10 - 2. * self.a5 * r.a2 * r.a14 + 2. * self.a6 * r.a0 * r.a9 - 2. * self.a6 * r.a3 * r.a14 + 2. * self.a7 * r.a0 * r.a8 - 2. * self.a7 * r.a4 * r.a14 + 2. * self.a8 * r.a0 * r.a7 + 2. * self.a8 * r.a2 * r.a12 - 2. * self.a8 * r.a3 * r.a13 - 2. * self.a8 * r.a8 * r.a15 + 2. * self.a9 * r.a0 * r.a6 - 2. * self.a9 * r.a2 * r.a11 + 2. * self.a9 * r.a4 * r.a13 - 2. * self.a9 * r.a9 * r.a15 + 2. * self.a10 * r.a0 * r.a5 + 2. * self.a10 * r.a3 * r.a11 - 2. * self.a10 * r.a4 * r.a12 - 2. * self.a10 * r.a10 * r.a15 + 2. * self.a11 * r.a2 * r.a9 - 2. * self.a11 * r.a3 * r.a10 - 2. * self.a12 * r.a2 * r.a8 + 2. * self.a12 * r.a4 * r.a10 + 2. * self.a13 * r.a3 * r.a8 - 2. * self.a13 * r.a4 * r.a9 + 2. * self.a14 * r.a2 * r.a5 + 2. * self.a14 * r.a3 * r.a6 + 2. * self.a14 * r.a4 * r.a7 - 2. * self.a14 * r.a14 * r.a15 + self.a15 * r.a0.powi(2) - self.a15 * r.a2.powi(2) - self.a15 * r.a3.powi(2) - self.a15 * r.a4.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2) + self.a15 * r.a14.powi(2),
+            a0: self.a0 * r.a0.powi(2) + self.a0 * r.a2.powi(2) + self.a0 * r.a3.powi(2) + self.a0 * r.a4.powi(2) - self.a0 * r.a8.powi(2) - self.a0 * r.a9.powi(2) - self.a0 * r.a10.powi(2) - self.a0 * r.a14.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a2 - T::from_f64(2.) * self.a2 * r.a10 * r.a14 + T::from_f64(2.) * self.a3 * r.a0 * r.a3 - T::from_f64(2.) * self.a3 * r.a9 * r.a14 + T::from_f64(2.) * self.a4 * r.a0 * r.a4 - T::from_f64(2.) * self.a4 * r.a8 * r.a14 - T::from_f64(2.) * self.a8 * r.a0 * r.a8 - T::from_f64(2.) * self.a8 * r.a4 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a9 - T::from_f64(2.) * self.a9 * r.a3 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a10 - T::from_f64(2.) * self.a10 * r.a2 * r.a14 - T::from_f64(2.) * self.a14 * r.a0 * r.a14 - T::from_f64(2.) * self.a14 * r.a2 * r.a10 - T::from_f64(2.) * self.a14 * r.a3 * r.a9 - T::from_f64(2.) * self.a14 * r.a4 * r.a8,
+            a1: T::from_f64(2.) * self.a0 * r.a0 * r.a1 + T::from_f64(2.) * self.a0 * r.a8 * r.a11 + T::from_f64(2.) * self.a0 * r.a9 * r.a12 + T::from_f64(2.) * self.a0 * r.a10 * r.a13 + self.a1 * r.a0.powi(2) - self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) - self.a1 * r.a8.powi(2) - self.a1 * r.a9.powi(2) - self.a1 * r.a10.powi(2) + self.a1 * r.a14.powi(2) + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a2 * r.a6 * r.a8 - T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a2 * r.a13 * r.a14 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 - T::from_f64(2.) * self.a3 * r.a5 * r.a8 + T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a3 * r.a12 * r.a14 + T::from_f64(2.) * self.a4 * r.a1 * r.a4 + T::from_f64(2.) * self.a4 * r.a5 * r.a9 - T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a4 * r.a11 * r.a14 - T::from_f64(2.) * self.a5 * r.a3 * r.a8 + T::from_f64(2.) * self.a5 * r.a4 * r.a9 + T::from_f64(2.) * self.a6 * r.a2 * r.a8 - T::from_f64(2.) * self.a6 * r.a4 * r.a10 - T::from_f64(2.) * self.a7 * r.a2 * r.a9 + T::from_f64(2.) * self.a7 * r.a3 * r.a10 + T::from_f64(2.) * self.a8 * r.a0 * r.a11 - T::from_f64(2.) * self.a8 * r.a1 * r.a8 - T::from_f64(2.) * self.a8 * r.a2 * r.a6 + T::from_f64(2.) * self.a8 * r.a3 * r.a5 + T::from_f64(2.) * self.a9 * r.a0 * r.a12 - T::from_f64(2.) * self.a9 * r.a1 * r.a9 + T::from_f64(2.) * self.a9 * r.a2 * r.a7 - T::from_f64(2.) * self.a9 * r.a4 * r.a5 + T::from_f64(2.) * self.a10 * r.a0 * r.a13 - T::from_f64(2.) * self.a10 * r.a1 * r.a10 - T::from_f64(2.) * self.a10 * r.a3 * r.a7 + T::from_f64(2.) * self.a10 * r.a4 * r.a6 + T::from_f64(2.) * self.a11 * r.a0 * r.a8 - T::from_f64(2.) * self.a11 * r.a4 * r.a14 + T::from_f64(2.) * self.a12 * r.a0 * r.a9 - T::from_f64(2.) * self.a12 * r.a3 * r.a14 + T::from_f64(2.) * self.a13 * r.a0 * r.a10 - T::from_f64(2.) * self.a13 * r.a2 * r.a14 - T::from_f64(2.) * self.a14 * r.a1 * r.a14 + T::from_f64(2.) * self.a14 * r.a2 * r.a13 + T::from_f64(2.) * self.a14 * r.a3 * r.a12 + T::from_f64(2.) * self.a14 * r.a4 * r.a11,
+            a2: T::from_f64(2.) * self.a0 * r.a0 * r.a2 - T::from_f64(2.) * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + self.a2 * r.a8.powi(2) + self.a2 * r.a9.powi(2) - self.a2 * r.a10.powi(2) - self.a2 * r.a14.powi(2) + T::from_f64(2.) * self.a3 * r.a2 * r.a3 - T::from_f64(2.) * self.a3 * r.a9 * r.a10 + T::from_f64(2.) * self.a4 * r.a2 * r.a4 - T::from_f64(2.) * self.a4 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a2 * r.a8 - T::from_f64(2.) * self.a8 * r.a4 * r.a10 - T::from_f64(2.) * self.a9 * r.a2 * r.a9 - T::from_f64(2.) * self.a9 * r.a3 * r.a10 - T::from_f64(2.) * self.a10 * r.a0 * r.a14 - T::from_f64(2.) * self.a10 * r.a2 * r.a10 + T::from_f64(2.) * self.a10 * r.a3 * r.a9 + T::from_f64(2.) * self.a10 * r.a4 * r.a8 - T::from_f64(2.) * self.a14 * r.a0 * r.a10 - T::from_f64(2.) * self.a14 * r.a2 * r.a14,
+            a3: T::from_f64(2.) * self.a0 * r.a0 * r.a3 - T::from_f64(2.) * self.a0 * r.a9 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a3 - T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + self.a3 * r.a8.powi(2) - self.a3 * r.a9.powi(2) + self.a3 * r.a10.powi(2) - self.a3 * r.a14.powi(2) + T::from_f64(2.) * self.a4 * r.a3 * r.a4 - T::from_f64(2.) * self.a4 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a3 * r.a8 - T::from_f64(2.) * self.a8 * r.a4 * r.a9 - T::from_f64(2.) * self.a9 * r.a0 * r.a14 + T::from_f64(2.) * self.a9 * r.a2 * r.a10 - T::from_f64(2.) * self.a9 * r.a3 * r.a9 + T::from_f64(2.) * self.a9 * r.a4 * r.a8 - T::from_f64(2.) * self.a10 * r.a2 * r.a9 - T::from_f64(2.) * self.a10 * r.a3 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a9 - T::from_f64(2.) * self.a14 * r.a3 * r.a14,
+            a4: T::from_f64(2.) * self.a0 * r.a0 * r.a4 - T::from_f64(2.) * self.a0 * r.a8 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a4 - T::from_f64(2.) * self.a2 * r.a8 * r.a10 + T::from_f64(2.) * self.a3 * r.a3 * r.a4 - T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2) - self.a4 * r.a8.powi(2) + self.a4 * r.a9.powi(2) + self.a4 * r.a10.powi(2) - self.a4 * r.a14.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a14 + T::from_f64(2.) * self.a8 * r.a2 * r.a10 + T::from_f64(2.) * self.a8 * r.a3 * r.a9 - T::from_f64(2.) * self.a8 * r.a4 * r.a8 - T::from_f64(2.) * self.a9 * r.a3 * r.a8 - T::from_f64(2.) * self.a9 * r.a4 * r.a9 - T::from_f64(2.) * self.a10 * r.a2 * r.a8 - T::from_f64(2.) * self.a10 * r.a4 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a8 - T::from_f64(2.) * self.a14 * r.a4 * r.a14,
+            a5: T::from_f64(2.) * self.a0 * r.a0 * r.a5 - T::from_f64(2.) * self.a0 * r.a3 * r.a11 + T::from_f64(2.) * self.a0 * r.a4 * r.a12 - T::from_f64(2.) * self.a0 * r.a10 * r.a15 + T::from_f64(2.) * self.a1 * r.a3 * r.a8 - T::from_f64(2.) * self.a1 * r.a4 * r.a9 + T::from_f64(2.) * self.a2 * r.a2 * r.a5 - T::from_f64(2.) * self.a2 * r.a3 * r.a6 - T::from_f64(2.) * self.a2 * r.a4 * r.a7 - T::from_f64(2.) * self.a2 * r.a14 * r.a15 - T::from_f64(2.) * self.a3 * r.a0 * r.a11 - T::from_f64(2.) * self.a3 * r.a1 * r.a8 + T::from_f64(2.) * self.a3 * r.a2 * r.a6 + T::from_f64(2.) * self.a3 * r.a3 * r.a5 + T::from_f64(2.) * self.a4 * r.a0 * r.a12 + T::from_f64(2.) * self.a4 * r.a1 * r.a9 + T::from_f64(2.) * self.a4 * r.a2 * r.a7 + T::from_f64(2.) * self.a4 * r.a4 * r.a5 + self.a5 * r.a0.powi(2) - self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) + self.a5 * r.a8.powi(2) + self.a5 * r.a9.powi(2) - self.a5 * r.a10.powi(2) + self.a5 * r.a14.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 - T::from_f64(2.) * self.a7 * r.a8 * r.a10 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a8 * r.a5 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a10 - T::from_f64(2.) * self.a8 * r.a12 * r.a14 - T::from_f64(2.) * self.a9 * r.a1 * r.a4 - T::from_f64(2.) * self.a9 * r.a5 * r.a9 - T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a9 * r.a11 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 - T::from_f64(2.) * self.a10 * r.a5 * r.a10 + T::from_f64(2.) * self.a10 * r.a6 * r.a9 + T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a11 * r.a0 * r.a3 - T::from_f64(2.) * self.a11 * r.a9 * r.a14 + T::from_f64(2.) * self.a12 * r.a0 * r.a4 + T::from_f64(2.) * self.a12 * r.a8 * r.a14 - T::from_f64(2.) * self.a14 * r.a2 * r.a15 - T::from_f64(2.) * self.a14 * r.a5 * r.a14 - T::from_f64(2.) * self.a14 * r.a8 * r.a12 + T::from_f64(2.) * self.a14 * r.a9 * r.a11 - T::from_f64(2.) * self.a15 * r.a0 * r.a10 + T::from_f64(2.) * self.a15 * r.a2 * r.a14,
+            a6: T::from_f64(2.) * self.a0 * r.a0 * r.a6 + T::from_f64(2.) * self.a0 * r.a2 * r.a11 - T::from_f64(2.) * self.a0 * r.a4 * r.a13 - T::from_f64(2.) * self.a0 * r.a9 * r.a15 - T::from_f64(2.) * self.a1 * r.a2 * r.a8 + T::from_f64(2.) * self.a1 * r.a4 * r.a10 + T::from_f64(2.) * self.a2 * r.a0 * r.a11 + T::from_f64(2.) * self.a2 * r.a1 * r.a8 + T::from_f64(2.) * self.a2 * r.a2 * r.a6 + T::from_f64(2.) * self.a2 * r.a3 * r.a5 - T::from_f64(2.) * self.a3 * r.a2 * r.a5 + T::from_f64(2.) * self.a3 * r.a3 * r.a6 - T::from_f64(2.) * self.a3 * r.a4 * r.a7 - T::from_f64(2.) * self.a3 * r.a14 * r.a15 - T::from_f64(2.) * self.a4 * r.a0 * r.a13 - T::from_f64(2.) * self.a4 * r.a1 * r.a10 + T::from_f64(2.) * self.a4 * r.a3 * r.a7 + T::from_f64(2.) * self.a4 * r.a4 * r.a6 - T::from_f64(2.) * self.a5 * r.a2 * r.a3 - T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) + self.a6 * r.a8.powi(2) - self.a6 * r.a9.powi(2) + self.a6 * r.a10.powi(2) + self.a6 * r.a14.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a7 * r.a8 * r.a9 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 - T::from_f64(2.) * self.a8 * r.a6 * r.a8 - T::from_f64(2.) * self.a8 * r.a7 * r.a9 + T::from_f64(2.) * self.a8 * r.a13 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 + T::from_f64(2.) * self.a9 * r.a5 * r.a10 - T::from_f64(2.) * self.a9 * r.a6 * r.a9 + T::from_f64(2.) * self.a9 * r.a7 * r.a8 + T::from_f64(2.) * self.a10 * r.a1 * r.a4 - T::from_f64(2.) * self.a10 * r.a5 * r.a9 - T::from_f64(2.) * self.a10 * r.a6 * r.a10 - T::from_f64(2.) * self.a10 * r.a11 * r.a14 + T::from_f64(2.) * self.a11 * r.a0 * r.a2 + T::from_f64(2.) * self.a11 * r.a10 * r.a14 - T::from_f64(2.) * self.a13 * r.a0 * r.a4 - T::from_f64(2.) * self.a13 * r.a8 * r.a14 - T::from_f64(2.) * self.a14 * r.a3 * r.a15 - T::from_f64(2.) * self.a14 * r.a6 * r.a14 + T::from_f64(2.) * self.a14 * r.a8 * r.a13 - T::from_f64(2.) * self.a14 * r.a10 * r.a11 - T::from_f64(2.) * self.a15 * r.a0 * r.a9 + T::from_f64(2.) * self.a15 * r.a3 * r.a14,
+            a7: T::from_f64(2.) * self.a0 * r.a0 * r.a7 - T::from_f64(2.) * self.a0 * r.a2 * r.a12 + T::from_f64(2.) * self.a0 * r.a3 * r.a13 - T::from_f64(2.) * self.a0 * r.a8 * r.a15 + T::from_f64(2.) * self.a1 * r.a2 * r.a9 - T::from_f64(2.) * self.a1 * r.a3 * r.a10 - T::from_f64(2.) * self.a2 * r.a0 * r.a12 - T::from_f64(2.) * self.a2 * r.a1 * r.a9 + T::from_f64(2.) * self.a2 * r.a2 * r.a7 + T::from_f64(2.) * self.a2 * r.a4 * r.a5 + T::from_f64(2.) * self.a3 * r.a0 * r.a13 + T::from_f64(2.) * self.a3 * r.a1 * r.a10 + T::from_f64(2.) * self.a3 * r.a3 * r.a7 + T::from_f64(2.) * self.a3 * r.a4 * r.a6 - T::from_f64(2.) * self.a4 * r.a2 * r.a5 - T::from_f64(2.) * self.a4 * r.a3 * r.a6 + T::from_f64(2.) * self.a4 * r.a4 * r.a7 - T::from_f64(2.) * self.a4 * r.a14 * r.a15 - T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 - T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) - self.a7 * r.a8.powi(2) + self.a7 * r.a9.powi(2) + self.a7 * r.a10.powi(2) + self.a7 * r.a14.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 + T::from_f64(2.) * self.a8 * r.a5 * r.a10 + T::from_f64(2.) * self.a8 * r.a6 * r.a9 - T::from_f64(2.) * self.a8 * r.a7 * r.a8 + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a9 * r.a6 * r.a8 - T::from_f64(2.) * self.a9 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a13 * r.a14 - T::from_f64(2.) * self.a10 * r.a1 * r.a3 - T::from_f64(2.) * self.a10 * r.a5 * r.a8 - T::from_f64(2.) * self.a10 * r.a7 * r.a10 + T::from_f64(2.) * self.a10 * r.a12 * r.a14 - T::from_f64(2.) * self.a12 * r.a0 * r.a2 - T::from_f64(2.) * self.a12 * r.a10 * r.a14 + T::from_f64(2.) * self.a13 * r.a0 * r.a3 + T::from_f64(2.) * self.a13 * r.a9 * r.a14 - T::from_f64(2.) * self.a14 * r.a4 * r.a15 - T::from_f64(2.) * self.a14 * r.a7 * r.a14 - T::from_f64(2.) * self.a14 * r.a9 * r.a13 + T::from_f64(2.) * self.a14 * r.a10 * r.a12 - T::from_f64(2.) * self.a15 * r.a0 * r.a8 + T::from_f64(2.) * self.a15 * r.a4 * r.a14,
+            a8: T::from_f64(2.) * self.a0 * r.a0 * r.a8 + T::from_f64(2.) * self.a0 * r.a4 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a8 + T::from_f64(2.) * self.a2 * r.a4 * r.a10 + T::from_f64(2.) * self.a3 * r.a3 * r.a8 + T::from_f64(2.) * self.a3 * r.a4 * r.a9 + T::from_f64(2.) * self.a4 * r.a0 * r.a14 - T::from_f64(2.) * self.a4 * r.a2 * r.a10 - T::from_f64(2.) * self.a4 * r.a3 * r.a9 + T::from_f64(2.) * self.a4 * r.a4 * r.a8 + self.a8 * r.a0.powi(2) - self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) - self.a8 * r.a8.powi(2) + self.a8 * r.a9.powi(2) + self.a8 * r.a10.powi(2) - self.a8 * r.a14.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 - T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a2 * r.a4 - T::from_f64(2.) * self.a10 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a4 - T::from_f64(2.) * self.a14 * r.a8 * r.a14,
+            a9: T::from_f64(2.) * self.a0 * r.a0 * r.a9 + T::from_f64(2.) * self.a0 * r.a3 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a9 + T::from_f64(2.) * self.a2 * r.a3 * r.a10 + T::from_f64(2.) * self.a3 * r.a0 * r.a14 - T::from_f64(2.) * self.a3 * r.a2 * r.a10 + T::from_f64(2.) * self.a3 * r.a3 * r.a9 - T::from_f64(2.) * self.a3 * r.a4 * r.a8 + T::from_f64(2.) * self.a4 * r.a3 * r.a8 + T::from_f64(2.) * self.a4 * r.a4 * r.a9 + T::from_f64(2.) * self.a8 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + self.a9 * r.a8.powi(2) - self.a9 * r.a9.powi(2) + self.a9 * r.a10.powi(2) - self.a9 * r.a14.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3 - T::from_f64(2.) * self.a10 * r.a9 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a3 - T::from_f64(2.) * self.a14 * r.a9 * r.a14,
+            a10: T::from_f64(2.) * self.a0 * r.a0 * r.a10 + T::from_f64(2.) * self.a0 * r.a2 * r.a14 + T::from_f64(2.) * self.a2 * r.a0 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a10 - T::from_f64(2.) * self.a2 * r.a3 * r.a9 - T::from_f64(2.) * self.a2 * r.a4 * r.a8 + T::from_f64(2.) * self.a3 * r.a2 * r.a9 + T::from_f64(2.) * self.a3 * r.a3 * r.a10 + T::from_f64(2.) * self.a4 * r.a2 * r.a8 + T::from_f64(2.) * self.a4 * r.a4 * r.a10 + T::from_f64(2.) * self.a8 * r.a2 * r.a4 - T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 - T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2) + self.a10 * r.a8.powi(2) + self.a10 * r.a9.powi(2) - self.a10 * r.a10.powi(2) - self.a10 * r.a14.powi(2) + T::from_f64(2.) * self.a14 * r.a0 * r.a2 - T::from_f64(2.) * self.a14 * r.a10 * r.a14,
+            a11: T::from_f64(2.) * self.a0 * r.a0 * r.a11 - T::from_f64(2.) * self.a0 * r.a1 * r.a8 + T::from_f64(2.) * self.a0 * r.a2 * r.a6 - T::from_f64(2.) * self.a0 * r.a3 * r.a5 - T::from_f64(2.) * self.a1 * r.a0 * r.a8 + T::from_f64(2.) * self.a1 * r.a4 * r.a14 + T::from_f64(2.) * self.a2 * r.a0 * r.a6 + T::from_f64(2.) * self.a2 * r.a2 * r.a11 + T::from_f64(2.) * self.a2 * r.a4 * r.a13 + T::from_f64(2.) * self.a2 * r.a9 * r.a15 - T::from_f64(2.) * self.a3 * r.a0 * r.a5 + T::from_f64(2.) * self.a3 * r.a3 * r.a11 + T::from_f64(2.) * self.a3 * r.a4 * r.a12 - T::from_f64(2.) * self.a3 * r.a10 * r.a15 - T::from_f64(2.) * self.a4 * r.a1 * r.a14 - T::from_f64(2.) * self.a4 * r.a2 * r.a13 - T::from_f64(2.) * self.a4 * r.a3 * r.a12 + T::from_f64(2.) * self.a4 * r.a4 * r.a11 - T::from_f64(2.) * self.a5 * r.a0 * r.a3 - T::from_f64(2.) * self.a5 * r.a9 * r.a14 + T::from_f64(2.) * self.a6 * r.a0 * r.a2 + T::from_f64(2.) * self.a6 * r.a10 * r.a14 - T::from_f64(2.) * self.a8 * r.a0 * r.a1 - T::from_f64(2.) * self.a8 * r.a8 * r.a11 + T::from_f64(2.) * self.a8 * r.a9 * r.a12 + T::from_f64(2.) * self.a8 * r.a10 * r.a13 - T::from_f64(2.) * self.a9 * r.a2 * r.a15 + T::from_f64(2.) * self.a9 * r.a5 * r.a14 - T::from_f64(2.) * self.a9 * r.a8 * r.a12 - T::from_f64(2.) * self.a9 * r.a9 * r.a11 + T::from_f64(2.) * self.a10 * r.a3 * r.a15 - T::from_f64(2.) * self.a10 * r.a6 * r.a14 - T::from_f64(2.) * self.a10 * r.a8 * r.a13 - T::from_f64(2.) * self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - self.a11 * r.a8.powi(2) + self.a11 * r.a9.powi(2) + self.a11 * r.a10.powi(2) + self.a11 * r.a14.powi(2) - T::from_f64(2.) * self.a12 * r.a3 * r.a4 - T::from_f64(2.) * self.a12 * r.a8 * r.a9 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 - T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a1 * r.a4 + T::from_f64(2.) * self.a14 * r.a5 * r.a9 - T::from_f64(2.) * self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a11 * r.a14 - T::from_f64(2.) * self.a15 * r.a2 * r.a9 + T::from_f64(2.) * self.a15 * r.a3 * r.a10,
+            a12: T::from_f64(2.) * self.a0 * r.a0 * r.a12 - T::from_f64(2.) * self.a0 * r.a1 * r.a9 - T::from_f64(2.) * self.a0 * r.a2 * r.a7 + T::from_f64(2.) * self.a0 * r.a4 * r.a5 - T::from_f64(2.) * self.a1 * r.a0 * r.a9 + T::from_f64(2.) * self.a1 * r.a3 * r.a14 - T::from_f64(2.) * self.a2 * r.a0 * r.a7 + T::from_f64(2.) * self.a2 * r.a2 * r.a12 + T::from_f64(2.) * self.a2 * r.a3 * r.a13 - T::from_f64(2.) * self.a2 * r.a8 * r.a15 - T::from_f64(2.) * self.a3 * r.a1 * r.a14 - T::from_f64(2.) * self.a3 * r.a2 * r.a13 + T::from_f64(2.) * self.a3 * r.a3 * r.a12 - T::from_f64(2.) * self.a3 * r.a4 * r.a11 + T::from_f64(2.) * self.a4 * r.a0 * r.a5 + T::from_f64(2.) * self.a4 * r.a3 * r.a11 + T::from_f64(2.) * self.a4 * r.a4 * r.a12 + T::from_f64(2.) * self.a4 * r.a10 * r.a15 + T::from_f64(2.) * self.a5 * r.a0 * r.a4 + T::from_f64(2.) * self.a5 * r.a8 * r.a14 - T::from_f64(2.) * self.a7 * r.a0 * r.a2 - T::from_f64(2.) * self.a7 * r.a10 * r.a14 + T::from_f64(2.) * self.a8 * r.a2 * r.a15 - T::from_f64(2.) * self.a8 * r.a5 * r.a14 - T::from_f64(2.) * self.a8 * r.a8 * r.a12 - T::from_f64(2.) * self.a8 * r.a9 * r.a11 - T::from_f64(2.) * self.a9 * r.a0 * r.a1 + T::from_f64(2.) * self.a9 * r.a8 * r.a11 - T::from_f64(2.) * self.a9 * r.a9 * r.a12 + T::from_f64(2.) * self.a9 * r.a10 * r.a13 - T::from_f64(2.) * self.a10 * r.a4 * r.a15 + T::from_f64(2.) * self.a10 * r.a7 * r.a14 - T::from_f64(2.) * self.a10 * r.a9 * r.a13 - T::from_f64(2.) * self.a10 * r.a10 * r.a12 - T::from_f64(2.) * self.a11 * r.a3 * r.a4 - T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) + self.a12 * r.a8.powi(2) - self.a12 * r.a9.powi(2) + self.a12 * r.a10.powi(2) + self.a12 * r.a14.powi(2) - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a1 * r.a3 - T::from_f64(2.) * self.a14 * r.a5 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a12 * r.a14 + T::from_f64(2.) * self.a15 * r.a2 * r.a8 - T::from_f64(2.) * self.a15 * r.a4 * r.a10,
+            a13: T::from_f64(2.) * self.a0 * r.a0 * r.a13 - T::from_f64(2.) * self.a0 * r.a1 * r.a10 + T::from_f64(2.) * self.a0 * r.a3 * r.a7 - T::from_f64(2.) * self.a0 * r.a4 * r.a6 - T::from_f64(2.) * self.a1 * r.a0 * r.a10 + T::from_f64(2.) * self.a1 * r.a2 * r.a14 - T::from_f64(2.) * self.a2 * r.a1 * r.a14 + T::from_f64(2.) * self.a2 * r.a2 * r.a13 - T::from_f64(2.) * self.a2 * r.a3 * r.a12 - T::from_f64(2.) * self.a2 * r.a4 * r.a11 + T::from_f64(2.) * self.a3 * r.a0 * r.a7 + T::from_f64(2.) * self.a3 * r.a2 * r.a12 + T::from_f64(2.) * self.a3 * r.a3 * r.a13 + T::from_f64(2.) * self.a3 * r.a8 * r.a15 - T::from_f64(2.) * self.a4 * r.a0 * r.a6 + T::from_f64(2.) * self.a4 * r.a2 * r.a11 + T::from_f64(2.) * self.a4 * r.a4 * r.a13 - T::from_f64(2.) * self.a4 * r.a9 * r.a15 - T::from_f64(2.) * self.a6 * r.a0 * r.a4 - T::from_f64(2.) * self.a6 * r.a8 * r.a14 + T::from_f64(2.) * self.a7 * r.a0 * r.a3 + T::from_f64(2.) * self.a7 * r.a9 * r.a14 - T::from_f64(2.) * self.a8 * r.a3 * r.a15 + T::from_f64(2.) * self.a8 * r.a6 * r.a14 - T::from_f64(2.) * self.a8 * r.a8 * r.a13 - T::from_f64(2.) * self.a8 * r.a10 * r.a11 + T::from_f64(2.) * self.a9 * r.a4 * r.a15 - T::from_f64(2.) * self.a9 * r.a7 * r.a14 - T::from_f64(2.) * self.a9 * r.a9 * r.a13 - T::from_f64(2.) * self.a9 * r.a10 * r.a12 - T::from_f64(2.) * self.a10 * r.a0 * r.a1 + T::from_f64(2.) * self.a10 * r.a8 * r.a11 + T::from_f64(2.) * self.a10 * r.a9 * r.a12 - T::from_f64(2.) * self.a10 * r.a10 * r.a13 - T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a11 * r.a8 * r.a10 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 - T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) + self.a13 * r.a8.powi(2) + self.a13 * r.a9.powi(2) - self.a13 * r.a10.powi(2) + self.a13 * r.a14.powi(2) - T::from_f64(2.) * self.a14 * r.a1 * r.a2 + T::from_f64(2.) * self.a14 * r.a6 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a13 * r.a14 - T::from_f64(2.) * self.a15 * r.a3 * r.a8 + T::from_f64(2.) * self.a15 * r.a4 * r.a9,
+            a14: T::from_f64(2.) * self.a0 * r.a0 * r.a14 + T::from_f64(2.) * self.a0 * r.a2 * r.a10 + T::from_f64(2.) * self.a0 * r.a3 * r.a9 + T::from_f64(2.) * self.a0 * r.a4 * r.a8 + T::from_f64(2.) * self.a2 * r.a0 * r.a10 + T::from_f64(2.) * self.a2 * r.a2 * r.a14 + T::from_f64(2.) * self.a3 * r.a0 * r.a9 + T::from_f64(2.) * self.a3 * r.a3 * r.a14 + T::from_f64(2.) * self.a4 * r.a0 * r.a8 + T::from_f64(2.) * self.a4 * r.a4 * r.a14 + T::from_f64(2.) * self.a8 * r.a0 * r.a4 - T::from_f64(2.) * self.a8 * r.a8 * r.a14 + T::from_f64(2.) * self.a9 * r.a0 * r.a3 - T::from_f64(2.) * self.a9 * r.a9 * r.a14 + T::from_f64(2.) * self.a10 * r.a0 * r.a2 - T::from_f64(2.) * self.a10 * r.a10 * r.a14 + self.a14 * r.a0.powi(2) + self.a14 * r.a2.powi(2) + self.a14 * r.a3.powi(2) + self.a14 * r.a4.powi(2) - self.a14 * r.a8.powi(2) - self.a14 * r.a9.powi(2) - self.a14 * r.a10.powi(2) - self.a14 * r.a14.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 + T::from_f64(2.) * self.a0 * r.a5 * r.a10 + T::from_f64(2.) * self.a0 * r.a6 * r.a9 + T::from_f64(2.) * self.a0 * r.a7 * r.a8 + T::from_f64(2.) * self.a2 * r.a2 * r.a15 + T::from_f64(2.) * self.a2 * r.a5 * r.a14 - T::from_f64(2.) * self.a2 * r.a8 * r.a12 + T::from_f64(2.) * self.a2 * r.a9 * r.a11 + T::from_f64(2.) * self.a3 * r.a3 * r.a15 + T::from_f64(2.) * self.a3 * r.a6 * r.a14 + T::from_f64(2.) * self.a3 * r.a8 * r.a13 - T::from_f64(2.) * self.a3 * r.a10 * r.a11 + T::from_f64(2.) * self.a4 * r.a4 * r.a15 + T::from_f64(2.) * self.a4 * r.a7 * r.a14 - T::from_f64(2.) * self.a4 * r.a9 * r.a13 + T::from_f64(2.) * self.a4 * r.a10 * r.a12 + T::from_f64(2.) * self.a5 * r.a0 * r.a10 - T::from_f64(2.) * self.a5 * r.a2 * r.a14 + T::from_f64(2.) * self.a6 * r.a0 * r.a9 - T::from_f64(2.) * self.a6 * r.a3 * r.a14 + T::from_f64(2.) * self.a7 * r.a0 * r.a8 - T::from_f64(2.) * self.a7 * r.a4 * r.a14 + T::from_f64(2.) * self.a8 * r.a0 * r.a7 + T::from_f64(2.) * self.a8 * r.a2 * r.a12 - T::from_f64(2.) * self.a8 * r.a3 * r.a13 - T::from_f64(2.) * self.a8 * r.a8 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a6 - T::from_f64(2.) * self.a9 * r.a2 * r.a11 + T::from_f64(2.) * self.a9 * r.a4 * r.a13 - T::from_f64(2.) * self.a9 * r.a9 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a5 + T::from_f64(2.) * self.a10 * r.a3 * r.a11 - T::from_f64(2.) * self.a10 * r.a4 * r.a12 - T::from_f64(2.) * self.a10 * r.a10 * r.a15 + T::from_f64(2.) * self.a11 * r.a2 * r.a9 - T::from_f64(2.) * self.a11 * r.a3 * r.a10 - T::from_f64(2.) * self.a12 * r.a2 * r.a8 + T::from_f64(2.) * self.a12 * r.a4 * r.a10 + T::from_f64(2.) * self.a13 * r.a3 * r.a8 - T::from_f64(2.) * self.a13 * r.a4 * r.a9 + T::from_f64(2.) * self.a14 * r.a2 * r.a5 + T::from_f64(2.) * self.a14 * r.a3 * r.a6 + T::from_f64(2.) * self.a14 * r.a4 * r.a7 - T::from_f64(2.) * self.a14 * r.a14 * r.a15 + self.a15 * r.a0.powi(2) - self.a15 * r.a2.powi(2) - self.a15 * r.a3.powi(2) - self.a15 * r.a4.powi(2) - self.a15 * r.a8.powi(2) - self.a15 * r.a9.powi(2) - self.a15 * r.a10.powi(2) + self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Float> for FullMultivector {
-    fn transform(self, r: Float) -> FullMultivector {
+impl<T: Scalar> Transform<T> for FullMultivector<T> {
+    fn transform(self, r: T) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.powi(2),
             a1: self.a1 * r.powi(2),
@@ -7508,155 +9844,391 @@ impl Transform<Float> for FullMultivector {
     }
 }
 
-impl Transform<Vector> for FullMultivector {
-    fn transform(self, r: Vector) -> FullMultivector {
+impl<T: Scalar> Transform<Vector<T>> for FullMultivector<T> {
+    fn transform(self, r: Vector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
-            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a3 * r.a1 * r.a3 + 2. * self.a4 * r.a1 * r.a4,
-            a2: self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + 2. * self.a3 * r.a2 * r.a3 + 2. * self.a4 * r.a2 * r.a4,
-            a3: 2. * self.a2 * r.a2 * r.a3 - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + 2. * self.a4 * r.a3 * r.a4,
-            a4: 2. * self.a2 * r.a2 * r.a4 + 2. * self.a3 * r.a3 * r.a4 - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2),
-            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - 2. * self.a6 * r.a2 * r.a3 - 2. * self.a7 * r.a2 * r.a4 + 2. * self.a8 * r.a1 * r.a3 - 2. * self.a9 * r.a1 * r.a4,
-            a6: -2. * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - 2. * self.a7 * r.a3 * r.a4 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a10 * r.a1 * r.a4,
-            a7: -2. * self.a5 * r.a2 * r.a4 - 2. * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + 2. * self.a9 * r.a1 * r.a2 - 2. * self.a10 * r.a1 * r.a3,
-            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + 2. * self.a9 * r.a3 * r.a4 + 2. * self.a10 * r.a2 * r.a4,
-            a9: 2. * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + 2. * self.a10 * r.a2 * r.a3,
-            a10: 2. * self.a8 * r.a2 * r.a4 + 2. * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
-            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - 2. * self.a12 * r.a3 * r.a4 - 2. * self.a13 * r.a2 * r.a4 - 2. * self.a14 * r.a1 * r.a4,
-            a12: -2. * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a14 * r.a1 * r.a3,
-            a13: -2. * self.a11 * r.a2 * r.a4 - 2. * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - 2. * self.a14 * r.a1 * r.a2,
+            a1: -self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 + T::from_f64(2.) * self.a4 * r.a1 * r.a4,
+            a2: self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) + T::from_f64(2.) * self.a3 * r.a2 * r.a3 + T::from_f64(2.) * self.a4 * r.a2 * r.a4,
+            a3: T::from_f64(2.) * self.a2 * r.a2 * r.a3 - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) + T::from_f64(2.) * self.a4 * r.a3 * r.a4,
+            a4: T::from_f64(2.) * self.a2 * r.a2 * r.a4 + T::from_f64(2.) * self.a3 * r.a3 * r.a4 - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2),
+            a5: -self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - T::from_f64(2.) * self.a6 * r.a2 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 - T::from_f64(2.) * self.a9 * r.a1 * r.a4,
+            a6: -T::from_f64(2.) * self.a5 * r.a2 * r.a3 + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - T::from_f64(2.) * self.a7 * r.a3 * r.a4 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a10 * r.a1 * r.a4,
+            a7: -T::from_f64(2.) * self.a5 * r.a2 * r.a4 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a1 * r.a2 - T::from_f64(2.) * self.a10 * r.a1 * r.a3,
+            a8: -self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a4,
+            a9: T::from_f64(2.) * self.a8 * r.a3 * r.a4 - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) + T::from_f64(2.) * self.a10 * r.a2 * r.a3,
+            a10: T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2),
+            a11: self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) - T::from_f64(2.) * self.a12 * r.a3 * r.a4 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 - T::from_f64(2.) * self.a14 * r.a1 * r.a4,
+            a12: -T::from_f64(2.) * self.a11 * r.a3 * r.a4 + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a14 * r.a1 * r.a3,
+            a13: -T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - T::from_f64(2.) * self.a14 * r.a1 * r.a2,
             a14: self.a14 * (r.a2.powi(2) + r.a3.powi(2) + r.a4.powi(2)),
             a15: self.a15 * (-r.a2.powi(2) - r.a3.powi(2) - r.a4.powi(2)),
         }
     }
 }
 
-impl Transform<Bivector> for FullMultivector {
-    fn transform(self, r: Bivector) -> FullMultivector {
+impl<T: Scalar> Transform<Bivector<T>> for FullMultivector<T> {
+    fn transform(self, r: Bivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
-            a1: self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - 2. * self.a2 * r.a6 * r.a8 + 2. * self.a2 * r.a7 * r.a9 + 2. * self.a3 * r.a5 * r.a8 - 2. * self.a3 * r.a7 * r.a10 - 2. * self.a4 * r.a5 * r.a9 + 2. * self.a4 * r.a6 * r.a10,
-            a2: -self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + 2. * self.a3 * r.a9 * r.a10 + 2. * self.a4 * r.a8 * r.a10,
-            a3: 2. * self.a2 * r.a9 * r.a10 - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + 2. * self.a4 * r.a8 * r.a9,
-            a4: 2. * self.a2 * r.a8 * r.a10 + 2. * self.a3 * r.a8 * r.a9 + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2),
-            a5: -self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + 2. * self.a6 * r.a9 * r.a10 + 2. * self.a7 * r.a8 * r.a10 + 2. * self.a8 * r.a5 * r.a8 + 2. * self.a8 * r.a7 * r.a10 + 2. * self.a9 * r.a5 * r.a9 + 2. * self.a9 * r.a6 * r.a10 + 2. * self.a10 * r.a5 * r.a10 - 2. * self.a10 * r.a6 * r.a9 - 2. * self.a10 * r.a7 * r.a8,
-            a6: 2. * self.a5 * r.a9 * r.a10 - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + 2. * self.a7 * r.a8 * r.a9 + 2. * self.a8 * r.a6 * r.a8 + 2. * self.a8 * r.a7 * r.a9 - 2. * self.a9 * r.a5 * r.a10 + 2. * self.a9 * r.a6 * r.a9 - 2. * self.a9 * r.a7 * r.a8 + 2. * self.a10 * r.a5 * r.a9 + 2. * self.a10 * r.a6 * r.a10,
-            a7: 2. * self.a5 * r.a8 * r.a10 + 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - 2. * self.a8 * r.a5 * r.a10 - 2. * self.a8 * r.a6 * r.a9 + 2. * self.a8 * r.a7 * r.a8 + 2. * self.a9 * r.a6 * r.a8 + 2. * self.a9 * r.a7 * r.a9 + 2. * self.a10 * r.a5 * r.a8 + 2. * self.a10 * r.a7 * r.a10,
-            a8: self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) + 2. * self.a9 * r.a8 * r.a9 + 2. * self.a10 * r.a8 * r.a10,
-            a9: 2. * self.a8 * r.a8 * r.a9 - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) + 2. * self.a10 * r.a9 * r.a10,
-            a10: 2. * self.a8 * r.a8 * r.a10 + 2. * self.a9 * r.a9 * r.a10 - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
-            a11: self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) + 2. * self.a12 * r.a8 * r.a9 + 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a5 * r.a9 + 2. * self.a14 * r.a6 * r.a10,
-            a12: 2. * self.a11 * r.a8 * r.a9 - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) + 2. * self.a13 * r.a9 * r.a10 + 2. * self.a14 * r.a5 * r.a8 - 2. * self.a14 * r.a7 * r.a10,
-            a13: 2. * self.a11 * r.a8 * r.a10 + 2. * self.a12 * r.a9 * r.a10 - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - 2. * self.a14 * r.a6 * r.a8 + 2. * self.a14 * r.a7 * r.a9,
+            a1: self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: -self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + T::from_f64(2.) * self.a3 * r.a9 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a10,
+            a3: T::from_f64(2.) * self.a2 * r.a9 * r.a10 - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + T::from_f64(2.) * self.a4 * r.a8 * r.a9,
+            a4: T::from_f64(2.) * self.a2 * r.a8 * r.a10 + T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2),
+            a5: -self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a9 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: T::from_f64(2.) * self.a5 * r.a9 * r.a10 - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a8 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a8 * r.a9 - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a11: self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: T::from_f64(2.) * self.a11 * r.a8 * r.a9 - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) + T::from_f64(2.) * self.a13 * r.a9 * r.a10 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9,
             a14: self.a14 * (r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
-            a15: -2. * self.a0 * r.a5 * r.a10 - 2. * self.a0 * r.a6 * r.a9 - 2. * self.a0 * r.a7 * r.a8 + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
+            a15: -T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Transform<Trivector> for FullMultivector {
-    fn transform(self, r: Trivector) -> FullMultivector {
+impl<T: Scalar> Transform<Trivector<T>> for FullMultivector<T> {
+    fn transform(self, r: Trivector<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * r.a14.powi(2),
-            a1: r.a14 * (-self.a1 * r.a14 - 2. * self.a2 * r.a13 - 2. * self.a3 * r.a12 - 2. * self.a4 * r.a11),
+            a1: r.a14 * (-self.a1 * r.a14 - T::from_f64(2.) * self.a2 * r.a13 - T::from_f64(2.) * self.a3 * r.a12 - T::from_f64(2.) * self.a4 * r.a11),
             a2: self.a2 * r.a14.powi(2),
             a3: self.a3 * r.a14.powi(2),
             a4: self.a4 * r.a14.powi(2),
-            a5: r.a14 * (-self.a5 * r.a14 + 2. * self.a8 * r.a12 - 2. * self.a9 * r.a11),
-            a6: r.a14 * (-self.a6 * r.a14 - 2. * self.a8 * r.a13 + 2. * self.a10 * r.a11),
-            a7: r.a14 * (-self.a7 * r.a14 + 2. * self.a9 * r.a13 - 2. * self.a10 * r.a12),
+            a5: r.a14 * (-self.a5 * r.a14 + T::from_f64(2.) * self.a8 * r.a12 - T::from_f64(2.) * self.a9 * r.a11),
+            a6: r.a14 * (-self.a6 * r.a14 - T::from_f64(2.) * self.a8 * r.a13 + T::from_f64(2.) * self.a10 * r.a11),
+            a7: r.a14 * (-self.a7 * r.a14 + T::from_f64(2.) * self.a9 * r.a13 - T::from_f64(2.) * self.a10 * r.a12),
             a8: self.a8 * r.a14.powi(2),
             a9: self.a9 * r.a14.powi(2),
             a10: self.a10 * r.a14.powi(2),
-            a11: r.a14 * (-self.a11 * r.a14 + 2. * self.a14 * r.a11),
-            a12: r.a14 * (-self.a12 * r.a14 + 2. * self.a14 * r.a12),
-            a13: r.a14 * (-self.a13 * r.a14 + 2. * self.a14 * r.a13),
+            a11: r.a14 * (-self.a11 * r.a14 + T::from_f64(2.) * self.a14 * r.a11),
+            a12: r.a14 * (-self.a12 * r.a14 + T::from_f64(2.) * self.a14 * r.a12),
+            a13: r.a14 * (-self.a13 * r.a14 + T::from_f64(2.) * self.a14 * r.a13),
             a14: self.a14 * r.a14.powi(2),
             a15: -self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Transform<Pseudoscalar> for FullMultivector {
-    fn transform(self, _r: Pseudoscalar) -> FullMultivector {
+impl<T: Scalar> Transform<Pseudoscalar<T>> for FullMultivector<T> {
+    fn transform(self, _r: Pseudoscalar<T>) -> FullMultivector<T> {
+        FullMultivector {
+            a0: T::zero(),
+            a1: T::zero(),
+            a2: T::zero(),
+            a3: T::zero(),
+            a4: T::zero(),
+            a5: T::zero(),
+            a6: T::zero(),
+            a7: T::zero(),
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+            a11: T::zero(),
+            a12: T::zero(),
+            a13: T::zero(),
+            a14: T::zero(),
+            a15: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> Transform<ScalarAndBivector<T>> for FullMultivector<T> {
+    fn transform(self, r: ScalarAndBivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: 0.,
-            a1: 0.,
-            a2: 0.,
-            a3: 0.,
-            a4: 0.,
-            a5: 0.,
-            a6: 0.,
-            a7: 0.,
-            a8: 0.,
-            a9: 0.,
-            a10: 0.,
-            a11: 0.,
-            a12: 0.,
-            a13: 0.,
-            a14: 0.,
-            a15: 0.,
+            a0: self.a0 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a5 - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a3 * r.a0 * r.a6 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a4 * r.a0 * r.a7 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10,
+            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + T::from_f64(2.) * self.a3 * r.a0 * r.a8 + T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a0 * r.a9 + T::from_f64(2.) * self.a4 * r.a8 * r.a10,
+            a3: -T::from_f64(2.) * self.a2 * r.a0 * r.a8 + T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + T::from_f64(2.) * self.a4 * r.a0 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a9,
+            a4: T::from_f64(2.) * self.a2 * r.a0 * r.a9 + T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2),
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10,
+            a8: self.a8 * r.a0.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a0 * r.a10 + T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a0 * r.a8 + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a0 * r.a9 + T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a8 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a0 * r.a10 + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a7 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10,
+            a12: T::from_f64(2.) * self.a11 * r.a0 * r.a10 + T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a0 * r.a8 + T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a6 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10,
+            a13: -T::from_f64(2.) * self.a11 * r.a0 * r.a9 + T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a5 - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9,
+            a14: self.a14 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
+            a15: -T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a0.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Transform<ScalarAndBivector> for FullMultivector {
-    fn transform(self, r: ScalarAndBivector) -> FullMultivector {
+impl<T: Scalar> Transform<Motor<T>> for FullMultivector<T> {
+    fn transform(self, r: Motor<T>) -> FullMultivector<T> {
         FullMultivector {
             a0: self.a0 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
-            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + 2. * self.a2 * r.a0 * r.a5 - 2. * self.a2 * r.a6 * r.a8 + 2. * self.a2 * r.a7 * r.a9 + 2. * self.a3 * r.a0 * r.a6 + 2. * self.a3 * r.a5 * r.a8 - 2. * self.a3 * r.a7 * r.a10 + 2. * self.a4 * r.a0 * r.a7 - 2. * self.a4 * r.a5 * r.a9 + 2. * self.a4 * r.a6 * r.a10,
-            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + 2. * self.a3 * r.a0 * r.a8 + 2. * self.a3 * r.a9 * r.a10 - 2. * self.a4 * r.a0 * r.a9 + 2. * self.a4 * r.a8 * r.a10,
-            a3: -2. * self.a2 * r.a0 * r.a8 + 2. * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + 2. * self.a4 * r.a0 * r.a10 + 2. * self.a4 * r.a8 * r.a9,
-            a4: 2. * self.a2 * r.a0 * r.a9 + 2. * self.a2 * r.a8 * r.a10 - 2. * self.a3 * r.a0 * r.a10 + 2. * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2),
-            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + 2. * self.a6 * r.a0 * r.a8 + 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a0 * r.a9 + 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a0 * r.a6 + 2. * self.a8 * r.a5 * r.a8 + 2. * self.a8 * r.a7 * r.a10 + 2. * self.a9 * r.a0 * r.a7 + 2. * self.a9 * r.a5 * r.a9 + 2. * self.a9 * r.a6 * r.a10 + 2. * self.a10 * r.a5 * r.a10 - 2. * self.a10 * r.a6 * r.a9 - 2. * self.a10 * r.a7 * r.a8,
-            a6: -2. * self.a5 * r.a0 * r.a8 + 2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + 2. * self.a7 * r.a0 * r.a10 + 2. * self.a7 * r.a8 * r.a9 + 2. * self.a8 * r.a0 * r.a5 + 2. * self.a8 * r.a6 * r.a8 + 2. * self.a8 * r.a7 * r.a9 - 2. * self.a9 * r.a5 * r.a10 + 2. * self.a9 * r.a6 * r.a9 - 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a0 * r.a7 + 2. * self.a10 * r.a5 * r.a9 + 2. * self.a10 * r.a6 * r.a10,
-            a7: 2. * self.a5 * r.a0 * r.a9 + 2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a0 * r.a10 + 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - 2. * self.a8 * r.a5 * r.a10 - 2. * self.a8 * r.a6 * r.a9 + 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a0 * r.a5 + 2. * self.a9 * r.a6 * r.a8 + 2. * self.a9 * r.a7 * r.a9 + 2. * self.a10 * r.a0 * r.a6 + 2. * self.a10 * r.a5 * r.a8 + 2. * self.a10 * r.a7 * r.a10,
-            a8: self.a8 * r.a0.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) - 2. * self.a9 * r.a0 * r.a10 + 2. * self.a9 * r.a8 * r.a9 + 2. * self.a10 * r.a0 * r.a9 + 2. * self.a10 * r.a8 * r.a10,
-            a9: 2. * self.a8 * r.a0 * r.a10 + 2. * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) - 2. * self.a10 * r.a0 * r.a8 + 2. * self.a10 * r.a9 * r.a10,
-            a10: -2. * self.a8 * r.a0 * r.a9 + 2. * self.a8 * r.a8 * r.a10 + 2. * self.a9 * r.a0 * r.a8 + 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
-            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - 2. * self.a12 * r.a0 * r.a10 + 2. * self.a12 * r.a8 * r.a9 + 2. * self.a13 * r.a0 * r.a9 + 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a0 * r.a7 - 2. * self.a14 * r.a5 * r.a9 + 2. * self.a14 * r.a6 * r.a10,
-            a12: 2. * self.a11 * r.a0 * r.a10 + 2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - 2. * self.a13 * r.a0 * r.a8 + 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a0 * r.a6 + 2. * self.a14 * r.a5 * r.a8 - 2. * self.a14 * r.a7 * r.a10,
-            a13: -2. * self.a11 * r.a0 * r.a9 + 2. * self.a11 * r.a8 * r.a10 + 2. * self.a12 * r.a0 * r.a8 + 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - 2. * self.a14 * r.a0 * r.a5 - 2. * self.a14 * r.a6 * r.a8 + 2. * self.a14 * r.a7 * r.a9,
+            a1: self.a1 * r.a0.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a5 - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a2 * r.a10 * r.a15 + T::from_f64(2.) * self.a3 * r.a0 * r.a6 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a3 * r.a9 * r.a15 + T::from_f64(2.) * self.a4 * r.a0 * r.a7 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a15,
+            a2: self.a2 * r.a0.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + T::from_f64(2.) * self.a3 * r.a0 * r.a8 + T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a0 * r.a9 + T::from_f64(2.) * self.a4 * r.a8 * r.a10,
+            a3: -T::from_f64(2.) * self.a2 * r.a0 * r.a8 + T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + T::from_f64(2.) * self.a4 * r.a0 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a9,
+            a4: T::from_f64(2.) * self.a2 * r.a0 * r.a9 + T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2),
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a8 * r.a9 * r.a15 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a8 * r.a15,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a10 * r.a15 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a10 * r.a9 * r.a15,
+            a8: self.a8 * r.a0.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) - T::from_f64(2.) * self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a9 + T::from_f64(2.) * self.a10 * r.a8 * r.a10,
+            a9: T::from_f64(2.) * self.a8 * r.a0 * r.a10 + T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) - T::from_f64(2.) * self.a10 * r.a0 * r.a8 + T::from_f64(2.) * self.a10 * r.a9 * r.a10,
+            a10: -T::from_f64(2.) * self.a8 * r.a0 * r.a9 + T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a8 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2),
+            a11: self.a11 * r.a0.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - T::from_f64(2.) * self.a12 * r.a0 * r.a10 + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a9 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a7 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a8 * r.a15,
+            a12: T::from_f64(2.) * self.a11 * r.a0 * r.a10 + T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - T::from_f64(2.) * self.a13 * r.a0 * r.a8 + T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a6 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a9 * r.a15,
+            a13: -T::from_f64(2.) * self.a11 * r.a0 * r.a9 + T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a0 * r.a8 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a5 - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a10 * r.a15,
             a14: self.a14 * (r.a0.powi(2) + r.a8.powi(2) + r.a9.powi(2) + r.a10.powi(2)),
-            a15: -2. * self.a0 * r.a5 * r.a10 - 2. * self.a0 * r.a6 * r.a9 - 2. * self.a0 * r.a7 * r.a8 + self.a15 * r.a0.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 - T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 + self.a15 * r.a0.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2),
         }
     }
 }
 
-impl Transform<FullMultivector> for FullMultivector {
-    fn transform(self, r: FullMultivector) -> FullMultivector {
+impl<T: Scalar> Transform<FullMultivector<T>> for FullMultivector<T> {
+    fn transform(self, r: FullMultivector<T>) -> FullMultivector<T> {
         FullMultivector {
-            a0: self.a0 * r.a0.powi(2) + self.a0 * r.a2.powi(2) + self.a0 * r.a3.powi(2) + self.a0 * r.a4.powi(2) + self.a0 * r.a8.powi(2) + self.a0 * r.a9.powi(2) + self.a0 * r.a10.powi(2) + self.a0 * r.a14.powi(2) + 2. * self.a2 * r.a0 * r.a2 - 2. * self.a2 * r.a3 * r.a8 + 2. * self.a2 * r.a4 * r.a9 + 2. * self.a2 * r.a10 * r.a14 + 2. * self.a3 * r.a0 * r.a3 + 2. * self.a3 * r.a2 * r.a8 - 2. * self.a3 * r.a4 * r.a10 + 2. * self.a3 * r.a9 * r.a14 + 2. * self.a4 * r.a0 * r.a4 - 2. * self.a4 * r.a2 * r.a9 + 2. * self.a4 * r.a3 * r.a10 + 2. * self.a4 * r.a8 * r.a14,
-            a1: 2. * self.a0 * r.a0 * r.a1 + 2. * self.a0 * r.a2 * r.a5 + 2. * self.a0 * r.a3 * r.a6 + 2. * self.a0 * r.a4 * r.a7 - 2. * self.a0 * r.a8 * r.a11 - 2. * self.a0 * r.a9 * r.a12 - 2. * self.a0 * r.a10 * r.a13 + 2. * self.a0 * r.a14 * r.a15 + self.a1 * r.a0.powi(2) - self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - self.a1 * r.a14.powi(2) + 2. * self.a2 * r.a0 * r.a5 + 2. * self.a2 * r.a1 * r.a2 + 2. * self.a2 * r.a3 * r.a11 - 2. * self.a2 * r.a4 * r.a12 - 2. * self.a2 * r.a6 * r.a8 + 2. * self.a2 * r.a7 * r.a9 + 2. * self.a2 * r.a10 * r.a15 - 2. * self.a2 * r.a13 * r.a14 + 2. * self.a3 * r.a0 * r.a6 + 2. * self.a3 * r.a1 * r.a3 - 2. * self.a3 * r.a2 * r.a11 + 2. * self.a3 * r.a4 * r.a13 + 2. * self.a3 * r.a5 * r.a8 - 2. * self.a3 * r.a7 * r.a10 + 2. * self.a3 * r.a9 * r.a15 - 2. * self.a3 * r.a12 * r.a14 + 2. * self.a4 * r.a0 * r.a7 + 2. * self.a4 * r.a1 * r.a4 + 2. * self.a4 * r.a2 * r.a12 - 2. * self.a4 * r.a3 * r.a13 - 2. * self.a4 * r.a5 * r.a9 + 2. * self.a4 * r.a6 * r.a10 + 2. * self.a4 * r.a8 * r.a15 - 2. * self.a4 * r.a11 * r.a14 + 2. * self.a15 * r.a0 * r.a14 - 2. * self.a15 * r.a2 * r.a10 - 2. * self.a15 * r.a3 * r.a9 - 2. * self.a15 * r.a4 * r.a8,
-            a2: 2. * self.a0 * r.a0 * r.a2 + 2. * self.a0 * r.a3 * r.a8 - 2. * self.a0 * r.a4 * r.a9 + 2. * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + self.a2 * r.a14.powi(2) + 2. * self.a3 * r.a0 * r.a8 + 2. * self.a3 * r.a2 * r.a3 - 2. * self.a3 * r.a4 * r.a14 + 2. * self.a3 * r.a9 * r.a10 - 2. * self.a4 * r.a0 * r.a9 + 2. * self.a4 * r.a2 * r.a4 + 2. * self.a4 * r.a3 * r.a14 + 2. * self.a4 * r.a8 * r.a10,
-            a3: 2. * self.a0 * r.a0 * r.a3 - 2. * self.a0 * r.a2 * r.a8 + 2. * self.a0 * r.a4 * r.a10 + 2. * self.a0 * r.a9 * r.a14 - 2. * self.a2 * r.a0 * r.a8 + 2. * self.a2 * r.a2 * r.a3 + 2. * self.a2 * r.a4 * r.a14 + 2. * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + self.a3 * r.a14.powi(2) + 2. * self.a4 * r.a0 * r.a10 - 2. * self.a4 * r.a2 * r.a14 + 2. * self.a4 * r.a3 * r.a4 + 2. * self.a4 * r.a8 * r.a9,
-            a4: 2. * self.a0 * r.a0 * r.a4 + 2. * self.a0 * r.a2 * r.a9 - 2. * self.a0 * r.a3 * r.a10 + 2. * self.a0 * r.a8 * r.a14 + 2. * self.a2 * r.a0 * r.a9 + 2. * self.a2 * r.a2 * r.a4 - 2. * self.a2 * r.a3 * r.a14 + 2. * self.a2 * r.a8 * r.a10 - 2. * self.a3 * r.a0 * r.a10 + 2. * self.a3 * r.a2 * r.a14 + 2. * self.a3 * r.a3 * r.a4 + 2. * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2) + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) + self.a4 * r.a14.powi(2),
-            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) - self.a5 * r.a14.powi(2) + 2. * self.a6 * r.a0 * r.a8 - 2. * self.a6 * r.a2 * r.a3 + 2. * self.a6 * r.a4 * r.a14 + 2. * self.a6 * r.a9 * r.a10 - 2. * self.a7 * r.a0 * r.a9 - 2. * self.a7 * r.a2 * r.a4 - 2. * self.a7 * r.a3 * r.a14 + 2. * self.a7 * r.a8 * r.a10 - 2. * self.a8 * r.a0 * r.a6 + 2. * self.a8 * r.a1 * r.a3 + 2. * self.a8 * r.a2 * r.a11 + 2. * self.a8 * r.a4 * r.a13 + 2. * self.a8 * r.a5 * r.a8 + 2. * self.a8 * r.a7 * r.a10 + 2. * self.a8 * r.a9 * r.a15 + 2. * self.a8 * r.a12 * r.a14 + 2. * self.a9 * r.a0 * r.a7 - 2. * self.a9 * r.a1 * r.a4 + 2. * self.a9 * r.a2 * r.a12 + 2. * self.a9 * r.a3 * r.a13 + 2. * self.a9 * r.a5 * r.a9 + 2. * self.a9 * r.a6 * r.a10 - 2. * self.a9 * r.a8 * r.a15 - 2. * self.a9 * r.a11 * r.a14 - 2. * self.a10 * r.a0 * r.a15 + 2. * self.a10 * r.a1 * r.a14 + 2. * self.a10 * r.a2 * r.a13 - 2. * self.a10 * r.a3 * r.a12 - 2. * self.a10 * r.a4 * r.a11 + 2. * self.a10 * r.a5 * r.a10 - 2. * self.a10 * r.a6 * r.a9 - 2. * self.a10 * r.a7 * r.a8 - 2. * self.a11 * r.a0 * r.a3 + 2. * self.a11 * r.a2 * r.a8 + 2. * self.a11 * r.a4 * r.a10 + 2. * self.a11 * r.a9 * r.a14 + 2. * self.a12 * r.a0 * r.a4 + 2. * self.a12 * r.a2 * r.a9 + 2. * self.a12 * r.a3 * r.a10 - 2. * self.a12 * r.a8 * r.a14 - 2. * self.a13 * r.a0 * r.a14 + 2. * self.a13 * r.a2 * r.a10 - 2. * self.a13 * r.a3 * r.a9 - 2. * self.a13 * r.a4 * r.a8 + 2. * self.a14 * r.a0 * r.a13 + 2. * self.a14 * r.a1 * r.a10 - 2. * self.a14 * r.a2 * r.a15 + 2. * self.a14 * r.a3 * r.a7 - 2. * self.a14 * r.a4 * r.a6 + 2. * self.a14 * r.a5 * r.a14 + 2. * self.a14 * r.a8 * r.a12 - 2. * self.a14 * r.a9 * r.a11,
-            a6: -2. * self.a5 * r.a0 * r.a8 - 2. * self.a5 * r.a2 * r.a3 - 2. * self.a5 * r.a4 * r.a14 + 2. * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) - self.a6 * r.a14.powi(2) + 2. * self.a7 * r.a0 * r.a10 + 2. * self.a7 * r.a2 * r.a14 - 2. * self.a7 * r.a3 * r.a4 + 2. * self.a7 * r.a8 * r.a9 + 2. * self.a8 * r.a0 * r.a5 - 2. * self.a8 * r.a1 * r.a2 + 2. * self.a8 * r.a3 * r.a11 + 2. * self.a8 * r.a4 * r.a12 + 2. * self.a8 * r.a6 * r.a8 + 2. * self.a8 * r.a7 * r.a9 - 2. * self.a8 * r.a10 * r.a15 - 2. * self.a8 * r.a13 * r.a14 - 2. * self.a9 * r.a0 * r.a15 + 2. * self.a9 * r.a1 * r.a14 - 2. * self.a9 * r.a2 * r.a13 + 2. * self.a9 * r.a3 * r.a12 - 2. * self.a9 * r.a4 * r.a11 - 2. * self.a9 * r.a5 * r.a10 + 2. * self.a9 * r.a6 * r.a9 - 2. * self.a9 * r.a7 * r.a8 - 2. * self.a10 * r.a0 * r.a7 + 2. * self.a10 * r.a1 * r.a4 + 2. * self.a10 * r.a2 * r.a12 + 2. * self.a10 * r.a3 * r.a13 + 2. * self.a10 * r.a5 * r.a9 + 2. * self.a10 * r.a6 * r.a10 + 2. * self.a10 * r.a8 * r.a15 + 2. * self.a10 * r.a11 * r.a14 + 2. * self.a11 * r.a0 * r.a2 + 2. * self.a11 * r.a3 * r.a8 + 2. * self.a11 * r.a4 * r.a9 - 2. * self.a11 * r.a10 * r.a14 - 2. * self.a12 * r.a0 * r.a14 - 2. * self.a12 * r.a2 * r.a10 + 2. * self.a12 * r.a3 * r.a9 - 2. * self.a12 * r.a4 * r.a8 - 2. * self.a13 * r.a0 * r.a4 + 2. * self.a13 * r.a2 * r.a9 + 2. * self.a13 * r.a3 * r.a10 + 2. * self.a13 * r.a8 * r.a14 + 2. * self.a14 * r.a0 * r.a12 + 2. * self.a14 * r.a1 * r.a9 - 2. * self.a14 * r.a2 * r.a7 - 2. * self.a14 * r.a3 * r.a15 + 2. * self.a14 * r.a4 * r.a5 + 2. * self.a14 * r.a6 * r.a14 - 2. * self.a14 * r.a8 * r.a13 + 2. * self.a14 * r.a10 * r.a11,
-            a7: 2. * self.a5 * r.a0 * r.a9 - 2. * self.a5 * r.a2 * r.a4 + 2. * self.a5 * r.a3 * r.a14 + 2. * self.a5 * r.a8 * r.a10 - 2. * self.a6 * r.a0 * r.a10 - 2. * self.a6 * r.a2 * r.a14 - 2. * self.a6 * r.a3 * r.a4 + 2. * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - self.a7 * r.a14.powi(2) - 2. * self.a8 * r.a0 * r.a15 + 2. * self.a8 * r.a1 * r.a14 - 2. * self.a8 * r.a2 * r.a13 - 2. * self.a8 * r.a3 * r.a12 + 2. * self.a8 * r.a4 * r.a11 - 2. * self.a8 * r.a5 * r.a10 - 2. * self.a8 * r.a6 * r.a9 + 2. * self.a8 * r.a7 * r.a8 - 2. * self.a9 * r.a0 * r.a5 + 2. * self.a9 * r.a1 * r.a2 + 2. * self.a9 * r.a3 * r.a11 + 2. * self.a9 * r.a4 * r.a12 + 2. * self.a9 * r.a6 * r.a8 + 2. * self.a9 * r.a7 * r.a9 + 2. * self.a9 * r.a10 * r.a15 + 2. * self.a9 * r.a13 * r.a14 + 2. * self.a10 * r.a0 * r.a6 - 2. * self.a10 * r.a1 * r.a3 + 2. * self.a10 * r.a2 * r.a11 + 2. * self.a10 * r.a4 * r.a13 + 2. * self.a10 * r.a5 * r.a8 + 2. * self.a10 * r.a7 * r.a10 - 2. * self.a10 * r.a9 * r.a15 - 2. * self.a10 * r.a12 * r.a14 - 2. * self.a11 * r.a0 * r.a14 - 2. * self.a11 * r.a2 * r.a10 - 2. * self.a11 * r.a3 * r.a9 + 2. * self.a11 * r.a4 * r.a8 - 2. * self.a12 * r.a0 * r.a2 + 2. * self.a12 * r.a3 * r.a8 + 2. * self.a12 * r.a4 * r.a9 + 2. * self.a12 * r.a10 * r.a14 + 2. * self.a13 * r.a0 * r.a3 + 2. * self.a13 * r.a2 * r.a8 + 2. * self.a13 * r.a4 * r.a10 - 2. * self.a13 * r.a9 * r.a14 + 2. * self.a14 * r.a0 * r.a11 + 2. * self.a14 * r.a1 * r.a8 + 2. * self.a14 * r.a2 * r.a6 - 2. * self.a14 * r.a3 * r.a5 - 2. * self.a14 * r.a4 * r.a15 + 2. * self.a14 * r.a7 * r.a14 + 2. * self.a14 * r.a9 * r.a13 - 2. * self.a14 * r.a10 * r.a12,
-            a8: self.a8 * r.a0.powi(2) - self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) + self.a8 * r.a14.powi(2) - 2. * self.a9 * r.a0 * r.a10 + 2. * self.a9 * r.a2 * r.a14 + 2. * self.a9 * r.a3 * r.a4 + 2. * self.a9 * r.a8 * r.a9 + 2. * self.a10 * r.a0 * r.a9 + 2. * self.a10 * r.a2 * r.a4 - 2. * self.a10 * r.a3 * r.a14 + 2. * self.a10 * r.a8 * r.a10 + 2. * self.a14 * r.a0 * r.a4 + 2. * self.a14 * r.a2 * r.a9 - 2. * self.a14 * r.a3 * r.a10 + 2. * self.a14 * r.a8 * r.a14,
-            a9: 2. * self.a8 * r.a0 * r.a10 - 2. * self.a8 * r.a2 * r.a14 + 2. * self.a8 * r.a3 * r.a4 + 2. * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) + self.a9 * r.a14.powi(2) - 2. * self.a10 * r.a0 * r.a8 + 2. * self.a10 * r.a2 * r.a3 + 2. * self.a10 * r.a4 * r.a14 + 2. * self.a10 * r.a9 * r.a10 + 2. * self.a14 * r.a0 * r.a3 - 2. * self.a14 * r.a2 * r.a8 + 2. * self.a14 * r.a4 * r.a10 + 2. * self.a14 * r.a9 * r.a14,
-            a10: -2. * self.a8 * r.a0 * r.a9 + 2. * self.a8 * r.a2 * r.a4 + 2. * self.a8 * r.a3 * r.a14 + 2. * self.a8 * r.a8 * r.a10 + 2. * self.a9 * r.a0 * r.a8 + 2. * self.a9 * r.a2 * r.a3 - 2. * self.a9 * r.a4 * r.a14 + 2. * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2) + self.a10 * r.a14.powi(2) + 2. * self.a14 * r.a0 * r.a2 + 2. * self.a14 * r.a3 * r.a8 - 2. * self.a14 * r.a4 * r.a9 + 2. * self.a14 * r.a10 * r.a14,
-            a11: -2. * self.a5 * r.a0 * r.a3 - 2. * self.a5 * r.a2 * r.a8 - 2. * self.a5 * r.a4 * r.a10 + 2. * self.a5 * r.a9 * r.a14 + 2. * self.a6 * r.a0 * r.a2 - 2. * self.a6 * r.a3 * r.a8 - 2. * self.a6 * r.a4 * r.a9 - 2. * self.a6 * r.a10 * r.a14 + 2. * self.a7 * r.a0 * r.a14 + 2. * self.a7 * r.a2 * r.a10 + 2. * self.a7 * r.a3 * r.a9 - 2. * self.a7 * r.a4 * r.a8 - 2. * self.a8 * r.a0 * r.a1 + 2. * self.a8 * r.a2 * r.a5 + 2. * self.a8 * r.a3 * r.a6 - 2. * self.a8 * r.a4 * r.a7 + 2. * self.a8 * r.a8 * r.a11 - 2. * self.a8 * r.a9 * r.a12 - 2. * self.a8 * r.a10 * r.a13 - 2. * self.a8 * r.a14 * r.a15 - 2. * self.a9 * r.a0 * r.a13 + 2. * self.a9 * r.a1 * r.a10 - 2. * self.a9 * r.a2 * r.a15 - 2. * self.a9 * r.a3 * r.a7 - 2. * self.a9 * r.a4 * r.a6 - 2. * self.a9 * r.a5 * r.a14 + 2. * self.a9 * r.a8 * r.a12 + 2. * self.a9 * r.a9 * r.a11 + 2. * self.a10 * r.a0 * r.a12 - 2. * self.a10 * r.a1 * r.a9 - 2. * self.a10 * r.a2 * r.a7 + 2. * self.a10 * r.a3 * r.a15 - 2. * self.a10 * r.a4 * r.a5 + 2. * self.a10 * r.a6 * r.a14 + 2. * self.a10 * r.a8 * r.a13 + 2. * self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - self.a11 * r.a14.powi(2) - 2. * self.a12 * r.a0 * r.a10 - 2. * self.a12 * r.a2 * r.a14 - 2. * self.a12 * r.a3 * r.a4 + 2. * self.a12 * r.a8 * r.a9 + 2. * self.a13 * r.a0 * r.a9 - 2. * self.a13 * r.a2 * r.a4 + 2. * self.a13 * r.a3 * r.a14 + 2. * self.a13 * r.a8 * r.a10 - 2. * self.a14 * r.a0 * r.a7 - 2. * self.a14 * r.a1 * r.a4 + 2. * self.a14 * r.a2 * r.a12 - 2. * self.a14 * r.a3 * r.a13 - 2. * self.a14 * r.a5 * r.a9 + 2. * self.a14 * r.a6 * r.a10 - 2. * self.a14 * r.a8 * r.a15 + 2. * self.a14 * r.a11 * r.a14,
-            a12: 2. * self.a5 * r.a0 * r.a4 - 2. * self.a5 * r.a2 * r.a9 - 2. * self.a5 * r.a3 * r.a10 - 2. * self.a5 * r.a8 * r.a14 + 2. * self.a6 * r.a0 * r.a14 + 2. * self.a6 * r.a2 * r.a10 - 2. * self.a6 * r.a3 * r.a9 + 2. * self.a6 * r.a4 * r.a8 - 2. * self.a7 * r.a0 * r.a2 - 2. * self.a7 * r.a3 * r.a8 - 2. * self.a7 * r.a4 * r.a9 + 2. * self.a7 * r.a10 * r.a14 + 2. * self.a8 * r.a0 * r.a13 - 2. * self.a8 * r.a1 * r.a10 + 2. * self.a8 * r.a2 * r.a15 - 2. * self.a8 * r.a3 * r.a7 - 2. * self.a8 * r.a4 * r.a6 + 2. * self.a8 * r.a5 * r.a14 + 2. * self.a8 * r.a8 * r.a12 + 2. * self.a8 * r.a9 * r.a11 - 2. * self.a9 * r.a0 * r.a1 + 2. * self.a9 * r.a2 * r.a5 - 2. * self.a9 * r.a3 * r.a6 + 2. * self.a9 * r.a4 * r.a7 - 2. * self.a9 * r.a8 * r.a11 + 2. * self.a9 * r.a9 * r.a12 - 2. * self.a9 * r.a10 * r.a13 - 2. * self.a9 * r.a14 * r.a15 - 2. * self.a10 * r.a0 * r.a11 + 2. * self.a10 * r.a1 * r.a8 - 2. * self.a10 * r.a2 * r.a6 - 2. * self.a10 * r.a3 * r.a5 - 2. * self.a10 * r.a4 * r.a15 - 2. * self.a10 * r.a7 * r.a14 + 2. * self.a10 * r.a9 * r.a13 + 2. * self.a10 * r.a10 * r.a12 + 2. * self.a11 * r.a0 * r.a10 + 2. * self.a11 * r.a2 * r.a14 - 2. * self.a11 * r.a3 * r.a4 + 2. * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - self.a12 * r.a14.powi(2) - 2. * self.a13 * r.a0 * r.a8 - 2. * self.a13 * r.a2 * r.a3 - 2. * self.a13 * r.a4 * r.a14 + 2. * self.a13 * r.a9 * r.a10 - 2. * self.a14 * r.a0 * r.a6 - 2. * self.a14 * r.a1 * r.a3 - 2. * self.a14 * r.a2 * r.a11 + 2. * self.a14 * r.a4 * r.a13 + 2. * self.a14 * r.a5 * r.a8 - 2. * self.a14 * r.a7 * r.a10 - 2. * self.a14 * r.a9 * r.a15 + 2. * self.a14 * r.a12 * r.a14,
-            a13: 2. * self.a5 * r.a0 * r.a14 - 2. * self.a5 * r.a2 * r.a10 + 2. * self.a5 * r.a3 * r.a9 + 2. * self.a5 * r.a4 * r.a8 - 2. * self.a6 * r.a0 * r.a4 - 2. * self.a6 * r.a2 * r.a9 - 2. * self.a6 * r.a3 * r.a10 + 2. * self.a6 * r.a8 * r.a14 + 2. * self.a7 * r.a0 * r.a3 - 2. * self.a7 * r.a2 * r.a8 - 2. * self.a7 * r.a4 * r.a10 - 2. * self.a7 * r.a9 * r.a14 - 2. * self.a8 * r.a0 * r.a12 + 2. * self.a8 * r.a1 * r.a9 - 2. * self.a8 * r.a2 * r.a7 - 2. * self.a8 * r.a3 * r.a15 - 2. * self.a8 * r.a4 * r.a5 - 2. * self.a8 * r.a6 * r.a14 + 2. * self.a8 * r.a8 * r.a13 + 2. * self.a8 * r.a10 * r.a11 + 2. * self.a9 * r.a0 * r.a11 - 2. * self.a9 * r.a1 * r.a8 - 2. * self.a9 * r.a2 * r.a6 - 2. * self.a9 * r.a3 * r.a5 + 2. * self.a9 * r.a4 * r.a15 + 2. * self.a9 * r.a7 * r.a14 + 2. * self.a9 * r.a9 * r.a13 + 2. * self.a9 * r.a10 * r.a12 - 2. * self.a10 * r.a0 * r.a1 - 2. * self.a10 * r.a2 * r.a5 + 2. * self.a10 * r.a3 * r.a6 + 2. * self.a10 * r.a4 * r.a7 - 2. * self.a10 * r.a8 * r.a11 - 2. * self.a10 * r.a9 * r.a12 + 2. * self.a10 * r.a10 * r.a13 - 2. * self.a10 * r.a14 * r.a15 - 2. * self.a11 * r.a0 * r.a9 - 2. * self.a11 * r.a2 * r.a4 - 2. * self.a11 * r.a3 * r.a14 + 2. * self.a11 * r.a8 * r.a10 + 2. * self.a12 * r.a0 * r.a8 - 2. * self.a12 * r.a2 * r.a3 + 2. * self.a12 * r.a4 * r.a14 + 2. * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - self.a13 * r.a14.powi(2) - 2. * self.a14 * r.a0 * r.a5 - 2. * self.a14 * r.a1 * r.a2 + 2. * self.a14 * r.a3 * r.a11 - 2. * self.a14 * r.a4 * r.a12 - 2. * self.a14 * r.a6 * r.a8 + 2. * self.a14 * r.a7 * r.a9 - 2. * self.a14 * r.a10 * r.a15 + 2. * self.a14 * r.a13 * r.a14,
-            a14: 2. * self.a8 * r.a0 * r.a4 - 2. * self.a8 * r.a2 * r.a9 + 2. * self.a8 * r.a3 * r.a10 + 2. * self.a8 * r.a8 * r.a14 + 2. * self.a9 * r.a0 * r.a3 + 2. * self.a9 * r.a2 * r.a8 - 2. * self.a9 * r.a4 * r.a10 + 2. * self.a9 * r.a9 * r.a14 + 2. * self.a10 * r.a0 * r.a2 - 2. * self.a10 * r.a3 * r.a8 + 2. * self.a10 * r.a4 * r.a9 + 2. * self.a10 * r.a10 * r.a14 + self.a14 * r.a0.powi(2) + self.a14 * r.a2.powi(2) + self.a14 * r.a3.powi(2) + self.a14 * r.a4.powi(2) + self.a14 * r.a8.powi(2) + self.a14 * r.a9.powi(2) + self.a14 * r.a10.powi(2) + self.a14 * r.a14.powi(2),
-            a15: 2. * self.a0 * r.a0 * r.a15 - 2. * self.a0 * r.a1 * r.a14 - 2. * self.a0 * r.a2 * r.a13 - 2. * self.a0 * r.a3 * r.a12 - 2. * self.a0 * r.a4 * r.a11 - 2. * self.a0 * r.a5 * r.a10 - 2. * self.a0 * r.a6 * r.a9 - 2. * self.a0 * r.a7 * r.a8 - 2. * self.a1 * r.a0 * r.a14 + 2. * self.a1 * r.a2 * r.a10 + 2. * self.a1 * r.a3 * r.a9 + 2. * self.a1 * r.a4 * r.a8 - 2. * self.a2 * r.a0 * r.a13 - 2. * self.a2 * r.a1 * r.a10 + 2. * self.a2 * r.a2 * r.a15 + 2. * self.a2 * r.a3 * r.a7 - 2. * self.a2 * r.a4 * r.a6 - 2. * self.a2 * r.a5 * r.a14 + 2. * self.a2 * r.a8 * r.a12 - 2. * self.a2 * r.a9 * r.a11 - 2. * self.a3 * r.a0 * r.a12 - 2. * self.a3 * r.a1 * r.a9 - 2. * self.a3 * r.a2 * r.a7 + 2. * self.a3 * r.a3 * r.a15 + 2. * self.a3 * r.a4 * r.a5 - 2. * self.a3 * r.a6 * r.a14 - 2. * self.a3 * r.a8 * r.a13 + 2. * self.a3 * r.a10 * r.a11 - 2. * self.a4 * r.a0 * r.a11 - 2. * self.a4 * r.a1 * r.a8 + 2. * self.a4 * r.a2 * r.a6 - 2. * self.a4 * r.a3 * r.a5 + 2. * self.a4 * r.a4 * r.a15 - 2. * self.a4 * r.a7 * r.a14 + 2. * self.a4 * r.a9 * r.a13 - 2. * self.a4 * r.a10 * r.a12 + self.a15 * r.a0.powi(2) - self.a15 * r.a2.powi(2) - self.a15 * r.a3.powi(2) - self.a15 * r.a4.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2) - self.a15 * r.a14.powi(2),
+            a0: self.a0 * r.a0.powi(2) + self.a0 * r.a2.powi(2) + self.a0 * r.a3.powi(2) + self.a0 * r.a4.powi(2) + self.a0 * r.a8.powi(2) + self.a0 * r.a9.powi(2) + self.a0 * r.a10.powi(2) + self.a0 * r.a14.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a2 - T::from_f64(2.) * self.a2 * r.a3 * r.a8 + T::from_f64(2.) * self.a2 * r.a4 * r.a9 + T::from_f64(2.) * self.a2 * r.a10 * r.a14 + T::from_f64(2.) * self.a3 * r.a0 * r.a3 + T::from_f64(2.) * self.a3 * r.a2 * r.a8 - T::from_f64(2.) * self.a3 * r.a4 * r.a10 + T::from_f64(2.) * self.a3 * r.a9 * r.a14 + T::from_f64(2.) * self.a4 * r.a0 * r.a4 - T::from_f64(2.) * self.a4 * r.a2 * r.a9 + T::from_f64(2.) * self.a4 * r.a3 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a14,
+            a1: T::from_f64(2.) * self.a0 * r.a0 * r.a1 + T::from_f64(2.) * self.a0 * r.a2 * r.a5 + T::from_f64(2.) * self.a0 * r.a3 * r.a6 + T::from_f64(2.) * self.a0 * r.a4 * r.a7 - T::from_f64(2.) * self.a0 * r.a8 * r.a11 - T::from_f64(2.) * self.a0 * r.a9 * r.a12 - T::from_f64(2.) * self.a0 * r.a10 * r.a13 + T::from_f64(2.) * self.a0 * r.a14 * r.a15 + self.a1 * r.a0.powi(2) - self.a1 * r.a2.powi(2) - self.a1 * r.a3.powi(2) - self.a1 * r.a4.powi(2) + self.a1 * r.a8.powi(2) + self.a1 * r.a9.powi(2) + self.a1 * r.a10.powi(2) - self.a1 * r.a14.powi(2) + T::from_f64(2.) * self.a2 * r.a0 * r.a5 + T::from_f64(2.) * self.a2 * r.a1 * r.a2 + T::from_f64(2.) * self.a2 * r.a3 * r.a11 - T::from_f64(2.) * self.a2 * r.a4 * r.a12 - T::from_f64(2.) * self.a2 * r.a6 * r.a8 + T::from_f64(2.) * self.a2 * r.a7 * r.a9 + T::from_f64(2.) * self.a2 * r.a10 * r.a15 - T::from_f64(2.) * self.a2 * r.a13 * r.a14 + T::from_f64(2.) * self.a3 * r.a0 * r.a6 + T::from_f64(2.) * self.a3 * r.a1 * r.a3 - T::from_f64(2.) * self.a3 * r.a2 * r.a11 + T::from_f64(2.) * self.a3 * r.a4 * r.a13 + T::from_f64(2.) * self.a3 * r.a5 * r.a8 - T::from_f64(2.) * self.a3 * r.a7 * r.a10 + T::from_f64(2.) * self.a3 * r.a9 * r.a15 - T::from_f64(2.) * self.a3 * r.a12 * r.a14 + T::from_f64(2.) * self.a4 * r.a0 * r.a7 + T::from_f64(2.) * self.a4 * r.a1 * r.a4 + T::from_f64(2.) * self.a4 * r.a2 * r.a12 - T::from_f64(2.) * self.a4 * r.a3 * r.a13 - T::from_f64(2.) * self.a4 * r.a5 * r.a9 + T::from_f64(2.) * self.a4 * r.a6 * r.a10 + T::from_f64(2.) * self.a4 * r.a8 * r.a15 - T::from_f64(2.) * self.a4 * r.a11 * r.a14 + T::from_f64(2.) * self.a15 * r.a0 * r.a14 - T::from_f64(2.) * self.a15 * r.a2 * r.a10 - T::from_f64(2.) * self.a15 * r.a3 * r.a9 - T::from_f64(2.) * self.a15 * r.a4 * r.a8,
+            a2: T::from_f64(2.) * self.a0 * r.a0 * r.a2 + T::from_f64(2.) * self.a0 * r.a3 * r.a8 - T::from_f64(2.) * self.a0 * r.a4 * r.a9 + T::from_f64(2.) * self.a0 * r.a10 * r.a14 + self.a2 * r.a0.powi(2) + self.a2 * r.a2.powi(2) - self.a2 * r.a3.powi(2) - self.a2 * r.a4.powi(2) - self.a2 * r.a8.powi(2) - self.a2 * r.a9.powi(2) + self.a2 * r.a10.powi(2) + self.a2 * r.a14.powi(2) + T::from_f64(2.) * self.a3 * r.a0 * r.a8 + T::from_f64(2.) * self.a3 * r.a2 * r.a3 - T::from_f64(2.) * self.a3 * r.a4 * r.a14 + T::from_f64(2.) * self.a3 * r.a9 * r.a10 - T::from_f64(2.) * self.a4 * r.a0 * r.a9 + T::from_f64(2.) * self.a4 * r.a2 * r.a4 + T::from_f64(2.) * self.a4 * r.a3 * r.a14 + T::from_f64(2.) * self.a4 * r.a8 * r.a10,
+            a3: T::from_f64(2.) * self.a0 * r.a0 * r.a3 - T::from_f64(2.) * self.a0 * r.a2 * r.a8 + T::from_f64(2.) * self.a0 * r.a4 * r.a10 + T::from_f64(2.) * self.a0 * r.a9 * r.a14 - T::from_f64(2.) * self.a2 * r.a0 * r.a8 + T::from_f64(2.) * self.a2 * r.a2 * r.a3 + T::from_f64(2.) * self.a2 * r.a4 * r.a14 + T::from_f64(2.) * self.a2 * r.a9 * r.a10 + self.a3 * r.a0.powi(2) - self.a3 * r.a2.powi(2) + self.a3 * r.a3.powi(2) - self.a3 * r.a4.powi(2) - self.a3 * r.a8.powi(2) + self.a3 * r.a9.powi(2) - self.a3 * r.a10.powi(2) + self.a3 * r.a14.powi(2) + T::from_f64(2.) * self.a4 * r.a0 * r.a10 - T::from_f64(2.) * self.a4 * r.a2 * r.a14 + T::from_f64(2.) * self.a4 * r.a3 * r.a4 + T::from_f64(2.) * self.a4 * r.a8 * r.a9,
+            a4: T::from_f64(2.) * self.a0 * r.a0 * r.a4 + T::from_f64(2.) * self.a0 * r.a2 * r.a9 - T::from_f64(2.) * self.a0 * r.a3 * r.a10 + T::from_f64(2.) * self.a0 * r.a8 * r.a14 + T::from_f64(2.) * self.a2 * r.a0 * r.a9 + T::from_f64(2.) * self.a2 * r.a2 * r.a4 - T::from_f64(2.) * self.a2 * r.a3 * r.a14 + T::from_f64(2.) * self.a2 * r.a8 * r.a10 - T::from_f64(2.) * self.a3 * r.a0 * r.a10 + T::from_f64(2.) * self.a3 * r.a2 * r.a14 + T::from_f64(2.) * self.a3 * r.a3 * r.a4 + T::from_f64(2.) * self.a3 * r.a8 * r.a9 + self.a4 * r.a0.powi(2) - self.a4 * r.a2.powi(2) - self.a4 * r.a3.powi(2) + self.a4 * r.a4.powi(2) + self.a4 * r.a8.powi(2) - self.a4 * r.a9.powi(2) - self.a4 * r.a10.powi(2) + self.a4 * r.a14.powi(2),
+            a5: self.a5 * r.a0.powi(2) - self.a5 * r.a2.powi(2) + self.a5 * r.a3.powi(2) + self.a5 * r.a4.powi(2) - self.a5 * r.a8.powi(2) - self.a5 * r.a9.powi(2) + self.a5 * r.a10.powi(2) - self.a5 * r.a14.powi(2) + T::from_f64(2.) * self.a6 * r.a0 * r.a8 - T::from_f64(2.) * self.a6 * r.a2 * r.a3 + T::from_f64(2.) * self.a6 * r.a4 * r.a14 + T::from_f64(2.) * self.a6 * r.a9 * r.a10 - T::from_f64(2.) * self.a7 * r.a0 * r.a9 - T::from_f64(2.) * self.a7 * r.a2 * r.a4 - T::from_f64(2.) * self.a7 * r.a3 * r.a14 + T::from_f64(2.) * self.a7 * r.a8 * r.a10 - T::from_f64(2.) * self.a8 * r.a0 * r.a6 + T::from_f64(2.) * self.a8 * r.a1 * r.a3 + T::from_f64(2.) * self.a8 * r.a2 * r.a11 + T::from_f64(2.) * self.a8 * r.a4 * r.a13 + T::from_f64(2.) * self.a8 * r.a5 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a10 + T::from_f64(2.) * self.a8 * r.a9 * r.a15 + T::from_f64(2.) * self.a8 * r.a12 * r.a14 + T::from_f64(2.) * self.a9 * r.a0 * r.a7 - T::from_f64(2.) * self.a9 * r.a1 * r.a4 + T::from_f64(2.) * self.a9 * r.a2 * r.a12 + T::from_f64(2.) * self.a9 * r.a3 * r.a13 + T::from_f64(2.) * self.a9 * r.a5 * r.a9 + T::from_f64(2.) * self.a9 * r.a6 * r.a10 - T::from_f64(2.) * self.a9 * r.a8 * r.a15 - T::from_f64(2.) * self.a9 * r.a11 * r.a14 - T::from_f64(2.) * self.a10 * r.a0 * r.a15 + T::from_f64(2.) * self.a10 * r.a1 * r.a14 + T::from_f64(2.) * self.a10 * r.a2 * r.a13 - T::from_f64(2.) * self.a10 * r.a3 * r.a12 - T::from_f64(2.) * self.a10 * r.a4 * r.a11 + T::from_f64(2.) * self.a10 * r.a5 * r.a10 - T::from_f64(2.) * self.a10 * r.a6 * r.a9 - T::from_f64(2.) * self.a10 * r.a7 * r.a8 - T::from_f64(2.) * self.a11 * r.a0 * r.a3 + T::from_f64(2.) * self.a11 * r.a2 * r.a8 + T::from_f64(2.) * self.a11 * r.a4 * r.a10 + T::from_f64(2.) * self.a11 * r.a9 * r.a14 + T::from_f64(2.) * self.a12 * r.a0 * r.a4 + T::from_f64(2.) * self.a12 * r.a2 * r.a9 + T::from_f64(2.) * self.a12 * r.a3 * r.a10 - T::from_f64(2.) * self.a12 * r.a8 * r.a14 - T::from_f64(2.) * self.a13 * r.a0 * r.a14 + T::from_f64(2.) * self.a13 * r.a2 * r.a10 - T::from_f64(2.) * self.a13 * r.a3 * r.a9 - T::from_f64(2.) * self.a13 * r.a4 * r.a8 + T::from_f64(2.) * self.a14 * r.a0 * r.a13 + T::from_f64(2.) * self.a14 * r.a1 * r.a10 - T::from_f64(2.) * self.a14 * r.a2 * r.a15 + T::from_f64(2.) * self.a14 * r.a3 * r.a7 - T::from_f64(2.) * self.a14 * r.a4 * r.a6 + T::from_f64(2.) * self.a14 * r.a5 * r.a14 + T::from_f64(2.) * self.a14 * r.a8 * r.a12 - T::from_f64(2.) * self.a14 * r.a9 * r.a11,
+            a6: -T::from_f64(2.) * self.a5 * r.a0 * r.a8 - T::from_f64(2.) * self.a5 * r.a2 * r.a3 - T::from_f64(2.) * self.a5 * r.a4 * r.a14 + T::from_f64(2.) * self.a5 * r.a9 * r.a10 + self.a6 * r.a0.powi(2) + self.a6 * r.a2.powi(2) - self.a6 * r.a3.powi(2) + self.a6 * r.a4.powi(2) - self.a6 * r.a8.powi(2) + self.a6 * r.a9.powi(2) - self.a6 * r.a10.powi(2) - self.a6 * r.a14.powi(2) + T::from_f64(2.) * self.a7 * r.a0 * r.a10 + T::from_f64(2.) * self.a7 * r.a2 * r.a14 - T::from_f64(2.) * self.a7 * r.a3 * r.a4 + T::from_f64(2.) * self.a7 * r.a8 * r.a9 + T::from_f64(2.) * self.a8 * r.a0 * r.a5 - T::from_f64(2.) * self.a8 * r.a1 * r.a2 + T::from_f64(2.) * self.a8 * r.a3 * r.a11 + T::from_f64(2.) * self.a8 * r.a4 * r.a12 + T::from_f64(2.) * self.a8 * r.a6 * r.a8 + T::from_f64(2.) * self.a8 * r.a7 * r.a9 - T::from_f64(2.) * self.a8 * r.a10 * r.a15 - T::from_f64(2.) * self.a8 * r.a13 * r.a14 - T::from_f64(2.) * self.a9 * r.a0 * r.a15 + T::from_f64(2.) * self.a9 * r.a1 * r.a14 - T::from_f64(2.) * self.a9 * r.a2 * r.a13 + T::from_f64(2.) * self.a9 * r.a3 * r.a12 - T::from_f64(2.) * self.a9 * r.a4 * r.a11 - T::from_f64(2.) * self.a9 * r.a5 * r.a10 + T::from_f64(2.) * self.a9 * r.a6 * r.a9 - T::from_f64(2.) * self.a9 * r.a7 * r.a8 - T::from_f64(2.) * self.a10 * r.a0 * r.a7 + T::from_f64(2.) * self.a10 * r.a1 * r.a4 + T::from_f64(2.) * self.a10 * r.a2 * r.a12 + T::from_f64(2.) * self.a10 * r.a3 * r.a13 + T::from_f64(2.) * self.a10 * r.a5 * r.a9 + T::from_f64(2.) * self.a10 * r.a6 * r.a10 + T::from_f64(2.) * self.a10 * r.a8 * r.a15 + T::from_f64(2.) * self.a10 * r.a11 * r.a14 + T::from_f64(2.) * self.a11 * r.a0 * r.a2 + T::from_f64(2.) * self.a11 * r.a3 * r.a8 + T::from_f64(2.) * self.a11 * r.a4 * r.a9 - T::from_f64(2.) * self.a11 * r.a10 * r.a14 - T::from_f64(2.) * self.a12 * r.a0 * r.a14 - T::from_f64(2.) * self.a12 * r.a2 * r.a10 + T::from_f64(2.) * self.a12 * r.a3 * r.a9 - T::from_f64(2.) * self.a12 * r.a4 * r.a8 - T::from_f64(2.) * self.a13 * r.a0 * r.a4 + T::from_f64(2.) * self.a13 * r.a2 * r.a9 + T::from_f64(2.) * self.a13 * r.a3 * r.a10 + T::from_f64(2.) * self.a13 * r.a8 * r.a14 + T::from_f64(2.) * self.a14 * r.a0 * r.a12 + T::from_f64(2.) * self.a14 * r.a1 * r.a9 - T::from_f64(2.) * self.a14 * r.a2 * r.a7 - T::from_f64(2.) * self.a14 * r.a3 * r.a15 + T::from_f64(2.) * self.a14 * r.a4 * r.a5 + T::from_f64(2.) * self.a14 * r.a6 * r.a14 - T::from_f64(2.) * self.a14 * r.a8 * r.a13 + T::from_f64(2.) * self.a14 * r.a10 * r.a11,
+            a7: T::from_f64(2.) * self.a5 * r.a0 * r.a9 - T::from_f64(2.) * self.a5 * r.a2 * r.a4 + T::from_f64(2.) * self.a5 * r.a3 * r.a14 + T::from_f64(2.) * self.a5 * r.a8 * r.a10 - T::from_f64(2.) * self.a6 * r.a0 * r.a10 - T::from_f64(2.) * self.a6 * r.a2 * r.a14 - T::from_f64(2.) * self.a6 * r.a3 * r.a4 + T::from_f64(2.) * self.a6 * r.a8 * r.a9 + self.a7 * r.a0.powi(2) + self.a7 * r.a2.powi(2) + self.a7 * r.a3.powi(2) - self.a7 * r.a4.powi(2) + self.a7 * r.a8.powi(2) - self.a7 * r.a9.powi(2) - self.a7 * r.a10.powi(2) - self.a7 * r.a14.powi(2) - T::from_f64(2.) * self.a8 * r.a0 * r.a15 + T::from_f64(2.) * self.a8 * r.a1 * r.a14 - T::from_f64(2.) * self.a8 * r.a2 * r.a13 - T::from_f64(2.) * self.a8 * r.a3 * r.a12 + T::from_f64(2.) * self.a8 * r.a4 * r.a11 - T::from_f64(2.) * self.a8 * r.a5 * r.a10 - T::from_f64(2.) * self.a8 * r.a6 * r.a9 + T::from_f64(2.) * self.a8 * r.a7 * r.a8 - T::from_f64(2.) * self.a9 * r.a0 * r.a5 + T::from_f64(2.) * self.a9 * r.a1 * r.a2 + T::from_f64(2.) * self.a9 * r.a3 * r.a11 + T::from_f64(2.) * self.a9 * r.a4 * r.a12 + T::from_f64(2.) * self.a9 * r.a6 * r.a8 + T::from_f64(2.) * self.a9 * r.a7 * r.a9 + T::from_f64(2.) * self.a9 * r.a10 * r.a15 + T::from_f64(2.) * self.a9 * r.a13 * r.a14 + T::from_f64(2.) * self.a10 * r.a0 * r.a6 - T::from_f64(2.) * self.a10 * r.a1 * r.a3 + T::from_f64(2.) * self.a10 * r.a2 * r.a11 + T::from_f64(2.) * self.a10 * r.a4 * r.a13 + T::from_f64(2.) * self.a10 * r.a5 * r.a8 + T::from_f64(2.) * self.a10 * r.a7 * r.a10 - T::from_f64(2.) * self.a10 * r.a9 * r.a15 - T::from_f64(2.) * self.a10 * r.a12 * r.a14 - T::from_f64(2.) * self.a11 * r.a0 * r.a14 - T::from_f64(2.) * self.a11 * r.a2 * r.a10 - T::from_f64(2.) * self.a11 * r.a3 * r.a9 + T::from_f64(2.) * self.a11 * r.a4 * r.a8 - T::from_f64(2.) * self.a12 * r.a0 * r.a2 + T::from_f64(2.) * self.a12 * r.a3 * r.a8 + T::from_f64(2.) * self.a12 * r.a4 * r.a9 + T::from_f64(2.) * self.a12 * r.a10 * r.a14 + T::from_f64(2.) * self.a13 * r.a0 * r.a3 + T::from_f64(2.) * self.a13 * r.a2 * r.a8 + T::from_f64(2.) * self.a13 * r.a4 * r.a10 - T::from_f64(2.) * self.a13 * r.a9 * r.a14 + T::from_f64(2.) * self.a14 * r.a0 * r.a11 + T::from_f64(2.) * self.a14 * r.a1 * r.a8 + T::from_f64(2.) * self.a14 * r.a2 * r.a6 - T::from_f64(2.) * self.a14 * r.a3 * r.a5 - T::from_f64(2.) * self.a14 * r.a4 * r.a15 + T::from_f64(2.) * self.a14 * r.a7 * r.a14 + T::from_f64(2.) * self.a14 * r.a9 * r.a13 - T::from_f64(2.) * self.a14 * r.a10 * r.a12,
+            a8: self.a8 * r.a0.powi(2) - self.a8 * r.a2.powi(2) - self.a8 * r.a3.powi(2) + self.a8 * r.a4.powi(2) + self.a8 * r.a8.powi(2) - self.a8 * r.a9.powi(2) - self.a8 * r.a10.powi(2) + self.a8 * r.a14.powi(2) - T::from_f64(2.) * self.a9 * r.a0 * r.a10 + T::from_f64(2.) * self.a9 * r.a2 * r.a14 + T::from_f64(2.) * self.a9 * r.a3 * r.a4 + T::from_f64(2.) * self.a9 * r.a8 * r.a9 + T::from_f64(2.) * self.a10 * r.a0 * r.a9 + T::from_f64(2.) * self.a10 * r.a2 * r.a4 - T::from_f64(2.) * self.a10 * r.a3 * r.a14 + T::from_f64(2.) * self.a10 * r.a8 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a4 + T::from_f64(2.) * self.a14 * r.a2 * r.a9 - T::from_f64(2.) * self.a14 * r.a3 * r.a10 + T::from_f64(2.) * self.a14 * r.a8 * r.a14,
+            a9: T::from_f64(2.) * self.a8 * r.a0 * r.a10 - T::from_f64(2.) * self.a8 * r.a2 * r.a14 + T::from_f64(2.) * self.a8 * r.a3 * r.a4 + T::from_f64(2.) * self.a8 * r.a8 * r.a9 + self.a9 * r.a0.powi(2) - self.a9 * r.a2.powi(2) + self.a9 * r.a3.powi(2) - self.a9 * r.a4.powi(2) - self.a9 * r.a8.powi(2) + self.a9 * r.a9.powi(2) - self.a9 * r.a10.powi(2) + self.a9 * r.a14.powi(2) - T::from_f64(2.) * self.a10 * r.a0 * r.a8 + T::from_f64(2.) * self.a10 * r.a2 * r.a3 + T::from_f64(2.) * self.a10 * r.a4 * r.a14 + T::from_f64(2.) * self.a10 * r.a9 * r.a10 + T::from_f64(2.) * self.a14 * r.a0 * r.a3 - T::from_f64(2.) * self.a14 * r.a2 * r.a8 + T::from_f64(2.) * self.a14 * r.a4 * r.a10 + T::from_f64(2.) * self.a14 * r.a9 * r.a14,
+            a10: -T::from_f64(2.) * self.a8 * r.a0 * r.a9 + T::from_f64(2.) * self.a8 * r.a2 * r.a4 + T::from_f64(2.) * self.a8 * r.a3 * r.a14 + T::from_f64(2.) * self.a8 * r.a8 * r.a10 + T::from_f64(2.) * self.a9 * r.a0 * r.a8 + T::from_f64(2.) * self.a9 * r.a2 * r.a3 - T::from_f64(2.) * self.a9 * r.a4 * r.a14 + T::from_f64(2.) * self.a9 * r.a9 * r.a10 + self.a10 * r.a0.powi(2) + self.a10 * r.a2.powi(2) - self.a10 * r.a3.powi(2) - self.a10 * r.a4.powi(2) - self.a10 * r.a8.powi(2) - self.a10 * r.a9.powi(2) + self.a10 * r.a10.powi(2) + self.a10 * r.a14.powi(2) + T::from_f64(2.) * self.a14 * r.a0 * r.a2 + T::from_f64(2.) * self.a14 * r.a3 * r.a8 - T::from_f64(2.) * self.a14 * r.a4 * r.a9 + T::from_f64(2.) * self.a14 * r.a10 * r.a14,
+            a11: -T::from_f64(2.) * self.a5 * r.a0 * r.a3 - T::from_f64(2.) * self.a5 * r.a2 * r.a8 - T::from_f64(2.) * self.a5 * r.a4 * r.a10 + T::from_f64(2.) * self.a5 * r.a9 * r.a14 + T::from_f64(2.) * self.a6 * r.a0 * r.a2 - T::from_f64(2.) * self.a6 * r.a3 * r.a8 - T::from_f64(2.) * self.a6 * r.a4 * r.a9 - T::from_f64(2.) * self.a6 * r.a10 * r.a14 + T::from_f64(2.) * self.a7 * r.a0 * r.a14 + T::from_f64(2.) * self.a7 * r.a2 * r.a10 + T::from_f64(2.) * self.a7 * r.a3 * r.a9 - T::from_f64(2.) * self.a7 * r.a4 * r.a8 - T::from_f64(2.) * self.a8 * r.a0 * r.a1 + T::from_f64(2.) * self.a8 * r.a2 * r.a5 + T::from_f64(2.) * self.a8 * r.a3 * r.a6 - T::from_f64(2.) * self.a8 * r.a4 * r.a7 + T::from_f64(2.) * self.a8 * r.a8 * r.a11 - T::from_f64(2.) * self.a8 * r.a9 * r.a12 - T::from_f64(2.) * self.a8 * r.a10 * r.a13 - T::from_f64(2.) * self.a8 * r.a14 * r.a15 - T::from_f64(2.) * self.a9 * r.a0 * r.a13 + T::from_f64(2.) * self.a9 * r.a1 * r.a10 - T::from_f64(2.) * self.a9 * r.a2 * r.a15 - T::from_f64(2.) * self.a9 * r.a3 * r.a7 - T::from_f64(2.) * self.a9 * r.a4 * r.a6 - T::from_f64(2.) * self.a9 * r.a5 * r.a14 + T::from_f64(2.) * self.a9 * r.a8 * r.a12 + T::from_f64(2.) * self.a9 * r.a9 * r.a11 + T::from_f64(2.) * self.a10 * r.a0 * r.a12 - T::from_f64(2.) * self.a10 * r.a1 * r.a9 - T::from_f64(2.) * self.a10 * r.a2 * r.a7 + T::from_f64(2.) * self.a10 * r.a3 * r.a15 - T::from_f64(2.) * self.a10 * r.a4 * r.a5 + T::from_f64(2.) * self.a10 * r.a6 * r.a14 + T::from_f64(2.) * self.a10 * r.a8 * r.a13 + T::from_f64(2.) * self.a10 * r.a10 * r.a11 + self.a11 * r.a0.powi(2) + self.a11 * r.a2.powi(2) + self.a11 * r.a3.powi(2) - self.a11 * r.a4.powi(2) + self.a11 * r.a8.powi(2) - self.a11 * r.a9.powi(2) - self.a11 * r.a10.powi(2) - self.a11 * r.a14.powi(2) - T::from_f64(2.) * self.a12 * r.a0 * r.a10 - T::from_f64(2.) * self.a12 * r.a2 * r.a14 - T::from_f64(2.) * self.a12 * r.a3 * r.a4 + T::from_f64(2.) * self.a12 * r.a8 * r.a9 + T::from_f64(2.) * self.a13 * r.a0 * r.a9 - T::from_f64(2.) * self.a13 * r.a2 * r.a4 + T::from_f64(2.) * self.a13 * r.a3 * r.a14 + T::from_f64(2.) * self.a13 * r.a8 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a7 - T::from_f64(2.) * self.a14 * r.a1 * r.a4 + T::from_f64(2.) * self.a14 * r.a2 * r.a12 - T::from_f64(2.) * self.a14 * r.a3 * r.a13 - T::from_f64(2.) * self.a14 * r.a5 * r.a9 + T::from_f64(2.) * self.a14 * r.a6 * r.a10 - T::from_f64(2.) * self.a14 * r.a8 * r.a15 + T::from_f64(2.) * self.a14 * r.a11 * r.a14,
+            a12: T::from_f64(2.) * self.a5 * r.a0 * r.a4 - T::from_f64(2.) * self.a5 * r.a2 * r.a9 - T::from_f64(2.) * self.a5 * r.a3 * r.a10 - T::from_f64(2.) * self.a5 * r.a8 * r.a14 + T::from_f64(2.) * self.a6 * r.a0 * r.a14 + T::from_f64(2.) * self.a6 * r.a2 * r.a10 - T::from_f64(2.) * self.a6 * r.a3 * r.a9 + T::from_f64(2.) * self.a6 * r.a4 * r.a8 - T::from_f64(2.) * self.a7 * r.a0 * r.a2 - T::from_f64(2.) * self.a7 * r.a3 * r.a8 - T::from_f64(2.) * self.a7 * r.a4 * r.a9 + T::from_f64(2.) * self.a7 * r.a10 * r.a14 + T::from_f64(2.) * self.a8 * r.a0 * r.a13 - T::from_f64(2.) * self.a8 * r.a1 * r.a10 + T::from_f64(2.) * self.a8 * r.a2 * r.a15 - T::from_f64(2.) * self.a8 * r.a3 * r.a7 - T::from_f64(2.) * self.a8 * r.a4 * r.a6 + T::from_f64(2.) * self.a8 * r.a5 * r.a14 + T::from_f64(2.) * self.a8 * r.a8 * r.a12 + T::from_f64(2.) * self.a8 * r.a9 * r.a11 - T::from_f64(2.) * self.a9 * r.a0 * r.a1 + T::from_f64(2.) * self.a9 * r.a2 * r.a5 - T::from_f64(2.) * self.a9 * r.a3 * r.a6 + T::from_f64(2.) * self.a9 * r.a4 * r.a7 - T::from_f64(2.) * self.a9 * r.a8 * r.a11 + T::from_f64(2.) * self.a9 * r.a9 * r.a12 - T::from_f64(2.) * self.a9 * r.a10 * r.a13 - T::from_f64(2.) * self.a9 * r.a14 * r.a15 - T::from_f64(2.) * self.a10 * r.a0 * r.a11 + T::from_f64(2.) * self.a10 * r.a1 * r.a8 - T::from_f64(2.) * self.a10 * r.a2 * r.a6 - T::from_f64(2.) * self.a10 * r.a3 * r.a5 - T::from_f64(2.) * self.a10 * r.a4 * r.a15 - T::from_f64(2.) * self.a10 * r.a7 * r.a14 + T::from_f64(2.) * self.a10 * r.a9 * r.a13 + T::from_f64(2.) * self.a10 * r.a10 * r.a12 + T::from_f64(2.) * self.a11 * r.a0 * r.a10 + T::from_f64(2.) * self.a11 * r.a2 * r.a14 - T::from_f64(2.) * self.a11 * r.a3 * r.a4 + T::from_f64(2.) * self.a11 * r.a8 * r.a9 + self.a12 * r.a0.powi(2) + self.a12 * r.a2.powi(2) - self.a12 * r.a3.powi(2) + self.a12 * r.a4.powi(2) - self.a12 * r.a8.powi(2) + self.a12 * r.a9.powi(2) - self.a12 * r.a10.powi(2) - self.a12 * r.a14.powi(2) - T::from_f64(2.) * self.a13 * r.a0 * r.a8 - T::from_f64(2.) * self.a13 * r.a2 * r.a3 - T::from_f64(2.) * self.a13 * r.a4 * r.a14 + T::from_f64(2.) * self.a13 * r.a9 * r.a10 - T::from_f64(2.) * self.a14 * r.a0 * r.a6 - T::from_f64(2.) * self.a14 * r.a1 * r.a3 - T::from_f64(2.) * self.a14 * r.a2 * r.a11 + T::from_f64(2.) * self.a14 * r.a4 * r.a13 + T::from_f64(2.) * self.a14 * r.a5 * r.a8 - T::from_f64(2.) * self.a14 * r.a7 * r.a10 - T::from_f64(2.) * self.a14 * r.a9 * r.a15 + T::from_f64(2.) * self.a14 * r.a12 * r.a14,
+            a13: T::from_f64(2.) * self.a5 * r.a0 * r.a14 - T::from_f64(2.) * self.a5 * r.a2 * r.a10 + T::from_f64(2.) * self.a5 * r.a3 * r.a9 + T::from_f64(2.) * self.a5 * r.a4 * r.a8 - T::from_f64(2.) * self.a6 * r.a0 * r.a4 - T::from_f64(2.) * self.a6 * r.a2 * r.a9 - T::from_f64(2.) * self.a6 * r.a3 * r.a10 + T::from_f64(2.) * self.a6 * r.a8 * r.a14 + T::from_f64(2.) * self.a7 * r.a0 * r.a3 - T::from_f64(2.) * self.a7 * r.a2 * r.a8 - T::from_f64(2.) * self.a7 * r.a4 * r.a10 - T::from_f64(2.) * self.a7 * r.a9 * r.a14 - T::from_f64(2.) * self.a8 * r.a0 * r.a12 + T::from_f64(2.) * self.a8 * r.a1 * r.a9 - T::from_f64(2.) * self.a8 * r.a2 * r.a7 - T::from_f64(2.) * self.a8 * r.a3 * r.a15 - T::from_f64(2.) * self.a8 * r.a4 * r.a5 - T::from_f64(2.) * self.a8 * r.a6 * r.a14 + T::from_f64(2.) * self.a8 * r.a8 * r.a13 + T::from_f64(2.) * self.a8 * r.a10 * r.a11 + T::from_f64(2.) * self.a9 * r.a0 * r.a11 - T::from_f64(2.) * self.a9 * r.a1 * r.a8 - T::from_f64(2.) * self.a9 * r.a2 * r.a6 - T::from_f64(2.) * self.a9 * r.a3 * r.a5 + T::from_f64(2.) * self.a9 * r.a4 * r.a15 + T::from_f64(2.) * self.a9 * r.a7 * r.a14 + T::from_f64(2.) * self.a9 * r.a9 * r.a13 + T::from_f64(2.) * self.a9 * r.a10 * r.a12 - T::from_f64(2.) * self.a10 * r.a0 * r.a1 - T::from_f64(2.) * self.a10 * r.a2 * r.a5 + T::from_f64(2.) * self.a10 * r.a3 * r.a6 + T::from_f64(2.) * self.a10 * r.a4 * r.a7 - T::from_f64(2.) * self.a10 * r.a8 * r.a11 - T::from_f64(2.) * self.a10 * r.a9 * r.a12 + T::from_f64(2.) * self.a10 * r.a10 * r.a13 - T::from_f64(2.) * self.a10 * r.a14 * r.a15 - T::from_f64(2.) * self.a11 * r.a0 * r.a9 - T::from_f64(2.) * self.a11 * r.a2 * r.a4 - T::from_f64(2.) * self.a11 * r.a3 * r.a14 + T::from_f64(2.) * self.a11 * r.a8 * r.a10 + T::from_f64(2.) * self.a12 * r.a0 * r.a8 - T::from_f64(2.) * self.a12 * r.a2 * r.a3 + T::from_f64(2.) * self.a12 * r.a4 * r.a14 + T::from_f64(2.) * self.a12 * r.a9 * r.a10 + self.a13 * r.a0.powi(2) - self.a13 * r.a2.powi(2) + self.a13 * r.a3.powi(2) + self.a13 * r.a4.powi(2) - self.a13 * r.a8.powi(2) - self.a13 * r.a9.powi(2) + self.a13 * r.a10.powi(2) - self.a13 * r.a14.powi(2) - T::from_f64(2.) * self.a14 * r.a0 * r.a5 - T::from_f64(2.) * self.a14 * r.a1 * r.a2 + T::from_f64(2.) * self.a14 * r.a3 * r.a11 - T::from_f64(2.) * self.a14 * r.a4 * r.a12 - T::from_f64(2.) * self.a14 * r.a6 * r.a8 + T::from_f64(2.) * self.a14 * r.a7 * r.a9 - T::from_f64(2.) * self.a14 * r.a10 * r.a15 + T::from_f64(2.) * self.a14 * r.a13 * r.a14,
+            a14: T::from_f64(2.) * self.a8 * r.a0 * r.a4 - T::from_f64(2.) * self.a8 * r.a2 * r.a9 + T::from_f64(2.) * self.a8 * r.a3 * r.a10 + T::from_f64(2.) * self.a8 * r.a8 * r.a14 + T::from_f64(2.) * self.a9 * r.a0 * r.a3 + T::from_f64(2.) * self.a9 * r.a2 * r.a8 - T::from_f64(2.) * self.a9 * r.a4 * r.a10 + T::from_f64(2.) * self.a9 * r.a9 * r.a14 + T::from_f64(2.) * self.a10 * r.a0 * r.a2 - T::from_f64(2.) * self.a10 * r.a3 * r.a8 + T::from_f64(2.) * self.a10 * r.a4 * r.a9 + T::from_f64(2.) * self.a10 * r.a10 * r.a14 + self.a14 * r.a0.powi(2) + self.a14 * r.a2.powi(2) + self.a14 * r.a3.powi(2) + self.a14 * r.a4.powi(2) + self.a14 * r.a8.powi(2) + self.a14 * r.a9.powi(2) + self.a14 * r.a10.powi(2) + self.a14 * r.a14.powi(2),
+            a15: T::from_f64(2.) * self.a0 * r.a0 * r.a15 - T::from_f64(2.) * self.a0 * r.a1 * r.a14 - T::from_f64(2.) * self.a0 * r.a2 * r.a13 - T::from_f64(2.) * self.a0 * r.a3 * r.a12 - T::from_f64(2.) * self.a0 * r.a4 * r.a11 - T::from_f64(2.) * self.a0 * r.a5 * r.a10 - T::from_f64(2.) * self.a0 * r.a6 * r.a9 - T::from_f64(2.) * self.a0 * r.a7 * r.a8 - T::from_f64(2.) * self.a1 * r.a0 * r.a14 + T::from_f64(2.) * self.a1 * r.a2 * r.a10 + T::from_f64(2.) * self.a1 * r.a3 * r.a9 + T::from_f64(2.) * self.a1 * r.a4 * r.a8 - T::from_f64(2.) * self.a2 * r.a0 * r.a13 - T::from_f64(2.) * self.a2 * r.a1 * r.a10 + T::from_f64(2.) * self.a2 * r.a2 * r.a15 + T::from_f64(2.) * self.a2 * r.a3 * r.a7 - T::from_f64(2.) * self.a2 * r.a4 * r.a6 - T::from_f64(2.) * self.a2 * r.a5 * r.a14 + T::from_f64(2.) * self.a2 * r.a8 * r.a12 - T::from_f64(2.) * self.a2 * r.a9 * r.a11 - T::from_f64(2.) * self.a3 * r.a0 * r.a12 - T::from_f64(2.) * self.a3 * r.a1 * r.a9 - T::from_f64(2.) * self.a3 * r.a2 * r.a7 + T::from_f64(2.) * self.a3 * r.a3 * r.a15 + T::from_f64(2.) * self.a3 * r.a4 * r.a5 - T::from_f64(2.) * self.a3 * r.a6 * r.a14 - T::from_f64(2.) * self.a3 * r.a8 * r.a13 + T::from_f64(2.) * self.a3 * r.a10 * r.a11 - T::from_f64(2.) * self.a4 * r.a0 * r.a11 - T::from_f64(2.) * self.a4 * r.a1 * r.a8 + T::from_f64(2.) * self.a4 * r.a2 * r.a6 - T::from_f64(2.) * self.a4 * r.a3 * r.a5 + T::from_f64(2.) * self.a4 * r.a4 * r.a15 - T::from_f64(2.) * self.a4 * r.a7 * r.a14 + T::from_f64(2.) * self.a4 * r.a9 * r.a13 - T::from_f64(2.) * self.a4 * r.a10 * r.a12 + self.a15 * r.a0.powi(2) - self.a15 * r.a2.powi(2) - self.a15 * r.a3.powi(2) - self.a15 * r.a4.powi(2) + self.a15 * r.a8.powi(2) + self.a15 * r.a9.powi(2) + self.a15 * r.a10.powi(2) - self.a15 * r.a14.powi(2),
         }
     }
 }
 
-impl Bivector {
-    pub fn exp(self) -> ScalarAndBivector {
+impl<T: Scalar> Bivector<T> {
+    // Exponential of a bivector, producing a motor.
+    // For a simple bivector (a line), this is a rotation around the line
+    // (or a translation, if the line is ideal.)
+    // A general bivector additionally picks up an e0123 part,
+    // which represents translation along the axis of rotation (i.e. a screw motion.)
+    pub fn exp(self) -> Motor<T> {
         let theta = self.norm();
-        if theta.abs() < FLOAT_DIVISION_EPSILON {
-            1. + self
+        if theta.abs() < T::from_f64(FLOAT_DIVISION_EPSILON) {
+            return Motor {
+                a0: T::one(),
+                a5: self.a5,
+                a6: self.a6,
+                a7: self.a7,
+                a8: self.a8,
+                a9: self.a9,
+                a10: self.a10,
+                a15: T::zero(),
+            };
+        }
+        let l = theta * theta;
+        let m = self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8;
+        let c = theta.cos();
+        let s = theta.sin() / theta;
+        let t = m / l * (c - s);
+        Motor {
+            a0: c,
+            a5: s * self.a5 + t * self.a10,
+            a6: s * self.a6 + t * self.a9,
+            a7: s * self.a7 + t * self.a8,
+            a8: s * self.a8,
+            a9: s * self.a9,
+            a10: s * self.a10,
+            a15: m * s,
+        }
+    }
+
+    // The e0123 coefficient of (self ^ self) / 2,
+    // which is zero exactly when the bivector is simple (a single line.)
+    fn screw_m(self) -> T {
+        self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8
+    }
+
+    // Split a bivector into a Euclidean line and an ideal line
+    // which commute and sum to the original bivector.
+    // exp() of the first part is a rotation, and exp() of the second part is a translation along its axis.
+    // A bivector with no Euclidean part is already an ideal line,
+    // so it decomposes into zero and itself.
+    pub fn decompose(self) -> (Bivector<T>, Bivector<T>) {
+        let l = self.norm().powi(2);
+        if l < T::from_f64(FLOAT_DIVISION_EPSILON) {
+            return (Bivector::zero(), self);
+        }
+        let k = self.screw_m() / l;
+        let ideal = Bivector {
+            a5: k * self.a10,
+            a6: k * self.a9,
+            a7: k * self.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+        };
+        (self - ideal, ideal)
+    }
+
+    // Distance travelled along the axis per radian of rotation around it
+    // for the screw motion exp(self).
+    // Positive for a right-handed screw, and zero for a line.
+    pub fn pitch(self) -> T {
+        -self.screw_m() / self.norm().powi(2)
+    }
+
+    // The normalized line that this bivector rotates around
+    pub fn axis(self) -> Bivector<T> {
+        self.decompose().0.hat()
+    }
+
+    // The ideal point in the direction of the line
+    pub fn direction(self) -> Trivector<T> {
+        Trivector {
+            a11: self.a8,
+            a12: self.a9,
+            a13: self.a10,
+            a14: T::zero(),
+        }
+    }
+
+    // The moment of the line about the origin (p x d for a point p on the line with direction d),
+    // as an ideal point
+    pub fn moment(self) -> Trivector<T> {
+        Trivector {
+            a11: -self.a7,
+            a12: -self.a6,
+            a13: -self.a5,
+            a14: T::zero(),
+        }
+    }
+
+    // The bivector part of (self * r - r * self) / 2.
+    // For two normalized lines, this is a screw around their common perpendicular.
+    fn commutator(self, r: Bivector<T>) -> Bivector<T> {
+        let m = (self * r - r * self) * T::from_f64(0.5);
+        Bivector {
+            a5: m.a5,
+            a6: m.a6,
+            a7: m.a7,
+            a8: m.a8,
+            a9: m.a9,
+            a10: m.a10,
+        }
+    }
+
+    // The shortest distance between two lines
+    pub fn distance(self, r: Bivector<T>) -> T {
+        let a = self.hat();
+        let b = r.hat();
+        let c = a.commutator(b);
+        let sin = c.norm();
+        if sin < T::from_f64(FLOAT_DIVISION_EPSILON) {
+            // Parallel lines: the commutator is an ideal line whose weight is the distance
+            c.inorm()
+        } else {
+            (a ^ b).a15.abs() / sin
+        }
+    }
+
+    // The angle between the directions of two lines, between 0 and 180 degrees
+    pub fn angle(self, r: Bivector<T>) -> T {
+        let cos = -(self.hat() | r.hat());
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    // The normalized line that meets both lines at right angles,
+    // or None if the lines are parallel (and it is not unique.)
+    pub fn common_perpendicular(self, r: Bivector<T>) -> Option<Bivector<T>> {
+        let c = self.hat().commutator(r.hat());
+        if c.norm() < T::from_f64(FLOAT_DIVISION_EPSILON) {
+            None
+        } else {
+            Some(c.axis())
+        }
+    }
+}
+
+impl<T: Scalar> Motor<T> {
+    // Return the bivector that generates this (normalized) motor,
+    // i.e. the inverse of exp: m.log().exp() == m
+    pub fn log(self) -> Bivector<T> {
+        // The scalar part is the cosine of half the rotation angle, and the Euclidean part has its sine as its norm.
+        // Taking the sine from the Euclidean part keeps its precision near a half angle of 0 or 180 degrees.
+        let s = self.a0;
+        let sin_sq = self.a8 * self.a8 + self.a9 * self.a9 + self.a10 * self.a10;
+        let (b, c) = if sin_sq < T::from_f64(FLOAT_DIVISION_EPSILON) && s > T::zero() {
+            // Limits of the expressions below as the rotation angle goes to zero
+            (T::one(), self.a15 / T::from_f64(3.))
+        } else if sin_sq < T::from_f64(FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON) {
+            // A full turn (m = -1, up to a translation) has no particular axis,
+            // so return the generator of -m instead, which performs the same motion
+            return (-self).log();
         } else {
-            theta.cos() + (theta.sin() / theta) * self
+            let a = T::one() / sin_sq;
+            let b = sin_sq.sqrt().atan2(s) * a.sqrt();
+            (b, a * self.a15 * (T::one() - s * b))
+        };
+        Bivector {
+            a5: c * self.a10 + b * self.a5,
+            a6: c * self.a9 + b * self.a6,
+            a7: c * self.a8 + b * self.a7,
+            a8: b * self.a8,
+            a9: b * self.a9,
+            a10: b * self.a10,
+        }
+    }
+
+    // Return a normalized copy of this motor, such that m * m.reverse() == 1
+    pub fn normalize(self) -> Motor<T> {
+        let a = T::one() / (self.a0 * self.a0 + self.a8 * self.a8 + self.a9 * self.a9 + self.a10 * self.a10).sqrt();
+        let b = (self.a15 * self.a0 - (self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8)) * a * a;
+        Motor {
+            a0: a * self.a0,
+            a5: a * (self.a5 + b * self.a10),
+            a6: a * (self.a6 + b * self.a9),
+            a7: a * (self.a7 + b * self.a8),
+            a8: a * self.a8,
+            a9: a * self.a9,
+            a10: a * self.a10,
+            a15: a * (self.a15 - b * self.a0),
         }
     }
+
+    // Return the motor that performs half of this motor's motion,
+    // i.e. m.sqrt() * m.sqrt() == m
+    pub fn sqrt(self) -> Motor<T> {
+        (self + T::one()).normalize()
+    }
+
+    // Screw-linear interpolation between two motors.
+    // alpha = 0 gives a, alpha = 1 gives b (or -b, which is the same motion),
+    // and intermediate values follow the constant-velocity screw motion that takes a to b.
+    pub fn interpolate(a: Motor<T>, b: Motor<T>, alpha: T) -> Motor<T> {
+        let relative = a.reverse() * b;
+        // m and -m perform the same motion; take the shorter path
+        let relative = if relative.a0 < T::zero() { -relative } else { relative };
+        a * (relative.log() * alpha).exp()
+    }
+}
+
+impl<T: Scalar> ScalarAndBivector<T> {
+    // Return the bivector that generates this (normalized) motor,
+    // i.e. the inverse of exp: m.log().exp() == m
+    pub fn log(self) -> Bivector<T> {
+        self.motor().log()
+    }
+
+    // Return the motor that performs half of this motor's motion,
+    // i.e. m.sqrt() * m.sqrt() == m
+    pub fn sqrt(self) -> ScalarAndBivector<T> {
+        (self + T::one()).hat()
+    }
 }
 
 pub const I: Pseudoscalar = Pseudoscalar { a15: 1. };
 
+#[cfg(test)]
+mod tests;
+
 
@@ -0,0 +1,137 @@
+use crate::pga::*;
+use crate::global::*;
+use crate::construct::*;
+use crate::approx::*;
+use crate::scalar::DualNumber;
+
+// A screw about a line through (1, 2, 0) parallel to Z:
+// a rotation by twice the given half-angle, along with a translation along the line
+fn screw(half_angle: Float, slide: Float) -> Bivector {
+    let axis = (point_from_xyz(1., 2., 0.) & point_from_xyz(1., 2., 1.)).hat();
+    half_angle * axis + slide * (I * axis)
+}
+
+// Whether two motors move a handful of points to the same places,
+// which is true of m and -m even though they differ as multivectors
+fn same_motion(a: Motor, b: Motor) -> bool {
+    [point_from_xyz(0., 0., 0.), point_from_xyz(1., -2., 3.), point_from_xyz(-4., 0.5, 2.)].iter()
+        .all(|&p| p.transform(a).hat().approx_eq_tol(&p.transform(b).hat(), 1e-7))
+}
+
+#[test]
+fn log_inverts_exp() {
+    for &(half_angle, slide) in &[(0., 0.7), (0.4, 0.), (0.4, -0.3), (1.5, 0.2), (3., 0.5)] {
+        let b = screw(half_angle, slide);
+        assert!(b.exp().log().approx_eq_tol(&b, 1e-9), "log(exp(b)) != b for {:?}", b);
+    }
+}
+
+#[test]
+fn log_near_full_turn() {
+    // A rotation by just under 360 degrees has a half-angle just under 180 degrees,
+    // and its generator must keep that size rather than collapsing towards zero
+    let b = screw(PI - 1e-6, 0.25);
+    let log = b.exp().log();
+    assert!((log.norm() - (PI - 1e-6)).abs() < 1e-6);
+    assert!(log.approx_eq_tol(&b, 1e-6));
+    assert!(same_motion(log.exp(), b.exp()));
+}
+
+#[test]
+fn log_of_full_turn() {
+    // A full turn has no axis, but its log must still perform the same motion
+    let m = -motor_from_translation(1., 2., 3.);
+    assert!(same_motion(m.log().exp(), m));
+}
+
+#[test]
+fn sqrt_squares_to_motor() {
+    for &(half_angle, slide) in &[(0., 0.7), (0.4, -0.3), (1.5, 0.2), (3., 0.5)] {
+        let m = screw(half_angle, slide).exp();
+        let r = m.sqrt();
+        assert!((r * r).approx_eq_tol(&m, 1e-9));
+        assert!(r.approx_eq_tol(&screw(0.5 * half_angle, 0.5 * slide).exp(), 1e-9));
+    }
+}
+
+#[test]
+fn interpolate_follows_screw() {
+    let a = motor_from_translation(1., 0., 0.);
+    let b = screw(1.2, 0.4).exp() * a;
+    assert!(Motor::interpolate(a, b, 0.).approx_eq_tol(&a, 1e-9));
+    assert!(same_motion(Motor::interpolate(a, b, 1.), b));
+    // Halfway is half of the relative motion
+    let half = a * (a.reverse() * b).sqrt();
+    assert!(same_motion(Motor::interpolate(a, b, 0.5), half));
+}
+
+#[test]
+fn scalar_on_either_side() {
+    // Each scalar type can multiply from the left as well as the right
+    let v64 = Vector::new(1., 2., 3., 4.);
+    assert_eq!(2. * v64, v64 * 2.);
+    let v32 = Vector::new(1_f32, 2., 3., 4.);
+    assert_eq!(2_f32 * v32, v32 * 2.);
+    let d = DualNumber::variable(2.);
+    let vd = Vector::new(d, d, d, d);
+    assert_eq!(d * vd, vd * d);
+}
+
+#[test]
+fn decompose_screw() {
+    let b = screw(1.2, 0.4);
+    let (euclidean, ideal) = b.decompose();
+    assert!((euclidean + ideal).approx_eq(&b));
+    // The parts commute, the first is a line through the axis, and the second is ideal
+    assert!((euclidean * ideal - ideal * euclidean).approx_eq(&Motor::zero()));
+    assert!(euclidean.hat().approx_eq(&b.axis()));
+    assert!(ideal.norm() < 1e-12);
+
+    // A bivector with no euclidean part is already ideal
+    let (euclidean, ideal) = (I * b).decompose();
+    assert!(euclidean.approx_eq(&Bivector::zero()));
+    assert!(ideal.approx_eq(&(I * b)));
+}
+
+#[test]
+fn pitch_matches_motion() {
+    let axis = point_from_xyz(1., 2., 0.) & point_from_xyz(1., 2., 1.);
+    let (dx, dy, dz, _) = axis.direction().as_tuple();
+    let d = [dx, dy, dz];
+    for &(half_angle, slide) in &[(0.5, 0.3), (0.5, -0.3), (1.1, 0.)] {
+        let b = screw(half_angle, slide);
+        let m = b.exp();
+        // Follow a point next to the axis: it turns about the axis and slides along it
+        let (x, y, z, _) = point_from_xyz(2., 2., 0.).transform(m).hat().as_tuple();
+        let turned = [x - 1., y - 2.];
+        let angle = (d[2].signum() * turned[1]).atan2(turned[0]);
+        let slid = z * d[2].signum();
+        assert!((b.pitch() - slid / angle).abs() < 1e-9);
+    }
+    assert!(axis.pitch().abs() < 1e-12);
+}
+
+#[test]
+fn line_queries() {
+    // The line through (1, 2, 0) parallel to Z
+    let l = point_from_xyz(1., 2., 0.) & point_from_xyz(1., 2., 1.);
+    let (dx, dy, dz, dw) = l.direction().as_tuple();
+    assert_eq!((dx, dy, dw), (0., 0., 0.));
+    // The moment is p x d for the point p = (1, 2, 0) on the line
+    assert!(l.moment().approx_eq(&inf_point_from_xyz(2. * dz, -dz, 0.)));
+
+    // Skew lines: the X axis, and the line through (5, 0, 3) parallel to Y
+    let x_axis = point_from_xyz(0., 0., 0.) & point_from_xyz(1., 0., 0.);
+    let skew = point_from_xyz(5., 0., 3.) & point_from_xyz(5., 1., 3.);
+    assert!((x_axis.distance(skew) - 3.).abs() < 1e-12);
+    assert!((x_axis.angle(skew) - 0.5 * PI).abs() < 1e-12);
+    let perpendicular = x_axis.common_perpendicular(skew).unwrap();
+    let expected = (point_from_xyz(5., 0., 0.) & point_from_xyz(5., 0., 3.)).hat();
+    assert!(perpendicular.approx_eq(&expected) || perpendicular.approx_eq(&-expected));
+
+    // Parallel lines have a distance but no unique common perpendicular
+    let parallel = point_from_xyz(0., 4., 0.) & point_from_xyz(2., 4., 0.);
+    assert!((x_axis.distance(parallel) - 4.).abs() < 1e-12);
+    assert!(x_axis.angle(parallel).abs() < 1e-6);
+    assert!(x_axis.common_perpendicular(parallel).is_none());
+}
@@ -1,4 +1,22 @@
+import re
 import sympy
+from sympy.printing.codeprinter import CodePrinter
+from sympy.printing.rust import RustCodePrinter
+
+class PgaRustCodePrinter(RustCodePrinter):
+    """ Rust printer that leaves products and sums alone.
+    Newer versions of sympy wrap real symbols in implicit float casts here,
+    which loses the parentheses around sums inside of products.
+    """
+
+    def _print_Mul(self, expr):
+        return CodePrinter._print_Mul(self, expr)
+
+    def _print_Add(self, expr, order=None):
+        return CodePrinter._print_Add(self, expr, order)
+
+def rust_code(expr):
+    return PgaRustCodePrinter().doprint(expr)
 
 # What objects we want to represent
 
@@ -11,6 +29,7 @@ objects = {
     "Trivector": [0] * 11 + [1] * 4 + [0],
     "Pseudoscalar": [0] * 15 + [1],
     "ScalarAndBivector": [1] + [0] * 4 + [1] * 6 + [0] * 5,
+    "Motor": [1] + [0] * 4 + [1] * 6 + [0] * 4 + [1],
     "FullMultivector": [1] * 16,
 }
 
@@ -139,6 +158,7 @@ unary_operations = {
     "bivector": select_object("Bivector"),
     "trivector": select_object("Trivector"),
     "pseudoscalar": select_object("Pseudoscalar"),
+    "motor": select_object("Motor"),
     "full_multivector": lambda x: x,
 }
 
@@ -197,6 +217,30 @@ def indent(s, tabs=1):
 
     return "\n".join("    " * tabs + line if line else "" for line in s.split("\n"))
 
+def ty(obj_name):
+    """ Returns the rust type for the given object name,
+    generic over the scalar type T.
+    """
+
+    if obj_name == "Float":
+        return "T"
+    return f"{obj_name}<T>"
+
+def scalar_literals(s):
+    """ Replaces float literals (e.g. "2.") with constructions of the scalar type T,
+    since a bare literal is only an f64 or f32.
+    """
+
+    def replace(m):
+        literal = m.group(1)
+        if float(literal) == 0:
+            return "T::zero()"
+        if float(literal) == 1:
+            return "T::one()"
+        return f"T::from_f64({literal})"
+
+    return re.sub(r"(?<![\w.])(\d+\.\d*)", replace, s)
+
 def fix_float(s):
     """ Replaces bare "0" with "0." for rust. """
 
@@ -251,21 +295,21 @@ def gen_code_for_mv(mv, components=None, sub_a="a", sub_b="b", a_is_scalar=False
 
     if result_is_scalar:
         # Special-case scalars
-        code = fix_float(sympy.rust_code(mv[0]))
+        code = fix_float(rust_code(mv[0]))
         for find, replace in subs:
             code = code.replace(find, replace)
         #code = code.replace(".a0", "")
-        return code
+        return scalar_literals(code)
     else:
         result = []
         for i, x in enumerate(mv):
             if components is not None and components[i] == 0:
                 assert x == 0, "Tried to set an element not present in type!"
                 continue
-            code = fix_float(sympy.rust_code(x))
+            code = fix_float(rust_code(x))
             for find, replace in subs:
                 code = code.replace(find, replace)
-            result.append(f"a{i}: {code},")
+            result.append(f"a{i}: {scalar_literals(code)},")
 
         return "\n".join(result)
 
@@ -308,11 +352,11 @@ def gen_unary_operator(obj_name, op_name, impl=None, result_type=None, pub=False
         a_is_scalar=obj_name == "Float",
         result_is_scalar=result_type == "Float"
     )
-    rust_code = f"""{pub_str}fn {op_name}(self) -> {result_type} {{
+    rust_code = f"""{pub_str}fn {op_name}(self) -> {ty(result_type)} {{
 {indent(result_code)}
 }}"""
     if impl is not None:
-        rust_code = wrap_impl(rust_code, obj_name, impl, types=[("Output", result_type)])
+        rust_code = wrap_impl(rust_code, obj_name, impl, types=[("Output", ty(result_type))])
 
     return rust_code
 
@@ -344,17 +388,21 @@ def gen_binary_operator(obj_name, rhs_obj_name, op_name, impl=None, pub=False, r
         b_is_scalar=rhs_obj_name == "Float",
         result_is_scalar=result_type == "Float",
     )
-    underscore = "" if "r" in result_code.replace(result_type, "") else "_" # A bit of a hack, might not catch all cases of unused "r"
+    underscore = "" if re.search(r"\br\b", result_code) else "_"
 
     pub_str = "pub " if pub else ""
 
-    rust_code = f"""fn {op_name}(self, {underscore}r: {rhs_obj_name}) -> {result_type} {{
+    rust_code = f"""fn {op_name}(self, {underscore}r: {ty(rhs_obj_name)}) -> {ty(result_type)} {{
 {indent(result_code)}
 }}"""
 
     if impl is not None:
-        types = [("Output", result_type)] if require_output_type is None else None
-        rust_code = wrap_impl(rust_code, obj_name, impl, template=rhs_obj_name, types=types)
+        types = [("Output", ty(result_type))] if require_output_type is None else None
+        rust_code = wrap_impl(rust_code, obj_name, impl, template=ty(rhs_obj_name), types=types)
+        if impl in ("BitXor", "BitAnd", "BitOr"):
+            # The wedge, vee and dot products are sums of products of coefficients,
+            # which clippy would otherwise flag as a mistaken operator
+            rust_code = "#[allow(clippy::suspicious_arithmetic_impl)]\n" + rust_code
     return rust_code
 
 def wrap_impl(rust_code, obj_name, impl_name=None, template=None, types=None):
@@ -370,7 +418,9 @@ def wrap_impl(rust_code, obj_name, impl_name=None, template=None, types=None):
     types_str = ""
     if types is not None:
         types_str = "\n".join(f"    type {l} = {r};" for (l, r) in types) + "\n\n"
-    return f"""impl{" " + impl_name if impl_name is not None else ""}{"<" + template + ">" if template is not None else ""}{" for" if impl_name is not None else ""} {obj_name} {{
+    # Scalar types can't be covered by a generic impl, see impl_scalar_ops
+    generics = "" if obj_name == "Float" else "<T: Scalar>"
+    return f"""impl{generics}{" " + impl_name if impl_name is not None else ""}{"<" + template + ">" if template is not None else ""}{" for" if impl_name is not None else ""} {ty(obj_name)} {{
 {types_str}{indent(rust_code)}
 }}"""
 
@@ -378,10 +428,11 @@ def struct(obj_name):
     """ Generates the "struct" definition for the given type. """
 
     components = objects[obj_name]
-    a = "".join("    a{}: Float,\n".format(i) for i in range(BASIS_COUNT) if components[i])
+    a = "".join("    a{}: T,\n".format(i) for i in range(BASIS_COUNT) if components[i])
     return f"""
 #[derive(Default,Debug,Clone,Copy,PartialEq)]
-pub struct {obj_name} {{
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct {obj_name}<T = Float> {{
 {a}}}"""
 
 def gen_reverse_ops(obj_name):
@@ -413,7 +464,7 @@ def gen_dual_ops(obj_name):
 
     rust_code = "\n\n".join(x for x in rust_code if x is not None)
 
-    return wrap_impl(rust_code, obj_name, "Dual", types=[("Output", dual_type)])
+    return wrap_impl(rust_code, obj_name, "Dual", types=[("Output", ty(dual_type))])
 
 def gen_conjugate_ops(obj_name):
     """ Generates the "Conjugate" trait implementation for the given type """
@@ -441,7 +492,7 @@ def gen_normalize_ops(obj_name):
 
     rust_code = "\n\n".join(x for x in rust_code if x is not None)
 
-    return wrap_impl(rust_code, obj_name, "Normalize")
+    return wrap_impl(rust_code, obj_name, "Normalize<T>")
 
 def gen_normalize_infinite_ops(obj_name):
     """ Generates the "NormalizeInfinite" trait implementation for the given type """
@@ -455,7 +506,7 @@ def gen_normalize_infinite_ops(obj_name):
 
     rust_code = "\n\n".join(x for x in rust_code if x is not None)
 
-    return wrap_impl(rust_code, obj_name, "NormalizeInfinite")
+    return wrap_impl(rust_code, obj_name, "NormalizeInfinite<T>")
 
 def gen_impl_ops(obj_name):
     """ Generates the non-trait-based implementation for the given type. """
@@ -465,10 +516,12 @@ def gen_impl_ops(obj_name):
     # First generate a "new" constructor
 
     var_names = [f"a{i}" for i, e in enumerate(objects[obj_name]) if e]
-    args = ", ".join([f"{v}: Float" for v in var_names])
+    args = ", ".join([f"{v}: T" for v in var_names])
     constructor_code = ", ".join(var_names)
+    # A constructor takes every coefficient of its type, however many there are
+    allow = "#[allow(clippy::too_many_arguments)]\n" if len(var_names) > 7 else ""
     rust_code += [
-        f"""pub fn new({args}) -> {obj_name} {{
+        f"""{allow}pub fn new({args}) -> {ty(obj_name)} {{
     {obj_name} {{{constructor_code}}}
 }}"""
     ]
@@ -476,7 +529,7 @@ def gen_impl_ops(obj_name):
     # And a "zero" constructor
 
     rust_code += [
-        f"""pub fn zero() -> {obj_name} {{
+        f"""pub fn zero() -> {ty(obj_name)} {{
     Default::default()
 }}"""
     ]
@@ -484,7 +537,7 @@ def gen_impl_ops(obj_name):
     # And "as_tuple"
 
     var_names = [f"a{i}" for i, e in enumerate(objects[obj_name]) if e]
-    return_type = ", ".join(["Float"] * len(var_names))
+    return_type = ", ".join(["T"] * len(var_names))
     return_tuple = ", ".join([f"self.{v}" for v in var_names])
     if len(var_names) > 1:
         return_type = f"({return_type})"
@@ -497,13 +550,14 @@ def gen_impl_ops(obj_name):
 
     # Now generate some additional functions
 
-    mv_ops = ["scalar", "vector", "bivector", "trivector", "full_multivector"]
+    mv_ops = ["scalar", "vector", "bivector", "trivector", "motor", "full_multivector"]
 
     force_type = {
         "scalar": "Float",
         "vector": "Vector",
         "bivector": "Bivector",
         "trivector": "Trivector",
+        "motor": "Motor",
         "full_multivector": "FullMultivector",
     }
 
@@ -630,6 +684,44 @@ def gen_special_binary_operators(obj_name):
 
     return "\n\n".join(x for x in blocks if x is not None)
 
+def gen_scalar_ops_macro():
+    """ Generates the operations that have a scalar as "self".
+    Rust does not allow these to be implemented generically (e.g. impl<T> Mul<Vector<T>> for T)
+    so they are wrapped in a macro which is invoked once per scalar type.
+    """
+
+    blocks = [
+        gen_reverse_ops("Float"),
+        gen_dual_ops("Float"),
+        gen_conjugate_ops("Float"),
+        gen_normalize_ops("Float"),
+        gen_normalize_infinite_ops("Float"),
+        gen_binary_arithmetic("Float"),
+        gen_special_binary_operators("Float"),
+    ]
+
+    code = "\n\n".join(b for b in blocks if b)
+    code = code.replace("T::", "<$t>::")
+    code = re.sub(r"\bT\b", "$t", code)
+
+    scalar_types = ["f64", "f32", "DualNumber"]
+    invocations = "\n".join(f"impl_scalar_ops!({t});" for t in scalar_types)
+
+    return f"""// ===========================================================================
+// Float
+// ===========================================================================
+
+// Operations with a scalar on the left-hand side
+// can't be implemented generically over the scalar type,
+// so this macro implements them for one concrete scalar type.
+macro_rules! impl_scalar_ops {{
+    ($t:ty) => {{
+{indent(code, 2)}
+    }};
+}}
+
+{invocations}"""
+
 def main():
     """ Generates the pga.rs file, containing:
       * Header shown below, including the Multivector trait
@@ -644,20 +736,21 @@ def main():
 
     blocks = []
     for obj in objects:
+        if obj == "Float":
+            blocks.append(gen_scalar_ops_macro())
+            continue
         header = f"""// ===========================================================================
 // {obj}
 // ==========================================================================="""
         blocks.append(header)
-        if obj != "Float":
-            blocks.append(struct(obj))
+        blocks.append(struct(obj))
         blocks.append(gen_reverse_ops(obj))
         blocks.append(gen_dual_ops(obj))
         blocks.append(gen_conjugate_ops(obj))
         blocks.append(gen_normalize_ops(obj))
         blocks.append(gen_normalize_infinite_ops(obj))
-        if obj != "Float":
-            blocks.append(gen_impl_ops(obj))
-            blocks.append(gen_unary_arithmetic(obj))
+        blocks.append(gen_impl_ops(obj))
+        blocks.append(gen_unary_arithmetic(obj))
         blocks.append(gen_binary_arithmetic(obj))
         blocks.append(gen_special_binary_operators(obj))
 
@@ -689,7 +782,11 @@ def main():
 // so there is good interoperability.
 // One exception is that using ! to take the dual has been removed (use .dual())
 
+// All types are generic over their scalar type (see the scalar module),
+// which defaults to Float.
+
 use crate::global::{{Float, FLOAT_DIVISION_EPSILON}};
+use crate::scalar::{{Scalar, DualNumber}};
 use std::ops::{{Add, Sub, Mul, Neg, BitXor, BitAnd, BitOr}};
 
 pub trait Reverse {{
@@ -705,31 +802,31 @@ pub trait Conjugate {{
     fn conjugate(self) -> Self;
 }}
 
-pub trait Normalize {{
-    fn norm(self) -> Float;
+pub trait Normalize<T: Scalar = Float> {{
+    fn norm(self) -> T;
 
     // Return a normalized copy
-    fn hat(self) -> Self where Self: Copy + Mul<Float, Output=Self> {{
+    fn hat(self) -> Self where Self: Copy + Mul<T, Output=Self> {{
         assert!(self.is_finite(), "norm is zero");
-        self * (1. / self.norm())
+        self * (T::one() / self.norm())
     }}
 
     fn is_finite(self) -> bool where Self: Copy {{
-        return self.norm() > FLOAT_DIVISION_EPSILON;
+        return self.norm() > T::from_f64(FLOAT_DIVISION_EPSILON);
     }}
 }}
 
-pub trait NormalizeInfinite {{
-    fn inorm(self) -> Float;
+pub trait NormalizeInfinite<T: Scalar = Float> {{
+    fn inorm(self) -> T;
 
     // Return a infinite-normalized copy
-    fn ihat(self) -> Self where Self: Copy + Mul<Float, Output=Self> {{
+    fn ihat(self) -> Self where Self: Copy + Mul<T, Output=Self> {{
         assert!(self.is_infinite(), "inorm is zero");
-        self * (1. / self.inorm())
+        self * (T::one() / self.inorm())
     }}
 
     fn is_infinite(self) -> bool where Self: Copy {{
-        return self.inorm() > FLOAT_DIVISION_EPSILON;
+        return self.inorm() > T::from_f64(FLOAT_DIVISION_EPSILON);
     }}
 }}
 
@@ -747,19 +844,232 @@ pub trait Transform<Entity> {{
 
 {generated_code}
 
-impl Bivector {{
-    pub fn exp(self) -> ScalarAndBivector {{
+impl<T: Scalar> Bivector<T> {{
+    // Exponential of a bivector, producing a motor.
+    // For a simple bivector (a line), this is a rotation around the line
+    // (or a translation, if the line is ideal.)
+    // A general bivector additionally picks up an e0123 part,
+    // which represents translation along the axis of rotation (i.e. a screw motion.)
+    pub fn exp(self) -> Motor<T> {{
         let theta = self.norm();
-        if theta.abs() < FLOAT_DIVISION_EPSILON {{
-            1. + self
+        if theta.abs() < T::from_f64(FLOAT_DIVISION_EPSILON) {{
+            return Motor {{
+                a0: T::one(),
+                a5: self.a5,
+                a6: self.a6,
+                a7: self.a7,
+                a8: self.a8,
+                a9: self.a9,
+                a10: self.a10,
+                a15: T::zero(),
+            }};
+        }}
+        let l = theta * theta;
+        let m = self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8;
+        let c = theta.cos();
+        let s = theta.sin() / theta;
+        let t = m / l * (c - s);
+        Motor {{
+            a0: c,
+            a5: s * self.a5 + t * self.a10,
+            a6: s * self.a6 + t * self.a9,
+            a7: s * self.a7 + t * self.a8,
+            a8: s * self.a8,
+            a9: s * self.a9,
+            a10: s * self.a10,
+            a15: m * s,
+        }}
+    }}
+
+    // The e0123 coefficient of (self ^ self) / 2,
+    // which is zero exactly when the bivector is simple (a single line.)
+    fn screw_m(self) -> T {{
+        self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8
+    }}
+
+    // Split a bivector into a Euclidean line and an ideal line
+    // which commute and sum to the original bivector.
+    // exp() of the first part is a rotation, and exp() of the second part is a translation along its axis.
+    // A bivector with no Euclidean part is already an ideal line,
+    // so it decomposes into zero and itself.
+    pub fn decompose(self) -> (Bivector<T>, Bivector<T>) {{
+        let l = self.norm().powi(2);
+        if l < T::from_f64(FLOAT_DIVISION_EPSILON) {{
+            return (Bivector::zero(), self);
+        }}
+        let k = self.screw_m() / l;
+        let ideal = Bivector {{
+            a5: k * self.a10,
+            a6: k * self.a9,
+            a7: k * self.a8,
+            a8: T::zero(),
+            a9: T::zero(),
+            a10: T::zero(),
+        }};
+        (self - ideal, ideal)
+    }}
+
+    // Distance travelled along the axis per radian of rotation around it
+    // for the screw motion exp(self).
+    // Positive for a right-handed screw, and zero for a line.
+    pub fn pitch(self) -> T {{
+        -self.screw_m() / self.norm().powi(2)
+    }}
+
+    // The normalized line that this bivector rotates around
+    pub fn axis(self) -> Bivector<T> {{
+        self.decompose().0.hat()
+    }}
+
+    // The ideal point in the direction of the line
+    pub fn direction(self) -> Trivector<T> {{
+        Trivector {{
+            a11: self.a8,
+            a12: self.a9,
+            a13: self.a10,
+            a14: T::zero(),
+        }}
+    }}
+
+    // The moment of the line about the origin (p x d for a point p on the line with direction d),
+    // as an ideal point
+    pub fn moment(self) -> Trivector<T> {{
+        Trivector {{
+            a11: -self.a7,
+            a12: -self.a6,
+            a13: -self.a5,
+            a14: T::zero(),
+        }}
+    }}
+
+    // The bivector part of (self * r - r * self) / 2.
+    // For two normalized lines, this is a screw around their common perpendicular.
+    fn commutator(self, r: Bivector<T>) -> Bivector<T> {{
+        let m = (self * r - r * self) * T::from_f64(0.5);
+        Bivector {{
+            a5: m.a5,
+            a6: m.a6,
+            a7: m.a7,
+            a8: m.a8,
+            a9: m.a9,
+            a10: m.a10,
+        }}
+    }}
+
+    // The shortest distance between two lines
+    pub fn distance(self, r: Bivector<T>) -> T {{
+        let a = self.hat();
+        let b = r.hat();
+        let c = a.commutator(b);
+        let sin = c.norm();
+        if sin < T::from_f64(FLOAT_DIVISION_EPSILON) {{
+            // Parallel lines: the commutator is an ideal line whose weight is the distance
+            c.inorm()
         }} else {{
-            theta.cos() + (theta.sin() / theta) * self
+            (a ^ b).a15.abs() / sin
+        }}
+    }}
+
+    // The angle between the directions of two lines, between 0 and 180 degrees
+    pub fn angle(self, r: Bivector<T>) -> T {{
+        let cos = -(self.hat() | r.hat());
+        cos.max(-T::one()).min(T::one()).acos()
+    }}
+
+    // The normalized line that meets both lines at right angles,
+    // or None if the lines are parallel (and it is not unique.)
+    pub fn common_perpendicular(self, r: Bivector<T>) -> Option<Bivector<T>> {{
+        let c = self.hat().commutator(r.hat());
+        if c.norm() < T::from_f64(FLOAT_DIVISION_EPSILON) {{
+            None
+        }} else {{
+            Some(c.axis())
         }}
     }}
 }}
 
+impl<T: Scalar> Motor<T> {{
+    // Return the bivector that generates this (normalized) motor,
+    // i.e. the inverse of exp: m.log().exp() == m
+    pub fn log(self) -> Bivector<T> {{
+        // The scalar part is the cosine of half the rotation angle, and the Euclidean part has its sine as its norm.
+        // Taking the sine from the Euclidean part keeps its precision near a half angle of 0 or 180 degrees.
+        let s = self.a0;
+        let sin_sq = self.a8 * self.a8 + self.a9 * self.a9 + self.a10 * self.a10;
+        let (b, c) = if sin_sq < T::from_f64(FLOAT_DIVISION_EPSILON) && s > T::zero() {{
+            // Limits of the expressions below as the rotation angle goes to zero
+            (T::one(), self.a15 / T::from_f64(3.))
+        }} else if sin_sq < T::from_f64(FLOAT_DIVISION_EPSILON * FLOAT_DIVISION_EPSILON) {{
+            // A full turn (m = -1, up to a translation) has no particular axis,
+            // so return the generator of -m instead, which performs the same motion
+            return (-self).log();
+        }} else {{
+            let a = T::one() / sin_sq;
+            let b = sin_sq.sqrt().atan2(s) * a.sqrt();
+            (b, a * self.a15 * (T::one() - s * b))
+        }};
+        Bivector {{
+            a5: c * self.a10 + b * self.a5,
+            a6: c * self.a9 + b * self.a6,
+            a7: c * self.a8 + b * self.a7,
+            a8: b * self.a8,
+            a9: b * self.a9,
+            a10: b * self.a10,
+        }}
+    }}
+
+    // Return a normalized copy of this motor, such that m * m.reverse() == 1
+    pub fn normalize(self) -> Motor<T> {{
+        let a = T::one() / (self.a0 * self.a0 + self.a8 * self.a8 + self.a9 * self.a9 + self.a10 * self.a10).sqrt();
+        let b = (self.a15 * self.a0 - (self.a5 * self.a10 + self.a6 * self.a9 + self.a7 * self.a8)) * a * a;
+        Motor {{
+            a0: a * self.a0,
+            a5: a * (self.a5 + b * self.a10),
+            a6: a * (self.a6 + b * self.a9),
+            a7: a * (self.a7 + b * self.a8),
+            a8: a * self.a8,
+            a9: a * self.a9,
+            a10: a * self.a10,
+            a15: a * (self.a15 - b * self.a0),
+        }}
+    }}
+
+    // Return the motor that performs half of this motor's motion,
+    // i.e. m.sqrt() * m.sqrt() == m
+    pub fn sqrt(self) -> Motor<T> {{
+        (self + T::one()).normalize()
+    }}
+
+    // Screw-linear interpolation between two motors.
+    // alpha = 0 gives a, alpha = 1 gives b (or -b, which is the same motion),
+    // and intermediate values follow the constant-velocity screw motion that takes a to b.
+    pub fn interpolate(a: Motor<T>, b: Motor<T>, alpha: T) -> Motor<T> {{
+        let relative = a.reverse() * b;
+        // m and -m perform the same motion; take the shorter path
+        let relative = if relative.a0 < T::zero() {{ -relative }} else {{ relative }};
+        a * (relative.log() * alpha).exp()
+    }}
+}}
+
+impl<T: Scalar> ScalarAndBivector<T> {{
+    // Return the bivector that generates this (normalized) motor,
+    // i.e. the inverse of exp: m.log().exp() == m
+    pub fn log(self) -> Bivector<T> {{
+        self.motor().log()
+    }}
+
+    // Return the motor that performs half of this motor's motion,
+    // i.e. m.sqrt() * m.sqrt() == m
+    pub fn sqrt(self) -> ScalarAndBivector<T> {{
+        (self + T::one()).hat()
+    }}
+}}
+
 pub const I: Pseudoscalar = Pseudoscalar {{ a15: 1. }};
 
+#[cfg(test)]
+mod tests;
+
 """
 
     print(rust_template)
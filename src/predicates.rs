@@ -0,0 +1,492 @@
+//! Robust geometric predicates.
+//!
+//! Each predicate returns a value whose sign is guaranteed to be correct
+//! for the exact values of its (floating point) inputs.
+//! The magnitude is only an approximation and should not be relied on.
+//!
+//! The approach is the adaptive one of Shewchuk ("Adaptive Precision Floating-Point Arithmetic
+//! and Fast Robust Geometric Predicates"): the predicate is first evaluated in ordinary floating point
+//! along with an error bound. Only when the result is too close to zero to trust
+//! is it re-evaluated exactly, using floating-point expansions.
+//!
+//! Points are homogeneous, so they need not be normalized.
+//! The PGA-flavored predicates (orient3d, plane_side, line_side) have exactly the sign of the
+//! corresponding join computed in `pga`, e.g. orient3d(a, b, c, d) has the sign of a & b & c & d.
+
+use crate::pga::*;
+use crate::global::*;
+
+const EPSILON: Float = f64::EPSILON * 0.5; // Unit roundoff: the relative error of a single operation
+
+// ===========================================================================
+// Expansion arithmetic
+// ===========================================================================
+
+// An expansion is an unevaluated sum of floating point numbers
+// that are sorted by increasing magnitude and do not overlap,
+// so it represents a value exactly. Zero is the empty expansion.
+#[derive(Debug, Clone)]
+struct Expansion(Vec<Float>);
+
+fn two_sum(a: Float, b: Float) -> (Float, Float) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+// Requires |a| >= |b|
+fn fast_two_sum(a: Float, b: Float) -> (Float, Float) {
+    let x = a + b;
+    (x, b - (x - a))
+}
+
+fn two_product(a: Float, b: Float) -> (Float, Float) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+impl Expansion {
+    fn from_float(x: Float) -> Expansion {
+        if x == 0. {
+            Expansion(vec![])
+        } else {
+            Expansion(vec![x])
+        }
+    }
+
+    fn grow(&self, b: Float) -> Expansion {
+        let mut h = Vec::with_capacity(self.0.len() + 1);
+        let mut q = b;
+        for &e in self.0.iter() {
+            let (sum, err) = two_sum(q, e);
+            if err != 0. {
+                h.push(err);
+            }
+            q = sum;
+        }
+        if q != 0. {
+            h.push(q);
+        }
+        Expansion(h)
+    }
+
+    fn scale(&self, b: Float) -> Expansion {
+        let mut h = Vec::with_capacity(2 * self.0.len());
+        let mut iter = self.0.iter();
+        let (mut q, err) = match iter.next() {
+            Some(&e) => two_product(e, b),
+            None => return Expansion(h),
+        };
+        if err != 0. {
+            h.push(err);
+        }
+        for &e in iter {
+            let (p1, p0) = two_product(e, b);
+            let (sum, err) = two_sum(q, p0);
+            if err != 0. {
+                h.push(err);
+            }
+            let (new_q, err) = fast_two_sum(p1, sum);
+            if err != 0. {
+                h.push(err);
+            }
+            q = new_q;
+        }
+        if q != 0. {
+            h.push(q);
+        }
+        Expansion(h)
+    }
+
+    // Approximate value, with the correct sign
+    fn estimate(&self) -> Float {
+        if self.0.is_empty() {
+            0.
+        } else {
+            self.0.iter().sum()
+        }
+    }
+}
+
+// The arithmetic needed to evaluate a predicate.
+// This is implemented for plain floats (the fast path),
+// for error bounds (which track the sum of the magnitudes of all terms)
+// and for expansions (the exact path),
+// so that each predicate only needs to be written once.
+trait Arith: Clone {
+    fn add(&self, r: &Self) -> Self;
+    fn sub(&self, r: &Self) -> Self;
+    fn mul(&self, r: &Self) -> Self;
+    fn neg(&self) -> Self;
+}
+
+impl Arith for Float {
+    fn add(&self, r: &Self) -> Self { self + r }
+    fn sub(&self, r: &Self) -> Self { self - r }
+    fn mul(&self, r: &Self) -> Self { self * r }
+    fn neg(&self) -> Self { -self }
+}
+
+// The permanent of an expression: its value when all inputs are replaced by their magnitudes
+// and all subtractions by additions. Rounding error in the floating point evaluation
+// is bounded by a small multiple of this.
+#[derive(Debug, Clone)]
+struct Permanent(Float);
+
+impl Arith for Permanent {
+    fn add(&self, r: &Self) -> Self { Permanent(self.0 + r.0) }
+    fn sub(&self, r: &Self) -> Self { Permanent(self.0 + r.0) }
+    fn mul(&self, r: &Self) -> Self { Permanent(self.0 * r.0) }
+    fn neg(&self) -> Self { self.clone() }
+}
+
+impl Arith for Expansion {
+    fn add(&self, r: &Self) -> Self {
+        r.0.iter().fold(self.clone(), |acc, &x| acc.grow(x))
+    }
+
+    fn sub(&self, r: &Self) -> Self {
+        r.0.iter().fold(self.clone(), |acc, &x| acc.grow(-x))
+    }
+
+    fn mul(&self, r: &Self) -> Self {
+        r.0.iter().fold(Expansion(vec![]), |acc, &x| acc.add(&self.scale(x)))
+    }
+
+    fn neg(&self) -> Self {
+        Expansion(self.0.iter().map(|x| -x).collect())
+    }
+}
+
+// Evaluates f in floating point, and falls back to exact arithmetic
+// if the result is within the error bound (error_factor * unit roundoff * permanent) of zero.
+// error_factor must be at least the number of rounding operations along any path of the expression.
+fn adaptive<F, G, H>(error_factor: Float, fast: F, bound: G, exact: H) -> Float
+where F: Fn() -> Float, G: Fn() -> Permanent, H: Fn() -> Expansion {
+    let value = fast();
+    let error_bound = (error_factor * EPSILON) * (1. + error_factor * EPSILON) * bound().0;
+    if value.abs() > error_bound {
+        value
+    } else {
+        exact().estimate()
+    }
+}
+
+// ===========================================================================
+// Predicate formulas
+// ===========================================================================
+
+// These mirror the joins generated in pga.rs.
+// Points are [a11, a12, a13, a14], lines are [a5, a6, a7, a8, a9, a10] and planes are [a1, a2, a3, a4].
+
+fn join_point_point<A: Arith>(p: &[A; 4], q: &[A; 4]) -> [A; 6] {
+    [
+        p[1].mul(&q[0]).sub(&p[0].mul(&q[1])),
+        p[0].mul(&q[2]).sub(&p[2].mul(&q[0])),
+        p[2].mul(&q[1]).sub(&p[1].mul(&q[2])),
+        p[3].mul(&q[0]).sub(&p[0].mul(&q[3])),
+        p[3].mul(&q[1]).sub(&p[1].mul(&q[3])),
+        p[3].mul(&q[2]).sub(&p[2].mul(&q[3])),
+    ]
+}
+
+fn join_line_point<A: Arith>(l: &[A; 6], p: &[A; 4]) -> [A; 4] {
+    [
+        l[0].mul(&p[2]).add(&l[1].mul(&p[1])).add(&l[2].mul(&p[0])),
+        l[3].mul(&p[1]).sub(&l[4].mul(&p[0])).sub(&l[0].mul(&p[3])),
+        l[5].mul(&p[0]).sub(&l[3].mul(&p[2])).sub(&l[1].mul(&p[3])),
+        l[4].mul(&p[2]).sub(&l[5].mul(&p[1])).sub(&l[2].mul(&p[3])),
+    ]
+}
+
+fn join_plane_point<A: Arith>(v: &[A; 4], p: &[A; 4]) -> A {
+    v[0].mul(&p[3])
+        .add(&v[1].mul(&p[2]))
+        .add(&v[2].mul(&p[1]))
+        .add(&v[3].mul(&p[0]))
+        .neg()
+}
+
+fn det3<A: Arith>(m: &[[A; 3]; 3]) -> A {
+    let minor = |i: usize, j: usize| m[1][i].mul(&m[2][j]).sub(&m[1][j].mul(&m[2][i]));
+    m[0][0].mul(&minor(1, 2))
+        .sub(&m[0][1].mul(&minor(0, 2)))
+        .add(&m[0][2].mul(&minor(0, 1)))
+}
+
+// Determinant of a square matrix by cofactor expansion along the first row
+fn det<A: Arith>(m: &[Vec<A>]) -> A {
+    let n = m.len();
+    if n == 3 {
+        let rows = [
+            [m[0][0].clone(), m[0][1].clone(), m[0][2].clone()],
+            [m[1][0].clone(), m[1][1].clone(), m[1][2].clone()],
+            [m[2][0].clone(), m[2][1].clone(), m[2][2].clone()],
+        ];
+        return det3(&rows);
+    }
+    let mut result: Option<A> = None;
+    for j in 0..n {
+        let minor: Vec<Vec<A>> = m[1..].iter().map(|row| {
+            row.iter().enumerate().filter(|&(k, _)| k != j).map(|(_, x)| x.clone()).collect()
+        }).collect();
+        let term = m[0][j].mul(&det(&minor));
+        result = Some(match result {
+            None => term,
+            Some(acc) if j % 2 == 0 => acc.add(&term),
+            Some(acc) => acc.sub(&term),
+        });
+    }
+    result.unwrap()
+}
+
+// Rows of the lifted matrix used by incircle and insphere.
+// A point (x, y, w) in the XY plane lifts to (xw, yw, x^2 + y^2, w^2),
+// which is the row (x/w, y/w, (x/w)^2 + (y/w)^2, 1) scaled by the positive number w^2.
+fn lift<A: Arith>(coords: &[A]) -> Vec<A> {
+    let (w, xyz) = coords.split_last().unwrap();
+    let mut row: Vec<A> = xyz.iter().map(|x| x.mul(w)).collect();
+    let mut square = xyz[0].mul(&xyz[0]);
+    for x in &xyz[1..] {
+        square = square.add(&x.mul(x));
+    }
+    row.push(square);
+    row.push(w.mul(w));
+    row
+}
+
+fn point<A: Arith>(p: Trivector, f: &dyn Fn(Float) -> A) -> [A; 4] {
+    let (x, y, z, w) = p.as_tuple();
+    [f(x), f(y), f(z), f(w)]
+}
+
+fn plane<A: Arith>(v: Vector, f: &dyn Fn(Float) -> A) -> [A; 4] {
+    let (a1, a2, a3, a4) = v.as_tuple();
+    [f(a1), f(a2), f(a3), f(a4)]
+}
+
+fn line<A: Arith>(l: Bivector, f: &dyn Fn(Float) -> A) -> [A; 6] {
+    let (a5, a6, a7, a8, a9, a10) = l.as_tuple();
+    [f(a5), f(a6), f(a7), f(a8), f(a9), f(a10)]
+}
+
+fn float(x: Float) -> Float {
+    x
+}
+
+fn permanent(x: Float) -> Permanent {
+    Permanent(x.abs())
+}
+
+fn expansion(x: Float) -> Expansion {
+    Expansion::from_float(x)
+}
+
+fn sign(x: Float) -> Float {
+    if x > 0. {
+        1.
+    } else if x < 0. {
+        -1.
+    } else {
+        0.
+    }
+}
+
+// ===========================================================================
+// Predicates
+// ===========================================================================
+
+/// Returns a value with the sign of plane & p:
+/// positive or negative depending on which side of the plane p lies, and zero if p lies on it.
+pub fn plane_side(v: Vector, p: Trivector) -> Float {
+    adaptive(8.,
+        || join_plane_point(&plane(v, &float), &point(p, &float)),
+        || join_plane_point(&plane(v, &permanent), &point(p, &permanent)),
+        || join_plane_point(&plane(v, &expansion), &point(p, &expansion)),
+    )
+}
+
+/// Returns a value with the sign of (l & p) & q:
+/// positive or negative depending on which side of the plane through l and p the point q lies,
+/// and zero if l, p and q are coplanar.
+pub fn line_side(l: Bivector, p: Trivector, q: Trivector) -> Float {
+    fn eval<A: Arith>(l: Bivector, p: Trivector, q: Trivector, f: &dyn Fn(Float) -> A) -> A {
+        join_plane_point(&join_line_point(&line(l, f), &point(p, f)), &point(q, f))
+    }
+    adaptive(16.,
+        || eval(l, p, q, &float),
+        || eval(l, p, q, &permanent),
+        || eval(l, p, q, &expansion),
+    )
+}
+
+/// Returns a value with the sign of a & b & c & d:
+/// positive or negative depending on which side of the plane through a, b and c the point d lies,
+/// and zero if the four points are coplanar.
+pub fn orient3d(a: Trivector, b: Trivector, c: Trivector, d: Trivector) -> Float {
+    fn eval<A: Arith>(a: Trivector, b: Trivector, c: Trivector, d: Trivector, f: &dyn Fn(Float) -> A) -> A {
+        let l = join_point_point(&point(a, f), &point(b, f));
+        join_plane_point(&join_line_point(&l, &point(c, f)), &point(d, f))
+    }
+    adaptive(16.,
+        || eval(a, b, c, d, &float),
+        || eval(a, b, c, d, &permanent),
+        || eval(a, b, c, d, &expansion),
+    )
+}
+
+/// Returns whether the three points lie exactly on one line (i.e. a & b & c is exactly zero.)
+pub fn collinear(a: Trivector, b: Trivector, c: Trivector) -> bool {
+    let l = join_point_point(&point(a, &expansion), &point(b, &expansion));
+    join_line_point(&l, &point(c, &expansion)).iter().all(|x| x.0.is_empty())
+}
+
+/// Returns whether the four points lie exactly on one plane.
+pub fn coplanar(a: Trivector, b: Trivector, c: Trivector, d: Trivector) -> bool {
+    orient3d(a, b, c, d) == 0.
+}
+
+/// Looking at the points projected onto the XY plane,
+/// returns a positive value if a, b and c are in counterclockwise order,
+/// a negative value if they are in clockwise order, and zero if they are collinear.
+/// The points must be finite.
+pub fn orient2d(a: Trivector, b: Trivector, c: Trivector) -> Float {
+    fn rows<A: Arith>(a: Trivector, b: Trivector, c: Trivector, f: &dyn Fn(Float) -> A) -> [[A; 3]; 3] {
+        let row = |p: Trivector| {
+            let [x, y, _, w] = point(p, f);
+            [x, y, w]
+        };
+        [row(a), row(b), row(c)]
+    }
+    // Each homogeneous row is the euclidean row scaled by its weight
+    let weights = [a, b, c].iter().map(|p| sign(p.as_tuple().3)).product::<Float>();
+    weights * adaptive(8.,
+        || det3(&rows(a, b, c, &float)),
+        || det3(&rows(a, b, c, &permanent)),
+        || det3(&rows(a, b, c, &expansion)),
+    )
+}
+
+/// Looking at the points projected onto the XY plane,
+/// returns a positive value if d lies inside the circle through a, b and c,
+/// a negative value if it lies outside, and zero if the four points are cocircular.
+/// a, b and c must be in counterclockwise order (see orient2d), otherwise the sign is reversed.
+pub fn incircle(a: Trivector, b: Trivector, c: Trivector, d: Trivector) -> Float {
+    fn rows<A: Arith>(pts: &[Trivector], f: &dyn Fn(Float) -> A) -> Vec<Vec<A>> {
+        pts.iter().map(|&p| {
+            let [x, y, _, w] = point(p, f);
+            lift(&[x, y, w])
+        }).collect()
+    }
+    let pts = [a, b, c, d];
+    adaptive(64.,
+        || det(&rows(&pts, &float)),
+        || det(&rows(&pts, &permanent)),
+        || det(&rows(&pts, &expansion)),
+    )
+}
+
+/// Returns a positive value if e lies inside the sphere through a, b, c and d,
+/// a negative value if it lies outside, and zero if the five points are cospherical.
+/// orient3d(a, b, c, d) must be positive, otherwise the sign is reversed.
+pub fn insphere(a: Trivector, b: Trivector, c: Trivector, d: Trivector, e: Trivector) -> Float {
+    fn rows<A: Arith>(pts: &[Trivector], f: &dyn Fn(Float) -> A) -> Vec<Vec<A>> {
+        pts.iter().map(|&p| lift(&point(p, f))).collect()
+    }
+    let pts = [a, b, c, d, e];
+    adaptive(256.,
+        || det(&rows(&pts, &float)),
+        || det(&rows(&pts, &permanent)),
+        || det(&rows(&pts, &expansion)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::construct::*;
+
+    // Points a tiny distance to either side of the line y = x, which floating point evaluation gets wrong
+    fn near_diagonal(k: i32) -> Trivector {
+        point_from_xyz(0.5 + k as Float * Float::EPSILON, 0.5, 0.)
+    }
+
+    #[test]
+    fn orient2d_signs() {
+        let a = point_from_xyz(0., 0., 0.);
+        let b = point_from_xyz(1., 0., 0.);
+        let c = point_from_xyz(0., 1., 0.);
+        assert!(orient2d(a, b, c) > 0.);
+        assert!(orient2d(a, c, b) < 0.);
+        assert_eq!(orient2d(a, b, point_from_xyz(7., 0., 3.)), 0.);
+
+        // A point's weight, including its sign, does not change which side it is on
+        assert!(orient2d(a, b, 2. * c) > 0.);
+        assert!(orient2d(a, b, -1. * c) > 0.);
+
+        let p = point_from_xyz(12., 12., 0.);
+        let q = point_from_xyz(24., 24., 0.);
+        assert_eq!(orient2d(near_diagonal(0), p, q), 0.);
+        for k in 1..64 {
+            assert!(orient2d(near_diagonal(k), p, q) < 0.);
+            assert!(orient2d(near_diagonal(-k), p, q) > 0.);
+        }
+    }
+
+    #[test]
+    fn collinear_is_exact() {
+        let p = point_from_xyz(12., 12., 0.);
+        let q = point_from_xyz(24., 24., 0.);
+        assert!(collinear(near_diagonal(0), p, q));
+        assert!(!collinear(near_diagonal(1), p, q));
+    }
+
+    #[test]
+    fn orient3d_matches_join() {
+        let a = point_from_xyz(0., 0., 0.);
+        let b = point_from_xyz(1., 0., 0.);
+        let c = point_from_xyz(0., 1., 0.);
+        for &d in &[point_from_xyz(0.3, 0.2, 1.), point_from_xyz(-2., 5., -0.1)] {
+            let join = a & b & c & d;
+            assert_eq!(orient3d(a, b, c, d).signum(), join.signum());
+            assert_eq!(orient3d(b, a, c, d).signum(), -join.signum());
+        }
+        assert!(coplanar(a, b, c, point_from_xyz(5., -3., 0.)));
+        assert!(!coplanar(a, b, c, point_from_xyz(5., -3., Float::EPSILON)));
+    }
+
+    #[test]
+    fn plane_and_line_sides() {
+        let plane = plane_from_three_points(point_from_xyz(0., 0., 1.), point_from_xyz(1., 0., 1.), point_from_xyz(0., 1., 1.));
+        let above = point_from_xyz(0.5, 0.5, 2.);
+        let below = point_from_xyz(0.5, 0.5, 0.);
+        assert_eq!(plane_side(plane, above).signum(), (plane & above).signum());
+        assert_eq!(plane_side(plane, above).signum(), -plane_side(plane, below).signum());
+        assert_eq!(plane_side(plane, point_from_xyz(3., -4., 1.)), 0.);
+
+        let l = point_from_xyz(0., 0., 1.) & point_from_xyz(1., 0., 1.);
+        let p = point_from_xyz(0., 1., 1.);
+        assert_eq!(line_side(l, p, above).signum(), orient3d(point_from_xyz(0., 0., 1.), point_from_xyz(1., 0., 1.), p, above).signum());
+        assert_eq!(line_side(l, p, point_from_xyz(7., 7., 1.)), 0.);
+    }
+
+    #[test]
+    fn incircle_and_insphere() {
+        let a = point_from_xyz(1., 0., 0.);
+        let b = point_from_xyz(0., 1., 0.);
+        let c = point_from_xyz(-1., 0., 0.);
+        assert!(orient2d(a, b, c) > 0.);
+        assert!(incircle(a, b, c, point_from_xyz(0., 0., 0.)) > 0.);
+        assert!(incircle(a, b, c, point_from_xyz(2., 0., 0.)) < 0.);
+        assert_eq!(incircle(a, b, c, point_from_xyz(0., -1., 0.)), 0.);
+
+        let mut d = [a, b, c, point_from_xyz(0., 0., 1.)];
+        if orient3d(d[0], d[1], d[2], d[3]) < 0. {
+            d.swap(0, 1);
+        }
+        assert!(insphere(d[0], d[1], d[2], d[3], point_from_xyz(0., 0., 0.1)) > 0.);
+        assert!(insphere(d[0], d[1], d[2], d[3], point_from_xyz(0., 0., -1.5)) < 0.);
+        assert_eq!(insphere(d[0], d[1], d[2], d[3], point_from_xyz(0., 0., -1.)), 0.);
+    }
+}
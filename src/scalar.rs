@@ -0,0 +1,238 @@
+//! The scalar types that the PGA types in `pga` may be built on.
+//!
+//! `f64` is the default (see `global::Float`), `f32` is useful for compact
+//! data such as tessellation output, and `DualNumber` carries a derivative
+//! alongside each value, so running any computation with it yields exact
+//! first derivatives with respect to one chosen input (forward-mode differentiation.)
+
+use std::fmt::Debug;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+pub trait Scalar: Copy + Default + Debug + PartialEq + PartialOrd
+    + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self> {
+
+    fn from_f64(x: f64) -> Self;
+
+    // Return the (real) value as an f64, dropping any extra information such as derivatives
+    fn to_f64(self) -> f64;
+
+    fn zero() -> Self {
+        Self::from_f64(0.)
+    }
+
+    fn one() -> Self {
+        Self::from_f64(1.)
+    }
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+
+    // The angle of the point (x, self) from the positive x axis, between -180 and 180 degrees
+    fn atan2(self, x: Self) -> Self;
+
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+}
+
+impl Scalar for f64 {
+    fn from_f64(x: f64) -> Self { x }
+    fn to_f64(self) -> f64 { self }
+    fn abs(self) -> Self { f64::abs(self) }
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+    fn powi(self, n: i32) -> Self { f64::powi(self, n) }
+    fn sin(self) -> Self { f64::sin(self) }
+    fn cos(self) -> Self { f64::cos(self) }
+    fn acos(self) -> Self { f64::acos(self) }
+    fn sinh(self) -> Self { f64::sinh(self) }
+    fn cosh(self) -> Self { f64::cosh(self) }
+    fn atan2(self, x: Self) -> Self { f64::atan2(self, x) }
+}
+
+impl Scalar for f32 {
+    fn from_f64(x: f64) -> Self { x as f32 }
+    fn to_f64(self) -> f64 { self as f64 }
+    fn abs(self) -> Self { f32::abs(self) }
+    fn sqrt(self) -> Self { f32::sqrt(self) }
+    fn powi(self, n: i32) -> Self { f32::powi(self, n) }
+    fn sin(self) -> Self { f32::sin(self) }
+    fn cos(self) -> Self { f32::cos(self) }
+    fn acos(self) -> Self { f32::acos(self) }
+    fn sinh(self) -> Self { f32::sinh(self) }
+    fn cosh(self) -> Self { f32::cosh(self) }
+    fn atan2(self, x: Self) -> Self { f32::atan2(self, x) }
+}
+
+/// A dual number value + derivative * ε, where ε^2 = 0.
+/// Arithmetic on dual numbers propagates the derivative using the chain rule.
+/// To differentiate with respect to some input,
+/// pass that input in as `DualNumber::variable` and everything else as `DualNumber::constant`.
+/// Comparisons only look at the value.
+#[derive(Default,Debug,Clone,Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DualNumber {
+    pub value: f64,
+    pub derivative: f64,
+}
+
+impl DualNumber {
+    pub fn new(value: f64, derivative: f64) -> DualNumber {
+        DualNumber {value, derivative}
+    }
+
+    // A quantity that does not depend on the input being differentiated
+    pub fn constant(value: f64) -> DualNumber {
+        DualNumber {value, derivative: 0.}
+    }
+
+    // The input being differentiated
+    pub fn variable(value: f64) -> DualNumber {
+        DualNumber {value, derivative: 1.}
+    }
+}
+
+impl PartialEq for DualNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for DualNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Add for DualNumber {
+    type Output = DualNumber;
+
+    fn add(self, r: DualNumber) -> DualNumber {
+        DualNumber::new(self.value + r.value, self.derivative + r.derivative)
+    }
+}
+
+impl Sub for DualNumber {
+    type Output = DualNumber;
+
+    fn sub(self, r: DualNumber) -> DualNumber {
+        DualNumber::new(self.value - r.value, self.derivative - r.derivative)
+    }
+}
+
+impl Mul for DualNumber {
+    type Output = DualNumber;
+
+    fn mul(self, r: DualNumber) -> DualNumber {
+        DualNumber::new(self.value * r.value, self.derivative * r.value + self.value * r.derivative)
+    }
+}
+
+impl Div for DualNumber {
+    type Output = DualNumber;
+
+    fn div(self, r: DualNumber) -> DualNumber {
+        DualNumber::new(
+            self.value / r.value,
+            (self.derivative * r.value - self.value * r.derivative) / (r.value * r.value),
+        )
+    }
+}
+
+impl Neg for DualNumber {
+    type Output = DualNumber;
+
+    fn neg(self) -> DualNumber {
+        DualNumber::new(-self.value, -self.derivative)
+    }
+}
+
+impl Scalar for DualNumber {
+    fn from_f64(x: f64) -> Self {
+        DualNumber::constant(x)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        if self.value < 0. { -self } else { self }
+    }
+
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        DualNumber::new(value, self.derivative / (2. * value))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        DualNumber::new(self.value.powi(n), n as f64 * self.value.powi(n - 1) * self.derivative)
+    }
+
+    fn sin(self) -> Self {
+        DualNumber::new(self.value.sin(), self.value.cos() * self.derivative)
+    }
+
+    fn cos(self) -> Self {
+        DualNumber::new(self.value.cos(), -self.value.sin() * self.derivative)
+    }
+
+    fn acos(self) -> Self {
+        DualNumber::new(self.value.acos(), -self.derivative / (1. - self.value * self.value).sqrt())
+    }
+
+    fn sinh(self) -> Self {
+        DualNumber::new(self.value.sinh(), self.value.cosh() * self.derivative)
+    }
+
+    fn cosh(self) -> Self {
+        DualNumber::new(self.value.cosh(), self.value.sinh() * self.derivative)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        let r_sq = x.value * x.value + self.value * self.value;
+        DualNumber::new(self.value.atan2(x.value), (x.value * self.derivative - self.value * x.derivative) / r_sq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compares a dual number's derivative against a central difference of its value
+    fn check<F: Fn(DualNumber) -> DualNumber>(f: F, x: f64) {
+        let h = 1e-6;
+        let expected = (f(DualNumber::constant(x + h)).value - f(DualNumber::constant(x - h)).value) / (2. * h);
+        let actual = f(DualNumber::variable(x));
+        assert!((actual.derivative - expected).abs() < 1e-6 * (1. + expected.abs()), "{:?} != {}", actual, expected);
+    }
+
+    #[test]
+    fn dual_number_chain_rule() {
+        for &x in &[0.3, 0.9, 2.] {
+            check(|x| x * x * x - x / (x + DualNumber::constant(1.)), x);
+            check(|x| x.sqrt().sin() * x.cos(), x);
+            check(|x| x.powi(3).sinh() + x.cosh(), x);
+            check(|x| (x * DualNumber::constant(0.3)).acos(), x);
+            check(|x| x.atan2(DualNumber::constant(1.) - x), x);
+            check(|x| (-x).abs().max(x.min(DualNumber::constant(0.5))), x);
+        }
+    }
+
+    #[test]
+    fn comparisons_ignore_derivative() {
+        assert!(DualNumber::new(1., 5.) < DualNumber::new(2., -5.));
+        assert_eq!(DualNumber::new(1., 0.), DualNumber::new(1., 5.));
+        assert_eq!(DualNumber::new(1., 0.).partial_cmp(&DualNumber::new(1., 5.)), Some(std::cmp::Ordering::Equal));
+        assert_eq!(DualNumber::variable(3.).to_f64(), 3.);
+    }
+}
@@ -6,24 +6,71 @@
 ///
 /// Surfaces as defined by this module may exist outside the context of a Topo
 /// and are at a lower level of abstraction.
+///
+/// Surfaces are generic over their scalar type (see the scalar module), which defaults to Float.
+/// Only evaluation (d0) is available for other scalar types,
+/// e.g. a Surface<DualNumber> gives the derivative of a point with respect to a construction parameter.
 
 use crate::pga::*;
 use crate::global::*;
+use crate::vec3::{xyz, dot, cross, sub, length, dehomogenize};
+use crate::bounds::*;
+use crate::scalar::Scalar;
 
 #[derive(Debug, Clone)]
-pub enum Surface {
-    Plane(Plane),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Surface<T = Float> {
+    Plane(Plane<T>),
 }
 
-impl Surface {
+impl<T: Scalar> Surface<T> {
     // Evaluate the point on the surface at parameter values u, v
-    pub fn d0(&self, u: Float, v: Float) -> Trivector {
+    pub fn d0(&self, u: T, v: T) -> Trivector<T> {
         match &self {
             Surface::Plane(x) => x.d0(u, v),
         }
     }
 }
 
+impl Surface {
+    // Reflect the surface about the given entity (point, line, plane)
+    pub fn reflect<T>(&self, entity: T) -> Surface
+    where Vector: Reflect<T>, Bivector: Reflect<T>, Trivector: Reflect<T>, FullMultivector: Reflect<T>, T: Copy {
+        match &self {
+            Surface::Plane(x) => Surface::Plane(x.reflect(entity)),
+        }
+    }
+
+    // Transform the surface with the given motor
+    pub fn transform<T>(&self, entity: T) -> Surface
+    where Vector: Transform<T>, Bivector: Transform<T>, Trivector: Transform<T>, FullMultivector: Transform<T>, T: Copy {
+        match &self {
+            Surface::Plane(x) => Surface::Plane(x.transform(entity)),
+        }
+    }
+
+    /// Get a set of points, the convex hull of which bounds the surface on the given parameter ranges
+    pub fn hull(&self, u_start: Float, u_end: Float, v_start: Float, v_end: Float) -> Vec<Trivector> {
+        match &self {
+            Surface::Plane(x) => x.hull(u_start, u_end, v_start, v_end),
+        }
+    }
+
+    /// Get the smallest axis-aligned box containing the surface on the given parameter ranges
+    pub fn bounding_box(&self, u_start: Float, u_end: Float, v_start: Float, v_end: Float) -> BoundingBox {
+        match &self {
+            // The hull of a plane is the corners of the region, which are its extremes
+            Surface::Plane(x) => BoundingBox::from_points(&x.hull(u_start, u_end, v_start, v_end)),
+        }
+    }
+
+    /// Get a box, not necessarily axis-aligned, containing the surface on the given parameter ranges.
+    /// It is fitted to the surface's hull, so it is cheap to compute but not always tight.
+    pub fn oriented_bounding_box(&self, u_start: Float, u_end: Float, v_start: Float, v_end: Float) -> OrientedBoundingBox {
+        OrientedBoundingBox::from_points(&self.hull(u_start, u_end, v_start, v_end)).unwrap()
+    }
+}
+
 // A plane is parameterized by a (euclidean) point p0 and two infinite lines du and dv.
 // The plane starts at p0 and extends mutually orthogonal to du and dv.
 // The parameters u and v are the signed distance from p0 in the du and dv directions.
@@ -31,23 +78,191 @@ impl Surface {
 // r(u, v) = m(u, v) * p0 * ~m(u, v)
 // where m(u, v) = exp(u / 2 * du) * exp(v / 2 * dv) = exp(1/2 * (u * du + v * dv))
 #[derive(Debug, Clone)]
-pub struct Plane {
-    pub p0: Trivector,
-    pub du: Bivector,
-    pub dv: Bivector,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane<T = Float> {
+    pub p0: Trivector<T>,
+    pub du: Bivector<T>,
+    pub dv: Bivector<T>,
+}
+
+impl<T: Scalar> Plane<T> {
+    pub fn d0(&self, u: T, v: T) -> Trivector<T> {
+        self.p0.transform(((self.du * u + self.dv * v) * T::from_f64(0.5)).exp())
+    }
 }
 
 impl Plane {
-    pub fn d0(&self, u: Float, v: Float) -> Trivector {
-        self.p0.transform((0.5 * (u * self.du + v * self.dv)).exp())
+    pub fn reflect<T>(&self, entity: T) -> Plane
+    where Bivector: Reflect<T>, Trivector: Reflect<T>, T: Copy {
+        Plane {p0: self.p0.reflect(entity), du: self.du.reflect(entity), dv: self.dv.reflect(entity)}
+    }
+
+    pub fn transform<T>(&self, entity: T) -> Plane
+    where Bivector: Transform<T>, Trivector: Transform<T>, T: Copy {
+        Plane {p0: self.p0.transform(entity), du: self.du.transform(entity), dv: self.dv.transform(entity)}
+    }
+
+    pub fn hull(&self, u_start: Float, u_end: Float, v_start: Float, v_end: Float) -> Vec<Trivector> {
+        // A region of a plane bounded by constant U and V is a parallelogram
+        vec![self.d0(u_start, v_start), self.d0(u_end, v_start), self.d0(u_end, v_end), self.d0(u_start, v_end)]
+    }
+
+    // Returns the origin of the plane, and how far it moves per unit of U and V
+    fn frame(&self) -> ([Float; 3], [Float; 3], [Float; 3]) {
+        let origin = xyz(dehomogenize(self.d0(0., 0.)));
+        (origin, sub(xyz(dehomogenize(self.d0(1., 0.))), origin), sub(xyz(dehomogenize(self.d0(0., 1.))), origin))
+    }
+
+    // Returns the map from the (u, v) parameters of this plane to those of another plane,
+    // or None if the planes are not coincident
+    fn coincident_remap(&self, other: &Plane) -> Option<SurfaceRemap> {
+        let (origin0, eu0, ev0) = self.frame();
+        let (origin1, eu1, ev1) = other.frame();
+
+        let normal0 = cross(eu0, ev0);
+        let normal1 = cross(eu1, ev1);
+        let (area0, area1) = (length(normal0), length(normal1));
+        assert!(area0 > FLOAT_DIVISION_EPSILON && area1 > FLOAT_DIVISION_EPSILON, "du and dv must not be parallel");
+        if length(cross(normal0, normal1)) > FLOAT_DIVISION_EPSILON * area0 * area1 {
+            return None;
+        }
+        if (dot(sub(origin0, origin1), normal1) / area1).abs() > EPSILON_COINCIDENT_DISTANCE {
+            return None;
+        }
+
+        // Express a displacement within the plane in terms of the other plane's U and V directions
+        let (uu, uv, vv) = (dot(eu1, eu1), dot(eu1, ev1), dot(ev1, ev1));
+        let det = uu * vv - uv * uv;
+        let coordinates = |w: [Float; 3]| {
+            let (wu, wv) = (dot(eu1, w), dot(ev1, w));
+            [(vv * wu - uv * wv) / det, (uu * wv - uv * wu) / det]
+        };
+
+        let offset = coordinates(sub(origin0, origin1));
+        let [muu, mvu] = coordinates(eu0);
+        let [muv, mvv] = coordinates(ev0);
+        Some(SurfaceRemap {offset, matrix: [[muu, muv], [mvu, mvv]]})
     }
 }
 
-pub fn surfaces_coincident(s0: &Surface, s1: &Surface) -> Option<Direction> {
-    // Some(Forward) => Surfaces are coincident and have the same "sense"
-    // Some(Reverse) => Surfaces are coincident but have opposing "sense"
+/// How the parameters of a surface map onto the parameters of a coincident surface:
+/// (u1, v1) = offset + matrix * (u0, v0)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceRemap {
+    pub offset: [Float; 2],
+    pub matrix: [[Float; 2]; 2],
+}
+
+impl SurfaceRemap {
+    /// The remap of a surface onto itself
+    pub fn identity() -> SurfaceRemap {
+        SurfaceRemap {offset: [0., 0.], matrix: [[1., 0.], [0., 1.]]}
+    }
+
+    pub fn apply(&self, u: Float, v: Float) -> (Float, Float) {
+        let [[a, b], [c, d]] = self.matrix;
+        (self.offset[0] + a * u + b * v, self.offset[1] + c * u + d * v)
+    }
+
+    // A remap that mirrors the parameters reverses the sense of the surface
+    pub fn direction(&self) -> Direction {
+        let [[a, b], [c, d]] = self.matrix;
+        if a * d - b * c < 0. { Direction::Reverse } else { Direction::Forward }
+    }
+}
+
+/// Determine whether two surfaces cover the same points.
+/// If so, returns whether they have the same "sense",
+/// along with the map from parameters on the first surface to parameters on the second.
+pub fn surfaces_coincident(s0: &Surface, s1: &Surface) -> Option<(Direction, SurfaceRemap)> {
+    // Some((Forward, remap)) => Surfaces are coincident and have the same "sense"
+    // Some((Reverse, remap)) => Surfaces are coincident but have opposing "sense"
     // None => Surfaces are not coincident
 
-    // TODO implement this
-    None
+    let remap = match (s0, s1) {
+        (Surface::Plane(p0), Surface::Plane(p1)) => p0.coincident_remap(p1)?,
+    };
+    Some((remap.direction(), remap))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::construct::*;
+    use crate::approx::*;
+    use crate::scalar::DualNumber;
+
+    fn constant_line(l: Bivector) -> Bivector<DualNumber> {
+        let (a5, a6, a7, a8, a9, a10) = l.as_tuple();
+        let c = DualNumber::constant;
+        Bivector::new(c(a5), c(a6), c(a7), c(a8), c(a9), c(a10))
+    }
+
+    #[test]
+    fn dual_number_derivative_with_respect_to_origin() {
+        let Surface::Plane(plane) = Surface::plane_from_three_points(point_from_xyz(1., 2., 3.), point_from_xyz(2., 2., 3.), point_from_xyz(1., 3., 3.));
+
+        // The same plane, differentiated with respect to the height of its origin
+        let c = DualNumber::constant;
+        let (x, y, z, w) = plane.p0.as_tuple();
+        let p0 = Trivector::new(c(x), c(y), DualNumber::new(z, w), c(w));
+        let surface = Surface::Plane(Plane {p0, du: constant_line(plane.du), dv: constant_line(plane.dv)});
+
+        for &(u, v) in &[(0., 0.), (1.5, -2.), (-0.3, 0.7)] {
+            let (x, y, z, w) = surface.d0(c(u), c(v)).as_tuple();
+            assert!(Trivector::new(x.value, y.value, z.value, w.value).approx_eq(&plane.d0(u, v)));
+            // Raising the origin raises every point of the plane by the same amount
+            let derivative = Trivector::new(x.derivative, y.derivative, z.derivative, w.derivative);
+            assert!(derivative.approx_eq(&inf_point_from_xyz(0., 0., 1.)));
+        }
+    }
+
+    fn plane(p0: [Float; 3], p1: [Float; 3], p2: [Float; 3]) -> Surface {
+        let point = |[x, y, z]: [Float; 3]| point_from_xyz(x, y, z);
+        Surface::plane_from_three_points(point(p0), point(p1), point(p2))
+    }
+
+    #[test]
+    fn coincident_planes() {
+        let s0 = plane([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let check = |s1: &Surface, expected: Direction| {
+            let (direction, remap) = surfaces_coincident(&s0, s1).unwrap();
+            assert_eq!(direction, expected);
+            for &(u, v) in &[(0., 0.), (1., 2.), (-3., 0.5)] {
+                let (u1, v1) = remap.apply(u, v);
+                assert!(s0.d0(u, v).hat().approx_eq(&s1.d0(u1, v1).hat()));
+            }
+        };
+
+        // The same plane with a different origin, and with du and dv turned a quarter turn
+        check(&plane([2., 3., 0.], [2., 4., 0.], [1., 3., 0.]), Direction::Forward);
+        // du and dv that are not perpendicular
+        check(&plane([1., 1., 0.], [2., 1., 0.], [2., 2., 0.]), Direction::Forward);
+        // Swapping du and dv flips the sense of the surface
+        check(&plane([0., 0., 0.], [0., 1., 0.], [1., 0., 0.]), Direction::Reverse);
+
+        assert!(surfaces_coincident(&s0, &plane([0., 0., 1.], [1., 0., 1.], [0., 1., 1.])).is_none());
+        assert!(surfaces_coincident(&s0, &plane([0., 0., 0.], [1., 0., 0.], [0., 1., 1.])).is_none());
+    }
+
+    #[test]
+    fn plane_bounding_boxes() {
+        let s = plane([1., 2., 3.], [2., 3., 3.], [1., 2., 4.]);
+        let b = s.bounding_box(-1., 2., 0.5, 3.);
+        let obb = s.oriented_bounding_box(-1., 2., 0.5, 3.);
+        // The corners of the region are its extremes
+        for &(u, v) in &[(-1., 0.5), (-1., 3.), (2., 0.5), (2., 3.)] {
+            let p = s.d0(u, v).hat();
+            assert!(b.contains(p, 1e-12));
+            assert!(obb.contains(p, 1e-9));
+        }
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let (u, v) = (-1. + 0.75 * i as Float, 0.5 + 0.625 * j as Float);
+                assert!(b.contains(s.d0(u, v).hat(), 1e-12) && obb.contains(s.d0(u, v).hat(), 1e-9));
+            }
+        }
+        let corners = BoundingBox::from_points(&[(-1., 0.5), (-1., 3.), (2., 0.5), (2., 3.)].map(|(u, v)| s.d0(u, v).hat()));
+        assert!((0..3).all(|i| (b.min[i] - corners.min[i]).abs() < 1e-12 && (b.max[i] - corners.max[i]).abs() < 1e-12));
+    }
 }
@@ -7,6 +7,8 @@ use crate::curve::*;
 use crate::surface::*;
 use crate::construct::*;
 use crate::global::*;
+use crate::approx::*;
+use crate::bounds::*;
 
 pub type VertexIndex = usize;
 pub type EdgeIndex = usize;
@@ -72,6 +74,61 @@ impl Topo {
         &self.solids
     }
 
+    /// Returns the section of curve that an edge covers:
+    /// the edge's curve trimmed to its endpoints,
+    /// or the whole curve if the edge is unbounded (i.e. a closed curve.)
+    pub fn edge_curve(&self, edge: EdgeIndex) -> Curve {
+        let curve = self.curves[self.edges[edge].curve].clone();
+        match self.edges[edge].bounds {
+            Some(_) => {
+                let (t_start, t_end) = curve_bounds_for_edge(self, edge);
+                curve.trim(t_start, t_end)
+            },
+            None => curve,
+        }
+    }
+
+    /// Returns the smallest axis-aligned box containing an edge
+    pub fn edge_bounding_box(&self, edge: EdgeIndex) -> BoundingBox {
+        let curve = self.edge_curve(edge);
+        curve.bounding_box(curve.t_min().unwrap(), curve.t_max().unwrap())
+    }
+
+    /// Returns the smallest axis-aligned box containing a face
+    pub fn face_bounding_box(&self, face: FaceIndex) -> BoundingBox {
+        let Face { surface, bounds } = &self.faces[face];
+        let boundary = bounds.iter().flat_map(|l| l.elements.iter())
+            .fold(BoundingBox::empty(), |b, directed_edge| b.union(&self.edge_bounding_box(directed_edge.edge)));
+        match &self.surfaces[*surface] {
+            // A region of a plane lies within the convex hull of its boundary
+            Surface::Plane(_) => {
+                assert!(!bounds.is_empty(), "A face on a plane must have bounds");
+                boundary
+            },
+        }
+    }
+
+    /// Returns the smallest axis-aligned box containing all of the geometry,
+    /// which is empty if there is none
+    pub fn bounding_box(&self) -> BoundingBox {
+        let vertices = BoundingBox::from_points(&self.vertices);
+        let edges = (0..self.edges.len()).fold(vertices, |b, edge| b.union(&self.edge_bounding_box(edge)));
+        (0..self.faces.len()).fold(edges, |b, face| b.union(&self.face_bounding_box(face)))
+    }
+
+    /// Returns a box, not necessarily axis-aligned, containing all of the geometry,
+    /// or None if there is none.
+    /// It is fitted to the vertices and the hulls of the edges,
+    /// which also bound the faces since they all lie on planes.
+    pub fn oriented_bounding_box(&self) -> Option<OrientedBoundingBox> {
+        let mut points = self.vertices.clone();
+        for edge in 0..self.edges.len() {
+            let curve = self.edge_curve(edge);
+            points.extend(curve.hull(curve.t_min().unwrap(), curve.t_max().unwrap()));
+        }
+        OrientedBoundingBox::from_points(&points)
+    }
+
     /// Empty topology, containing no geometry
     pub fn empty() -> Self {
         Default::default()
@@ -101,42 +158,59 @@ impl Topo {
         self.push_vertex(other.vertices[vertex])
     }
 
-    // Returns the CurveIndex and a direction
-    // indicating whether the returned curve has a reversed travel direction
+    // Returns the CurveIndex and the map from parameters on the provided curve
+    // to parameters on the returned one.
+    // Its direction indicates whether the returned curve has a reversed travel direction
     // from the provided one
     // (This means that e.g. edge endpoints need to be swapped)
-    fn push_curve(&mut self, curve: Curve) -> (CurveIndex, Direction) {
+    fn push_curve(&mut self, curve: Curve) -> (CurveIndex, CurveRemap) {
+        // Bounded curves can only coincide where their boxes overlap,
+        // which rules out most curves far more cheaply than comparing them point by point
+        let bounds = |c: &Curve| Some(c.bounding_box(c.t_min()?, c.t_max()?));
+        let curve_bounds = bounds(&curve);
         for (i, existing_curve) in self.curves.iter().enumerate() {
-            if let Some(direction) = curves_coincident(&curve, existing_curve) {
-                return (i, direction);
+            if curve.approx_eq(existing_curve) {
+                return (i, CurveRemap::identity());
+            }
+            if let (Some(b0), Some(b1)) = (&curve_bounds, bounds(existing_curve)) {
+                if !b0.intersects(&b1, EPSILON_COINCIDENT_DISTANCE) {
+                    continue;
+                }
+            }
+            if let Some((_, remap)) = curves_coincident(&curve, existing_curve) {
+                return (i, remap);
             }
         };
 
         let ix = self.curves.len();
         self.curves.push(curve);
-        (ix, Direction::Forward)
+        (ix, CurveRemap::identity())
     }
 
-    fn push_other_curve(&mut self, other: &Topo, curve: CurveIndex) -> (CurveIndex, Direction) {
+    fn push_other_curve(&mut self, other: &Topo, curve: CurveIndex) -> (CurveIndex, CurveRemap) {
         self.push_curve(other.curves[curve].clone())
     }
 
-    // Returns the CurveIndex and a direction
-    // indicating whether the returned curve has a reversed "sense"
+    // Returns the SurfaceIndex and the map from parameters on the provided surface
+    // to parameters on the returned one.
+    // Its direction indicates whether the returned surface has a reversed "sense"
     // (i.e. either U or V was flipped, but not both, since that would amount to a 180 degree rotation)
-    fn push_surface(&mut self, surface: Surface) -> (SurfaceIndex, Direction) {
+    fn push_surface(&mut self, surface: Surface) -> (SurfaceIndex, SurfaceRemap) {
         for (i, existing_surface) in self.surfaces.iter().enumerate() {
-            if let Some(direction) = surfaces_coincident(&surface, existing_surface) {
-                return (i, direction);
+            if surface.approx_eq(existing_surface) {
+                return (i, SurfaceRemap::identity());
+            }
+            if let Some((_, remap)) = surfaces_coincident(&surface, existing_surface) {
+                return (i, remap);
             }
         };
 
         let ix = self.surfaces.len();
         self.surfaces.push(surface);
-        (ix, Direction::Forward)
+        (ix, SurfaceRemap::identity())
     }
 
-    fn push_other_surface(&mut self, other: &Topo, surface: SurfaceIndex) -> (SurfaceIndex, Direction) {
+    fn push_other_surface(&mut self, other: &Topo, surface: SurfaceIndex) -> (SurfaceIndex, SurfaceRemap) {
         self.push_surface(other.surfaces[surface].clone())
     }
 
@@ -167,7 +241,8 @@ impl Topo {
     // Push an edge from another topo to this topo, along with any dependent geometry like curves and vertices
     fn push_other_edge(&mut self, other: &Topo, edge: EdgeIndex) -> TopoResult<(EdgeIndex, Direction)> {
         let Edge { curve, bounds } = &other.edges[edge];
-        let (curve, direction) = self.push_other_curve(other, *curve);
+        let (curve, remap) = self.push_other_curve(other, *curve);
+        let direction = remap.direction();
         let bounds = bounds.as_ref().map(|EdgeEndpoints { start, end }| Ok(EdgeEndpoints::new_with_direction(
             self.push_other_vertex(other, *start)?,
             self.push_other_vertex(other, *end)?,
@@ -204,7 +279,8 @@ impl Topo {
     // Push a face from another topo to this topo, along with any dependent geometry like edges, curves, surfaces, and vertices
     fn push_other_face(&mut self, other: &Topo, face: FaceIndex) -> TopoResult<(FaceIndex, Direction)> {
         let Face { surface, bounds } = &other.faces[face];
-        let (surface, surface_direction) = self.push_other_surface(other, *surface);
+        let (surface, surface_remap) = self.push_other_surface(other, *surface);
+        let surface_direction = surface_remap.direction();
         let bounds = bounds.iter().map(|Loop { elements }| {
             Ok(Loop { elements: elements.iter().map(|DirectedEdge { edge, direction: loop_edge_direction }| {
                 let (edge, curve_edge_direction) = self.push_other_edge(other, *edge)?;
@@ -231,7 +307,8 @@ impl Topo {
 
         let mut topo = Topo::empty();
 
-        let (curve_index, direction) = topo.push_curve(curve);
+        let (curve_index, remap) = topo.push_curve(curve);
+        let direction = remap.direction();
 
         let bounds = bounds.map(|(start_pt, end_pt)| {
             if vertices_coincident(start_pt, end_pt) {
@@ -262,6 +339,18 @@ impl Topo {
         Self::edge(Curve::circle_from_three_points(start, middle, end), Some((start, end)))
     }
 
+    /// Convenience function for making a chain of line segment edges through the given points,
+    /// with a vertex at each point.
+    /// If closed, a final edge joins the last point back to the first.
+    pub fn line_segments_from_points(points: Vec<Trivector>, closed: bool) -> TopoResult<Self> {
+        let polyline = match Curve::polyline(points, closed) {
+            Curve::Polyline(x) => x,
+            _ => unreachable!(),
+        };
+        let edges = polyline.segments().into_iter().map(|(curve, bounds)| Self::edge(curve, Some(bounds))).collect::<TopoResult<Vec<Topo>>>()?;
+        combine(&edges)
+    }
+
     /// Keep only specific entities from the topo
     pub fn select(self, _solids: &[SolidIndex], faces: &[FaceIndex], edges: &[EdgeIndex], vertices: &[VertexIndex]) -> Self {
         let mut result = Self::empty();
@@ -382,6 +471,7 @@ impl Topo {
 /// Inner struct for Edge.
 /// Contains the indices of the start & end points
 #[derive(Debug,Clone,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EdgeEndpoints {
     pub start: VertexIndex,
     pub end: VertexIndex,
@@ -414,6 +504,7 @@ impl EdgeEndpoints {
 /// If bounds is None, then the curve must be closed & periodic (e.g. a circle.)
 /// If bounds is Some, bounds.start must not be coincident with bounds.end.
 #[derive(Debug,Clone,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge {
     pub curve: CurveIndex,
     pub bounds: Option<EdgeEndpoints>,
@@ -421,8 +512,12 @@ pub struct Edge {
 }
 
 impl Edge {
-    pub fn remap(&self, vertex_remap: &[VertexIndex], curve_remap: &[(CurveIndex, Direction)]) -> (Edge, Direction) {
-        let (curve, direction) = curve_remap[self.curve];
+    /// Moves the edge onto new vertex and curve indices.
+    /// Its endpoints are vertices, so they are swapped if the new curve runs the other way,
+    /// and the returned direction says whether they were.
+    pub fn remap(&self, vertex_remap: &[VertexIndex], curve_remap: &[(CurveIndex, CurveRemap)]) -> (Edge, Direction) {
+        let (curve, remap) = curve_remap[self.curve];
+        let direction = remap.direction();
         (Edge {
             curve,
             bounds: self.bounds.as_ref().map(|endpoints| {
@@ -439,6 +534,7 @@ impl Edge {
 /// Inner struct for Loop.
 /// Includes the edge index, and the direction it is being used in.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectedEdge {
     pub edge: EdgeIndex,
     pub direction: Direction,
@@ -449,6 +545,7 @@ pub struct DirectedEdge {
 /// The edges are listed in-order and with a consistent winding direction
 /// such that the face lies to the right of the loop.
 #[derive(Debug, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Loop {
     pub elements: Vec<DirectedEdge>,
 }
@@ -486,6 +583,7 @@ impl PartialEq for Loop {
 /// "Ridges" contains a list of edges that trace any 1D discontinuities in the surface normals.
 /// "Peaks" contains a list of points that mark any 0D discontinuities in the surface normals.
 #[derive(Debug, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Face {
     pub surface: SurfaceIndex,
     pub bounds: Vec<Loop>,
@@ -514,6 +612,7 @@ impl PartialEq for Face {
 /// Inner struct for Shell.
 /// Includes the face index, and the direction it is being used in.
 #[derive(Debug,Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectedFace {
     pub face: FaceIndex,
     pub direction: Direction,
@@ -524,6 +623,7 @@ pub struct DirectedFace {
 /// The faces must be oriented with a consistent winding direction
 /// such that the solid always lies on the positive side of the surface.
 #[derive(Debug,Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shell {
     pub elements: Vec<DirectedFace>,
 }
@@ -532,6 +632,7 @@ pub struct Shell {
 /// A non-hollow solid will contain just a single shell.
 /// A solid with one or more internal voids will have two or more shells.
 #[derive(Debug,Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Solid {
     pub bounds: Vec<Shell>,
 }
@@ -560,3 +661,9 @@ fn curve_bounds_for_edge(topo: &Topo, edge: EdgeIndex) -> (Float, Float) {
 // High-level operations
 mod op;
 pub use op::*;
+
+// Serialization (behind the "serde" feature)
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::*;
@@ -1,9 +1,12 @@
 use crate::pga::*;
 use crate::global::*;
+use crate::predicates::*;
 //use crate::curve::*;
 use crate::topo::*;
 
-/// Returns a new Topo containing everything in the old one, reflected across a mirror plane
+/// Returns a new Topo containing everything in the old one, reflected across a mirror plane.
+/// Vertices, curves and surfaces are all reflected;
+/// note that this reverses the handedness of each surface's (U, V) parameterization.
 pub fn reflect(mut topo: Topo, plane: Vector) -> Topo {
     // Since we just reflect everything,
     // we won't need to re-index
@@ -16,10 +19,29 @@ pub fn reflect(mut topo: Topo, plane: Vector) -> Topo {
         *curve = curve.reflect(plane);
     }
 
-    // TODO implement .reflect() for surfaces
-    //for surface in topo.surfaces.iter_mut() {
-    //    *surface = surface.reflect(plane);
-    //}
+    for surface in topo.surfaces.iter_mut() {
+        *surface = surface.reflect(plane);
+    }
+
+    topo
+}
+
+/// Returns a new Topo containing everything in the old one, moved by the given rigid motion
+pub fn transform(mut topo: Topo, motor: Motor) -> Topo {
+    // Rigid motions preserve connectivity and orientation,
+    // so nothing needs to be re-indexed
+
+    for vertex in topo.vertices.iter_mut() {
+        *vertex = vertex.transform(motor);
+    }
+
+    for curve in topo.curves.iter_mut() {
+        *curve = curve.transform(motor);
+    }
+
+    for surface in topo.surfaces.iter_mut() {
+        *surface = surface.transform(motor);
+    }
 
     topo
 }
@@ -32,7 +54,7 @@ pub fn combine(topos: &[Topo]) -> TopoResult<Topo> {
     let mut result_topo = Topo::empty();
 
     let vertex_index_map = topos.iter().map(|topo| topo.vertices.iter().map(|vertex| result_topo.push_vertex(*vertex)).collect()).collect::<TopoResult<Vec<Vec<VertexIndex>>>>()?;
-    let curve_index_map = topos.iter().map(|topo| topo.curves.iter().map(|curve| result_topo.push_curve(curve.clone())).collect()).collect::<Vec<Vec<(CurveIndex, Direction)>>>();
+    let curve_index_map = topos.iter().map(|topo| topo.curves.iter().map(|curve| result_topo.push_curve(curve.clone())).collect()).collect::<Vec<Vec<(CurveIndex, CurveRemap)>>>();
 
     // TODO push surfaces and generate remap
     //let surface_index_map = topos.iter().map(|topo| topo.surfaces.iter().map(|surface| result_topo.push_surface(surface.clone())).collect()).collect::<Vec<Vec<SurfaceIndex>>>();
@@ -69,23 +91,26 @@ pub fn planar_face(mut topo: Topo) -> TopoResult<Topo> {
         loop_vertices.sort();
         loop_vertices.dedup();
 
+        // Three points define a plane if they are not collinear,
+        // and they define it well enough to use if they are not nearly collinear either
+        let defines_plane = |pt0, pt1, pt2| !collinear(pt0, pt1, pt2) && plane_from_three_points(pt0, pt1, pt2).is_finite();
+
         // First, try to define a plane from a set of 3 vertices
-        let mut plane: Option<Vector> = None;
+        let mut plane_points: Option<[Trivector; 3]> = None;
         if loop_vertices.len() >= 3 {
             for i in 0..loop_vertices.len() - 3 {
                 let pt0 = topo.vertices[loop_vertices[i + 0]];
                 let pt1 = topo.vertices[loop_vertices[i + 1]];
                 let pt2 = topo.vertices[loop_vertices[i + 2]];
-                let test_plane = plane_from_three_points(pt0, pt1, pt2);
-                if test_plane.is_finite() {
-                    plane = Some(test_plane);
+                if defines_plane(pt0, pt1, pt2) {
+                    plane_points = Some([pt0, pt1, pt2]);
                     break;
                 }
             }
         }
 
         // If that didn't work, then the plane is defined by a curve.
-        if plane.is_none() {
+        if plane_points.is_none() {
             for DirectedEdge {edge, direction: _} in l.elements.iter() {
                 let curve = &topo.curves[topo.edges[*edge].curve];
                 let (t_start, t_end) = curve_bounds_for_edge(&topo, *edge);
@@ -93,19 +118,17 @@ pub fn planar_face(mut topo: Topo) -> TopoResult<Topo> {
                 // If a curve has 2 or fewer hull points, then it is a line and doesn't define a plane
                 // If a curve has 3 or more hull points, then they must all be planar for the curve to be planar.
                 let hull_pts = curve.hull(t_start, t_end);
-                for i in 0..hull_pts.len() - 3 {
-                    let pt0 = hull_pts[i + 0];
-                    let pt1 = hull_pts[i + 1];
-                    let pt2 = hull_pts[i + 2];
-                    let test_plane = plane_from_three_points(pt0, pt1, pt2);
-                    if test_plane.is_finite() {
-                        plane = Some(test_plane);
+                for window in hull_pts.windows(3) {
+                    let (pt0, pt1, pt2) = (window[0], window[1], window[2]);
+                    if defines_plane(pt0, pt1, pt2) {
+                        plane_points = Some([pt0, pt1, pt2]);
                         break;
                     }
                 }
             }
         }
-        let plane = plane.expect("Loop is degenerate (0D or 1D)");
+        let [pt0, pt1, pt2] = plane_points.expect("Loop is degenerate (0D or 1D)");
+        let plane = plane_from_three_points(pt0, pt1, pt2);
 
         // Now, ensure that all edges lie within the plane.
         for DirectedEdge {edge, direction: _} in l.elements.iter() {
@@ -114,7 +137,12 @@ pub fn planar_face(mut topo: Topo) -> TopoResult<Topo> {
             // Look at a curve's bounding hull points to determine if it lies in the plane
             let hull_pts = curve.hull(t_start, t_end);
             for &pt in hull_pts.iter() {
-                let distance = (plane & pt).norm();
+                if coplanar(pt0, pt1, pt2, pt) {
+                    continue;
+                }
+                // Hull points are computed, so they may be off the plane by rounding.
+                // Ideal points have no weight to divide out, so for them this measures how far their direction leaves the plane
+                let distance = if pt.is_finite() { (plane.hat() & pt.hat()).norm() } else { (plane.hat() & pt).norm() };
                 if distance > EPSILON_COINCIDENT_DISTANCE {
                     return None; // This loop contains a curve that lies outside the test plane
                 }
@@ -129,7 +157,7 @@ pub fn planar_face(mut topo: Topo) -> TopoResult<Topo> {
 
     // Remove any loops that are not planar, and construct Plane surfaces for those that are.
     let faces: Vec<FaceIndex> = loops_and_planes.into_iter().map(|(l, pl)| {
-        let (surface, _direction) = topo.push_surface(Surface::plane(pl));
+        let (surface, _remap) = topo.push_surface(Surface::plane(pl));
         topo.push_face(Face {
             surface,
             bounds: vec![l.clone()],
@@ -138,3 +166,131 @@ pub fn planar_face(mut topo: Topo) -> TopoResult<Topo> {
 
     Ok(topo.select(&[], &faces, &[], &[]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec3::xyz;
+
+    // A unit square face in the XY plane, with one corner at (1, 2, 3)
+    fn square() -> Topo {
+        let points = vec![
+            point_from_xyz(1., 2., 3.),
+            point_from_xyz(2., 2., 3.),
+            point_from_xyz(2., 3., 3.),
+            point_from_xyz(1., 3., 3.),
+        ];
+        planar_face(Topo::line_segments_from_points(points, true).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn reflect_mirrors_surfaces() {
+        let topo = square();
+        assert_eq!(topo.surfaces().len(), 1);
+        // The plane x = 5
+        let mirror = plane_from_three_points(point_from_xyz(5., 0., 0.), point_from_xyz(5., 1., 0.), point_from_xyz(5., 0., 1.)).hat();
+        let reflected = reflect(topo.clone(), mirror);
+
+        // Every point of the surface lands on its mirror image
+        for &(u, v) in &[(0., 0.), (1., 0.), (0.3, -2.)] {
+            let p = topo.surfaces()[0].d0(u, v).reflect(mirror).hat();
+            assert!(reflected.surfaces()[0].d0(u, v).hat().approx_eq(&p));
+            assert!((xyz(p)[0] + xyz(topo.surfaces()[0].d0(u, v).hat())[0] - 10.).abs() < 1e-9);
+        }
+
+        // A reflection is its own inverse
+        assert!(reflect(reflected, mirror).approx_eq(&topo));
+    }
+
+    #[test]
+    fn transform_inverts() {
+        let topo = square();
+        let motor = motor_from_rotation((point_from_xyz(0., 0., 0.) & point_from_xyz(1., 1., 0.)).hat(), 1.2)
+            * motor_from_translation(3., -1., 2.);
+        let moved = transform(topo.clone(), motor);
+        assert!(!moved.approx_eq(&topo));
+        assert!(moved.vertices()[0].hat().approx_eq(&topo.vertices()[0].transform(motor).hat()));
+        assert!(transform(moved, motor.reverse()).approx_eq(&topo));
+    }
+
+    #[test]
+    fn planar_face_checks_bezier_hull() {
+        // A Bezier edge closed off by a line segment is planar only if its control points are
+        let loop_with = |lift: Float| {
+            let (start, end) = (point_from_xyz(0., 0., 0.), point_from_xyz(3., 0., 0.));
+            let bezier = Curve::bezier(vec![start, point_from_xyz(1., 2., lift), point_from_xyz(2., 2., 0.), end]);
+            let topo = combine(&[
+                Topo::edge(bezier, Some((start, end))).unwrap(),
+                Topo::line_segment_from_two_points(end, start).unwrap(),
+            ]).unwrap();
+            planar_face(topo).unwrap()
+        };
+        assert_eq!(loop_with(0.).faces().len(), 1);
+        assert_eq!(loop_with(0.5).faces().len(), 0);
+    }
+
+    #[test]
+    fn planar_face_skips_collinear_vertices() {
+        // The first three vertices are collinear, or nearly so, so the plane comes from later ones
+        let pentagon = |lift: Float, bend: Float| {
+            let points = vec![
+                point_from_xyz(0., 0., 0.),
+                point_from_xyz(1., bend, 0.),
+                point_from_xyz(2., 0., 0.),
+                point_from_xyz(2., 1., lift),
+                point_from_xyz(0., 1., 0.),
+            ];
+            planar_face(Topo::line_segments_from_points(points, true).unwrap()).unwrap()
+        };
+        assert_eq!(pentagon(0., 0.).faces().len(), 1);
+        assert_eq!(pentagon(1e-6, 0.).faces().len(), 0);
+        assert_eq!(pentagon(0., 1e-12).faces().len(), 1);
+    }
+
+    #[test]
+    fn combine_shares_coincident_geometry() {
+        // Two segments of the X axis, one running backwards, share a single line
+        let topo = combine(&[
+            Topo::line_segment_from_two_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.)).unwrap(),
+            Topo::line_segment_from_two_points(point_from_xyz(3., 0., 0.), point_from_xyz(2., 0., 0.)).unwrap(),
+        ]).unwrap();
+        assert_eq!(topo.curves().len(), 1);
+        assert_eq!(topo.edges().len(), 2);
+
+        // Mirrored halves of a circle share it, even though reflection reverses the circle's axis
+        let half = Topo::circular_arc_from_three_points(point_from_xyz(2., 0., 0.), point_from_xyz(0., 2., 0.), point_from_xyz(-2., 0., 0.)).unwrap();
+        let mirror = plane_from_three_points(point_from_xyz(0., 0., 0.), point_from_xyz(1., 0., 0.), point_from_xyz(0., 0., 1.)).hat();
+        let topo = combine(&[half.clone(), reflect(half, mirror)]).unwrap();
+        assert_eq!(topo.curves().len(), 1);
+        assert_eq!(topo.edges().len(), 2);
+        assert_eq!(topo.vertices().len(), 2);
+
+        // A copy of the circle moved well away from it is kept apart
+        let far = transform(topo.clone(), motor_from_translation(10., 0., 0.));
+        let topo = combine(&[topo, far]).unwrap();
+        assert_eq!(topo.curves().len(), 2);
+        assert_eq!(topo.edges().len(), 4);
+    }
+
+    #[test]
+    fn topo_bounding_boxes() {
+        let topo = square();
+        assert_eq!(topo.bounding_box(), BoundingBox {min: [1., 2., 3.], max: [2., 3., 3.]});
+        assert_eq!(topo.face_bounding_box(0), topo.bounding_box());
+        let obb = topo.oriented_bounding_box().unwrap();
+        assert!(topo.vertices().iter().all(|&p| obb.contains(p, 1e-9)));
+        assert!(obb.volume().abs() < 1e-9);
+
+        // A half circle of radius 2 bulges past its endpoints
+        let arc = Topo::circular_arc_from_three_points(point_from_xyz(2., 0., 0.), point_from_xyz(0., 2., 0.), point_from_xyz(-2., 0., 0.)).unwrap();
+        let b = arc.bounding_box();
+        assert!((0..3).all(|i| (b.min[i] - [-2., 0., 0.][i]).abs() < 1e-9 && (b.max[i] - [2., 2., 0.][i]).abs() < 1e-9));
+        assert_eq!(arc.edge_bounding_box(0), b);
+        let obb = arc.oriented_bounding_box().unwrap();
+        let curve = arc.edge_curve(0);
+        assert!((0..=20).all(|i| obb.contains(curve.d0(curve.t_min().unwrap() + (curve.t_max().unwrap() - curve.t_min().unwrap()) * i as Float / 20.), 1e-9)));
+
+        assert!(Topo::empty().bounding_box().is_empty());
+        assert!(Topo::empty().oriented_bounding_box().is_none());
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::de::Error;
+use crate::topo::*;
+
+/// The version of the serialized layout of a Topo.
+/// This is written out alongside every Topo and checked when reading one back in,
+/// so it must be incremented whenever the serialized form of a Topo
+/// (or of anything it contains, such as curves and surfaces) changes.
+pub const TOPO_FORMAT_VERSION: u32 = 1;
+
+// The two structs below define the serialized layout of a Topo.
+// They must have the same fields in the same order,
+// since binary formats are not self-describing.
+
+#[derive(Serialize)]
+struct TopoLayoutRef<'a> {
+    version: u32,
+    vertices: &'a [Trivector],
+    curves: &'a [Curve],
+    surfaces: &'a [Surface],
+    edges: &'a [Edge],
+    faces: &'a [Face],
+    solids: &'a [Solid],
+}
+
+#[derive(Deserialize)]
+struct TopoLayout {
+    version: u32,
+    vertices: Vec<Trivector>,
+    curves: Vec<Curve>,
+    surfaces: Vec<Surface>,
+    edges: Vec<Edge>,
+    faces: Vec<Face>,
+    solids: Vec<Solid>,
+}
+
+impl Serialize for Topo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TopoLayoutRef {
+            version: TOPO_FORMAT_VERSION,
+            vertices: &self.vertices,
+            curves: &self.curves,
+            surfaces: &self.surfaces,
+            edges: &self.edges,
+            faces: &self.faces,
+            solids: &self.solids,
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Topo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let TopoLayout {version, vertices, curves, surfaces, edges, faces, solids} = TopoLayout::deserialize(deserializer)?;
+        if version != TOPO_FORMAT_VERSION {
+            return Err(D::Error::custom(format!(
+                "unsupported Topo format version {} (expected {})", version, TOPO_FORMAT_VERSION
+            )));
+        }
+        Ok(Topo {vertices, curves, surfaces, edges, faces, solids})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let square = vec![
+            point_from_xyz(0., 0., 0.),
+            point_from_xyz(1., 0., 0.),
+            point_from_xyz(1., 1., 0.),
+            point_from_xyz(0., 1., 0.),
+        ];
+        let face = crate::topo::op::planar_face(Topo::line_segments_from_points(square, true).unwrap()).unwrap();
+        let arc = Topo::circular_arc_from_three_points(point_from_xyz(2., 0., 0.), point_from_xyz(3., 1., 0.), point_from_xyz(4., 0., 0.)).unwrap();
+        let topo = crate::topo::op::combine(&[face, arc]).unwrap();
+
+        let json = serde_json::to_string(&topo).unwrap();
+        let back: Topo = serde_json::from_str(&json).unwrap();
+        assert!(back.approx_eq_tol(&topo, 0.));
+    }
+
+    #[test]
+    fn pinned_format() {
+        // If this test fails, the serialized layout has changed: bump TOPO_FORMAT_VERSION and update the expected text
+        let topo = Topo::line_segment_from_two_points(point_from_xyz(1., 2., 3.), point_from_xyz(4., 5., 6.)).unwrap();
+        let json = serde_json::to_string(&topo).unwrap();
+        assert_eq!(json, concat!(
+            r#"{"version":1,"#,
+            r#""vertices":[{"a11":1.0,"a12":2.0,"a13":3.0,"a14":1.0},{"a11":4.0,"a12":5.0,"a13":6.0,"a14":1.0}],"#,
+            r#""curves":[{"Line":{"p0":{"a11":1.0,"a12":2.0,"a13":3.0,"a14":1.0},"#,
+            r#""d":{"a5":-0.5773502691896257,"a6":-0.5773502691896257,"a7":-0.5773502691896257,"a8":0.0,"a9":0.0,"a10":0.0}}}],"#,
+            r#""surfaces":[],"edges":[{"curve":0,"bounds":{"start":0,"end":1}}],"faces":[],"solids":[]}"#,
+        ));
+        let back: Topo = serde_json::from_str(&json).unwrap();
+        assert!(back.approx_eq_tol(&topo, 0.));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let json = serde_json::to_string(&Topo::vertex(point_from_xyz(1., 2., 3.))).unwrap();
+        let other = json.replace(&format!("\"version\":{}", TOPO_FORMAT_VERSION), &format!("\"version\":{}", TOPO_FORMAT_VERSION + 1));
+        assert_ne!(other, json);
+        assert!(serde_json::from_str::<Topo>(&other).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Vector helpers for differential geometry on the euclidean coordinates of points and ideal points.
+//! PGA is the right tool for incidence and motion,
+//! but measurements like lengths, angles and frames are simpler on plain 3-vectors.
+//! They are generic over the scalar type, so that curve evaluation built on them is too.
+
+use crate::pga::*;
+use crate::global::*;
+use crate::scalar::Scalar;
+
+// Converts a homogeneous point to a euclidean point with unit weight
+pub(crate) fn dehomogenize<T: Scalar>(p: Trivector<T>) -> Trivector<T> {
+    let (x, y, z, w) = p.as_tuple();
+    Trivector::new(x / w, y / w, z / w, T::one())
+}
+
+pub(crate) fn xyz<T: Scalar>(p: Trivector<T>) -> [T; 3] {
+    let (x, y, z, _) = p.as_tuple();
+    [x, y, z]
+}
+
+pub(crate) fn dot<T: Scalar>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn add<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn sub<T: Scalar>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn scale<T: Scalar>(a: [T; 3], k: T) -> [T; 3] {
+    [a[0] * k, a[1] * k, a[2] * k]
+}
+
+pub(crate) fn length<T: Scalar>(a: [T; 3]) -> T {
+    dot(a, a).sqrt()
+}
+
+pub(crate) fn unit<T: Scalar>(a: [T; 3]) -> Option<[T; 3]> {
+    let l = length(a);
+    if l > T::from_f64(FLOAT_DIVISION_EPSILON) {
+        Some(scale(a, T::one() / l))
+    } else {
+        None
+    }
+}
+
+// Returns the point on the given euclidean line closest to p
+pub(crate) fn foot_on_line<T: Scalar>(line: Bivector<T>, p: [T; 3]) -> [T; 3] {
+    let direction = xyz(line.direction());
+    let length_sq = dot(direction, direction);
+    let axis_point = scale(cross(direction, xyz(line.moment())), T::one() / length_sq);
+    add(axis_point, scale(direction, dot(sub(p, axis_point), direction) / length_sq))
+}
+
+// Returns some unit vector perpendicular to the given unit vector
+pub(crate) fn any_perpendicular(a: [Float; 3]) -> [Float; 3] {
+    // Cross with whichever axis is least parallel to a
+    let axis = if a[0].abs() < a[1].abs() && a[0].abs() < a[2].abs() {
+        [1., 0., 0.]
+    } else if a[1].abs() < a[2].abs() {
+        [0., 1., 0.]
+    } else {
+        [0., 0., 1.]
+    };
+    unit(cross(a, axis)).unwrap()
+}
@@ -4,3 +4,30 @@ use crate::global::*;
 pub fn vertices_coincident(p0: Trivector, p1: Trivector) -> bool {
     (p0 & p1).norm() < EPSILON_COINCIDENT_DISTANCE
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::construct::*;
+
+    #[test]
+    fn coincidence_ignores_weight() {
+        let p = point_from_xyz(1., 2., 3.);
+        assert!(vertices_coincident(p, p));
+        assert!(vertices_coincident(p, -1. * p));
+        assert!(vertices_coincident(-1. * p, p));
+        assert!(vertices_coincident(p, 2. * p));
+        assert!(vertices_coincident(p, point_from_xyz(1., 2., 3. + 0.1 * EPSILON_COINCIDENT_DISTANCE)));
+        assert!(!vertices_coincident(p, point_from_xyz(1., 2., 3. + 10. * EPSILON_COINCIDENT_DISTANCE)));
+        assert!(!vertices_coincident(p, -1. * point_from_xyz(1., 2., 4.)));
+    }
+
+    #[test]
+    fn ideal_points() {
+        // Ideal points can be compared without panicking
+        let d = inf_point_from_xyz(1., 0., 0.);
+        assert!(vertices_coincident(d, d));
+        assert!(vertices_coincident(d, -1. * d));
+        assert!(!vertices_coincident(d, point_from_xyz(1., 0., 0.)));
+    }
+}